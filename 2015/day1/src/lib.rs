@@ -0,0 +1,68 @@
+use parsing::ParseError;
+use solution::Solution;
+
+// Up a floor for each '(' and down one for each ')'
+pub fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+    parsing::single_line(input)?.map_chars(|ch| match ch {
+        '(' => Some(1),
+        ')' => Some(-1),
+        _ => None
+    })
+}
+
+pub fn part1(steps: &[i64]) -> i64 {
+    steps.iter().sum()
+}
+
+pub fn part2(steps: &[i64]) -> usize {
+    let mut floor = 0;
+    for (i, step) in steps.iter().enumerate() {
+        floor += step;
+        if floor == -1 { return i + 1; }
+    }
+    panic!()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse("(())").unwrap()), 0);
+        assert_eq!(part1(&parse("()()").unwrap()), 0);
+        assert_eq!(part1(&parse("(((").unwrap()), 3);
+        assert_eq!(part1(&parse("(()(()(").unwrap()), 3);
+        assert_eq!(part1(&parse("))(((((").unwrap()), 3);
+        assert_eq!(part1(&parse("())").unwrap()), -1);
+        assert_eq!(part1(&parse("))(").unwrap()), -1);
+        assert_eq!(part1(&parse(")))").unwrap()), -3);
+        assert_eq!(part1(&parse(")())())").unwrap()), -3);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(")").unwrap()), 1);
+        assert_eq!(part2(&parse("()())").unwrap()), 5);
+    }
+}
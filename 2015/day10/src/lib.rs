@@ -0,0 +1,72 @@
+use solution::Solution;
+
+fn look_and_say(input: &str) -> String {
+    let mut ret = String::new();
+    let mut cnt = 0;
+    let mut last_ch = '_';
+    for ch in input.chars() {
+        if ch == last_ch {
+            cnt += 1;
+        } else {
+            if cnt != 0 {
+                ret.push_str(&format!("{}", cnt));
+                ret.push(last_ch);
+            }
+            last_ch = ch;
+            cnt = 1;
+        }
+    }
+    ret.push_str(&format!("{}", cnt));
+    ret.push(last_ch);
+    ret
+}
+
+fn iterate_look_and_say(input: &str, rounds: usize) -> usize {
+    let mut seq = input.trim_end().to_string();
+    for _ in 0..rounds {
+        seq = look_and_say(&seq);
+    }
+    seq.len()
+}
+
+pub fn part1(input: &str) -> usize {
+    iterate_look_and_say(input, 40)
+}
+
+pub fn part2(input: &str) -> usize {
+    iterate_look_and_say(input, 50)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        Ok(puzzle_input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(&look_and_say("1"), "11");
+        assert_eq!(&look_and_say("11"), "21");
+        assert_eq!(&look_and_say("21"), "1211");
+        assert_eq!(&look_and_say("1211"), "111221");
+        assert_eq!(&look_and_say("111221"), "312211");
+    }
+}
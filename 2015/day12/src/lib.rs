@@ -0,0 +1,81 @@
+use parsing::{Line, ParseError};
+use serde_json::{json, Value};
+use solution::Solution;
+
+pub fn parse(input: &str) -> Result<Value, ParseError> {
+    serde_json::from_str(input).map_err(|e| {
+        let text = input.lines().nth(e.line().max(1) - 1).unwrap_or("");
+        Line { number: e.line(), text }.error(e.column().max(1), e.to_string())
+    })
+}
+
+fn sum_json(val: &Value, ignore_red: bool) -> i64 {
+    match val {
+        Value::Number(x) => x.as_i64().unwrap(),
+        Value::Array(vec) => vec.iter().map(|x| sum_json(x, ignore_red)).sum(),
+        Value::Object(map) => {
+            if ignore_red && map.values().any(|x| *x == json!("red")) { 0 }
+            else { map.values().map(|x| sum_json(x, ignore_red)).sum() }
+        },
+        _ => 0
+    }
+}
+
+pub fn part1(json: &Value) -> i64 {
+    sum_json(json, false)
+}
+
+pub fn part2(json: &Value) -> i64 {
+    sum_json(json, true)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Value;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> i64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse("[1,2,3]").unwrap()), 6);
+        assert_eq!(part1(&parse(r#"{"a":2,"b":4}"#).unwrap()), 6);
+        assert_eq!(part1(&parse("[[[3]]]").unwrap()), 3);
+        assert_eq!(part1(&parse(r#"{"a":{"b":4},"c":-1}"#).unwrap()), 3);
+        assert_eq!(part1(&parse(r#"{"a":[-1,1]}"#).unwrap()), 0);
+        assert_eq!(part1(&parse(r#"[-1,{"a":1}]"#).unwrap()), 0);
+        assert_eq!(part1(&parse("[]").unwrap()), 0);
+        assert_eq!(part1(&parse("{}").unwrap()), 0);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse("[1,2,3]").unwrap()), 6);
+        assert_eq!(part2(&parse(r#"[1,{"c":"red","b":2},3]"#).unwrap()), 4);
+        assert_eq!(part2(&parse(r#"{"d":"red","e":[1,2,3,4],"f":5}"#).unwrap()), 0);
+        assert_eq!(part2(&parse(r#"[1,"red",5]"#).unwrap()), 6);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("[1,\n{\"a\" 2}]").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 6, "{\"a\" 2}]"));
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use parsing::ParseError;
+use permutohedron::LexicalPermutation;
+use regex::Regex;
+use solution::Solution;
+
+pub fn parse(input: &str) -> Result<BTreeMap<[String; 2], i64>, ParseError> {
+    let re = Regex::new("^([A-Za-z]+) would (gain|lose) (\\d+) happiness units by sitting next to ([A-Za-z]+).$").unwrap();
+    let mut pairs = BTreeMap::new();
+    for line in parsing::lines(input) {
+        let cap = re.captures(line.text)
+            .ok_or_else(|| line.error(1, "expected e.g. \"Alice would gain 54 happiness units by sitting next to Bob.\""))?;
+        let a = cap[1].to_string();
+        let mut amount: i64 = line.field(&cap[3]).parse()?;
+        if &cap[2] == "lose" { amount = -amount; }
+        let b = cap[4].to_string();
+        pairs.insert([a, b], amount);
+    }
+    Ok(pairs)
+}
+
+fn sum_happiness(happiness_pairs: &BTreeMap<[String; 2], i64>, people: &Vec<String>) -> i64 {
+    let mut sum = 0;
+    let n = people.len();
+    for i in 0..n {
+        sum += happiness_pairs[&[people[i].to_string(), people[(i + 1) % n].to_string()]];
+        sum += happiness_pairs[&[people[i].to_string(), people[(i + n - 1) % n].to_string()]];
+    }
+    sum
+}
+
+fn best_happiness_sum(happiness_pairs: &BTreeMap<[String; 2], i64>) -> i64 {
+    let people: BTreeSet<_> = happiness_pairs.keys().flat_map(|[a,b]| vec![a.clone(), b.clone()]).collect();
+    let mut people: Vec<_> = people.into_iter().collect();
+    let mut best_sum = i64::min_value();
+    while {
+        let sum = sum_happiness(happiness_pairs, &people);
+        if sum > best_sum { best_sum = sum; }
+        people.next_permutation()
+    } {}
+    best_sum
+}
+
+pub fn part1(happiness_pairs: &BTreeMap<[String; 2], i64>) -> i64 {
+    best_happiness_sum(happiness_pairs)
+}
+
+pub fn part2(happiness_pairs: &BTreeMap<[String; 2], i64>) -> i64 {
+    let mut happiness_pairs = happiness_pairs.clone();
+    let people: BTreeSet<_> = happiness_pairs.keys().flat_map(|[a,b]| vec![a.clone(), b.clone()]).collect();
+    let myself = "myself";
+    for person in people {
+        happiness_pairs.insert([myself.to_string(), person.to_string()], 0);
+        happiness_pairs.insert([person.to_string(), myself.to_string()], 0);
+    }
+
+    best_happiness_sum(&happiness_pairs)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = BTreeMap<[String; 2], i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> i64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "\
+Alice would gain 54 happiness units by sitting next to Bob.
+Alice would lose 79 happiness units by sitting next to Carol.
+Alice would lose 2 happiness units by sitting next to David.
+Bob would gain 83 happiness units by sitting next to Alice.
+Bob would lose 7 happiness units by sitting next to Carol.
+Bob would lose 63 happiness units by sitting next to David.
+Carol would lose 62 happiness units by sitting next to Alice.
+Carol would gain 60 happiness units by sitting next to Bob.
+Carol would gain 55 happiness units by sitting next to David.
+David would gain 46 happiness units by sitting next to Alice.
+David would lose 7 happiness units by sitting next to Bob.
+David would gain 41 happiness units by sitting next to Carol.";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 330);
+    }
+}
@@ -0,0 +1,133 @@
+use parsing::ParseError;
+use regex::Regex;
+use solution::Solution;
+
+pub struct Ingredient {
+    capacity: i64,
+    durability: i64,
+    flavor: i64,
+    texture: i64,
+    calories: i64
+}
+
+pub fn parse_ingredients(input: &str) -> Result<Vec<Ingredient>, ParseError> {
+    let re = Regex::new("^[A-Za-z]+: capacity (-?\\d+), durability (-?\\d+), flavor (-?\\d+), texture (-?\\d+), calories (-?\\d+)$").unwrap();
+    parsing::parse_lines(input, |line| {
+        let cap = re.captures(line.text)
+            .ok_or_else(|| line.error(1, "expected e.g. \"Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3\""))?;
+        let capacity = line.field(&cap[1]).parse()?;
+        let durability = line.field(&cap[2]).parse()?;
+        let flavor = line.field(&cap[3]).parse()?;
+        let texture = line.field(&cap[4]).parse()?;
+        let calories = line.field(&cap[5]).parse()?;
+        Ok(Ingredient {
+            capacity: capacity,
+            durability: durability,
+            flavor: flavor,
+            texture: texture,
+            calories: calories
+        })
+    })
+}
+
+// TODO - there's probably a better way to use a combinations library to iterate through all these possibilities
+fn iterate_all_possible_amounts<F>(ingredients: &[Ingredient], amounts: &mut [u64], depth: usize, amount_left: u64, f: &mut F)
+    where F: FnMut(&[u64])
+{
+    if depth == ingredients.len() - 1 {
+        amounts[depth] = amount_left;
+        f(amounts);
+    } else {
+        for amount in 0..=amount_left {
+            amounts[depth] = amount;
+            iterate_all_possible_amounts(ingredients, amounts, depth + 1, amount_left - amount, f);
+        }
+    }
+}
+
+fn score_recipe(ingredients: &[Ingredient], amounts: &[u64]) -> u64 {
+    let mut capacity = 0;
+    let mut durability = 0;
+    let mut flavor = 0;
+    let mut texture = 0;
+    for (ingredient, amount) in ingredients.iter().zip(amounts.iter()) {
+        capacity += ingredient.capacity * *amount as i64;
+        durability += ingredient.durability * *amount as i64;
+        flavor += ingredient.flavor * *amount as i64;
+        texture += ingredient.texture * *amount as i64;
+    }
+    if capacity > 0 && durability > 0 && flavor > 0 && texture > 0 {
+        (capacity * durability * flavor * texture) as u64
+    } else {
+        0
+    }
+}
+
+fn count_calories(ingredients: &[Ingredient], amounts: &[u64]) -> i64 {
+    let mut calories = 0;
+    for (ingredient, amount) in ingredients.iter().zip(amounts.iter()) {
+        calories += ingredient.calories * *amount as i64;
+    }
+    calories
+}
+
+pub fn part1(ingredients: &[Ingredient]) -> u64 {
+    let mut amounts = vec![0; ingredients.len()];
+    let mut best_score = 0;
+    iterate_all_possible_amounts(ingredients, &mut amounts, 0, 100, &mut |amounts| {
+        let score = score_recipe(ingredients, amounts);
+        if score > best_score { best_score = score; }
+    });
+    best_score
+}
+
+pub fn part2(ingredients: &[Ingredient]) -> u64 {
+    let mut amounts = vec![0; ingredients.len()];
+    let mut best_score = 0;
+    iterate_all_possible_amounts(ingredients, &mut amounts, 0, 100, &mut |amounts| {
+        if count_calories(ingredients, amounts) == 500 {
+            let score = score_recipe(ingredients, amounts);
+            if score > best_score { best_score = score; }
+        }
+    });
+    best_score
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Ingredient>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse_ingredients(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "\
+Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8
+Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse_ingredients(EX).unwrap()), 62842880);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse_ingredients(EX).unwrap()), 57600000);
+    }
+}
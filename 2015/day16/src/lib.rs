@@ -0,0 +1,68 @@
+use std::collections::BTreeMap;
+
+use parsing::ParseError;
+use regex::Regex;
+use solution::Solution;
+
+pub fn parse(input: &str) -> Result<Vec<BTreeMap<String, u32>>, ParseError> {
+    let re = Regex::new("([a-z]+): (\\d+)").unwrap(); 
+    parsing::parse_lines(input, |line| {
+        re.captures_iter(line.text).map(|cap| {
+            Ok((cap[1].to_string(), line.field(&cap[2]).parse()?))
+        }).collect()
+    })
+}
+
+pub fn part1(aunts: &[BTreeMap<String, u32>]) -> usize {
+    let reading = parse("children: 3, cats: 7, samoyeds: 2, pomeranians: 3, akitas: 0, vizslas: 0, goldfish: 5, trees: 3, cars: 2, perfumes: 1").unwrap().into_iter().next().unwrap();
+
+    for (i, aunt) in aunts.iter().enumerate() {
+        let mut matches = true;
+        for (k, v) in aunt.iter() {
+            if *v != reading[k] {
+                matches = false;
+            }
+        }
+        if matches { return i + 1; }
+    }
+    panic!("No aunt matched");
+}
+
+pub fn part2(aunts: &[BTreeMap<String, u32>]) -> usize {
+    let reading = parse("children: 3, cats: 7, samoyeds: 2, pomeranians: 3, akitas: 0, vizslas: 0, goldfish: 5, trees: 3, cars: 2, perfumes: 1").unwrap().into_iter().next().unwrap();
+
+    for (i, aunt) in aunts.iter().enumerate() {
+        let mut matches = true;
+        for (k, v) in aunt.iter() {
+            if k == "cats" || k == "trees" {
+                if *v <= reading[k] { matches = false; }
+            } else if k == "pomeranians" || k == "goldfish" {
+                if *v >= reading[k] { matches = false; }
+            } else if *v != reading[k] {
+                matches = false;
+            }
+        }
+        if matches { return i + 1; }
+    }
+    panic!("No aunt matched");
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<BTreeMap<String, u32>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
@@ -0,0 +1,89 @@
+use std::collections::BTreeMap;
+
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse_containers(input: &str) -> Result<Vec<u32>, ParseError> {
+    parsing::parse_lines(input, |line| line.parse())
+}
+
+fn sum_combo(containers: &[u32], combo: u64) -> u32 {
+    let mut sum = 0;
+    for i in 0..containers.len() {
+        if combo & (1 << i) != 0 {
+            sum += containers[i];
+        }
+    }
+    sum
+}
+
+fn matching_combos_by_size(containers: &[u32], total: u32) -> BTreeMap<u32, u64> {
+    assert!(containers.len() < 64);
+    let mut counts = BTreeMap::new();
+    for combo in 0..(1 << containers.len()) {
+        if sum_combo(containers, combo) == total {
+            counts.entry(combo.count_ones())
+                .and_modify(|cnt| *cnt += 1)
+                .or_insert(1);
+        }
+    }
+    counts
+}
+
+fn num_matching_combos(containers: &[u32], total: u32) -> u64 {
+    matching_combos_by_size(containers, total).values().sum()
+}
+
+fn num_combos_of_min_containers(containers: &[u32], total: u32) -> u64 {
+    *matching_combos_by_size(containers, total).values().next().unwrap()
+}
+
+pub fn part1(containers: &[u32]) -> u64 {
+    num_matching_combos(containers, 150)
+}
+
+pub fn part2(containers: &[u32]) -> u64 {
+    num_combos_of_min_containers(containers, 150)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<u32>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse_containers(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "\
+20
+15
+10
+5
+5";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(num_matching_combos(&parse_containers(EX).unwrap(), 25), 4);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(num_combos_of_min_containers(&parse_containers(EX).unwrap(), 25), 3);
+    }
+}
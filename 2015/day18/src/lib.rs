@@ -0,0 +1,122 @@
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse_map(input: &str) -> Result<Vec<Vec<bool>>, ParseError> {
+    let lines = parsing::lines(input);
+    let map = parsing::grid(&lines, |ch| {
+        match ch {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None
+        }
+    })?;
+    let height = map.len();
+    if map.iter().any(|row| row.len() != height) {
+        return Err(lines[0].error(1, format!("expected {} columns to make the map square", height)));
+    }
+    Ok(map)
+}
+
+fn neighbors_on(x: usize, y: usize, map: &Vec<Vec<bool>>) -> usize {
+    let len = map.len();
+    let mut cnt = 0;
+    if x > 0 {
+        if y > 0 && map[y-1][x-1] { cnt += 1 }
+        if map[y][x-1] { cnt += 1 }
+        if y < len-1 && map[y+1][x-1] { cnt += 1 }
+    }
+    if y > 0 && map[y-1][x] { cnt += 1 }
+    if y < len-1 && map[y+1][x] { cnt += 1 }
+    if x < len-1 {
+        if y > 0 && map[y-1][x+1] { cnt += 1 }
+        if map[y][x+1] { cnt += 1 }
+        if y < len-1 && map[y+1][x+1] { cnt += 1 }
+    }
+    cnt
+}
+
+fn next_map(curr_map: Vec<Vec<bool>>) -> Vec<Vec<bool>> {
+    let len = curr_map.len();
+    let mut map = vec![vec![false; len]; len];
+    for y in 0..len {
+        for x in 0..len {
+            map[y][x] = if curr_map[y][x] {
+                let neighbors = neighbors_on(x, y, &curr_map);
+                neighbors == 2 || neighbors == 3
+            } else {
+                neighbors_on(x, y, &curr_map) == 3
+            }
+        }
+    }
+    map
+}
+
+fn set_corners_on(map: &mut Vec<Vec<bool>>) {
+    let len = map.len();
+    map[0][0] = true;
+    map[0][len-1] = true;
+    map[len-1][0] = true;
+    map[len-1][len-1] = true;
+}
+
+fn count_lights_after_steps(map: &[Vec<bool>], steps: usize, corners_on: bool) -> usize {
+    let mut map = map.to_vec();
+    if corners_on { set_corners_on(&mut map) }
+    for _ in 0..steps {
+        map = next_map(map);
+        if corners_on { set_corners_on(&mut map) }
+    }
+
+    map.iter().map(|row| row.iter().filter(|light| **light).count()).sum()
+}
+
+pub fn part1(map: &[Vec<bool>]) -> usize {
+    count_lights_after_steps(map, 100, false)
+}
+
+pub fn part2(map: &[Vec<bool>]) -> usize {
+    count_lights_after_steps(map, 100, true)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<bool>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse_map(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "\
+.#.#.#
+...##.
+#....#
+..#...
+#.#..#
+####..";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(count_lights_after_steps(&parse_map(EX).unwrap(), 4, false), 4);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(count_lights_after_steps(&parse_map(EX).unwrap(), 5, true), 17);
+    }
+}
@@ -0,0 +1,240 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet, VecDeque};
+use std::convert::TryFrom;
+
+use parsing::ParseError;
+use regex::Regex;
+use solution::Solution;
+
+pub fn parse(input: &str) -> Result<(BTreeMap<String, Vec<String>>, String), ParseError> {
+    let re = Regex::new("^([A-Za-z]+) => ([A-Za-z]+)$").unwrap();
+    let mut replacements: BTreeMap<_, Vec<_>> = BTreeMap::new();
+    let mut molecule = String::new();
+    for line in parsing::lines(input) {
+        if line.is_blank() {
+            // Skip
+        } else if let Some(cap) = re.captures(line.text) {
+            replacements.entry(cap[1].to_string())
+                .and_modify(|strs| strs.push(cap[2].to_string()))
+                .or_insert(vec![cap[2].to_string()]);
+        } else if molecule.is_empty() && line.text.chars().all(|ch| ch.is_ascii_alphabetic()) {
+            molecule = line.text.to_string();
+        } else {
+            return Err(line.error(1, "expected a replacement like \"H => HO\" or the molecule"));
+        }
+    }
+    Ok((replacements, molecule))
+}
+
+fn possible_replacements(replacements: &BTreeMap<String, Vec<String>>, molecule: &str) -> BTreeSet<String> {
+    let mut ret = BTreeSet::new();
+    for (start_str, end_strs) in replacements.iter() {
+        for (i, _) in molecule.match_indices(start_str) {
+            for end_str in end_strs.iter() {
+                ret.insert(format!("{}{}{}", &molecule[..i], end_str, &molecule[i+start_str.len()..]));
+            }
+        }
+    }
+    ret
+}
+
+pub fn part1((replacements, molecule): &(BTreeMap<String, Vec<String>>, String)) -> usize {
+    possible_replacements(replacements, molecule).len()
+}
+
+// Splits a molecule into its elements, an upper case letter and any lower case ones after it, or e
+fn elements(molecule: &str) -> Vec<&str> {
+    let mut ret = Vec::new();
+    let mut start = 0;
+    for (i, ch) in molecule.char_indices().skip(1) {
+        if ch.is_ascii_uppercase() {
+            ret.push(&molecule[start..i]);
+            start = i;
+        }
+    }
+    if start < molecule.len() {
+        ret.push(&molecule[start..]);
+    }
+    ret
+}
+
+// Counting Rn and Ar as nothing and Y as minus one, every rule in the puzzle input adds exactly one
+// to the weight of the molecule
+fn weight(elements: &[&str]) -> i64 {
+    elements.iter().map(|&element| match element {
+        "Rn" | "Ar" => 0,
+        "Y" => -1,
+        _ => 1
+    }).sum()
+}
+
+// When every rule adds one to the weight, every way of making the molecule takes the same number
+// of steps, which is the token counting answer: elements - Rn - Ar - 2 * Y - 1
+fn fixed_step_count(rules: &[(Vec<&str>, &str)], molecule: &[&str]) -> Option<usize> {
+    if rules.iter().all(|(to, from)| weight(to) == weight(&[*from]) + 1) {
+        usize::try_from(weight(molecule) - 1).ok()
+    } else {
+        None
+    }
+}
+
+// Runs the replacements backwards, always undoing the rightmost one it can find. A greedy choice
+// can get stuck with a dead end left in the molecule, so then it starts again with the replacements
+// tried in a different order. Every rule has to make the molecule longer, or undoing them could go
+// round in circles.
+fn greedy_steps(mut rules: Vec<(Vec<&str>, &str)>, molecule: &[&str]) -> Option<usize> {
+    rules.sort_by_key(|(to, _)| std::cmp::Reverse(to.len()));
+    let mut seed = 19u64;
+    for _ in 0..1000 {
+        let mut current = molecule.to_vec();
+        let mut steps = 0;
+        while current != ["e"] {
+            let found = rules.iter().find_map(|(to, from)| {
+                // e only ever starts the molecule, so it can only replace the whole thing
+                if *from == "e" && current.len() != to.len() {
+                    return None;
+                }
+                current.windows(to.len()).rposition(|window| window == to.as_slice()).map(|i| (i, to.len(), *from))
+            });
+            match found {
+                Some((i, len, from)) => {
+                    current.splice(i..i + len, std::iter::once(from));
+                    steps += 1;
+                },
+                None => break,
+            }
+        }
+        if current == ["e"] {
+            return Some(steps);
+        }
+        // Fisher-Yates with a small xorshift, so the answer doesn't depend on a random crate
+        for i in (1..rules.len()).rev() {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            rules.swap(i, (seed % (i as u64 + 1)) as usize);
+        }
+    }
+    None
+}
+
+// Plenty for the examples, the puzzle input would need far more
+const MAX_STATES: usize = 1_000_000;
+
+// A breadth first search backwards from the molecule, which finds the fewest steps for any rules,
+// including ones that swap one element for another and can go round in circles
+fn search_steps(rules: &[(Vec<&str>, &str)], molecule: &[&str]) -> Result<usize, String> {
+    let mut seen = HashSet::new();
+    seen.insert(molecule.to_vec());
+    let mut queue = VecDeque::new();
+    queue.push_back((molecule.to_vec(), 0));
+    while let Some((current, steps)) = queue.pop_front() {
+        if current == ["e"] {
+            return Ok(steps);
+        }
+        for (to, from) in rules.iter() {
+            if *from == "e" && current.len() != to.len() {
+                continue;
+            }
+            for i in 0..current.len().saturating_sub(to.len() - 1) {
+                if current[i..i + to.len()] != to[..] {
+                    continue;
+                }
+                let mut next = current.clone();
+                next.splice(i..i + to.len(), std::iter::once(*from));
+                if seen.insert(next.clone()) {
+                    if seen.len() > MAX_STATES {
+                        return Err(format!("gave up after searching {} molecules", MAX_STATES));
+                    }
+                    queue.push_back((next, steps + 1));
+                }
+            }
+        }
+    }
+    Err("the molecule can't be made from e".to_string())
+}
+
+fn steps_to_make(replacements: &BTreeMap<String, Vec<String>>, molecule: &str) -> Result<usize, String> {
+    let rules: Vec<(Vec<&str>, &str)> = replacements.iter()
+        .flat_map(|(from, tos)| tos.iter().map(move |to| (elements(to), from.as_str())))
+        .collect();
+    let molecule = elements(molecule);
+    match fixed_step_count(&rules, &molecule) {
+        // The count only says how many steps it would take, so check the molecule can be made at all
+        Some(count) => match greedy_steps(rules, &molecule) {
+            Some(steps) if steps == count => Ok(count),
+            Some(steps) => Err(format!("reduced it in {} steps but the token count is {}", steps, count)),
+            None => Err("couldn't reduce the molecule to e".to_string())
+        },
+        None => search_steps(&rules, &molecule)
+    }
+}
+
+pub fn part2((replacements, molecule): &(BTreeMap<String, Vec<String>>, String)) -> usize {
+    steps_to_make(replacements, molecule).unwrap_or_else(|reason| panic!("Can't make the molecule: {}", reason))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (BTreeMap<String, Vec<String>>, String);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let (replacements, _) = parse("\
+H => HO
+H => OH
+O => HH").unwrap();
+        assert_eq!(possible_replacements(&replacements, "HOH").len(), 4);
+        assert_eq!(possible_replacements(&replacements, "HOHOHO").len(), 7);
+    }
+
+    #[test]
+    fn test_part2() {
+        let (replacements, _) = parse("\
+e => H
+e => O
+H => HO
+H => OH
+O => HH").unwrap();
+        assert_eq!(steps_to_make(&replacements, "HOH"), Ok(3));
+        assert_eq!(steps_to_make(&replacements, "HOHOHO"), Ok(6));
+        assert!(steps_to_make(&replacements, "HOX").is_err());
+        let (cyclic, _) = parse("\
+e => HO
+H => O
+O => H").unwrap();
+        assert_eq!(steps_to_make(&cyclic, "HO"), Ok(1));
+        assert_eq!(steps_to_make(&cyclic, "OO"), Ok(2));
+        assert!(steps_to_make(&cyclic, "HOH").is_err());
+        let (tokens, _) = parse("\
+e => HF
+H => HCa
+F => CRnFYFAr").unwrap();
+        let molecule = elements("HCaCRnFYFAr");
+        let rules: Vec<(Vec<&str>, &str)> = vec![(vec!["H", "F"], "e"), (vec!["H", "Ca"], "H"), (elements("CRnFYFAr"), "F")];
+        assert_eq!(fixed_step_count(&rules, &molecule), Some(3));
+        assert_eq!(fixed_step_count(&[(vec!["H"], "e")], &molecule), None);
+        assert_eq!(steps_to_make(&tokens, "HCaCRnFYFAr"), Ok(3));
+        assert!(steps_to_make(&tokens, "HCaCRnFYAr").is_err());
+        assert_eq!(elements("CRnSiTh"), vec!["C", "Rn", "Si", "Th"]);
+    }
+}
@@ -0,0 +1,88 @@
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse(input: &str) -> Result<Vec<[u64; 3]>, ParseError> {
+    parsing::parse_lines(input, |line| {
+        match line.split("x")[..] {
+            [l, w, h] => Ok([l.parse()?, w.parse()?, h.parse()?]),
+            _ => Err(line.error(1, "expected LxWxH"))
+        }
+    })
+}
+
+fn surface_area([l,w,h]: [u64; 3]) -> u64 {
+    2*l*w + 2*w*h + 2*h*l
+}
+
+fn smallest_face_area([l,w,h]: [u64; 3]) -> u64 {
+    let mut areas = [l*w, w*h, h*l];
+    areas.sort();
+    areas[0]
+}
+
+fn volume([l,w,h]: [u64; 3]) -> u64 {
+    l*w*h
+}
+
+fn smallest_face_perimeter([l,w,h]: [u64; 3]) -> u64 {
+    let mut perims = [2*(l+w), 2*(w+h), 2*(h+l)];
+    perims.sort();
+    perims[0]
+}
+
+pub fn part1(boxes: &[[u64; 3]]) -> u64 {
+    boxes.iter().copied()
+        .map(|dims| surface_area(dims) + smallest_face_area(dims))
+        .sum()
+}
+
+pub fn part2(boxes: &[[u64; 3]]) -> u64 {
+    boxes.iter().copied()
+        .map(|dims| smallest_face_perimeter(dims) + volume(dims))
+        .sum()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<[u64; 3]>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse("2x3x4").unwrap()), 58);
+        assert_eq!(part1(&parse("1x1x10").unwrap()), 43);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse("2x3x4").unwrap()), 34);
+        assert_eq!(part2(&parse("1x1x10").unwrap()), 14);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(parse("2x3x4\r\n1x1x10\r\n").unwrap(), vec![[2, 3, 4], [1, 1, 10]]);
+        let err = parse("2x3x4\n1x1xten\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
+        assert_eq!(parse("2x3\n").unwrap_err().message, "expected LxWxH");
+    }
+}
@@ -0,0 +1,70 @@
+use std::cmp::min;
+
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse(input: &str) -> Result<usize, ParseError> {
+    parsing::single_line(input)?.parse()
+}
+
+fn first_house_with_enough_presents(houses: &Vec<usize>, min_presents: usize) -> usize {
+    for (i, p) in houses.iter().enumerate() {
+        if *p >= min_presents {
+            return i;
+        }
+    }
+    panic!()
+}
+
+// Similar algorithm to sieve of eratosthenes
+pub fn part1(min_presents: usize) -> usize {
+    let mut houses = vec![0; min_presents/10+1];
+    for elf in 1..houses.len() {
+        for i in 1..=(houses.len()-1)/elf {
+            houses[i*elf] += 10*elf;
+        }
+    }
+    first_house_with_enough_presents(&houses, min_presents)
+}
+
+pub fn part2(min_presents: usize) -> usize {
+    let mut houses = vec![0; min_presents/10+1];
+    for elf in 1..houses.len() {
+        for i in 1..=min(50, (houses.len()-1)/elf) {
+            houses[i*elf] += 11*elf;
+        }
+    }
+    first_house_with_enough_presents(&houses, min_presents)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = usize;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(*input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(*input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(parse("30").unwrap()), 2);
+        assert_eq!(part1(parse("120").unwrap()), 6);
+        assert_eq!(part1(parse("130").unwrap()), 8);
+    }
+}
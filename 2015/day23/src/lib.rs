@@ -0,0 +1,111 @@
+use parsing::ParseError;
+use regex::Regex;
+use register_machine::{Flow, Instruction, Machine, Registers};
+use solution::Solution;
+
+pub enum Op {
+    Hlf(char),
+    Tpl(char),
+    Inc(char),
+    Jmp(i64),
+    Jie(char, i64),
+    Jio(char, i64)
+}
+
+pub fn parse_program(input: &str) -> Result<Vec<Op>, ParseError> {
+    let unary_re = Regex::new("^(hlf|tpl|inc) ([ab])$").unwrap();
+    let jmp_re = Regex::new("^jmp ([+-]\\d+)$").unwrap();
+    let binary_re = Regex::new("^(jie|jio) ([ab]), ([+-]\\d+)$").unwrap();
+    parsing::parse_lines(input, |line| {
+        Ok(if let Some(cap) = unary_re.captures(line.text) {
+            let reg = cap[2].chars().next().unwrap();
+            match &cap[1] {
+                "hlf" => Op::Hlf(reg),
+                "tpl" => Op::Tpl(reg),
+                "inc" => Op::Inc(reg),
+                _ => panic!()
+            }
+        } else if let Some(cap) = jmp_re.captures(line.text) {
+            let offset = line.field(&cap[1]).parse()?;
+            Op::Jmp(offset)
+        } else if let Some(cap) = binary_re.captures(line.text) {
+            let reg = cap[2].chars().next().unwrap();
+            let offset = line.field(&cap[3]).parse()?;
+            match &cap[1] {
+                "jie" => Op::Jie(reg, offset),
+                "jio" => Op::Jio(reg, offset),
+                _ => panic!()
+            }
+        } else {
+            return Err(line.error(1, "expected an instruction like \"jio a, +2\""));
+        })
+    })
+}
+
+impl Instruction<Registers<char, u64>> for Op {
+    fn execute(&self, _pc: usize, registers: &mut Registers<char, u64>, _io: &mut ()) -> Flow {
+        match *self {
+            Op::Hlf(reg) => *registers.get_mut(reg) /= 2,
+            Op::Tpl(reg) => *registers.get_mut(reg) *= 3,
+            Op::Inc(reg) => *registers.get_mut(reg) += 1,
+            Op::Jmp(offset) => return Flow::JumpRelative(offset),
+            Op::Jie(reg, offset) => if registers.get(&reg).is_multiple_of(2) { return Flow::JumpRelative(offset) },
+            Op::Jio(reg, offset) => if registers.get(&reg) == 1 { return Flow::JumpRelative(offset) }
+        }
+        Flow::Next
+    }
+}
+
+fn execute_to_completion(program: &[Op], registers: Registers<char, u64>) -> Registers<char, u64> {
+    let mut machine = Machine::new(registers);
+    machine.run(program, &mut ());
+    machine.registers
+}
+
+pub fn part1(program: &[Op]) -> u64 {
+    let registers = execute_to_completion(program, Registers::new());
+    registers.get(&'b')
+}
+
+pub fn part2(program: &[Op]) -> u64 {
+    let registers = execute_to_completion(program, vec![('a', 1)].into_iter().collect());
+    registers.get(&'b')
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Op>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse_program(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "\
+inc a
+jio a, +2
+tpl a
+inc a";
+
+    #[test]
+    fn test_part1() {
+        let program = parse_program(EX).unwrap();
+        let registers = execute_to_completion(&program, Registers::new());
+        assert_eq!(registers.get(&'a'), 2);
+    }
+}
@@ -0,0 +1,60 @@
+use parsing::ParseError;
+use regex::Regex;
+use solution::Solution;
+
+pub fn parse(input: &str) -> Result<(u64, u64), ParseError> {
+    let re = Regex::new("^To continue, please consult the code grid in the manual.  Enter the code at row (\\d+), column (\\d+)\\.$").unwrap();
+    let line = parsing::single_line(input)?;
+    let cap = re.captures(line.text).ok_or_else(|| line.error(1, "expected the sentence giving the row and column of the code"))?;
+    Ok((line.field(&cap[2]).parse()?, line.field(&cap[1]).parse()?))
+}
+
+// This sequence is called the triangle numbers
+fn coordinate_to_num(x: u64, y: u64) -> u64 {
+    let n = x + y - 2;
+    n*(n+1)/2 + x
+}
+
+fn calc(x: u64, y: u64) -> u64 {
+    let num = coordinate_to_num(x, y);
+    let mut code = 20151125;
+    for _ in 1..num {
+        code = (code * 252533) % 33554393;
+    }
+    code
+}
+
+pub fn part1((x, y): (u64, u64)) -> u64 {
+    calc(x, y)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (u64, u64);
+    type Answer1 = u64;
+    type Answer2 = &'static str;
+
+    const PARTS: usize = 1;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(*input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(calc(4, 6), 24659492);
+        assert_eq!(calc(6, 3), 16474243);
+        assert_eq!(calc(1, 5), 77061);
+        assert_eq!(calc(5, 1), 10071777);
+    }
+}
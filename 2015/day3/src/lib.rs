@@ -0,0 +1,88 @@
+use std::collections::BTreeSet;
+
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse(input: &str) -> Result<Vec<(i64, i64)>, ParseError> {
+    parsing::single_line(input)?.map_chars(|ch| match ch {
+        '^' => Some((0, -1)),
+        'v' => Some((0, 1)),
+        '<' => Some((-1, 0)),
+        '>' => Some((1, 0)),
+        _ => None
+    })
+}
+
+fn take_step(step: (i64, i64), pos: &mut (i64, i64)) {
+    pos.0 += step.0;
+    pos.1 += step.1;
+}
+
+pub fn part1(steps: &[(i64, i64)]) -> usize {
+    let mut seen = BTreeSet::new();
+    let mut pos = (0, 0);
+    seen.insert(pos);
+    for &step in steps {
+        take_step(step, &mut pos);
+        seen.insert(pos);
+    }
+    seen.len()
+}
+
+pub fn part2(steps: &[(i64, i64)]) -> usize {
+    let mut seen = BTreeSet::new();
+    let mut santa_pos = (0, 0);
+    let mut robo_pos = (0, 0);
+    seen.insert(santa_pos);
+    let mut real_santa = true;
+    for &step in steps {
+        if real_santa {
+            take_step(step, &mut santa_pos);
+            seen.insert(santa_pos);
+        } else {
+            take_step(step, &mut robo_pos);
+            seen.insert(robo_pos);
+        }
+        real_santa = !real_santa;
+    }
+    seen.len()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<(i64, i64)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(">").unwrap()), 2);
+        assert_eq!(part1(&parse("^>v<").unwrap()), 4);
+        assert_eq!(part1(&parse("^v^v^v^v^v").unwrap()), 2);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse("^v").unwrap()), 3);
+        assert_eq!(part2(&parse("^>v<").unwrap()), 3);
+        assert_eq!(part2(&parse("^v^v^v^v^v").unwrap()), 11);
+    }
+}
@@ -0,0 +1,48 @@
+use solution::Solution;
+
+fn first_md5_suffix_with_leading_zeros(input: &str, num_zeros: usize) -> usize {
+    let (i, _) = hash_mining::search(input.trim_end().as_bytes(), 1, |digest| hash_mining::leading_zeros(digest, num_zeros))
+        .next()
+        .unwrap();
+    i as usize
+}
+
+pub fn part1(input: &str) -> usize {
+    first_md5_suffix_with_leading_zeros(input, 5)
+}
+
+pub fn part2(input: &str) -> usize {
+    first_md5_suffix_with_leading_zeros(input, 6)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        Ok(puzzle_input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // NOTE - make sure to use cargo test --release or it will take forever
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1("abcdef"), 609043);
+        assert_eq!(part1("pqrstuv"), 1048970);
+    }
+}
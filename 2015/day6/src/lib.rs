@@ -0,0 +1,110 @@
+use parsing::ParseError;
+use regex::Regex;
+use solution::Solution;
+
+type Pos = (usize, usize);
+
+#[derive(Clone, Copy)]
+enum OpType {
+    On, Off, Toggle
+}
+
+#[derive(Clone, Copy)]
+pub struct Op {
+    op_type: OpType,
+    start: Pos,
+    end: Pos
+}
+
+pub fn parse(input: &str) -> Result<Vec<Op>, ParseError> {
+    let re = Regex::new("^(turn on|turn off|toggle) (\\d+),(\\d+) through (\\d+),(\\d+)$").unwrap();
+    parsing::parse_lines(input, |line| {
+        let cap = re.captures(line.text)
+            .ok_or_else(|| line.error(1, "expected e.g. \"toggle 0,0 through 999,0\""))?;
+        let start = (line.field(&cap[2]).parse()?, line.field(&cap[3]).parse()?);
+        let end = (line.field(&cap[4]).parse()?, line.field(&cap[5]).parse()?);
+        let op_type = match &cap[1] {
+            "turn on" => OpType::On,
+            "turn off" => OpType::Off,
+            "toggle" => OpType::Toggle,
+            _ => panic!()
+        };
+        Ok(Op { op_type: op_type, start: start, end: end })
+    })
+}
+
+pub fn part1(instructions: &[Op]) -> usize {
+    let mut lights = vec![false; 1_000_000];
+
+    for &Op { op_type, start: (x1,y1), end: (x2,y2) } in instructions {
+        for y in y1..=y2 {
+            for x in x1..=x2 {
+                let light = &mut lights[y*1000 + x];
+                match op_type {
+                    OpType::On => { *light = true; },
+                    OpType::Off => { *light = false; },
+                    OpType::Toggle => { *light = !*light; }
+                }
+            }
+        }
+    }
+
+    lights.iter().filter(|light| **light).count()
+}
+
+pub fn part2(instructions: &[Op]) -> usize {
+    let mut lights = vec![0; 1_000_000];
+
+    for &Op { op_type, start: (x1,y1), end: (x2,y2) } in instructions {
+        for y in y1..=y2 {
+            for x in x1..=x2 {
+                let light = &mut lights[y*1000 + x];
+                match op_type {
+                    OpType::On => { *light += 1; },
+                    OpType::Off => { if *light > 0 { *light -= 1; } },
+                    OpType::Toggle => { *light += 2; }
+                }
+            }
+        }
+    }
+
+    lights.iter().sum()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Op>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse("turn on 0,0 through 999,999").unwrap()), 1_000_000);
+        assert_eq!(part1(&parse("toggle 0,0 through 999,0").unwrap()), 1000);
+        assert_eq!(part1(&parse("turn on 0,0 through 999,999\nturn off 499,499 through 500,500").unwrap()), 1_000_000 - 4);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse("turn on 0,0 through 0,0").unwrap()), 1);
+        assert_eq!(part2(&parse("toggle 0,0 through 999,999").unwrap()), 2_000_000);
+    }
+}
@@ -0,0 +1,173 @@
+use std::collections::{BTreeMap, VecDeque};
+
+use parsing::ParseError;
+use regex::Regex;
+use solution::Solution;
+
+#[derive(Clone, Debug)]
+pub enum Elem {
+    Set(u16, String),
+    Cpy(String, String),
+    And(String, String, String),
+    AndVal(u16, String, String),
+    Or(String, String, String),
+    Lshift(String, u16, String),
+    Rshift(String, u16, String),
+    Not(String, String)
+}
+
+pub fn parse_circuit(input: &str) -> Result<Vec<Elem>, ParseError> {
+    let set_re = Regex::new("^(\\d+|[a-z]+) -> ([a-z]+)$").unwrap();
+    let shift_re = Regex::new("^([a-z]+) (LSHIFT|RSHIFT) (\\d+) -> ([a-z]+)$").unwrap();
+    let binary_re = Regex::new("^(\\d+|[a-z]+) (AND|OR) ([a-z]+) -> ([a-z]+)$").unwrap();
+    let not_re = Regex::new("^NOT ([a-z]+) -> ([a-z]+)$").unwrap();
+    parsing::parse_lines(input, |line| {
+        Ok(if let Some(cap) = set_re.captures(line.text) {
+            if let Ok(n) = cap[1].parse() {
+                Elem::Set(n, cap[2].to_string())
+            } else {
+                Elem::Cpy(cap[1].to_string(), cap[2].to_string())
+            }
+        } else if let Some(cap) = shift_re.captures(line.text) {
+            let x = cap[1].to_string();
+            let y = line.field(&cap[3]).parse()?;
+            let z = cap[4].to_string();
+            match &cap[2] {
+                "LSHIFT" => Elem::Lshift(x, y, z),
+                "RSHIFT" => Elem::Rshift(x, y, z),
+                _ => panic!()
+            }
+        } else if let Some(cap) = binary_re.captures(line.text) {
+            let x = cap[1].to_string();
+            let y = cap[3].to_string();
+            let z = cap[4].to_string();
+            match &cap[2] {
+                "AND" => {
+                    if let Ok(n) = cap[1].parse() {
+                        Elem::AndVal(n, y, z)
+                    } else {
+                        Elem::And(x, y, z)
+                    }
+                },
+                "OR" => Elem::Or(x, y, z),
+                _ => panic!()
+            }
+        } else if let Some(cap) = not_re.captures(line.text) {
+            Elem::Not(cap[1].to_string(), cap[2].to_string())
+        } else {
+            return Err(line.error(1, "expected a gate like \"x AND y -> d\""));
+        })
+    })
+}
+
+fn ready(signals: &BTreeMap<String, u16>, wires: &[&str]) -> bool {
+    wires.iter().all(|wire| signals.contains_key(*wire))
+}
+
+fn set(signals: &mut BTreeMap<String, u16>, wire: &str, val: u16) {
+    // Ignore is the value is already set
+    signals.entry(wire.to_string()).or_insert(val);
+}
+
+fn simulate(circuit: &[Elem], signals: &mut BTreeMap<String, u16>) {
+    let mut circuit = VecDeque::from(circuit.to_vec());
+    while let Some(elem) = circuit.pop_front() {
+        match &elem {
+            Elem::Set(v, z) => set(signals, z, *v),
+            Elem::Cpy(x, z) => {
+                if !ready(&signals, &[x]) { circuit.push_back(elem); }
+                else { set(signals, z, signals[x]); }
+            },
+            Elem::And(x, y, z) => {
+                if !ready(&signals, &[x, y]) { circuit.push_back(elem); }
+                else { set(signals, z, signals[x] & signals[y]); }
+            },
+            Elem::AndVal(n, y, z) => {
+                if !ready(&signals, &[y]) { circuit.push_back(elem); }
+                else { set(signals, z,  n & signals[y]); }
+            },
+            Elem::Or(x, y, z) => {
+                if !ready(&signals, &[x, y]) { circuit.push_back(elem); }
+                else { set(signals, z, signals[x] | signals[y]); }
+            },
+            Elem::Lshift(x, n, z) => {
+                if !ready(&signals, &[x]) { circuit.push_back(elem); }
+                else { set(signals, z, signals[x] << n); }
+            },
+            Elem::Rshift(x, n, z) => {
+                if !ready(&signals, &[x]) { circuit.push_back(elem); }
+                else { set(signals, z, signals[x] >> n); }
+            },
+            Elem::Not(x, z) => {
+                if !ready(&signals, &[x]) { circuit.push_back(elem); }
+                else { set(signals, z, !signals[x]); }
+            }
+        }
+    }
+}
+
+pub fn part1(circuit: &[Elem]) -> u16 {
+    let mut signals = BTreeMap::new();
+    simulate(circuit, &mut signals);
+    signals["a"]
+}
+
+pub fn part2(circuit: &[Elem]) -> u16 {
+    let mut signals = BTreeMap::new();
+    simulate(circuit, &mut signals);
+    let mut signals2 = BTreeMap::new();
+    signals2.insert("b".to_string(), signals["a"]);
+    simulate(circuit, &mut signals2);
+    signals2["a"]
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Elem>;
+    type Answer1 = u16;
+    type Answer2 = u16;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse_circuit(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u16 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u16 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let circuit_str = "\
+123 -> x
+456 -> y
+x AND y -> d
+x OR y -> e
+x LSHIFT 2 -> f
+y RSHIFT 2 -> g
+NOT x -> h
+NOT y -> i";
+        let mut expected_signals = BTreeMap::new();
+        set(&mut expected_signals, "d", 72);
+        set(&mut expected_signals, "e", 507);
+        set(&mut expected_signals, "f", 492);
+        set(&mut expected_signals, "g", 114);
+        set(&mut expected_signals, "h", 65412);
+        set(&mut expected_signals, "i", 65079);
+        set(&mut expected_signals, "x", 123);
+        set(&mut expected_signals, "y", 456);
+
+        let mut signals = BTreeMap::new();
+        simulate(&parse_circuit(circuit_str).unwrap(), &mut signals);
+        assert_eq!(signals, expected_signals);
+    }
+}
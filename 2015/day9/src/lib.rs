@@ -0,0 +1,93 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use parsing::ParseError;
+use permutohedron::LexicalPermutation;
+use regex::Regex;
+use solution::Solution;
+
+pub type Distances = BTreeMap<[String; 2], u32>;
+
+pub fn parse_distances(input: &str) -> Result<Distances, ParseError> {
+    let re = Regex::new("^([a-zA-Z]+) to ([a-zA-Z]+) = (\\d+)$").unwrap();
+    let mut distances = BTreeMap::new();
+    for line in parsing::lines(input) {
+        let cap = re.captures(line.text)
+            .ok_or_else(|| line.error(1, "expected e.g. \"London to Dublin = 464\""))?;
+        let x = cap[1].to_string();
+        let y = cap[2].to_string();
+        let d = line.field(&cap[3]).parse()?;
+        distances.insert([x.clone(), y.clone()], d);
+        distances.insert([y, x], d);
+    }
+    Ok(distances)
+}
+
+fn trip_distance(route: &Vec<String>, distances: &BTreeMap<[String; 2], u32>) -> u32 {
+    let mut dist = 0;
+    for pair in route.windows(2) {
+        dist += distances.get(pair).unwrap();
+    }
+    dist
+}
+
+fn find_shortest_longest_routes(distances: &Distances) -> (u32, u32) {
+    let cities: BTreeSet<_> = distances.keys().flat_map(|[city1, city2]| vec![city1, city2]).cloned().collect();
+    let mut cities: Vec<_> = cities.into_iter().collect();
+    let mut min_dist = std::u32::MAX;
+    let mut max_dist = std::u32::MIN;
+    while {
+        let dist = trip_distance(&cities, distances);
+        if dist < min_dist { min_dist = dist; }
+        if dist > max_dist { max_dist = dist; }
+        cities.next_permutation()
+    } { }
+    (min_dist, max_dist)
+}
+
+pub fn part1(distances: &Distances) -> u32 {
+    find_shortest_longest_routes(distances).0
+}
+
+pub fn part2(distances: &Distances) -> u32 {
+    find_shortest_longest_routes(distances).1
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Distances;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse_distances(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "\
+London to Dublin = 464
+London to Belfast = 518
+Dublin to Belfast = 141";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse_distances(EX).unwrap()), 605);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse_distances(EX).unwrap()), 982);
+    }
+}
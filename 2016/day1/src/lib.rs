@@ -0,0 +1,124 @@
+use std::collections::BTreeSet;
+
+use lazy_static::lazy_static;
+use parsing::ParseError;
+use regex::Regex;
+use solution::Solution;
+
+#[derive(Copy, Clone, Debug)]
+pub enum Turn {
+    Left, Right
+}
+
+pub fn parse(input: &str) -> Result<Vec<(Turn, usize)>, ParseError> {
+    lazy_static!{
+        static ref RE: Regex = Regex::new("^(L|R)(\\d+)$").unwrap();
+    }
+    parsing::single_line(input)?.split(", ").into_iter()
+        .map(|field| {
+            let cap = RE.captures(field.text).ok_or_else(|| field.error("expected a turn and steps like \"R2\""))?;
+            let turn = match &cap[1] {
+                "L" => Turn::Left,
+                "R" => Turn::Right,
+                _ => unreachable!()
+            };
+            let steps = field.line.field(&cap[2]).parse()?;
+            Ok((turn, steps))
+        })
+        .collect()
+}
+
+fn turn_left(dir: &mut (i32, i32)) {
+    if dir.0 == 0 { // vertical
+        *dir = (dir.1, 0);
+    } else { // horizontal
+        *dir = (0, -dir.0);
+    }
+}
+
+fn turn_right(dir: &mut (i32, i32)) {
+    if dir.0 == 0 { // vertical
+        *dir = (-dir.1, 0);
+    } else { // horizontal
+        *dir = (0, dir.0);
+    }
+}
+
+fn turn(dir: &mut (i32, i32), turn: Turn) {
+    match turn {
+        Turn::Left => turn_left(dir),
+        Turn::Right => turn_right(dir)
+    }
+}
+
+fn walk(pos: &mut (i32, i32), dir: (i32, i32)) {
+    pos.0 += dir.0;
+    pos.1 += dir.1;
+}
+
+pub fn part1(instructions: &Vec<(Turn,usize)>) -> i32 {
+    let mut pos = (0, 0);
+    let mut dir = (0, -1);
+    for inst in instructions.iter() {
+        turn(&mut dir, inst.0);
+        for _ in 0..inst.1 {
+            walk(&mut pos, dir);
+        }
+    }
+
+    pos.0.abs() + pos.1.abs()
+}
+
+pub fn part2(instructions: &Vec<(Turn,usize)>) -> i32 {
+    let mut pos: (i32, i32) = (0, 0);
+    let mut dir = (0, -1);
+    let mut seen = BTreeSet::new();
+    for inst in instructions.iter() {
+        turn(&mut dir, inst.0);
+        for _ in 0..inst.1 {
+            if !seen.insert(pos) {
+                return pos.0.abs() + pos.1.abs()
+            }
+            walk(&mut pos, dir);
+        }
+    }
+    unreachable!()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<(Turn, usize)>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> i32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> i32 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse("R2, L3").unwrap()), 5);
+        assert_eq!(part1(&parse("R2, R2, R2").unwrap()), 2);
+        assert_eq!(part1(&parse("R5, L5, R5, R3").unwrap()), 12);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse("R8, R4, R4, R8").unwrap()), 4);
+    }
+
+}
@@ -0,0 +1,199 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap, HashMap, HashSet};
+
+use parsing::ParseError;
+use regex::Regex;
+use solution::Solution;
+
+const FLOORS: u8 = 4;
+
+// The floors of each element's generator and microchip. Which element is which never matters, only
+// which floors the pairs are on, so the pairs are kept sorted and two states that only differ by
+// swapping elements are the same state.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct State {
+    elevator: u8,
+    pairs: Vec<(u8, u8)>,
+}
+
+impl State {
+    fn canonical(elevator: u8, mut pairs: Vec<(u8, u8)>) -> State {
+        pairs.sort_unstable();
+        State { elevator, pairs }
+    }
+
+    // Two bits per floor, which is plenty for the seven pairs of part 2
+    fn key(&self) -> u64 {
+        self.pairs.iter().fold(self.elevator as u64, |key, &(generator, microchip)| {
+            key << 4 | (generator as u64) << 2 | microchip as u64
+        })
+    }
+
+    fn is_complete(&self) -> bool {
+        self.pairs.iter().all(|&(generator, microchip)| generator == FLOORS - 1 && microchip == FLOORS - 1)
+    }
+
+    // A microchip is fried if it's on a floor with another generator but not its own
+    fn is_safe(&self) -> bool {
+        self.pairs.iter().all(|&(generator, microchip)| {
+            generator == microchip || self.pairs.iter().all(|&(other, _)| other != microchip)
+        })
+    }
+
+    // Each move carries at most two items up one floor
+    fn min_moves_left(&self) -> usize {
+        let floors_left: usize = self.pairs.iter()
+            .map(|&(generator, microchip)| (2 * (FLOORS - 1) - generator - microchip) as usize)
+            .sum();
+        floors_left.div_ceil(2)
+    }
+
+    fn next_states(&self) -> Vec<State> {
+        // Items are (pair, 0 for the generator or 1 for the microchip)
+        let items: Vec<(usize, usize)> = self.pairs.iter().enumerate()
+            .flat_map(|(i, &(generator, microchip))| {
+                let mut here = Vec::new();
+                if generator == self.elevator { here.push((i, 0)); }
+                if microchip == self.elevator { here.push((i, 1)); }
+                here
+            })
+            .collect();
+        let mut loads: Vec<Vec<(usize, usize)>> = items.iter().map(|&item| vec![item]).collect();
+        for i in 0..items.len() {
+            for j in i + 1..items.len() {
+                loads.push(vec![items[i], items[j]]);
+            }
+        }
+        let mut next_floors = Vec::new();
+        if self.elevator > 0 { next_floors.push(self.elevator - 1); }
+        if self.elevator < FLOORS - 1 { next_floors.push(self.elevator + 1); }
+
+        let mut ret = Vec::new();
+        for &floor in next_floors.iter() {
+            for load in loads.iter() {
+                let mut pairs = self.pairs.clone();
+                for &(pair, which) in load {
+                    if which == 0 { pairs[pair].0 = floor; } else { pairs[pair].1 = floor; }
+                }
+                let state = State::canonical(floor, pairs);
+                if state.is_safe() {
+                    ret.push(state);
+                }
+            }
+        }
+        ret
+    }
+}
+
+pub fn parse(input: &str) -> Result<State, ParseError> {
+    let microchip_re = Regex::new("([a-z]+)-compatible microchip").unwrap();
+    let generator_re = Regex::new("([a-z]+) generator").unwrap();
+    let mut generators = BTreeMap::new();
+    let mut microchips = BTreeMap::new();
+    let lines = parsing::lines(input);
+    if let Some(line) = lines.get(FLOORS as usize) {
+        return Err(line.error(1, format!("expected only {} floors", FLOORS)));
+    }
+    for (floor, line) in lines.iter().enumerate() {
+        for cap in generator_re.captures_iter(line.text) {
+            generators.insert(cap[1].to_string(), (floor as u8, line.field(cap.get(0).unwrap().as_str())));
+        }
+        for cap in microchip_re.captures_iter(line.text) {
+            microchips.insert(cap[1].to_string(), (floor as u8, line.field(cap.get(0).unwrap().as_str())));
+        }
+    }
+    for (element, (_, item)) in generators.iter() {
+        if !microchips.contains_key(element) { return Err(item.error(format!("expected a {}-compatible microchip too", element))); }
+    }
+    for (element, (_, item)) in microchips.iter() {
+        if !generators.contains_key(element) { return Err(item.error(format!("expected a {} generator too", element))); }
+    }
+    let pairs = generators.iter().map(|(element, &(floor, _))| (floor, microchips[element].0)).collect();
+    Ok(State::canonical(0, pairs))
+}
+
+// A* from the starting state, giving the number of steps and how many states were expanded
+fn num_steps_to_complete(start: State) -> (usize, usize) {
+    let mut queue = BinaryHeap::new();
+    queue.push(Reverse((start.min_moves_left(), 0, start.key())));
+    let mut states = HashMap::new();
+    states.insert(start.key(), start);
+    let mut expanded = HashSet::new();
+
+    while let Some(Reverse((_, steps, key))) = queue.pop() {
+        if !expanded.insert(key) { continue; }
+        let state = &states[&key];
+        if state.is_complete() { return (steps, expanded.len()); }
+        for next in state.next_states() {
+            let next_key = next.key();
+            if expanded.contains(&next_key) { continue; }
+            queue.push(Reverse((steps + 1 + next.min_moves_left(), steps + 1, next_key)));
+            states.entry(next_key).or_insert(next);
+        }
+    }
+    unreachable!()
+}
+
+pub fn part1(state: &State) -> usize {
+    num_steps_to_complete(state.clone()).0
+}
+
+// Adds the elerium and dilithium generators and microchips to the first floor
+pub fn part2(state: &State) -> usize {
+    let mut state = state.clone();
+    state.pairs.extend([(0, 0), (0, 0)]);
+    let (steps, expanded) = num_steps_to_complete(State::canonical(0, state.pairs));
+    eprintln!("part 2 expanded {} states", expanded);
+    steps
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = State;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "\
+The first floor contains a hydrogen-compatible microchip and a lithium-compatible microchip.
+The second floor contains a hydrogen generator.
+The third floor contains a lithium generator.
+The fourth floor contains nothing relevant.";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 11);
+    }
+
+    #[test]
+    fn test_canonical() {
+        let a = State::canonical(1, vec![(1, 2), (0, 0)]);
+        let b = State::canonical(1, vec![(0, 0), (1, 2)]);
+        assert_eq!(a.key(), b.key());
+        assert!(!State::canonical(0, vec![(1, 0), (0, 1)]).is_safe());
+        assert!(State::canonical(0, vec![(0, 0), (1, 2)]).is_safe());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("The first floor contains a hydrogen generator and a lithium-compatible microchip.").unwrap_err();
+        assert_eq!((err.column, err.message.as_str()), (28, "expected a hydrogen-compatible microchip too"));
+    }
+}
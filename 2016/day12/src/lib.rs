@@ -1,20 +1,8 @@
-use std::collections::BTreeMap;
+use parsing::ParseError;
+use register_machine::{Flow, Machine, Operand, Registers, SelfModifying};
+use solution::Solution;
 
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
-pub enum Loc {
-    Val(i64),
-    Reg(String)
-}
-
-impl From<&str> for Loc {
-    fn from(s: &str) -> Loc {
-        if let Ok(val) = s.parse() {
-            Loc::Val(val)
-        } else {
-            Loc::Reg(s.to_string())
-        }
-    }
-}
+pub type Loc = Operand<String, i64>;
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Op {
@@ -26,64 +14,118 @@ pub enum Op {
     Out(Loc)
 }
 
-pub fn parse_ops(input: &str) -> Vec<Op> {
-    input.lines().map(|line| {
-        let mut tokens = line.split_whitespace();
-        match tokens.next().unwrap() {
-            "cpy" => Op::Cpy(Loc::from(tokens.next().unwrap()), Loc::from(tokens.next().unwrap())),
-            "jnz" => Op::Jnz(Loc::from(tokens.next().unwrap()), Loc::from(tokens.next().unwrap())),
-            "inc" => Op::Inc(Loc::from(tokens.next().unwrap())),
-            "dec" => Op::Dec(Loc::from(tokens.next().unwrap())),
-            "tgl" => Op::Tgl(Loc::from(tokens.next().unwrap())),
-            "out" => Op::Out(Loc::from(tokens.next().unwrap())),
-            _ => unreachable!(),
+pub fn parse_ops(input: &str) -> Result<Vec<Op>, ParseError> {
+    parsing::parse_lines(input, |line| {
+        let fields = line.fields();
+        let arity = match fields.first().map(|op| op.text) {
+            Some("cpy" | "jnz") => 2,
+            Some("inc" | "dec" | "tgl" | "out") => 1,
+            _ => return Err(line.error(1, "expected an instruction like \"cpy 41 a\"")),
+        };
+        if fields.len() != arity + 1 {
+            return Err(line.error(1, format!("expected {} operands", arity)));
         }
+        let loc = |i: usize| fields[i].parse::<Loc>();
+        Ok(match fields[0].text {
+            "cpy" => Op::Cpy(loc(1)?, loc(2)?),
+            "jnz" => Op::Jnz(loc(1)?, loc(2)?),
+            "inc" => Op::Inc(loc(1)?),
+            "dec" => Op::Dec(loc(1)?),
+            "tgl" => Op::Tgl(loc(1)?),
+            "out" => Op::Out(loc(1)?),
+            _ => unreachable!(),
+        })
     })
-    .collect()
 }
 
-fn lookup(loc: &Loc, registers: &BTreeMap<String, i64>) -> i64 {
-    match loc {
-        Loc::Val(x) => *x,
-        Loc::Reg(x) => *registers.get(x).unwrap_or(&0)
+// The io is the clock signal from the most recent out instruction
+impl SelfModifying<Registers<String, i64>, Option<i64>> for Op {
+    fn execute_in(program: &mut [Op], pc: usize, registers: &mut Registers<String, i64>, clock: &mut Option<i64>) -> Flow {
+        match &program[pc] {
+            Op::Cpy(_, Loc::Imm(_)) => (), // Invalid, skip
+            Op::Cpy(x, Loc::Reg(y)) => { registers.set(y.clone(), x.value(registers)); },
+            Op::Jnz(x, y) => if x.value(registers) != 0 { return Flow::JumpRelative(y.value(registers)) },
+            Op::Inc(Loc::Imm(_)) => (), // Invalid, skip
+            Op::Inc(Loc::Reg(x)) => *registers.get_mut(x.clone()) += 1,
+            Op::Dec(Loc::Imm(_)) => (), // Invalid, skip
+            Op::Dec(Loc::Reg(x)) => *registers.get_mut(x.clone()) -= 1,
+            Op::Tgl(x) => {
+                let off = pc as i64 + x.value(registers);
+                if off >= 0 && off < program.len() as i64 {
+                    program[off as usize] = match &program[off as usize] {
+                        Op::Cpy(x, y) => Op::Jnz(x.clone(), y.clone()),
+                        Op::Jnz(x, y) => Op::Cpy(x.clone(), y.clone()),
+                        Op::Inc(x) => Op::Dec(x.clone()),
+                        Op::Dec(x) => Op::Inc(x.clone()),
+                        Op::Tgl(x) => Op::Inc(x.clone()),
+                        Op::Out(x) => Op::Inc(x.clone())
+                    };
+                }
+            },
+            Op::Out(x) => { *clock = Some(x.value(registers)); }
+        }
+        Flow::Next
     }
 }
 
-pub fn execute_op(program: &mut Vec<Op>, pc: &mut i64, clock: &mut Option<i64>, registers: &mut BTreeMap<String, i64>) {
-    match &program[*pc as usize] {
-        Op::Cpy(_, Loc::Val(_)) => (), // Invalid, skip
-        Op::Cpy(x, Loc::Reg(y)) => { registers.insert(y.clone(), lookup(x, registers)); },
-        Op::Jnz(x, y) => if lookup(x, registers) != 0 { *pc += lookup(y, registers) - 1; },
-        Op::Inc(Loc::Val(_)) => (), // Invalid, skip
-        Op::Inc(Loc::Reg(x)) => { registers.entry(x.to_string()).and_modify(|v| *v += 1).or_insert(1); },
-        Op::Dec(Loc::Val(_)) => (), // Invalid, skip
-        Op::Dec(Loc::Reg(x)) => { registers.entry(x.to_string()).and_modify(|v| *v -= 1).or_insert(-1); },
-        Op::Tgl(x) => {
-            let off = *pc + lookup(x, registers);
-            if off >= 0 && off < program.len() as i64 {
-                program[off as usize] = match &program[off as usize] {
-                    Op::Cpy(x, y) => Op::Jnz(x.clone(), y.clone()),
-                    Op::Jnz(x, y) => Op::Cpy(x.clone(), y.clone()),
-                    Op::Inc(x) => Op::Dec(x.clone()),
-                    Op::Dec(x) => Op::Inc(x.clone()),
-                    Op::Tgl(x) => Op::Inc(x.clone()),
-                    Op::Out(x) => Op::Inc(x.clone())
-                };
-            }
-        },
-        Op::Out(x) => { *clock = Some(lookup(x, registers)); }
+pub fn execute_with_initial_state(program: &[Op], f: fn(&mut Registers<String, i64>)) -> i64 {
+    let mut program = program.to_vec();
+    let mut machine = Machine::new(Registers::new());
+    f(&mut machine.registers);
+    machine.run_mut(&mut program, &mut None);
+    machine.registers.get("a")
+}
+
+pub fn part1(program: &[Op]) -> i64 {
+    execute_with_initial_state(program, |_| {})
+}
+
+pub fn part2(program: &[Op]) -> i64 {
+    execute_with_initial_state(program, |registers| { registers.set("c".to_string(), 1); })
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Op>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse_ops(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> i64 {
+        part2(input)
     }
-    *pc += 1;
 }
 
-pub fn execute_with_initial_state(input: &str, f: fn(&mut BTreeMap<String, i64>)) -> i64 {
-    let mut program = parse_ops(input);
-    let mut pc = 0i64;
-    let mut registers = BTreeMap::new();
-    f(&mut registers);
-    while pc >= 0 && pc < program.len() as i64 {
-        let mut clock = None;
-        execute_op(&mut program, &mut pc, &mut clock, &mut registers);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "\
+cpy 41 a
+inc a
+inc a
+dec a
+jnz a 2
+dec a";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse_ops(EX).unwrap()), 42);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_ops("cpy 41 a\njmp 2").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse_ops("cpy 41 a\ninc 4b").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
     }
-    *registers.get("a").unwrap_or(&0)
 }
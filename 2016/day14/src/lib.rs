@@ -0,0 +1,94 @@
+use hash_mining::{Digest, HashCache, nibble};
+use solution::Solution;
+
+fn nibbles(hash: &Digest) -> impl Iterator<Item = u8> + '_ {
+    (0..32).map(move |i| nibble(hash, i))
+}
+
+fn get_triplet(hash: &Digest) -> Option<u8> {
+    let mut last = 16;
+    let mut repeats = 0;
+    for x in nibbles(hash) {
+        if x == last {
+            repeats += 1;
+            if repeats >= 3 { return Some(x); }
+        } else {
+            last = x;
+            repeats = 1;
+        }
+    }
+    None
+}
+
+fn has_quintuplet(hash: &Digest, x: u8) -> bool {
+    let mut repeats = 0;
+    for x2 in nibbles(hash) {
+        if x == x2 {
+            repeats += 1;
+            if repeats >= 5 { return true; }
+        } else {
+            repeats = 0;
+        }
+    }
+    false
+}
+
+fn calc(input: &str, stretch_factor: usize) -> usize {
+    let mut hashes = HashCache::new(input.trim_end().as_bytes(), stretch_factor);
+    let mut key_cnt = 0;
+    for i in 0.. {
+        if let Some(x) = get_triplet(hashes.get(i)) {
+            if (i + 1..=i + 1000).any(|j| has_quintuplet(hashes.get(j), x)) {
+                key_cnt += 1;
+                if key_cnt >= 64 {
+                    return i;
+                }
+            }
+        }
+    }
+    unreachable!()
+}
+
+pub fn part1(input: &str) -> usize {
+    calc(input, 0)
+}
+
+pub fn part2(input: &str) -> usize {
+    calc(input, 2016)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        Ok(puzzle_input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1("abc"), 22728);
+    }
+
+    // NOTE - Make sure to use cargo test --release
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2("abc"), 22551);
+    }
+}
@@ -0,0 +1,90 @@
+use parsing::ParseError;
+use regex::Regex;
+use solution::Solution;
+
+#[derive(Clone)]
+pub struct Disc {
+    start: usize,
+    total: usize
+}
+
+pub fn parse(input: &str) -> Result<Vec<Disc>, ParseError> {
+    let re = Regex::new("^Disc #\\d+ has (\\d+) positions; at time=0, it is at position (\\d+).$").unwrap();
+    parsing::parse_lines(input, |line| {
+        let cap = re.captures(line.text)
+            .ok_or_else(|| line.error(1, "expected e.g. \"Disc #1 has 5 positions; at time=0, it is at position 4.\""))?;
+        let total = line.field(&cap[1]).parse()?;
+        if total == 0 {
+            return Err(line.field(&cap[1]).error("expected at least one position"));
+        }
+        Ok(Disc { start: line.field(&cap[2]).parse()?, total })
+    })
+}
+
+fn passes_discs(start: usize, discs: &[Disc]) -> bool {
+    for (i, disc) in discs.iter().enumerate() {
+        if (disc.start + start + i + 1) % disc.total != 0 {
+            return false;
+        }
+    }
+    true
+}
+
+fn first_time_to_pass_discs(discs: &[Disc]) -> usize {
+    for start in 0.. {
+        if passes_discs(start, &discs) {
+            return start;
+        }
+    }
+    unreachable!()
+}
+
+pub fn part1(discs: &[Disc]) -> usize {
+    first_time_to_pass_discs(discs)
+}
+
+pub fn part2(discs: &[Disc]) -> usize {
+    let mut discs = discs.to_vec();
+    discs.push(Disc { start: 0, total: 11 });
+    first_time_to_pass_discs(&discs)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Disc>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "\
+Disc #1 has 5 positions; at time=0, it is at position 4.
+Disc #2 has 2 positions; at time=0, it is at position 1.";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 5);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("Disc #1 has 0 positions; at time=0, it is at position 0.").err().unwrap();
+        assert_eq!((err.column, err.message.as_str()), (13, "expected at least one position"));
+    }
+}
@@ -0,0 +1,93 @@
+use bit_vec::BitVec;
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse(input: &str) -> Result<BitVec, ParseError> {
+    let bits = parsing::single_line(input)?.map_chars(|ch| {
+        match ch {
+            '0' => Some(false),
+            '1' => Some(true),
+            _ => None
+        }
+    })?;
+    Ok(bits.into_iter().collect())
+}
+
+fn generate(len: usize, bits: &mut BitVec) {
+    while bits.len() < len {
+        bits.reserve(1 + bits.len());
+        let mut copied = BitVec::with_capacity(bits.len());
+        for bit in bits.iter().rev() {
+            copied.push(!bit);
+        }
+        bits.push(false);
+        bits.extend(copied.iter());
+    }
+    bits.truncate(len);
+}
+
+fn checksum(mut bits: BitVec) -> BitVec {
+    while bits.len() % 2 == 0 {
+        let mut checksum = BitVec::with_capacity(bits.len() / 2);
+        let mut bits_iter = bits.into_iter();
+        while let (Some(b1), Some(b2)) = (bits_iter.next(), bits_iter.next()) {
+            checksum.push(b1 == b2);
+        }
+        bits = checksum;
+    }
+    bits
+}
+
+fn bits_to_string(bits: &BitVec) -> String {
+    bits.iter().map(|b| if b { '1' } else { '0' }).collect()
+}
+
+fn calc(len: usize, bits: &BitVec) -> String {
+    let mut bits = bits.clone();
+    generate(len, &mut bits);
+    let bits = checksum(bits);
+    bits_to_string(&bits)
+}
+
+pub fn part1(bits: &BitVec) -> String {
+    calc(272, bits)
+}
+
+pub fn part2(bits: &BitVec) -> String {
+    calc(35651584, bits)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = BitVec;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksum() {
+        assert_eq!(checksum(parse("110010110100").unwrap()), parse("100").unwrap());
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(&calc(20, &parse("10000").unwrap()), "01100");
+    }
+}
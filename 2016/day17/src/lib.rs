@@ -0,0 +1,85 @@
+use std::collections::VecDeque;
+
+use hash_mining::{md5, nibble};
+use solution::Solution;
+
+// [up, down, left, right]
+fn doors_open(passcode: &str, path: &str) -> [bool; 4] {
+    // b to f are open
+    let md5 = md5(format!("{}{}", passcode, path).as_bytes());
+    [0, 1, 2, 3].map(|i| nibble(&md5, i) >= 0xb)
+}
+
+const MAX_X: usize = 3;
+const MAX_Y: usize = 3;
+
+fn take_step(input: &str, x: usize, y: usize, path: String, queue: &mut VecDeque<(usize, usize, String)>) {
+    let [up, down, left, right] = doors_open(input, &path);
+    if x > 0     && up    { queue.push_back((x - 1, y, format!("{}{}", path, 'U'))) }
+    if x < MAX_X && down  { queue.push_back((x + 1, y, format!("{}{}", path, 'D'))) }
+    if y > 0     && left  { queue.push_back((x, y - 1, format!("{}{}", path, 'L'))) }
+    if y < MAX_Y && right { queue.push_back((x, y + 1, format!("{}{}", path, 'R'))) }
+}
+
+pub fn part1(input: &str) -> String {
+    let mut queue = VecDeque::new();
+    queue.push_back((0, 0, "".to_string()));
+    while let Some((x, y, path)) = queue.pop_front() {
+        if x == MAX_X && y == MAX_Y { return path }
+        take_step(input, x, y, path, &mut queue);
+    }
+    unreachable!()
+}
+
+pub fn part2(input: &str) -> usize {
+    let mut queue = VecDeque::new();
+    queue.push_back((0, 0, "".to_string()));
+    let mut longest_path = 0;
+    while let Some((x, y, path)) = queue.pop_front() {
+        if x == MAX_X && y == MAX_Y {
+            if path.len() > longest_path { longest_path = path.len() }
+            continue;
+        }
+        take_step(input, x, y, path, &mut queue);
+    }
+    longest_path
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = String;
+    type Answer1 = String;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        Ok(puzzle_input.trim_end().to_string())
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(&part1("ihgpwlah"), "DDRRRD");
+        assert_eq!(&part1("kglvqrro"), "DDUDRLRRUDRD");
+        assert_eq!(&part1("ulqzkmiv"), "DRURDRUDDLLDLUURRDULRLDUUDDDRR");
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2("ihgpwlah"), 370);
+        assert_eq!(part2("kglvqrro"), 492);
+        assert_eq!(part2("ulqzkmiv"), 830);
+    }
+}
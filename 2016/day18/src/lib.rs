@@ -0,0 +1,96 @@
+use bit_vec::BitVec;
+use parsing::ParseError;
+use solution::Solution;
+
+// true if there is a trap
+pub fn parse_row(input: &str) -> Result<BitVec, ParseError> {
+    let row = parsing::single_line(input)?.map_chars(|ch| {
+        match ch {
+            '.' => Some(false),
+            '^' => Some(true),
+            _ => None
+        }
+    })?;
+    Ok(row.into_iter().collect())
+}
+
+#[allow(dead_code)]
+fn map_to_string(map: &Vec<BitVec>) -> String {
+    let mut out = String::new();
+    let mut first = true;
+    for row in map.iter() {
+        if first {
+            first = false;
+        } else {
+            out.push('\n');
+        }
+        for trap in row.iter() {
+            out.push(if trap { '^' } else { '.' });
+        }
+    }
+    out
+}
+
+// Each tile is true if there is a trap there
+fn generate_map(first_row: &BitVec, rows: usize) -> Vec<BitVec> {
+    let first_row = first_row.clone();
+    let width = first_row.len();
+    let mut map = vec![first_row];
+    for y in 0..rows-1 {
+        let row = (0..width).map(|x| {
+            let left = if x == 0 { false } else { map[y][x - 1] };
+            let center = map[y][x];
+            let right = if x == width - 1 { false } else { map[y][x + 1] };
+            (left && center && !right) ||
+                (!left && center && right) ||
+                (left && !center && !right) ||
+                (!left && !center && right)
+        }).collect();
+        map.push(row);
+    }
+    map
+}
+
+fn count_safe_tiles(map: &Vec<BitVec>) -> usize {
+    map.iter().map(|row| row.iter().filter(|trap| !*trap).count()).sum()
+}
+
+pub fn part1(first_row: &BitVec) -> usize {
+    count_safe_tiles(&generate_map(first_row, 40))
+}
+
+// NOTE - this is fast enough, but since we don't need to generate the full map it would be better
+// instead to count the tiles as we go and only keep the current row
+pub fn part2(first_row: &BitVec) -> usize {
+    count_safe_tiles(&generate_map(first_row, 400000))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = BitVec;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse_row(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(count_safe_tiles(&generate_map(&parse_row(".^^.^.^^^^").unwrap(), 10)), 38);
+    }
+}
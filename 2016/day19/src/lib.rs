@@ -0,0 +1,89 @@
+use linked_list::{LinkedList, Cursor};
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse(input: &str) -> Result<usize, ParseError> {
+    parsing::single_line(input)?.parse()
+}
+
+fn next_circular(cursor: &mut Cursor<usize>) -> usize {
+    if let Some(elem) = cursor.next() {
+        *elem
+    } else {
+        *cursor.next().unwrap()
+    }
+}
+
+fn remove_circular(cursor: &mut Cursor<usize>) -> usize {
+    if let Some(elem) = cursor.remove() {
+        elem
+    } else {
+        cursor.next();
+        cursor.remove().unwrap()
+    }
+}
+
+fn build_circle(num_elems: usize) -> LinkedList<usize> {
+    let mut circle = LinkedList::new();
+    for i in 1..=num_elems {
+        circle.push_back(i);
+    }
+    circle    
+}
+
+pub fn part1(starting_cnt: usize) -> usize {
+    let mut circle = build_circle(starting_cnt);
+    let mut cur = circle.cursor();
+    next_circular(&mut cur);
+    for _ in 1..starting_cnt {
+        remove_circular(&mut cur);
+        next_circular(&mut cur);
+    }
+    circle.pop_front().unwrap()
+}
+
+pub fn part2(starting_cnt: usize) -> usize {
+    let mut circle = build_circle(starting_cnt);
+    let mut cur = circle.cursor();
+    cur.seek_forward(starting_cnt/2);
+    for i in 1..starting_cnt {
+        remove_circular(&mut cur);
+        if i % 2 == starting_cnt % 2 { next_circular(&mut cur); }
+    }
+    circle.pop_front().unwrap()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = usize;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(*input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(*input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(5), 3);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(5), 2);
+    }
+}
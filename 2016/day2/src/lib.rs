@@ -0,0 +1,95 @@
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    parsing::parse_lines(input, |line| line.map_chars(|ch| "UDLR".contains(ch).then_some(ch)))
+}
+
+pub fn part1(input: &[Vec<char>]) -> String {
+    let mut code = String::new();
+    let mut pos = (0, 0);
+    let keypad = [['1', '2', '3'],
+                  ['4', '5', '6'],
+                  ['7', '8', '9']];
+
+    for line in input {
+        for &ch in line {
+            match ch {
+                'U' => if pos.1 > 0 { pos.1 -= 1 },
+                'D' => if pos.1 < 2 { pos.1 += 1 },
+                'L' => if pos.0 > 0 { pos.0 -= 1 },
+                'R' => if pos.0 < 2 { pos.0 += 1 },
+                _ => unreachable!()
+            }
+        }
+        code.push(keypad[pos.1][pos.0]);
+    }
+
+    code
+}
+
+pub fn part2(input: &[Vec<char>]) -> String {
+    let mut code = String::new();
+    let mut pos = (0, 2);
+    let keypad = [[' ', ' ', '1', ' ', ' '],
+                  [' ', '2', '3', '4', ' '],
+                  ['5', '6', '7', '8', '9'],
+                  [' ', 'A', 'B', 'C', ' '],
+                  [' ', ' ', 'D', ' ', ' ']];
+
+    for line in input {
+        for &ch in line {
+            match ch {
+                'U' => if pos.1 > 0 && keypad[pos.1 - 1][pos.0] != ' ' { pos.1 -= 1 },
+                'D' => if pos.1 < 4 && keypad[pos.1 + 1][pos.0] != ' ' { pos.1 += 1 },
+                'L' => if pos.0 > 0 && keypad[pos.1][pos.0 - 1] != ' ' { pos.0 -= 1 },
+                'R' => if pos.0 < 4 && keypad[pos.1][pos.0 + 1] != ' ' { pos.0 += 1 },
+                _ => unreachable!()
+            }
+        }
+        code.push(keypad[pos.1][pos.0]);
+    }
+
+    code
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<char>>;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "\
+ULL
+RRDDD
+LURDL
+UUUUD";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), "1985".to_string());
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), "5DB3".to_string());
+    }
+}
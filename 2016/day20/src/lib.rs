@@ -0,0 +1,95 @@
+use std::ops::RangeInclusive;
+
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse(input: &str) -> Result<Vec<RangeInclusive<u32>>, ParseError> {
+    parsing::parse_lines(input, |line| {
+        let (start, end) = line.as_field().split_once("-")?;
+        Ok(start.parse()? ..= end.parse()?)
+    })
+}
+
+fn contains(elem: u32, range: &RangeInclusive<u32>) -> bool {
+    elem >= *range.start() && elem <= *range.end()
+}
+
+// OK, this and part2 are O(n^2) but the input size is small enough that it runs instantly. To
+// scale better we'd need to use an interval tree.
+pub fn part1(blocked_ranges: &[RangeInclusive<u32>]) -> u32 {
+    let mut cur = 0;
+    'outer: loop {
+        for range in blocked_ranges.iter() {
+            if contains(cur, range) {
+                cur = range.end() + 1;
+                continue 'outer;
+            }
+        }
+        break;
+    }
+    cur
+}
+
+pub fn part2(blocked_ranges: &[RangeInclusive<u32>]) -> u32 {
+    let mut allowed = 0;
+    let mut cur = 0;
+    'outer: loop {
+        for range in blocked_ranges.iter() {
+            if contains(cur, range) {
+                if *range.end() == std::u32::MAX {
+                    break 'outer;
+                }
+                cur = range.end() + 1;
+                continue 'outer;
+            }
+        }
+
+        let next_blocked_opt = blocked_ranges.iter()
+            .map(|range| *range.start())
+            .filter(|start| *start > cur)
+            .min();
+        if let Some(next) = next_blocked_opt {
+            allowed += next - cur;
+            cur = next;
+        } else {
+            allowed += std::u32::MAX - cur + 1;
+            break;
+        }
+    }
+    allowed
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<RangeInclusive<u32>>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "\
+5-8
+0-2
+4-7";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 3);
+    }
+}
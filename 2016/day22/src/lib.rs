@@ -0,0 +1,172 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap};
+
+use parsing::ParseError;
+use regex::Regex;
+use solution::Solution;
+
+pub fn parse_df(input: &str) -> Result<Vec<Vec<(u16, u16)>>, ParseError> {
+    let re = Regex::new("^/dev/grid/node-x(\\d+)-y(\\d+)\\s+(\\d+)T\\s+(\\d+)T\\s+\\d+T\\s+\\d+%$").unwrap();
+    // NOTE - the lines before the first node are the df command and its header
+    let lines = parsing::lines(input);
+    let nodes = lines.iter().skip_while(|line| !line.text.starts_with("/dev/grid/")).map(|line| {
+        let cap = re.captures(line.text)
+            .ok_or_else(|| line.error(1, "expected e.g. \"/dev/grid/node-x0-y0   10T    8T     2T   80%\""))?;
+        let x: usize = line.field(&cap[1]).parse()?;
+        let y: usize = line.field(&cap[2]).parse()?;
+        Ok((x, y, line.field(&cap[4]).parse()?, line.field(&cap[3]).parse()?))
+    }).collect::<Result<Vec<(usize, usize, u16, u16)>, ParseError>>()?;
+    let width = nodes.iter().map(|node| node.0 + 1).max().unwrap_or(0);
+    let height = nodes.iter().map(|node| node.1 + 1).max().unwrap_or(0);
+    if width * height == 0 || nodes.len() != width * height {
+        let line = lines.last().copied().unwrap_or(parsing::Line { number: 1, text: "" });
+        return Err(line.error(1, format!("expected {} nodes for a {}x{} grid", width * height, width, height)));
+    }
+
+    let mut df = vec![vec![(0, 0); width]; height];
+    for (x, y, used, size) in nodes {
+        df[y][x] = (used, size);
+    }
+    Ok(df)
+}
+
+pub fn part1(df: &[Vec<(u16, u16)>]) -> usize {
+    let height = df.len();
+    let width = df[0].len();
+    let mut num_viable_pairs = 0;
+    for y1 in 0..height {
+        for x1 in 0..width {
+            for y2 in 0..height {
+                for x2 in 0..width {
+                    if (x1 != x2 || y1 != y2)
+                        && df[y1][x1].0 != 0
+                        && df[y2][x2].1 - df[y2][x2].0 >= df[y1][x1].0
+                    {
+                        num_viable_pairs += 1;
+                    }
+                }
+            }
+        }
+    }
+    num_viable_pairs
+}
+
+type Pos = (usize, usize);
+
+fn distance(a: Pos, b: Pos) -> usize {
+    a.0.abs_diff(b.0) + a.1.abs_diff(b.1)
+}
+
+// The only move that's ever possible is into the one empty node, and some nodes hold more data
+// than it could ever take, so the grid is really a sliding puzzle: open squares, walls, and the
+// empty square that moves by swapping with a neighbour. Returns the walls and the empty square.
+fn sliding_puzzle(df: &[Vec<(u16, u16)>]) -> (Vec<Vec<bool>>, Pos) {
+    let mut empty = None;
+    for (y, row) in df.iter().enumerate() {
+        for (x, &(used, _)) in row.iter().enumerate() {
+            if used == 0 {
+                assert!(empty.is_none(), "expected only one empty node");
+                empty = Some((x, y));
+            }
+        }
+    }
+    let empty = empty.expect("no empty node");
+    let capacity = df[empty.1][empty.0].1;
+    let walls = df.iter().map(|row| row.iter().map(|&(used, _)| used > capacity).collect()).collect();
+    (walls, empty)
+}
+
+// A* over where the empty square and the goal data are. The empty square has to get next to the
+// goal before it can move, and after that the TODO's 5 moves per step of the goal is what a
+// straight run along the top row costs, but a step can take as few as 3 where the path turns (2 to
+// get the empty square around the corner and 1 to move), so that's the bound used. It drops by 3
+// when the goal moves, which isn't consistent, so states are reopened if they're reached sooner.
+fn fewest_moves(walls: &[Vec<bool>], empty: Pos, goal: Pos) -> Option<usize> {
+    let height = walls.len();
+    let width = walls[0].len();
+    let heuristic = |empty: Pos, goal: Pos| match distance(goal, (0, 0)) {
+        0 => 0,
+        d => distance(empty, goal) - 1 + 3 * d - 2,
+    };
+    let mut best = BTreeMap::new();
+    best.insert((empty, goal), 0);
+    let mut queue = BinaryHeap::new();
+    queue.push(Reverse((heuristic(empty, goal), 0, empty, goal)));
+    while let Some(Reverse((_, moves, empty, goal))) = queue.pop() {
+        if goal == (0, 0) {
+            return Some(moves);
+        }
+        if best[&(empty, goal)] < moves {
+            continue;
+        }
+        let (x, y) = empty;
+        let neighbours = [
+            (x > 0).then(|| (x - 1, y)),
+            (x + 1 < width).then(|| (x + 1, y)),
+            (y > 0).then(|| (x, y - 1)),
+            (y + 1 < height).then(|| (x, y + 1)),
+        ];
+        for next in neighbours.iter().flatten().copied() {
+            if walls[next.1][next.0] {
+                continue;
+            }
+            // Moving the goal data into the empty node leaves its old node empty
+            let next_goal = if next == goal { empty } else { goal };
+            if best.get(&(next, next_goal)).is_none_or(|&b| moves + 1 < b) {
+                best.insert((next, next_goal), moves + 1);
+                queue.push(Reverse((moves + 1 + heuristic(next, next_goal), moves + 1, next, next_goal)));
+            }
+        }
+    }
+    None
+}
+
+pub fn part2(df: &[Vec<(u16, u16)>]) -> usize {
+    let (walls, empty) = sliding_puzzle(df);
+    fewest_moves(&walls, empty, (df[0].len() - 1, 0)).expect("the goal data can't reach the start")
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<(u16, u16)>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse_df(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part2() {
+        let input = "\
+Filesystem            Size  Used  Avail  Use%
+/dev/grid/node-x0-y0   10T    8T     2T   80%
+/dev/grid/node-x0-y1   11T    6T     5T   54%
+/dev/grid/node-x0-y2   32T   28T     4T   87%
+/dev/grid/node-x1-y0    9T    7T     2T   77%
+/dev/grid/node-x1-y1    8T    0T     8T    0%
+/dev/grid/node-x1-y2   11T    7T     4T   63%
+/dev/grid/node-x2-y0   10T    6T     4T   60%
+/dev/grid/node-x2-y1    9T    8T     1T   88%
+/dev/grid/node-x2-y2    9T    6T     3T   66%";
+        let df = parse_df(input).unwrap();
+        let (walls, empty) = sliding_puzzle(&df);
+        assert_eq!(empty, (1, 1));
+        assert!(walls[2][0]);
+        assert_eq!(part2(&df), 7);
+    }
+}
@@ -0,0 +1,51 @@
+use day12_2016::{execute_with_initial_state, parse_ops, Op};
+use solution::Solution;
+
+pub fn part1(program: &[Op]) -> i64 {
+    execute_with_initial_state(program, |registers| { registers.set("a".to_string(), 7); })
+}
+
+// TODO - This only took a few minutes to run so I didn't bother actually hand optimizing the
+// assembly code as in other similar challenges.
+pub fn part2(program: &[Op]) -> i64 {
+    execute_with_initial_state(program, |registers| { registers.set("a".to_string(), 12); })
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Op>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse_ops(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> i64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "\
+cpy 2 a
+tgl a
+tgl a
+tgl a
+cpy 1 a
+dec a
+dec a";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse_ops(EX).unwrap()), 3);
+    }
+}
@@ -0,0 +1,109 @@
+use std::collections::{BTreeSet, VecDeque};
+
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse_map(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    let lines = parsing::lines(input);
+    let map = parsing::grid(&lines, |ch| (ch == '#' || ch == '.' || ch.is_ascii_digit()).then_some(ch))?;
+    if !map.iter().flatten().any(|&ch| ch == '0') {
+        let line = lines.last().copied().unwrap_or(parsing::Line { number: 1, text: "" });
+        return Err(line.error(1, "expected a starting point 0 in the map"));
+    }
+    Ok(map)
+}
+
+fn get_points_needed(map: &[Vec<char>]) -> u32 {
+    let mut ret = 0;
+    for row in map.iter() {
+        for ch in row.iter() {
+            if let Some(poi) = ch.to_digit(10) {
+                ret |= 1 << poi;
+            }
+        }
+    }
+    ret
+}
+
+fn get_starting_pos(map: &[Vec<char>]) -> (usize, usize) {
+    for (y, row) in map.iter().enumerate() {
+        for (x, ch) in row.iter().enumerate() {
+            if *ch == '0' { return (x, y) }
+        }
+    }
+    panic!()
+}
+
+fn move_to(x: usize, y: usize, visited: u32, steps: usize, map: &[Vec<char>], queue: &mut VecDeque<((usize, usize), u32, usize)>) {
+    match map[y][x] {
+        '#' => (),
+        '.' => queue.push_back(((x, y), visited, steps + 1)),
+        ch => {
+            let poi = ch.to_digit(10).unwrap();
+            queue.push_back(((x, y), visited | (1 << poi), steps + 1));
+        }
+    }
+}
+
+fn steps_to_traverse(map: &[Vec<char>], return_to_start: bool) -> usize {
+    let needed = get_points_needed(map);
+    let starting_pos = get_starting_pos(map);
+    let mut seen = BTreeSet::new();
+    let mut queue = VecDeque::new();
+    queue.push_back((starting_pos, 1, 0));
+    while let Some(((x, y), visited, steps)) = queue.pop_front() {
+        if visited == needed && (!return_to_start || (x, y) == starting_pos)  { return steps }
+        if !seen.insert(((x, y), visited)) { continue }
+
+        move_to(x+1, y, visited, steps, map, &mut queue);
+        move_to(x-1, y, visited, steps, map, &mut queue);
+        move_to(x, y+1, visited, steps, map, &mut queue);
+        move_to(x, y-1, visited, steps, map, &mut queue);
+    }
+    panic!()
+}
+
+pub fn part1(map: &[Vec<char>]) -> usize {
+    steps_to_traverse(map, false)
+}
+
+pub fn part2(map: &[Vec<char>]) -> usize {
+    steps_to_traverse(map, true)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<char>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse_map(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "\
+###########
+#0.1.....2#
+#.#######.#
+#4.......3#
+###########";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse_map(EX).unwrap()), 14);
+    }
+}
@@ -0,0 +1,80 @@
+use std::collections::BTreeSet;
+
+use day12_2016::{parse_ops, Op};
+use register_machine::{Machine, Registers, Status};
+use solution::Solution;
+
+fn toggle_clock(clock: i64) -> i64 {
+    if clock == 0 { 1 } else { 0 }
+}
+
+fn check_toggling_clock(mut program: Vec<Op>, registers: Registers<String, i64>) -> bool {
+    let mut seen = BTreeSet::new();
+    let mut machine = Machine::new(registers);
+    let mut last_clock = 1;
+    loop {
+        if !seen.insert((program.clone(), machine.registers.clone(), machine.pc)) {
+            break;
+        }
+        let mut clock = None;
+        if machine.step_mut(&mut program, &mut clock) == Status::Halted { return false }
+        if let Some(clock) = clock {
+            if toggle_clock(last_clock) != clock {
+                return false
+            }
+            last_clock = clock;
+        }
+    }
+
+    let saved_state = (program.clone(), machine.registers.clone(), machine.pc);
+    let mut clock_changes = 0;
+    let mut cycle_len = 0;
+
+    loop {
+        if cycle_len != 0 && saved_state.0 == program && saved_state.1 == machine.registers && saved_state.2 == machine.pc {
+            return clock_changes > 0;
+        }
+        cycle_len += 1;
+
+        let mut clock = None;
+        if machine.step_mut(&mut program, &mut clock) == Status::Halted { return false }
+        if let Some(clock) = clock {
+            if toggle_clock(last_clock) != clock {
+                return false
+            }
+            last_clock = clock;
+            clock_changes += 1;
+        }
+    }
+}
+
+// TODO - this takes about a minute to run, probably there is a way to rewrite the input assembly
+// to be more efficient.
+pub fn part1(program: &[Op]) -> i64 {
+    for i in 0.. {
+        let mut registers = Registers::new();
+        registers.set("a".to_string(), i);
+        if check_toggling_clock(program.to_vec(), registers) {
+            return i;
+        }
+    }
+    unreachable!()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Op>;
+    type Answer1 = i64;
+    type Answer2 = &'static str;
+
+    const PARTS: usize = 1;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse_ops(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        part1(input)
+    }
+}
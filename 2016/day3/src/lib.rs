@@ -0,0 +1,67 @@
+use itertools::Itertools;
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse(input: &str) -> Result<Vec<[u32; 3]>, ParseError> {
+    parsing::parse_lines(input, |line| {
+        match line.fields()[..] {
+            [a, b, c] => Ok([a.parse()?, b.parse()?, c.parse()?]),
+            _ => Err(line.error(1, "expected three sides"))
+        }
+    })
+}
+
+fn possible_triangle(mut sides: [u32; 3]) -> bool {
+    sides.sort();
+    sides[0] + sides[1] > sides[2]
+}
+
+pub fn part1(triangles: &[[u32; 3]]) -> usize {
+    triangles.iter()
+        .filter(|triangle| possible_triangle(**triangle))
+        .count()
+}
+
+pub fn part2(rows: &[[u32; 3]]) -> usize {
+    rows.iter()
+        .map(|&[a, b, c]| (a, b, c))
+        .tuples()
+        .map(|((a1, b1, c1), (a2, b2, c2), (a3, b3, c3))| [[a1, a2, a3], [b1, b2, b3], [c1, c2, c3]])
+        .collect::<Vec<[[u32; 3]; 3]>>()
+        .iter()
+        .flat_map(|x| x)
+        .filter(|triangle| possible_triangle(**triangle))
+        .count()
+
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<[u32; 3]>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(possible_triangle([5, 10, 25]), false);
+        assert_eq!(possible_triangle([5, 10, 10]), true);
+    }
+}
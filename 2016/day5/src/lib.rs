@@ -0,0 +1,85 @@
+use std::cell::RefCell;
+
+use frames::{Animation, Frame};
+use hash_mining::{Digest, leading_zeros, nibble};
+use params::Params;
+use solution::Solution;
+
+const PASSWORD_LEN: usize = 8;
+
+fn interesting_hashes(input: &str) -> impl Iterator<Item = Digest> {
+    hash_mining::search(input.as_bytes(), 0, |digest| leading_zeros(digest, 5)).map(|(_, digest)| digest)
+}
+
+fn hex_digit(digest: &Digest, i: usize) -> char {
+    char::from_digit(nibble(digest, i) as u32, 16).unwrap()
+}
+
+pub fn part1(input: &str) -> String {
+    interesting_hashes(input)
+        .take(PASSWORD_LEN)
+        .map(|digest| hex_digit(&digest, 5))
+        .collect()
+}
+
+pub fn part2(input: &str, animation: &mut Animation) -> String {
+    let mut password = vec!['_'; PASSWORD_LEN];
+    for digest in interesting_hashes(input) {
+        let pos = nibble(&digest, 5) as usize;
+        if pos < PASSWORD_LEN && password[pos] == '_' {
+            password[pos] = hex_digit(&digest, 6);
+            animation.frame(|| Frame::from_text(&password.iter().collect::<String>()));
+            if password.iter().all(|ch| *ch != '_') {
+                break;
+            }
+        }
+    }
+    password.iter().collect()
+}
+
+// The door ID along with where part 2's frames go
+pub struct Door {
+    pub id: String,
+    pub animation: RefCell<Animation>,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Door;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        let id = puzzle_input.trim_end().to_string(); // Ignore the newline
+        Ok(Door { id, animation: RefCell::new(Animation::off()) })
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        part1(&input.id)
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        part2(&input.id, &mut input.animation.borrow_mut())
+    }
+
+    fn configure(input: &mut Self::Input, params: &Params) {
+        input.animation = RefCell::new(Animation::from_params(params));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // NOTE - make sure to use cargo test --release or this will take forever
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1("abc"), "18f47a30".to_string())
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2("abc", &mut Animation::off()), "05ace8e3".to_string())
+    }
+}
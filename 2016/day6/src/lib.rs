@@ -0,0 +1,87 @@
+use std::collections::BTreeMap;
+
+use parsing::ParseError;
+use solution::Solution;
+
+// Every message is the same length
+pub fn parse(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    parsing::grid(&parsing::lines(input), Some)
+}
+
+fn char_counts(chars: impl Iterator<Item=char>) -> BTreeMap<char, usize> {
+    let mut counts = BTreeMap::new();
+    for ch in chars {
+        counts.entry(ch).and_modify(|cnt| *cnt += 1).or_insert(1);
+    }
+    counts    
+}
+
+fn most_frequent(chars: impl Iterator<Item=char>) -> char {
+    char_counts(chars).into_iter().max_by_key(|(_,cnt)| *cnt).unwrap().0
+}
+
+fn least_frequent(chars: impl Iterator<Item=char>) -> char {
+    char_counts(chars).into_iter().min_by_key(|(_,cnt)| *cnt).unwrap().0
+}
+
+
+pub fn part1(signal: &[Vec<char>]) -> String {
+    (0..signal[0].len()).map(|i| most_frequent(signal.iter().map(|chars| chars[i]))).collect()
+}
+
+pub fn part2(signal: &[Vec<char>]) -> String {
+    (0..signal[0].len()).map(|i| least_frequent(signal.iter().map(|chars| chars[i]))).collect()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<char>>;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "\
+eedadn
+drvtee
+eandsr
+raavrd
+atevrs
+tsrnev
+sdttsa
+rasrtv
+nssdts
+ntnada
+svetve
+tesnvt
+vntsnd
+vrdear
+dvrsen
+enarar";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), "easter".to_string());
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), "advent".to_string());
+    }
+}
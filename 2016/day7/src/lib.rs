@@ -0,0 +1,145 @@
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+pub enum Sequence {
+    Supernet(String),
+    Hypernet(String)
+}
+
+fn parse_addr(line: Line) -> Result<Vec<Sequence>, ParseError> {
+    let mut ret = Vec::new();
+    let mut seq = String::new();
+    let mut in_brackets = false;
+    for (i, ch) in line.text.chars().enumerate() {
+        if ch == '[' {
+            if in_brackets { return Err(line.error(i + 1, "expected ']' before another '['")); }
+            in_brackets = true;
+            ret.push(Sequence::Supernet(seq));
+            seq = String::new();
+        } else if ch == ']' {
+            if !in_brackets { return Err(line.error(i + 1, "expected '[' before ']'")); }
+            in_brackets = false;
+            ret.push(Sequence::Hypernet(seq));
+            seq = String::new();
+        } else {
+            seq.push(ch);
+        }
+    }
+    if in_brackets { return Err(line.error(line.text.chars().count() + 1, "expected ']'")); }
+    ret.push(Sequence::Supernet(seq));
+    Ok(ret)
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<Sequence>>, ParseError> {
+    parsing::parse_lines(input, parse_addr)
+}
+
+fn has_abba(sequence: &str) -> bool {
+    let sequence = sequence.chars().collect::<Vec<_>>();
+    sequence.windows(4).any(|wnd| match wnd {
+        &[a, b, c, d] if a != b && a == d && b == c => true,
+        _ => false
+    })
+}
+
+fn supernets(ip: &Vec<Sequence>) -> impl Iterator<Item=&str> {
+    ip.iter().map(|seq| match seq {
+        Sequence::Supernet(s) => Some(s),
+        Sequence::Hypernet(_) => None
+    }).flat_map(|seq_opt| seq_opt).map(|seq| seq.as_str())
+}
+
+fn hypernets(ip: &Vec<Sequence>) -> impl Iterator<Item=&str> {
+    ip.iter().map(|seq| match seq {
+        Sequence::Supernet(_) => None,
+        Sequence::Hypernet(s) => Some(s)
+    }).flat_map(|seq_opt| seq_opt).map(|seq| seq.as_str())
+}
+
+fn supports_tls(ip: &Vec<Sequence>) -> bool {
+    supernets(ip).any(|seq| has_abba(seq)) &&
+        !hypernets(ip).any(|seq| has_abba(seq))
+}
+
+pub fn part1(ips: &[Vec<Sequence>]) -> usize {
+    ips.iter()
+        .filter(|ip| supports_tls(ip))
+        .count()
+}
+
+// Returns vec of (a, b)
+fn find_aba<'a>(sequences: impl Iterator<Item=&'a str>) -> Vec<(char, char)> {
+    let mut ret = Vec::new();
+    for sequence in sequences {
+        let sequence = sequence.chars().collect::<Vec<_>>();
+        for window in sequence.windows(3) {
+            if let &[a, b, c] = window {
+                if a != b && a == c { ret.push((a, b)); }
+            }
+        }
+    }
+    ret
+}
+
+fn supports_ssl(ip: &Vec<Sequence>) -> bool {
+    for (a, b) in find_aba(supernets(ip)) {
+        if find_aba(hypernets(ip)).contains(&(b, a)) {
+            return true;
+        }
+    }
+    false
+}
+
+pub fn part2(ips: &[Vec<Sequence>]) -> usize {
+    ips.iter()
+        .filter(|ip| supports_ssl(ip))
+        .count()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<Sequence>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert!(supports_tls(&parse("abba[mnop]qrst").unwrap()[0]));
+        assert!(!supports_tls(&parse("abcd[bddb]xyyx").unwrap()[0]));
+        assert!(!supports_tls(&parse("aaaa[qwer]tyui").unwrap()[0]));
+        assert!(supports_tls(&parse("ioxxoj[asdfgh]zxcvbn").unwrap()[0]));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert!(supports_ssl(&parse("aba[bab]xyz").unwrap()[0]));
+        assert!(!supports_ssl(&parse("xyx[xyx]xyx").unwrap()[0]));
+        assert!(supports_ssl(&parse("aaa[kek]eke").unwrap()[0]));
+        assert!(supports_ssl(&parse("zazbz[bzb]cdb").unwrap()[0]));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(parse("ab]c").err().map(|e| e.column), Some(3));
+        assert_eq!(parse("a[b[c]]").err().map(|e| e.column), Some(4));
+        assert_eq!(parse("abba\na[b").err().map(|e| (e.line, e.column)), Some((2, 4)));
+    }
+}
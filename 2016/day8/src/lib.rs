@@ -0,0 +1,161 @@
+use lazy_static::lazy_static;
+use params::Params;
+use parsing::ParseError;
+use regex::Regex;
+use solution::Solution;
+
+pub enum Cmd {
+    Rect(usize, usize),
+    RotateRow(usize, usize),
+    RotateCol(usize, usize)
+}
+
+pub fn parse(input: &str) -> Result<Vec<Cmd>, ParseError> {
+    lazy_static!{
+        static ref RECT_RE: Regex = Regex::new("^rect (\\d+)x(\\d+)$").unwrap();
+        static ref ROT_ROW_RE: Regex = Regex::new("^rotate row y=(\\d+) by (\\d+)$").unwrap();
+        static ref ROT_COL_RE: Regex = Regex::new("^rotate column x=(\\d+) by (\\d+)$").unwrap();
+    }
+    parsing::parse_lines(input, |line| {
+        let num = |text| line.field(text).parse();
+        if let Some(cap) = RECT_RE.captures(line.text) {
+            Ok(Cmd::Rect(num(&cap[1])?, num(&cap[2])?))
+        } else if let Some(cap) = ROT_ROW_RE.captures(line.text) {
+            Ok(Cmd::RotateRow(num(&cap[1])?, num(&cap[2])?))
+        } else if let Some(cap) = ROT_COL_RE.captures(line.text) {
+            Ok(Cmd::RotateCol(num(&cap[1])?, num(&cap[2])?))
+        } else {
+            Err(line.error(1, "expected \"rect AxB\", \"rotate row y=A by B\" or \"rotate column x=A by B\""))
+        }
+    })
+}
+
+fn new_screen(width: usize, height: usize) -> Vec<Vec<bool>> {
+    vec![vec![false; width]; height]
+}
+
+fn rotate(dots: &Vec<bool>, shift: usize) -> Vec<bool> {
+    let mut ret = vec![false; dots.len()];
+    for i in 0..dots.len() {
+        ret[(i + shift) % dots.len()] = dots[i];
+    }
+    ret
+}
+
+fn execute(screen: &mut Vec<Vec<bool>>, cmd: &Cmd) {
+    match *cmd {
+        Cmd::Rect(width, height) => {
+            for y in 0..height {
+                for x in 0..width {
+                    screen[y][x] = true;
+                }
+            }
+        },
+        Cmd::RotateRow(y, shift) => {
+            screen[y] = rotate(&screen[y], shift);
+        },
+        Cmd::RotateCol(x, shift) => {
+            let column = (0..screen.len()).map(|y| screen[y][x]).collect();
+            let column = rotate(&column, shift);
+            for (y, dot) in column.into_iter().enumerate() {
+                screen[y][x] = dot;
+            }
+        }
+    }
+}
+
+fn lit_pixels(screen: &Vec<Vec<bool>>) -> usize {
+    screen.iter()
+        .map(|row| row.iter().filter(|dot| **dot).count())
+        .sum()
+}
+
+fn screen_to_string(screen: &Vec<Vec<bool>>) -> String {
+    let mut ret = String::new();
+    for row in screen.iter() {
+        for dot in row.iter() {
+            ret.push(if *dot { '#' } else { '.' });
+        }
+        ret.push('\n');
+    }
+    ret
+}
+
+pub fn part1(cmds: &[Cmd], width: usize, height: usize) -> usize {
+    let mut screen = new_screen(width, height);
+    for cmd in cmds.iter() {
+        execute(&mut screen, cmd);
+    }
+    lit_pixels(&screen)
+}
+
+pub fn part2(cmds: &[Cmd], width: usize, height: usize) -> String {
+    let mut screen = new_screen(width, height);
+    for cmd in cmds.iter() {
+        execute(&mut screen, cmd);
+    }
+    screen_to_string(&screen)
+}
+
+// The real screen's size, which the example shrinks
+const WIDTH: usize = 50;
+const HEIGHT: usize = 6;
+
+// The commands along with the size of the screen they draw on
+pub struct Screen {
+    pub cmds: Vec<Cmd>,
+    pub width: usize,
+    pub height: usize,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Screen;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        let cmds = parse(puzzle_input).map_err(|e| e.to_string())?;
+        Ok(Screen { cmds, width: WIDTH, height: HEIGHT })
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(&input.cmds, input.width, input.height)
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        part2(&input.cmds, input.width, input.height)
+    }
+
+    fn configure(input: &mut Self::Input, params: &Params) {
+        input.width = params.get("width", WIDTH);
+        input.height = params.get("height", HEIGHT);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let ex = "\
+rect 3x2
+rotate column x=1 by 1
+rotate row y=0 by 4
+rotate column x=1 by 1";
+
+        let mut screen = new_screen(7, 3);
+        for cmd in parse(ex).unwrap().iter() {
+            execute(&mut screen, cmd);
+        }
+
+        let result = "\
+.#..#.#
+#.#....
+.#.....";
+
+        assert_eq!(screen_to_string(&screen).trim_end(), result);
+    }
+}
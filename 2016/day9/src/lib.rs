@@ -0,0 +1,114 @@
+use solution::Solution;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Mode {
+    Normal, MarkerLen, MarkerRepeat, Subsequent
+}
+
+// NOTE - we don't want to build the full string because it takes many gigabytes
+fn decompressed_length(input: &str, decompress_subsequent: bool) -> usize {
+    let mut len = 0;
+
+    let mut mode = Mode::Normal;
+    let mut marker_len = 0;
+    let mut marker_repeat = 0;
+    let mut subsequent = String::new();
+    for ch in input.chars() {
+        if ch.is_whitespace() { continue; }
+        match mode {
+            Mode::Normal => {
+                if ch == '(' {
+                    mode = Mode::MarkerLen;
+                    marker_len = 0;
+                } else {
+                    len += 1;
+                }
+            },
+            Mode::MarkerLen => {
+                if ch == 'x' {
+                    mode = Mode::MarkerRepeat;
+                    marker_repeat = 0;
+                } else {
+                    marker_len = marker_len * 10 + ch.to_digit(10).unwrap() as usize;
+                }
+            },
+            Mode::MarkerRepeat => {
+                if ch == ')' {
+                    mode = Mode::Subsequent;
+                    subsequent.clear();
+                } else {
+                    marker_repeat = marker_repeat * 10 + ch.to_digit(10).unwrap() as usize;
+                }
+            },
+            Mode::Subsequent => {
+                assert!(marker_len > 0);
+                if marker_len == 1 {
+                    subsequent.push(ch);
+                    mode = Mode::Normal;
+                    let subsequent_len = if decompress_subsequent {
+                        decompressed_length(&subsequent, true)
+                    } else {
+                        subsequent.chars().count()
+                    };
+                    len += subsequent_len * marker_repeat;
+                } else {
+                    marker_len -= 1;
+                    subsequent.push(ch);
+                }
+            }
+        }
+    }
+    assert_eq!(mode, Mode::Normal);
+    len
+}
+
+pub fn part1(input: &str) -> usize {
+    decompressed_length(input, false)
+}
+
+pub fn part2(input: &str) -> usize {
+    decompressed_length(input, true)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        Ok(puzzle_input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1("ADVENT"), "ADVENT".len());
+        assert_eq!(part1("A(1x5)BC"), "ABBBBBC".len());
+        assert_eq!(part1("(3x3)XYZ"), "XYZXYZXYZ".len());
+        assert_eq!(part1("A(2x2)BCD(2x2)EFG"), "ABCBCDEFEFG".len());
+        assert_eq!(part1("(6x1)(1x3)A"), "(1x3)A".len());
+        assert_eq!(part1("X(8x2)(3x3)ABCY"), "X(3x3)ABC(3x3)ABCY".len());
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2("(3x3)XYZ"), "XYZXYZXYZ".len());
+        assert_eq!(part2("X(8x2)(3x3)ABCY"), "XABCABCABCABCABCABCY".len());
+        assert_eq!(part2("(27x12)(20x12)(13x14)(7x10)(1x12)A"), 241920);
+        assert_eq!(part2("(25x3)(3x3)ABC(2x3)XY(5x2)PQRSTX(18x9)(3x2)TWO(5x7)SEVEN"), 445);
+    }
+}
@@ -0,0 +1,68 @@
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    parsing::single_line(input)?.map_chars(|ch| ch.to_digit(10).map(|d| d as usize))
+}
+
+pub fn part1(digits: &[usize]) -> usize {
+    let mut sum = 0;
+    for i in 0..digits.len() {
+        if digits[i] == digits[(i + 1) % digits.len()] {
+            sum += digits[i];
+        }
+    }
+    sum
+}
+
+pub fn part2(digits: &[usize]) -> usize {
+    let mut sum = 0;
+    for i in 0..digits.len() {
+        if digits[i] == digits[(i + digits.len()/2) % digits.len()] {
+            sum += digits[i];
+        }
+    }
+    sum
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse("1122").unwrap()), 3);
+        assert_eq!(part1(&parse("1111").unwrap()), 4);
+        assert_eq!(part1(&parse("1234").unwrap()), 0);
+        assert_eq!(part1(&parse("91212129").unwrap()), 9);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse("1212").unwrap()), 6);
+        assert_eq!(part2(&parse("1221").unwrap()), 0);
+        assert_eq!(part2(&parse("123425").unwrap()), 4);
+        assert_eq!(part2(&parse("123123").unwrap()), 12);
+        assert_eq!(part2(&parse("12131415").unwrap()), 4);
+    }
+}
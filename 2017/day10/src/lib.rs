@@ -1,3 +1,8 @@
+use std::fmt::Write;
+
+use parsing::ParseError;
+use solution::Solution;
+
 use crate::reverse_circular::reverse_circular;
 
 mod reverse_circular;
@@ -34,3 +39,75 @@ pub fn knot_hash(input: &str) -> Vec<u8> {
         .collect()
 }
 
+// Part 1 reads the input as a list of lengths, part 2 hashes its text
+pub fn parse(input: &str) -> Result<(Vec<usize>, String), ParseError> {
+    let lengths = parsing::single_line(input)?.split(",").iter()
+        .map(|len_str| len_str.parse())
+        .collect::<Result<_, _>>()?;
+    Ok((lengths, input.to_string()))
+}
+
+pub fn part1(lengths: &[usize]) -> usize {
+    let mut list = Vec::new();
+    for i in 0 ..= 255 { list.push(i) }
+
+    let mut curr = 0;
+    let mut skip = 0;
+    for &length in lengths {
+        reverse_circular(&mut list, curr, length);
+        curr += length + skip;
+        skip += 1;
+    }
+
+    list[0] * list[1]
+}
+
+pub fn part2(input: &str) -> String {
+    let hash_bytes = knot_hash(input);
+
+    let mut out = String::new();
+    for byte in hash_bytes.iter() {
+        write!(out, "{:02x}", byte).unwrap();
+    }
+
+    out
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<usize>, String);
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1((lengths, _): &Self::Input) -> usize {
+        part1(lengths)
+    }
+
+    fn part2((_, text): &Self::Input) -> String {
+        part2(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(""), "a2582a3a0e66e6e86e3812dcb672a272".to_string());
+        assert_eq!(part2("AoC 2017"), "33efeb34ea91902bb2f59c9920caa6cd".to_string());
+        assert_eq!(part2("1,2,3"), "3efbe78a8d82f29979031a4aa0b16a9d".to_string());
+        assert_eq!(part2("1,2,4"), "63960835bcdc130f0b66d7ff4f6a5a8e".to_string());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(parse("3,4,1,5").unwrap().0, vec![3, 4, 1, 5]);
+        assert_eq!(parse("3,4,,5").err().unwrap().column, 5);
+    }
+}
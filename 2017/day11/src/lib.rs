@@ -0,0 +1,195 @@
+use std::collections::BTreeMap;
+use std::cmp::min;
+use std::str::FromStr;
+
+use parsing::ParseError;
+use solution::Solution;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Dir {
+    NW, N, NE,
+    SW, S, SE
+}
+
+impl FromStr for Dir {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Dir, Self::Err> {
+        match s {
+            "nw" => Ok(Dir::NW),
+            "n"  => Ok(Dir::N),
+            "ne" => Ok(Dir::NE),
+            "sw" => Ok(Dir::SW),
+            "s"  => Ok(Dir::S),
+            "se" => Ok(Dir::SE),
+            _    => Err("expected one of n, ne, se, s, sw or nw")
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Dir>, ParseError> {
+    parsing::single_line(input)?.split(",").iter()
+        .map(|dir| dir.parse())
+        .collect()
+}
+
+// Any steps can be transposed and will reach the same destination, so just having the counts of
+// each direction completely represents the end
+fn dir_counts(steps: &[Dir]) -> BTreeMap<Dir, usize> {
+    let mut counts: BTreeMap<Dir, usize> = BTreeMap::new();
+    counts.insert(Dir::NW, 0);
+    counts.insert(Dir::N, 0);
+    counts.insert(Dir::NE, 0);
+    counts.insert(Dir::SW, 0);
+    counts.insert(Dir::S, 0);
+    counts.insert(Dir::SE, 0);
+    for step in steps {
+        counts.entry(*step).and_modify(|cnt| *cnt += 1);
+    }
+    counts
+}
+
+fn distance(counts: &BTreeMap<Dir, usize>) -> usize {
+    counts.values().sum()
+}
+
+// Returns whether any were cancelled
+fn cancel_opposites(counts: &mut BTreeMap<Dir, usize>) -> bool {
+    let orig_len = distance(counts);
+
+    if counts[&Dir::N] > counts[&Dir::S] {
+        let num = counts[&Dir::N] - counts[&Dir::S];
+        counts.insert(Dir::N, num);
+        counts.insert(Dir::S, 0);
+    } else {
+        let num = counts[&Dir::S] - counts[&Dir::N];
+        counts.insert(Dir::S, num);
+        counts.insert(Dir::N, 0);
+    }
+
+    if counts[&Dir::NW] > counts[&Dir::SE] {
+        let num = counts[&Dir::NW] - counts[&Dir::SE];
+        counts.insert(Dir::NW, num);
+        counts.insert(Dir::SE, 0);
+    } else {
+        let num = counts[&Dir::SE] - counts[&Dir::NW];
+        counts.insert(Dir::SE, num);
+        counts.insert(Dir::NW, 0);
+    }
+
+    if counts[&Dir::NE] > counts[&Dir::SW] {
+        let num = counts[&Dir::NE] - counts[&Dir::SW];
+        counts.insert(Dir::NE, num);
+        counts.insert(Dir::SW, 0);
+    } else {
+        let num = counts[&Dir::SW] - counts[&Dir::NE];
+        counts.insert(Dir::SW, num);
+        counts.insert(Dir::NE, 0);
+    }
+
+    orig_len != distance(counts)
+}
+
+// Returns whether any were cancelled
+fn cancel_one_step_off(counts: &mut BTreeMap<Dir, usize>) -> bool {
+    let orig_len = distance(counts);
+    {
+        let num = min(counts[&Dir::NW], counts[&Dir::NE]);
+        counts.entry(Dir::N).and_modify(|cnt| *cnt += num);
+        counts.entry(Dir::NW).and_modify(|cnt| *cnt -= num);
+        counts.entry(Dir::NE).and_modify(|cnt| *cnt -= num);
+    }
+    {
+        let num = min(counts[&Dir::N], counts[&Dir::SE]);
+        counts.entry(Dir::NE).and_modify(|cnt| *cnt += num);
+        counts.entry(Dir::N).and_modify(|cnt| *cnt -= num);
+        counts.entry(Dir::SE).and_modify(|cnt| *cnt -= num);
+    }
+    {
+        let num = min(counts[&Dir::NE], counts[&Dir::S]);
+        counts.entry(Dir::SE).and_modify(|cnt| *cnt += num);
+        counts.entry(Dir::NE).and_modify(|cnt| *cnt -= num);
+        counts.entry(Dir::S).and_modify(|cnt| *cnt -= num);
+    }
+    {
+        let num = min(counts[&Dir::SW], counts[&Dir::SE]);
+        counts.entry(Dir::S).and_modify(|cnt| *cnt += num);
+        counts.entry(Dir::SW).and_modify(|cnt| *cnt -= num);
+        counts.entry(Dir::SE).and_modify(|cnt| *cnt -= num);
+    }
+    {
+        let num = min(counts[&Dir::S], counts[&Dir::NW]);
+        counts.entry(Dir::SW).and_modify(|cnt| *cnt += num);
+        counts.entry(Dir::S).and_modify(|cnt| *cnt -= num);
+        counts.entry(Dir::NW).and_modify(|cnt| *cnt -= num);
+    }
+    {
+        let num = min(counts[&Dir::SW], counts[&Dir::N]);
+        counts.entry(Dir::NW).and_modify(|cnt| *cnt += num);
+        counts.entry(Dir::SW).and_modify(|cnt| *cnt -= num);
+        counts.entry(Dir::N).and_modify(|cnt| *cnt -= num);
+    }
+
+    orig_len != distance(counts)
+}
+
+fn distance_from_start(steps: &[Dir]) -> usize {
+    let mut counts = dir_counts(steps);
+    loop {
+        cancel_opposites(&mut counts);
+        if cancel_one_step_off(&mut counts) { continue }
+        break;
+    }
+    distance(&counts)
+}
+
+pub fn part1(steps: &[Dir]) -> usize {
+    distance_from_start(steps)
+}
+
+pub fn part2(steps: &[Dir]) -> usize {
+    (0..steps.len()).map(|i| {
+        distance_from_start(&steps[0..i])
+    })
+    .max().unwrap()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Dir>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse("ne,ne,ne").unwrap()), 3);
+        assert_eq!(part1(&parse("ne,ne,sw,sw").unwrap()), 0);
+        assert_eq!(part1(&parse("ne,ne,s,s").unwrap()), 2);
+        assert_eq!(part1(&parse("se,sw,se,sw,sw").unwrap()), 3);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("ne,ne,up").unwrap_err();
+        assert_eq!((err.column, err.message.as_str()), (7, "invalid value \"up\": expected one of n, ne, se, s, sw or nw"));
+    }
+}
@@ -0,0 +1,112 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use lazy_static::lazy_static;
+use parsing::ParseError;
+use regex::Regex;
+use solution::Solution;
+
+pub fn parse(input: &str) -> Result<BTreeMap<usize, BTreeSet<usize>>, ParseError> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new("^(\\d+) <-> ((\\d+(, )?)+)$").unwrap();
+    }
+    let mut pipes = BTreeMap::new();
+    let mut others = Vec::new();
+    for line in parsing::lines(input) {
+        let cap = RE.captures(line.text).ok_or_else(|| line.error(1, "expected e.g. \"2 <-> 0, 3, 4\""))?;
+        let id = line.field(&cap[1]).parse()?;
+        let fields = line.field(cap.get(2).unwrap().as_str()).split(", ");
+        let ids = fields.iter().map(|o| o.parse()).collect::<Result<Vec<usize>, ParseError>>()?;
+        others.extend(fields.into_iter().zip(ids.iter().copied()));
+        pipes.insert(id, ids.into_iter().collect());
+    }
+    if let Some((field, _)) = others.iter().find(|(_, other)| !pipes.contains_key(other)) {
+        return Err(field.error(format!("no program has id {}", field.text)));
+    }
+    Ok(pipes)
+}
+
+pub fn part1(pipes: &BTreeMap<usize, BTreeSet<usize>>) -> usize {
+    let mut group: BTreeSet<usize> = BTreeSet::new();
+    let mut queue = VecDeque::new();
+    queue.push_back(0);
+
+    while let Some(id) = queue.pop_front() {
+        if group.contains(&id) { continue }
+        group.insert(id);
+
+        for other in pipes[&id].iter() {
+            queue.push_back(*other);
+        }
+    }
+
+    group.len()
+}
+
+pub fn part2(pipes: &BTreeMap<usize, BTreeSet<usize>>) -> usize {
+    let pids: Vec<usize> = pipes.keys().map(|id| *id).collect();
+    let mut seen = BTreeSet::new();
+    let mut num_groups = 0;
+    let mut queue = VecDeque::new();
+
+    for starting_id in pids {
+        if seen.contains(&starting_id) { continue }
+        num_groups += 1;
+
+        queue.push_back(starting_id);
+        while let Some(id) = queue.pop_front() {
+            if seen.contains(&id) { continue }
+            seen.insert(id);
+            for other in pipes[&id].iter() {
+                queue.push_back(*other);
+            }
+        }
+    }
+
+    num_groups
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = BTreeMap<usize, BTreeSet<usize>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "\
+0 <-> 2
+1 <-> 1
+2 <-> 0, 3, 4
+3 <-> 2, 4
+4 <-> 2, 3, 6
+5 <-> 6
+6 <-> 4, 5";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 6);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 2);
+    }
+
+}
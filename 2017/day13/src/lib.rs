@@ -0,0 +1,121 @@
+use std::collections::BTreeMap;
+
+use lazy_static::lazy_static;
+use parsing::ParseError;
+use regex::Regex;
+use solution::Solution;
+
+pub fn parse_scanner_ranges(input: &str) -> Result<BTreeMap<usize, usize>, ParseError> {
+    lazy_static!{
+        static ref RE: Regex = Regex::new("^(\\d+): (\\d+)$").unwrap();
+    }
+    let ranges = parsing::parse_lines(input, |line| {
+        let cap = RE.captures(line.text).ok_or_else(|| line.error(1, "expected e.g. \"0: 3\""))?;
+        let range = line.field(&cap[2]).parse()?;
+        if range == 0 {
+            return Err(line.field(&cap[2]).error("expected a range of at least 1"));
+        }
+        Ok((line.field(&cap[1]).parse()?, range))
+    })?;
+    Ok(ranges.into_iter().collect())
+}
+
+fn update_scanner_positions(scanner_positions: &mut BTreeMap<usize, (usize, isize)>,
+                            scanner_ranges: &BTreeMap<usize, usize>) {
+    scanner_positions.iter_mut()
+        .for_each(|(scanner, (position, direction))| {
+            let range = scanner_ranges[scanner];
+            if range == 1 {
+                // Always at position 0
+            } else if *position == 0 {
+                *position = 1;
+                *direction = 1;
+            } else if *position == range - 1 {
+                *position = range - 2;
+                *direction = -1;
+            } else {
+                *position = (*position as isize + *direction) as usize;
+            }
+        });
+}
+
+pub fn part1(scanner_ranges: &BTreeMap<usize, usize>) -> usize {
+    let mut scanner_positions: BTreeMap<usize, (usize, isize)> = scanner_ranges.keys().map(|scanner| (*scanner, (0, 1))).collect();
+    let mut severity = 0;
+
+    for pos in 0 ..= *scanner_ranges.keys().max().unwrap() {
+        if scanner_positions.get(&pos).map(|p| p.0 == 0).unwrap_or(false) {
+            severity += pos * scanner_ranges[&pos];
+        }
+
+        update_scanner_positions(&mut scanner_positions, scanner_ranges);
+    }
+
+    severity
+}
+
+pub fn part2(scanner_ranges: &BTreeMap<usize, usize>) -> usize {
+    let mut scanner_positions: BTreeMap<usize, (usize, isize)> = scanner_ranges.keys().map(|scanner| (*scanner, (0, 1))).collect();
+    let mut my_positions = BTreeMap::new();
+    let max_pos = *scanner_ranges.keys().max().unwrap();
+
+    for time in 0.. {
+        for (_, pos) in my_positions.iter_mut() {
+            *pos += 1;
+        }
+        my_positions.insert(time, 0);
+
+        for start in my_positions.keys().map(|x| *x).collect::<Vec<usize>>() {
+            if scanner_positions.get(&my_positions[&start]).map(|p| p.0 == 0).unwrap_or(false) {
+                my_positions.remove(&start);
+            } else if my_positions[&start] > max_pos {
+                return start
+            }
+        }
+
+        update_scanner_positions(&mut scanner_positions, scanner_ranges);
+    }
+    unreachable!()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = BTreeMap<usize, usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse_scanner_ranges(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "\
+0: 3
+1: 2
+4: 4
+6: 4";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse_scanner_ranges(EX).unwrap()), 24);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse_scanner_ranges(EX).unwrap()), 10);
+    }
+
+}
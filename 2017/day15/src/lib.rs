@@ -0,0 +1,131 @@
+use lazy_static::lazy_static;
+use parsing::{Line, ParseError};
+use regex::Regex;
+use solution::Solution;
+
+const MUL_A: u64 = 16807;
+const MUL_B: u64 = 48271;
+const DIV: u64 = 2147483647;
+
+pub fn parse(input: &str) -> Result<(u64, u64), ParseError> {
+    lazy_static!{
+        static ref RE: Regex = Regex::new("starts with (\\d+)$").unwrap();
+    }
+    let start_state = |line: &Line| -> Result<u64, ParseError> {
+        let cap = RE.captures(line.text).ok_or_else(|| line.error(1, "expected e.g. \"Generator A starts with 65\""))?;
+        line.field(&cap[1]).parse()
+    };
+    match parsing::lines(input)[..] {
+        [a, b] => Ok((start_state(&a)?, start_state(&b)?)),
+        [_, _, extra, ..] => Err(extra.error(1, "expected only two generators")),
+        ref lines => Err(Line { number: lines.len() + 1, text: "" }.error(1, "expected two generators")),
+    }
+}
+
+fn next_value(value: u64, mul: u64) -> u64 {
+    (value * mul) % DIV
+}
+
+// The value n steps on, since each step just multiplies by mul
+fn skip(mut value: u64, mul: u64, mut n: u64) -> u64 {
+    let mut pow = mul;
+    while n > 0 {
+        if n & 1 == 1 { value = (value * pow) % DIV; }
+        pow = (pow * pow) % DIV;
+        n >>= 1;
+    }
+    value
+}
+
+fn count_matches(mut a: u64, mut b: u64, pairs: u64) -> usize {
+    let mut count = 0;
+    for _ in 0..pairs {
+        a = next_value(a, MUL_A);
+        b = next_value(b, MUL_B);
+
+        if (a & 0xffff) == (b & 0xffff) {
+            count += 1;
+        }
+    }
+    count
+}
+
+const PART1_PAIRS: u64 = 40000000;
+const PART1_CHUNKS: u64 = 64;
+
+// Skipping ahead splits the pairs into chunks that can be checked in parallel
+fn calc_part1(a: u64, b: u64) -> usize {
+    let chunk = PART1_PAIRS / PART1_CHUNKS;
+    let chunk_starts: Vec<u64> = (0..PART1_CHUNKS).map(|i| i * chunk).collect();
+    parallel::map(&chunk_starts, |&n| count_matches(skip(a, MUL_A, n), skip(b, MUL_B, n), chunk))
+        .into_iter()
+        .sum()
+}
+
+const PART2_PAIRS: usize = 5000000;
+
+// The low 16 bits of the first n values that are multiples of `multiple`
+fn judged_values(mut value: u64, mul: u64, multiple: u64, n: usize) -> Vec<u16> {
+    let mut ret = Vec::with_capacity(n);
+    while ret.len() < n {
+        value = next_value(value, mul);
+        if value.is_multiple_of(multiple) {
+            ret.push(value as u16);
+        }
+    }
+    ret
+}
+
+// Which values get paired depends on how many the other generator skipped, so instead the two
+// generators run in parallel
+fn calc_part2(a: u64, b: u64) -> usize {
+    let values = parallel::map(&[(a, MUL_A, 4), (b, MUL_B, 8)], |&(start, mul, multiple)| {
+        judged_values(start, mul, multiple, PART2_PAIRS)
+    });
+    values[0].iter().zip(&values[1]).filter(|(a, b)| a == b).count()
+}
+
+pub fn part1((a, b): (u64, u64)) -> usize {
+    calc_part1(a, b)
+}
+
+pub fn part2((a, b): (u64, u64)) -> usize {
+    calc_part2(a, b)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (u64, u64);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(*input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(*input)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(calc_part1(65, 8921), 588);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(calc_part2(65, 8921), 309);
+    }
+
+}
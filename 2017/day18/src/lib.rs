@@ -0,0 +1,337 @@
+use std::collections::VecDeque;
+
+use lazy_static::lazy_static;
+use parsing::ParseError;
+use regex::Regex;
+use register_machine::{Flow, Instruction, Machine, Registers, Status};
+use solution::Solution;
+
+pub type Operand = register_machine::Operand<char, i64>;
+
+#[derive(Copy, Clone, Debug)]
+pub enum Cmd {
+    Snd(Operand),
+    Rcv(char),
+    Set(char, Operand),
+    Add(char, Operand),
+    Mul(char, Operand),
+    Mod(char, Operand),
+    Jgz(Operand, Operand)
+}
+
+pub fn parse(input: &str) -> Result<Vec<Cmd>, ParseError> {
+    lazy_static!{
+        static ref UNARY_RE: Regex = Regex::new("^(snd|rcv) ([a-z]|-?[0-9]+)$").unwrap();
+        static ref BINARY_RE: Regex = Regex::new("^(set|add|mul|mod|jgz) ([a-z]|-?[0-9]+) ([a-z]|-?[0-9]+)$").unwrap();
+    }
+    parsing::parse_lines(input, |line| {
+        let reg = |operand: &str| match operand.chars().next() {
+            Some(ch) if ch.is_ascii_lowercase() => Ok(ch),
+            _ => Err(line.field(operand).error("expected a register")),
+        };
+        Ok(if let Some(cap) = UNARY_RE.captures(line.text) {
+            if &cap[1] == "snd" {
+                Cmd::Snd(line.field(&cap[2]).parse()?)
+            } else if &cap[1] == "rcv" {
+                Cmd::Rcv(reg(&cap[2])?)
+            } else {
+                unreachable!()
+            }
+        } else if let Some(cap) = BINARY_RE.captures(line.text) {
+            if &cap[1] == "set" {
+                Cmd::Set(reg(&cap[2])?, line.field(&cap[3]).parse()?)
+            } else if &cap[1] == "add" {
+                Cmd::Add(reg(&cap[2])?, line.field(&cap[3]).parse()?)
+            } else if &cap[1] == "mul" {
+                Cmd::Mul(reg(&cap[2])?, line.field(&cap[3]).parse()?)
+            } else if &cap[1] == "mod" {
+                Cmd::Mod(reg(&cap[2])?, line.field(&cap[3]).parse()?)
+            } else if &cap[1] == "jgz" {
+                Cmd::Jgz(line.field(&cap[2]).parse()?, line.field(&cap[3]).parse()?)
+            } else {
+                unreachable!()
+            }
+        } else {
+            return Err(line.error(1, "expected an instruction like \"set a 1\""));
+        })
+    })
+}
+
+// How snd/rcv are interpreted, everything else is the same for every use of the instructions
+pub trait DuetIo {
+    fn snd(&mut self, val: i64);
+    // Given the current value of the register, returns its new value or None to block
+    fn rcv(&mut self, val: i64) -> Option<i64>;
+}
+
+impl<Io: DuetIo> Instruction<Registers<char, i64>, Io> for Cmd {
+    fn execute(&self, _pc: usize, registers: &mut Registers<char, i64>, io: &mut Io) -> Flow {
+        match *self {
+            Cmd::Snd(x) => io.snd(x.value(registers)),
+            Cmd::Rcv(x) => match io.rcv(registers.get(&x)) {
+                Some(val) => registers.set(x, val),
+                None => return Flow::Block
+            },
+            Cmd::Set(x, y) => registers.set(x, y.value(registers)),
+            Cmd::Add(x, y) => {
+                let val = registers.get(&x).checked_add(y.value(registers)).unwrap();
+                registers.set(x, val);
+            },
+            Cmd::Mul(x, y) => {
+                let val = registers.get(&x).checked_mul(y.value(registers)).unwrap();
+                registers.set(x, val);
+            },
+            Cmd::Mod(x, y) => {
+                let val = registers.get(&x) % y.value(registers);
+                registers.set(x, val);
+            },
+            Cmd::Jgz(x, y) => if x.value(registers) > 0 { return Flow::JumpRelative(y.value(registers)) }
+        }
+        Flow::Next
+    }
+}
+
+struct Port<'a> {
+    inbox: &'a mut VecDeque<i64>,
+    sent: Option<i64>
+}
+
+impl DuetIo for Port<'_> {
+    fn snd(&mut self, val: i64) {
+        self.sent = Some(val);
+    }
+
+    fn rcv(&mut self, _val: i64) -> Option<i64> {
+        self.inbox.pop_front()
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DuetStatus {
+    Running,
+    // Every program that hasn't halted is waiting on rcv with an empty queue
+    Deadlocked,
+    Halted
+}
+
+// Programs running cooperatively, each with its own registers and with register p set to its id.
+// Sends go to the target program's queue and rcv takes from the program's own queue.
+pub struct Scheduler<'a> {
+    programs: Vec<&'a [Cmd]>,
+    machines: Vec<Machine<Registers<char, i64>>>,
+    queues: Vec<VecDeque<i64>>,
+    targets: Vec<usize>,
+    send_counts: Vec<usize>
+}
+
+impl<'a> Scheduler<'a> {
+    // One program per slot. Program i sends to program i+1, wrapping around, so two programs send
+    // to each other until connect says otherwise.
+    pub fn new(programs: &[&'a [Cmd]]) -> Self {
+        let count = programs.len();
+        Scheduler {
+            programs: programs.to_vec(),
+            machines: (0..count).map(|id| Machine::new(vec![('p', id as i64)].into_iter().collect())).collect(),
+            queues: vec![VecDeque::new(); count],
+            targets: (0..count).map(|id| (id + 1) % count).collect(),
+            send_counts: vec![0; count]
+        }
+    }
+
+    pub fn connect(&mut self, from: usize, to: usize) {
+        self.targets[from] = to;
+    }
+
+    pub fn send_counts(&self) -> &[usize] {
+        &self.send_counts
+    }
+
+    pub fn registers(&self, id: usize) -> &Registers<char, i64> {
+        &self.machines[id].registers
+    }
+
+    pub fn queue(&self, id: usize) -> &VecDeque<i64> {
+        &self.queues[id]
+    }
+
+    // Executes at most one instruction of each program, in order of id
+    pub fn step(&mut self) -> DuetStatus {
+        let mut progress = false;
+        let mut all_halted = true;
+        for id in 0..self.machines.len() {
+            let mut port = Port { inbox: &mut self.queues[id], sent: None };
+            match self.machines[id].step(self.programs[id], &mut port) {
+                Status::Running => progress = true,
+                Status::Blocked => (),
+                Status::Halted => continue,
+                Status::Looped => unreachable!()
+            }
+            all_halted = false;
+            if let Some(val) = port.sent {
+                self.queues[self.targets[id]].push_back(val);
+                self.send_counts[id] += 1;
+            }
+        }
+        if progress {
+            DuetStatus::Running
+        } else if all_halted {
+            DuetStatus::Halted
+        } else {
+            DuetStatus::Deadlocked
+        }
+    }
+
+    pub fn run(&mut self) -> DuetStatus {
+        loop {
+            match self.step() {
+                DuetStatus::Running => (),
+                status => return status
+            }
+        }
+    }
+}
+
+struct SoundCard {
+    sound: i64,
+    recovered: Option<i64>
+}
+
+impl DuetIo for SoundCard {
+    fn snd(&mut self, val: i64) {
+        self.sound = val;
+    }
+
+    // Blocks once a sound is recovered, which stops the machine there
+    fn rcv(&mut self, val: i64) -> Option<i64> {
+        if val != 0 {
+            self.recovered = Some(self.sound);
+            return None;
+        }
+        Some(val)
+    }
+}
+
+// None if the program halts without ever recovering a sound
+pub fn part1(instructions: &[Cmd]) -> Option<i64> {
+    let mut machine = Machine::new(Registers::new());
+    let mut sound_card = SoundCard { sound: 0, recovered: None };
+    match machine.run(instructions, &mut sound_card) {
+        Status::Blocked => sound_card.recovered,
+        _ => None
+    }
+}
+
+pub fn part2(instructions: &[Cmd]) -> usize {
+    let mut duet = Scheduler::new(&[instructions, instructions]);
+    assert_eq!(duet.run(), DuetStatus::Deadlocked);
+    duet.send_counts()[1]
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Cmd>;
+    type Answer1 = i64;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        part1(input).expect("The program halted without recovering a sound")
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "\
+snd 1
+snd 2
+snd p
+rcv a
+rcv b
+rcv c
+rcv d";
+
+    const EX1: &str = "\
+set a 1
+add a 2
+mul a a
+mod a 5
+snd a
+set a 0
+rcv a
+jgz a -1
+set a 1
+jgz a -2";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX1).unwrap()), Some(4));
+        assert_eq!(part1(&parse("set a 1\nsnd a\nrcv b").unwrap()), None);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 3);
+    }
+
+    #[test]
+    fn test_deadlock() {
+        let program = parse(EX).unwrap();
+        let mut duet = Scheduler::new(&[&program, &program]);
+        assert_eq!(duet.step(), DuetStatus::Running);
+        assert_eq!(duet.send_counts(), &[1, 1]);
+        assert_eq!(duet.run(), DuetStatus::Deadlocked);
+        assert_eq!(duet.send_counts(), &[3, 3]);
+        assert_eq!(duet.registers(0).get(&'c'), 1);
+        assert_eq!(duet.registers(1).get(&'c'), 0);
+        assert!(duet.queue(0).is_empty() && duet.queue(1).is_empty());
+    }
+
+    #[test]
+    fn test_ring() {
+        // Each program passes along what it receives plus its id, except program 0 which starts
+        // the chain and stops once it comes back around
+        let program = parse("\
+jgz p 4
+snd 100
+rcv a
+jgz 1 10
+rcv a
+add a p
+snd a").unwrap();
+        let mut duet = Scheduler::new(&[&program, &program, &program]);
+        assert_eq!(duet.run(), DuetStatus::Halted);
+        assert_eq!(duet.send_counts(), &[1, 1, 1]);
+        assert_eq!(duet.registers(0).get(&'a'), 103);
+    }
+
+    #[test]
+    fn test_connect() {
+        // The producer feeds the doubler, which feeds the collector, against the default ring order
+        let producer = parse("snd 1\nsnd 2\nsnd 3").unwrap();
+        let doubler = parse("rcv a\nmul a 2\nsnd a\njgz 1 -3").unwrap();
+        let collector = parse("rcv a\nadd b a\njgz 1 -2").unwrap();
+        let mut duet = Scheduler::new(&[&producer, &collector, &doubler]);
+        duet.connect(0, 2);
+        duet.connect(2, 1);
+        assert_eq!(duet.run(), DuetStatus::Deadlocked);
+        assert_eq!(duet.send_counts(), &[3, 0, 3]);
+        assert_eq!(duet.registers(1).get(&'b'), 12);
+        assert!(duet.queue(0).is_empty());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("snd 1\nset 1 2").unwrap_err();
+        assert_eq!((err.line, err.column, err.message.as_str()), (2, 5, "expected a register"));
+    }
+}
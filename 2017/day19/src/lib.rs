@@ -0,0 +1,120 @@
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+pub fn parse(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    let lines = parsing::lines(input);
+    let matrix = parsing::grid(&lines, |ch| matches!(ch, '|' | '-' | '+' | ' ' | 'A'..='Z').then_some(ch))?;
+    if !matrix.first().is_some_and(|row| row.contains(&'|')) {
+        let line = lines.first().copied().unwrap_or(Line { number: 1, text: "" });
+        return Err(line.error(1, "expected the path to start with a '|' on the first line"));
+    }
+    Ok(matrix)
+}
+
+fn start_x(matrix: &[Vec<char>]) -> usize {
+    for (i,ch) in matrix[0].iter().enumerate() {
+        if *ch == '|' {
+            return i
+        }
+    }
+    unreachable!()
+}
+
+fn step(x: &mut usize, y: &mut usize, dir_x: isize, dir_y: isize) {
+    *x = (*x as isize + dir_x) as usize;
+    *y = (*y as isize + dir_y) as usize;
+}
+
+fn solve(matrix: &[Vec<char>]) -> (String, usize) {
+    let mut path = String::new();
+
+    let width = matrix[0].len();
+    let height = matrix.len();
+    let mut x = start_x(matrix);
+    let mut y = 0;
+    let mut dir_x = 0;
+    let mut dir_y = 1;
+    let mut steps = 0;
+
+    loop {
+        match matrix[y][x] {
+            '|' | '-' => step(&mut x, &mut y, dir_x, dir_y),
+            ch @ 'A'...'Z' => {
+                path.push(ch);
+                step(&mut x, &mut y, dir_x, dir_y);
+            },
+            ' ' => break,
+            '+' => {
+                if dir_x != 0 && dir_y != 1 && y+1 < height && matrix[y+1][x] != ' ' {
+                    dir_x = 0; dir_y = 1;
+                } else if dir_x != 0 && dir_y != -1 && y > 0 && matrix[y-1][x] != ' ' {
+                    dir_x = 0; dir_y = -1;
+                } else if dir_x != 1 && dir_y != 0 && x+1 < width && matrix[y][x+1] != ' ' {
+                    dir_x = 1; dir_y = 0;
+                } else if dir_x != -1 && dir_y != 0 && x > 0 && matrix[y][x-1] != ' ' {
+                    dir_x = -1; dir_y = 0;
+                } else {
+                    unreachable!()
+                }
+                step(&mut x, &mut y, dir_x, dir_y);
+            },
+            _ => unreachable!()
+        }
+        steps += 1;
+    }
+
+    (path, steps)
+}
+
+pub fn part1(matrix: &[Vec<char>]) -> String {
+    solve(matrix).0
+}
+
+pub fn part2(matrix: &[Vec<char>]) -> usize {
+    solve(matrix).1
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<char>>;
+    type Answer1 = String;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str =
+"     |          
+     |  +--+    
+     A  |  C    
+ F---|----E|--+ 
+     |  |  |  D 
+     +B-+  +--+ ";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), "ABCDEF".to_string());
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 38);
+    }
+
+}
@@ -0,0 +1,80 @@
+use std::cmp::{min, max};
+
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse(input: &str) -> Result<Vec<Vec<usize>>, ParseError> {
+    parsing::parse_lines(input, |line| line.fields().iter().map(|field| field.parse()).collect())
+}
+
+pub fn part1(rows: &[Vec<usize>]) -> usize {
+    rows.iter()
+        .map(|row| row.iter().max().unwrap() - row.iter().min().unwrap())
+        .sum()
+}
+
+fn get_divisble_result(row: &Vec<usize>) -> usize {
+    for i in 0..row.len() {
+        for j in (i+1)..row.len() {
+            let x = min(row[i], row[j]);
+            let y = max(row[i], row[j]);
+
+            if y == y / x * x {
+                return y / x
+            }
+        }
+    }
+    unreachable!();
+}
+
+pub fn part2(rows: &[Vec<usize>]) -> usize {
+    rows.iter()
+        .map(get_divisble_result)
+        .sum()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<usize>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let ex = "\
+5 1 9 5
+7 5 3
+2 4 6 8";
+
+        assert_eq!(part1(&parse(ex).unwrap()), 18);
+    }
+
+    #[test]
+    fn test_part2() {
+        let ex = "\
+5 9 2 8
+9 4 7 3
+3 8 6 5";
+        assert_eq!(part2(&parse(ex).unwrap()), 9);
+    }
+
+}
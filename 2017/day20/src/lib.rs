@@ -0,0 +1,122 @@
+use std::collections::BTreeMap;
+
+use lazy_static::lazy_static;
+use parsing::ParseError;
+use regex::Regex;
+use solution::Solution;
+
+#[derive(Clone)]
+pub struct Particle {
+    position: (i64, i64, i64),
+    velocity: (i64, i64, i64),
+    acceleration: (i64, i64, i64)
+}
+
+pub fn parse(input: &str) -> Result<BTreeMap<usize, Particle>, ParseError> {
+    lazy_static!{
+        static ref RE: Regex = Regex::new("^p=<([0-9-]+),([0-9-]+),([0-9-]+)>, v=<([0-9-]+),([0-9-]+),([0-9-]+)>, a=<([0-9-]+),([0-9-]+),([0-9-]+)>$").unwrap();
+    }
+
+    let particles = parsing::parse_lines(input, |line| {
+        let cap = RE.captures(line.text)
+            .ok_or_else(|| line.error(1, "expected e.g. \"p=<3,0,0>, v=<2,0,0>, a=<-1,0,0>\""))?;
+        let triple = |i: usize| -> Result<(i64, i64, i64), ParseError> {
+            Ok((line.field(&cap[i]).parse()?, line.field(&cap[i + 1]).parse()?, line.field(&cap[i + 2]).parse()?))
+        };
+        Ok(Particle {
+            position: triple(1)?,
+            velocity: triple(4)?,
+            acceleration: triple(7)?
+        })
+    })?;
+    Ok(particles.into_iter().enumerate().collect())
+}
+
+fn step_particle(p: &mut Particle) {
+    p.velocity.0 += p.acceleration.0;
+    p.velocity.1 += p.acceleration.1;
+    p.velocity.2 += p.acceleration.2;
+
+    p.position.0 += p.velocity.0;
+    p.position.1 += p.velocity.1;
+    p.position.2 += p.velocity.2;
+}
+
+pub fn part1(particles: &BTreeMap<usize, Particle>) -> usize {
+    let mut particles = particles.clone();
+    for _ in 0..1000 { // arbitrary upper bound
+        for p in particles.values_mut() {
+            step_particle(p);
+        }
+    }
+
+    *particles.iter()
+        .min_by_key(|(_,p)| p.position.0.abs() + p.position.1.abs() + p.position.2.abs())
+        .unwrap().0
+}
+
+pub fn part2(particles: &BTreeMap<usize, Particle>) -> usize {
+    let mut particles = particles.clone();
+    for _ in 0..1000 { // arbitrary upper bound
+        for p in particles.values_mut() {
+            step_particle(p);
+        }
+        let mut seen = BTreeMap::new();
+        for p in particles.values() {
+            seen.entry(p.position).and_modify(|cnt| *cnt += 1).or_insert(1);
+        }
+        for id in particles.keys().cloned().collect::<Vec<usize>>() {
+            if seen[&particles[&id].position] > 1 {
+                particles.remove(&id);
+            }
+        }
+    }
+
+    particles.len()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = BTreeMap<usize, Particle>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let ex = "\
+p=<3,0,0>, v=<2,0,0>, a=<-1,0,0>
+p=<4,0,0>, v=<0,0,0>, a=<-2,0,0>";
+        assert_eq!(part1(&parse(ex).unwrap()), 0);
+    }
+
+
+    #[test]
+    fn test_part2() {
+        let ex = "\
+p=<-6,0,0>, v=<3,0,0>, a=<0,0,0>
+p=<-4,0,0>, v=<2,0,0>, a=<0,0,0>
+p=<-2,0,0>, v=<1,0,0>, a=<0,0,0>
+p=<3,0,0>, v=<-1,0,0>, a=<0,0,0>";
+        assert_eq!(part2(&parse(ex).unwrap()), 1);
+    }
+
+}
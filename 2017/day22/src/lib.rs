@@ -0,0 +1,179 @@
+use std::collections::{BTreeSet, BTreeMap};
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+#[derive(Copy, Clone, Debug)]
+enum Status {
+    //Clean, // implied by non-existence in the grid
+    Weakened,
+    Infected,
+    Flagged
+}
+
+pub fn parse(input: &str) -> Result<BTreeSet<(isize,isize)>, ParseError> {
+    let lines = parsing::lines(input);
+    let grid = parsing::grid(&lines, |ch| match ch {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None
+    })?;
+    let size = grid.len();
+    if size % 2 == 0 || grid[0].len() != size {
+        let line = lines.first().copied().unwrap_or(Line { number: 1, text: "" });
+        return Err(line.error(1, "expected a square grid with an odd size, so the carrier starts in the middle"));
+    }
+    let bound = (size as isize - 1) / 2;
+    let mut infected = BTreeSet::new();
+
+    for (y, row) in grid.iter().enumerate() {
+        for (x, &is_infected) in row.iter().enumerate() {
+            if is_infected {
+                infected.insert((x as isize - bound, y as isize - bound));
+            }
+        }
+    }
+    Ok(infected)
+}
+
+fn turn_left(dir: &mut (isize, isize)) {
+    if dir.0 == 0 { // vertical
+        *dir = (dir.1, 0)
+    } else { // horizontal
+        *dir = (0, -dir.0)
+    }
+}
+
+fn turn_right(dir: &mut (isize, isize)) {
+    if dir.0 == 0 { // vertical
+        *dir = (-dir.1, 0)
+    } else { // horizontal
+        *dir = (0, dir.0)
+    }
+}
+
+fn turn_around(dir: &mut (isize, isize)) {
+    *dir = (-dir.0, -dir.1);
+}
+
+// Return if it caused infection
+fn burst_part1(infected: &mut BTreeSet<(isize, isize)>, pos: &mut (isize, isize), dir: &mut (isize, isize)) -> bool {
+    let mut caused_infection = false;
+    if infected.contains(pos) {
+        turn_right(dir);
+        infected.remove(pos);
+    } else {
+        turn_left(dir);
+        infected.insert(*pos);
+        caused_infection = true;
+
+    }
+    pos.0 += dir.0;
+    pos.1 += dir.1;
+
+    caused_infection
+}
+
+pub fn part1(infected: &BTreeSet<(isize,isize)>) -> usize {
+    let mut infected = infected.clone();
+    let mut pos = (0, 0);
+    let mut dir = (0, -1);
+
+    let mut infected_bursts = 0;
+    for _ in 0..10000 {
+        if burst_part1(&mut infected, &mut pos, &mut dir) {
+            infected_bursts += 1;
+        }
+    }
+
+    infected_bursts
+}
+
+// Returns whether it caused infection
+fn burst_part2(grid: &mut BTreeMap<(isize, isize), Status>, pos: &mut (isize, isize), dir: &mut (isize, isize)) -> bool {
+    let mut caused_infection = false;
+    match grid.get(pos).map(|s| *s) {
+        None /* Clean */ => {
+            grid.insert(*pos, Status::Weakened);
+            turn_left(dir);
+        },
+        Some(Status::Weakened) => {
+            grid.insert(*pos, Status::Infected);
+            caused_infection = true;
+        },
+        Some(Status::Infected) => {
+            grid.insert(*pos, Status::Flagged);
+            turn_right(dir);
+        },
+        Some(Status::Flagged) => {
+            grid.remove(pos);
+            turn_around(dir);
+        }
+    }
+    pos.0 += dir.0;
+    pos.1 += dir.1;
+
+    caused_infection
+}
+
+fn calc_part2(infected: &BTreeSet<(isize,isize)>, iterations: usize) -> usize {
+    let mut grid = infected.iter().copied().map(|pos| (pos, Status::Infected)).collect();
+    let mut pos = (0, 0);
+    let mut dir = (0, -1);
+
+    let mut infected_bursts = 0;
+    for _ in 0..iterations {
+        if burst_part2(&mut grid, &mut pos, &mut dir) {
+            infected_bursts += 1;
+        }
+    }
+
+    infected_bursts
+}
+
+pub fn part2(infected: &BTreeSet<(isize,isize)>) -> usize {
+    calc_part2(infected, 10000000)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = BTreeSet<(isize,isize)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "\
+..#
+#..
+...";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 5587);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(calc_part2(&parse(EX).unwrap(), 100), 26);
+        assert_eq!(part2(&parse(EX).unwrap()), 2511944); // Warning: Takes a very long time in debug mode
+    }
+
+}
@@ -0,0 +1,297 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use lazy_static::lazy_static;
+use parsing::ParseError;
+use regex::Regex;
+use register_machine::{Flow, Instruction, Machine, Registers};
+use solution::Solution;
+
+type Operand = register_machine::Operand<char, i64>;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Cmd {
+    Set(char, Operand),
+    Sub(char, Operand),
+    Mul(char, Operand),
+    Mod(char, Operand),
+    Jnz(Operand, Operand),
+    Nop
+}
+
+impl fmt::Display for Cmd {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result  {
+        match *self {
+            Cmd::Set(x, y) => write!(f, "set {} {}", x, y),
+            Cmd::Sub(x, y) => write!(f, "sub {} {}", x, y),
+            Cmd::Mul(x, y) => write!(f, "mul {} {}", x, y),
+            Cmd::Mod(x, y) => write!(f, "mod {} {}", x, y),
+            Cmd::Jnz(x, y) => write!(f, "jnz {} {}", x, y),
+            Cmd::Nop => write!(f, "nop")
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Cmd>, ParseError> {
+    lazy_static!{
+        // Upper case registers are only used as placeholders in rewrite patterns
+        static ref BINARY_RE: Regex = Regex::new("^(set|sub|mul|mod|jnz) ([a-zA-Z]|-?[0-9]+) ([a-zA-Z]|-?[0-9]+)$").unwrap();
+    }
+    parsing::parse_lines(input, |line| {
+        let reg = |operand: &str| match operand.chars().next() {
+            Some(ch) if ch.is_ascii_alphabetic() => Ok(ch),
+            _ => Err(line.field(operand).error("expected a register")),
+        };
+        Ok(if line.text == "nop" {
+            Cmd::Nop
+        } else if let Some(cap) = BINARY_RE.captures(line.text) {
+            if &cap[1] == "set" {
+                Cmd::Set(reg(&cap[2])?, line.field(&cap[3]).parse()?)
+            } else if &cap[1] == "sub" {
+                Cmd::Sub(reg(&cap[2])?, line.field(&cap[3]).parse()?)
+            } else if &cap[1] == "mul" {
+                Cmd::Mul(reg(&cap[2])?, line.field(&cap[3]).parse()?)
+            } else if &cap[1] == "mod" {
+                Cmd::Mod(reg(&cap[2])?, line.field(&cap[3]).parse()?)
+            } else if &cap[1] == "jnz" {
+                Cmd::Jnz(line.field(&cap[2]).parse()?, line.field(&cap[3]).parse()?)
+            } else {
+                unreachable!()
+            }
+        } else {
+            return Err(line.error(1, "expected an instruction like \"set b 57\""));
+        })
+    })
+}
+
+impl Instruction<Registers<char, i64>> for Cmd {
+    fn execute(&self, _pc: usize, registers: &mut Registers<char, i64>, _io: &mut ()) -> Flow {
+        match *self {
+            Cmd::Set(x, y) => registers.set(x, y.value(registers)),
+            Cmd::Sub(x, y) => {
+                let val = registers.get(&x).checked_sub(y.value(registers)).unwrap();
+                registers.set(x, val);
+            },
+            Cmd::Mul(x, y) => {
+                let val = registers.get(&x).checked_mul(y.value(registers)).unwrap();
+                registers.set(x, val);
+            },
+            Cmd::Mod(x, y) => {
+                let val = registers.get(&x) % y.value(registers);
+                registers.set(x, val);
+            },
+            Cmd::Jnz(x, y) => if x.value(registers) != 0 { return Flow::JumpRelative(y.value(registers)) },
+            Cmd::Nop => ()
+        }
+        Flow::Next
+    }
+}
+
+pub fn part1(instructions: &[Cmd]) -> u64 {
+    let mut machine = Machine::new(Registers::new()).with_profiling(instructions.len());
+    machine.run(instructions, &mut ());
+    instructions.iter().zip(machine.profile().unwrap())
+        .filter(|(cmd, _)| matches!(cmd, Cmd::Mul(_, _)))
+        .map(|(_, count)| count)
+        .sum()
+}
+
+// Placeholder (upper case) registers in a pattern bind to whichever register the program uses in
+// that role. Each placeholder binds to a different register, anything else must match exactly.
+fn bind_reg(bindings: &mut BTreeMap<char, char>, pat: char, reg: char) -> bool {
+    if !pat.is_ascii_uppercase() {
+        pat == reg
+    } else if let Some(&bound) = bindings.get(&pat) {
+        bound == reg
+    } else if bindings.values().any(|&bound| bound == reg) {
+        false
+    } else {
+        bindings.insert(pat, reg);
+        true
+    }
+}
+
+fn bind_operand(bindings: &mut BTreeMap<char, char>, pat: Operand, op: Operand) -> bool {
+    match (pat, op) {
+        (Operand::Reg(pat), Operand::Reg(reg)) => bind_reg(bindings, pat, reg),
+        (Operand::Imm(pat), Operand::Imm(val)) => pat == val,
+        _ => false
+    }
+}
+
+fn bind_cmd(bindings: &mut BTreeMap<char, char>, pat: Cmd, cmd: Cmd) -> bool {
+    match (pat, cmd) {
+        (Cmd::Set(px, py), Cmd::Set(x, y)) | (Cmd::Sub(px, py), Cmd::Sub(x, y)) |
+        (Cmd::Mul(px, py), Cmd::Mul(x, y)) | (Cmd::Mod(px, py), Cmd::Mod(x, y)) =>
+            bind_reg(bindings, px, x) && bind_operand(bindings, py, y),
+        (Cmd::Jnz(px, py), Cmd::Jnz(x, y)) =>
+            bind_operand(bindings, px, x) && bind_operand(bindings, py, y),
+        (Cmd::Nop, Cmd::Nop) => true,
+        _ => false
+    }
+}
+
+fn substitute(bindings: &BTreeMap<char, char>, cmd: Cmd) -> Cmd {
+    let reg = |x: char| *bindings.get(&x).unwrap_or(&x);
+    let operand = |x: Operand| match x {
+        Operand::Reg(x) => Operand::Reg(reg(x)),
+        Operand::Imm(_) => x
+    };
+    match cmd {
+        Cmd::Set(x, y) => Cmd::Set(reg(x), operand(y)),
+        Cmd::Sub(x, y) => Cmd::Sub(reg(x), operand(y)),
+        Cmd::Mul(x, y) => Cmd::Mul(reg(x), operand(y)),
+        Cmd::Mod(x, y) => Cmd::Mod(reg(x), operand(y)),
+        Cmd::Jnz(x, y) => Cmd::Jnz(operand(x), operand(y)),
+        Cmd::Nop => Cmd::Nop
+    }
+}
+
+// Replaces every occurrence of pattern in the program, returning how many were found. The
+// replacement must be the same length so that jumps around it are unaffected.
+fn rewrite(program: &mut [Cmd], pattern: &[Cmd], replacement: &[Cmd]) -> usize {
+    assert_eq!(pattern.len(), replacement.len());
+    let mut count = 0;
+    let mut i = 0;
+    while i + pattern.len() <= program.len() {
+        let mut bindings = BTreeMap::new();
+        if pattern.iter().zip(&program[i..]).all(|(&pat, &cmd)| bind_cmd(&mut bindings, pat, cmd)) {
+            for (j, &cmd) in replacement.iter().enumerate() {
+                program[i + j] = substitute(&bindings, cmd);
+            }
+            count += 1;
+            i += pattern.len();
+        } else {
+            i += 1;
+        }
+    }
+    count
+}
+
+// For each d in 2..b, for each e in 2..b, clear the flag f if d*e == b. The registers are
+// deliberately named after the roles they play in the puzzle input.
+const TRIAL_DIVISION: &str = "\
+set E 2
+set G D
+mul G E
+sub G B
+jnz G 2
+set F 0
+sub E -1
+set G E
+sub G B
+jnz G -8
+sub D -1
+set G D
+sub G B
+jnz G -13";
+
+// Instead of trying every e, check b % d directly. Once a divisor is found skip straight to the
+// end of the outer loop, leaving d, e and g as they would have been when it finished.
+const TRIAL_DIVISION_FAST: &str = "\
+set G B
+mod G D
+jnz G 4
+set F 0
+set D B
+sub D 1
+set E B
+set G 0
+nop
+nop
+sub D -1
+set G D
+sub G B
+jnz G -13";
+
+fn optimize(program: &mut [Cmd]) -> Result<(), String> {
+    match rewrite(program, &parse(TRIAL_DIVISION).unwrap(), &parse(TRIAL_DIVISION_FAST).unwrap()) {
+        0 => Err("Couldn't find the trial division loop to optimize".to_string()),
+        _ => Ok(())
+    }
+}
+
+fn run_for_h(instructions: &[Cmd], a: i64) -> i64 {
+    let mut machine = Machine::new(vec![('a', a)].into_iter().collect());
+    machine.run(instructions, &mut ());
+    machine.registers.get(&'h')
+}
+
+pub fn part2(instructions: &[Cmd]) -> i64 {
+    let mut instructions = instructions.to_vec();
+    optimize(&mut instructions).unwrap();
+    run_for_h(&instructions, 1)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Cmd>;
+    type Answer1 = u64;
+    type Answer2 = i64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> i64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The same loops as the puzzle input but with different register names, shifted by an extra
+    // instruction at the start, and checking a smaller range of b (20, 37, 54)
+    const EX: &str = "\
+set z 0
+set p 20
+set q 54
+set r 1
+set s 2
+set t 2
+set u s
+mul u t
+sub u p
+jnz u 2
+set r 0
+sub t -1
+set u t
+sub u p
+jnz u -8
+sub s -1
+set u s
+sub u p
+jnz u -13
+jnz r 2
+sub h -1
+set u p
+sub u q
+jnz u 2
+jnz 1 3
+sub p -17
+jnz 1 -23";
+
+    #[test]
+    fn test_optimize() {
+        let mut instructions = parse(EX).unwrap();
+        let expected = run_for_h(&instructions, 0);
+        assert_eq!(expected, 2);
+        optimize(&mut instructions).unwrap();
+        assert_eq!(instructions[5], Cmd::Set('u', Operand::Reg('p')));
+        assert_eq!(instructions[6], Cmd::Mod('u', Operand::Reg('s')));
+        assert_eq!(run_for_h(&instructions, 0), expected);
+    }
+
+    #[test]
+    fn test_optimize_missing() {
+        let mut instructions = parse(&EX.replace("mul u t", "mul u s")).unwrap();
+        assert!(optimize(&mut instructions).is_err());
+    }
+}
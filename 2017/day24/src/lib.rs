@@ -0,0 +1,104 @@
+use std::collections::BTreeSet;
+
+use lazy_static::lazy_static;
+use parsing::ParseError;
+use regex::Regex;
+use solution::Solution;
+
+pub fn parse(input: &str) -> Result<Vec<(usize, usize)>, ParseError> {
+    lazy_static!{
+        static ref RE: Regex = Regex::new("^(\\d+)/(\\d+)$").unwrap();
+    }
+
+    parsing::parse_lines(input, |line| {
+        let cap = RE.captures(line.text).ok_or_else(|| line.error(1, "expected a component like \"0/2\""))?;
+        Ok((line.field(&cap[1]).parse()?, line.field(&cap[2]).parse()?))
+    })
+}
+
+fn all_bridges(pieces: &[(usize, usize)], curr: usize, used: BTreeSet<usize>) -> Vec<Vec<(usize, usize)>> {
+    let mut ret = Vec::new();
+    for (i, piece) in pieces.iter().enumerate() {
+        if used.contains(&i) { continue }
+        if piece.0 == curr {
+            let mut used2 = used.clone();
+            used2.insert(i);
+            ret.push(used2.iter().map(|j| pieces[*j]).collect());
+            ret.append(&mut all_bridges(pieces, piece.1, used2));
+        } else if piece.1 == curr {
+            let mut used2 = used.clone();
+            used2.insert(i);
+            ret.push(used2.iter().map(|j| pieces[*j]).collect());
+            ret.append(&mut all_bridges(pieces, piece.0, used2));
+        }
+    }
+    ret
+}
+
+pub fn part1(pieces: &[(usize, usize)]) -> usize {
+    let bridges = all_bridges(pieces, 0, BTreeSet::new());
+    bridges.iter()
+        .map(|bridge| bridge.iter().map(|piece| piece.0 + piece.1).sum())
+        .max()
+        .unwrap()
+}
+
+pub fn part2(pieces: &[(usize, usize)]) -> usize {
+    let bridges = all_bridges(pieces, 0, BTreeSet::new());
+    let max_length = bridges.iter()
+        .map(|bridge| bridge.len())
+        .max()
+        .unwrap();
+    bridges.iter()
+        .filter(|bridge| bridge.len() == max_length)
+        .map(|bridge| bridge.iter().map(|piece| piece.0 + piece.1).sum())
+        .max()
+        .unwrap()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<(usize, usize)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "\
+0/2
+2/2
+2/3
+3/4
+3/5
+0/1
+10/1
+9/10";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 31);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 19);
+    }
+
+}
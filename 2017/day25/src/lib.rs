@@ -0,0 +1,158 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use lazy_static::lazy_static;
+use parsing::{Line, ParseError};
+use regex::{Captures, Regex};
+use solution::Solution;
+
+pub struct Instruction {
+    write: bool,
+    move_offset: isize,
+    next_state: char
+}
+type StateInstructions = BTreeMap<bool, Instruction>;
+
+pub type Blueprint = (char, usize, BTreeMap<char, StateInstructions>);
+
+fn capture<'a>(line: Line<'a>, re: &Regex, example: &str) -> Result<Captures<'a>, ParseError> {
+    re.captures(line.text).ok_or_else(|| line.error(1, format!("expected e.g. {:?}", example)))
+}
+
+pub fn parse(input: &str) -> Result<Blueprint, ParseError> {
+    lazy_static!{
+        static ref BEGIN_RE: Regex = Regex::new("^Begin in state ([A-Z])\\.$").unwrap();
+        static ref CHECKSUM_RE: Regex = Regex::new("^Perform a diagnostic checksum after (\\d+) steps\\.$").unwrap();
+        static ref IN_STATE_RE: Regex = Regex::new("^In state ([A-Z]):$").unwrap();
+        static ref WRITE_RE: Regex = Regex::new("^    - Write the value ([01])\\.$").unwrap();
+        static ref MOVE_RE: Regex = Regex::new("^    - Move one slot to the (left|right)\\.$").unwrap();
+        static ref NEXT_STATE_RE: Regex = Regex::new("^    - Continue with state ([A-Z])\\.$").unwrap();
+    }
+    let letter = |s: &str| s.chars().next().unwrap();
+
+    let sections = parsing::sections(input);
+    let (header, states) = match sections.split_first() {
+        Some((header, states)) if header.len() == 2 => (header, states),
+        _ => return Err(Line { number: 1, text: "" }.error(1, "expected the starting state and the number of steps on the first two lines")),
+    };
+    let begin_state = letter(&capture(header[0], &BEGIN_RE, "Begin in state A.")?[1]);
+    let cap = capture(header[1], &CHECKSUM_RE, "Perform a diagnostic checksum after 6 steps.")?;
+    let checksum_after = header[1].field(&cap[1]).parse()?;
+    let mut instructions: BTreeMap<char, StateInstructions> = BTreeMap::new();
+    let mut next_states = vec![(header[0], begin_state)];
+    for lines in states {
+        if lines.len() != 9 {
+            return Err(lines[0].error(1, "expected a state and both of its instructions on 9 lines"));
+        }
+        let state = letter(&capture(lines[0], &IN_STATE_RE, "In state A:")?[1]);
+        let mut state_instructions = BTreeMap::new();
+        for (curr_val, lines) in [false, true].iter().zip(lines[1..].chunks(4)) {
+            let expected = format!("  If the current value is {}:", if *curr_val { 1 } else { 0 });
+            if lines[0].text != expected {
+                return Err(lines[0].error(1, format!("expected {:?}", expected)));
+            }
+            let write = &capture(lines[1], &WRITE_RE, "    - Write the value 1.")?[1] == "1";
+            let move_offset = match &capture(lines[2], &MOVE_RE, "    - Move one slot to the right.")?[1] {
+                "left" => -1,
+                _ => 1
+            };
+            let next_state = letter(&capture(lines[3], &NEXT_STATE_RE, "    - Continue with state B.")?[1]);
+            next_states.push((lines[3], next_state));
+            state_instructions.insert(*curr_val, Instruction { write: write, move_offset: move_offset, next_state: next_state });
+        }
+        instructions.insert(state, state_instructions);
+    }
+    if let Some((line, state)) = next_states.iter().find(|(_, state)| !instructions.contains_key(state)) {
+        return Err(line.error(line.text.len() - 1, format!("there are no instructions for state {}", state)));
+    }
+    Ok((begin_state, checksum_after, instructions))
+}
+
+fn diagnostic_checksum(tape: &BTreeSet<isize>) -> usize {
+    tape.len()
+}
+
+fn write_val(tape: &mut BTreeSet<isize>, pos: isize, val: bool) {
+    if val {
+        tape.insert(pos);
+    } else {
+        tape.remove(&pos);
+    }
+}
+
+pub fn part1(blueprint: &Blueprint) -> usize {
+    let (begin_state, checksum_after, all_instructions) = blueprint;
+    let mut state = *begin_state;
+    let mut tape = BTreeSet::new();
+    let mut pos = 0;
+
+    for _ in 0..*checksum_after {
+        let curr_val = tape.contains(&pos);
+        let inst = all_instructions.get(&state).unwrap().get(&curr_val).unwrap();
+        write_val(&mut tape, pos, inst.write);
+        pos += inst.move_offset;
+        state = inst.next_state;
+    }
+
+    diagnostic_checksum(&tape)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Blueprint;
+    type Answer1 = usize;
+    type Answer2 = &'static str;
+
+    const PARTS: usize = 1;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "\
+Begin in state A.
+Perform a diagnostic checksum after 6 steps.
+
+In state A:
+  If the current value is 0:
+    - Write the value 1.
+    - Move one slot to the right.
+    - Continue with state B.
+  If the current value is 1:
+    - Write the value 0.
+    - Move one slot to the left.
+    - Continue with state B.
+
+In state B:
+  If the current value is 0:
+    - Write the value 1.
+    - Move one slot to the left.
+    - Continue with state A.
+  If the current value is 1:
+    - Write the value 1.
+    - Move one slot to the right.
+    - Continue with state A.";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 3);
+    }
+
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse(&(EX.trim_end_matches("A.").to_string() + "C.")).err().unwrap();
+        assert_eq!((err.line, err.column), (22, 27));
+        assert_eq!(err.message, "there are no instructions for state C");
+    }
+}
@@ -0,0 +1,101 @@
+use std::collections::BTreeMap;
+
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse(input: &str) -> Result<usize, ParseError> {
+    parsing::single_line(input)?.parse()
+}
+
+fn grid_size(square: usize) -> usize {
+    for i in 0.. {
+        if square <= (2*i + 1) * (2*i + 1) {
+            return i
+        }
+    }
+    unreachable!()
+}
+
+pub fn part1(square: usize) -> usize {
+    let width = grid_size(square);
+
+    let mut dir: i32 = -1;
+    let mut cnt = 0;
+    let mut dist = 2 * width;
+    for _ in (square .. (2*width + 1)*(2*width + 1)).rev() {
+        cnt += 1;
+        if dir < 0 { dist -= 1; } else { dist += 1 }
+        if cnt >= width {
+            cnt = 0;
+            dir *= -1;
+        }
+    }
+
+    dist
+}
+
+fn get_adjacent_sum(x: i32, y: i32, grid: &BTreeMap<(i32, i32), usize>) -> usize {
+    let mut sum = 0;
+    sum += grid.get(&(x+1,y+1)).unwrap_or(&0);
+    sum += grid.get(&(x+1,y)).unwrap_or(&0);
+    sum += grid.get(&(x+1,y-1)).unwrap_or(&0);
+    sum += grid.get(&(x,y+1)).unwrap_or(&0);
+    sum += grid.get(&(x,y-1)).unwrap_or(&0);
+    sum += grid.get(&(x-1,y+1)).unwrap_or(&0);
+    sum += grid.get(&(x-1,y)).unwrap_or(&0);
+    sum += grid.get(&(x-1,y-1)).unwrap_or(&0);
+    sum
+}
+
+fn set_grid_value(x: i32, y: i32, grid: &mut BTreeMap<(i32, i32), usize>) -> usize {
+    let val = get_adjacent_sum(x, y, grid);
+    grid.insert((x, y), val);
+    val
+}
+
+pub fn part2(input_int: usize) -> usize {
+    let mut grid: BTreeMap<(i32, i32), usize> = BTreeMap::new();
+    grid.insert((0,0), 1);
+    for i in 1.. {
+        for y in (1-i) ..= (i-1) { let val = set_grid_value(i, y, &mut grid); if val > input_int { return val } }
+        for x in (-i ..= i).rev() { let val = set_grid_value(x, i, &mut grid); if val > input_int { return val } }
+        for y in ((1-i) ..= (i-1)).rev() { let val = set_grid_value(-i, y, &mut grid); if val > input_int { return val } }
+        for x in -i ..= i { let val = set_grid_value(x, -i, &mut grid); if val > input_int { return val } }
+    }
+    unreachable!();
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = usize;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(*input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(*input)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(parse("1").unwrap()), 0);
+        assert_eq!(part1(parse("12").unwrap()), 3);
+        assert_eq!(part1(parse("23").unwrap()), 2);
+        assert_eq!(part1(parse("1024").unwrap()), 31);
+    }
+
+}
@@ -0,0 +1,80 @@
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+    parsing::parse_lines(input, |line| line.parse())
+}
+
+pub fn part1(instructions: &[i32]) -> usize {
+    let mut instructions = instructions.to_vec();
+    let mut offset = 0i32;
+    let mut steps = 0;
+    while offset >= 0 && offset < instructions.len() as i32 {
+        steps += 1;
+        let new_offset = instructions[offset as usize] + offset;
+        instructions[offset as usize] += 1;
+        offset = new_offset;
+    }
+    steps
+}
+
+pub fn part2(instructions: &[i32]) -> usize {
+    let mut instructions = instructions.to_vec();
+    let mut offset = 0i32;
+    let mut steps = 0;
+    while offset >= 0 && offset < instructions.len() as i32 {
+        steps += 1;
+        let new_offset = instructions[offset as usize] + offset;
+        if instructions[offset as usize] >= 3 {
+            instructions[offset as usize] -= 1;
+        } else {
+            instructions[offset as usize] += 1;
+        }
+        offset = new_offset;
+    }
+    steps
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<i32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "\
+0
+3
+0
+1
+-3";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 5);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 10);
+    }
+
+}
@@ -0,0 +1,83 @@
+use std::collections::BTreeSet;
+
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    parsing::single_line(input)?.fields().iter().map(|field| field.parse()).collect()
+}
+
+fn redistribute(banks: &mut Vec<usize>) {
+    let (mut offset, mut left) = banks.iter()
+        .enumerate().map(|(i,cnt)| (i, *cnt))
+        .max_by_key(|(i,cnt)| (*cnt, -(*i as isize))).unwrap();
+    banks[offset] = 0;
+    while left > 0 {
+        left -= 1;
+        offset = (offset + 1) % banks.len();
+        banks[offset] += 1;
+    }
+}
+
+fn redistribute_until_cycle(banks: &mut Vec<usize>) -> usize {
+    let mut seen = BTreeSet::new();
+    seen.insert(banks.clone());
+    for steps in 1.. {
+        redistribute(banks);
+        if seen.contains(banks) {
+            return steps
+        }
+        seen.insert(banks.clone());
+    }
+    unreachable!();
+}
+
+pub fn part1(banks: &[usize]) -> usize {
+    let mut banks = banks.to_vec();
+    redistribute_until_cycle(&mut banks)
+}
+
+pub fn part2(banks: &[usize]) -> usize {
+    let mut banks = banks.to_vec();
+    redistribute_until_cycle(&mut banks);
+    redistribute_until_cycle(&mut banks)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "0 2 7 0";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 5);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 4);
+    }
+
+}
@@ -0,0 +1,147 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use lazy_static::lazy_static;
+use parsing::{Line, ParseError};
+use regex::Regex;
+use solution::Solution;
+
+pub struct Tower {
+    name: String,
+    weight: usize,
+    children: Vec<Box<Tower>>
+}
+
+fn build_node(programs: &BTreeMap<String, (usize, Vec<String>)>, name: &str) -> Tower {
+    Tower {
+        name: name.to_string(),
+        weight: programs[name].0,
+        children: programs[name].1.iter().map(|n| Box::new(build_node(programs, n))).collect()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Tower, ParseError> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new("^([a-z]+) \\(([0-9]+)\\)( -> (([a-z]+(, )?)+))?$").unwrap();
+    }
+
+    let lines = parsing::lines(input);
+    let mut programs: BTreeMap<String, (usize, Vec<String>)> = BTreeMap::new();
+    let mut children = Vec::new();
+    for line in lines.iter() {
+        let cap = RE.captures(line.text)
+            .ok_or_else(|| line.error(1, "expected e.g. \"fwft (72) -> ktlj, cntj, xhth\""))?;
+        let names = cap.get(4).map(|names| line.field(names.as_str()).split(", ")).unwrap_or(Vec::new());
+        programs.insert(cap[1].to_string(), (line.field(&cap[2]).parse()?, names.iter().map(|n| n.text.to_string()).collect()));
+        children.extend(names);
+    }
+    if let Some(child) = children.iter().find(|child| !programs.contains_key(child.text)) {
+        return Err(child.error(format!("no program is called {}", child.text)));
+    }
+    let root_name = programs.keys().find(|name| {
+            !programs.iter().any(|(_,(_,children))| children.contains(&name))
+        })
+        .ok_or_else(|| {
+            let line = lines.first().copied().unwrap_or(Line { number: 1, text: "" });
+            line.error(1, "expected a program at the bottom of the tower")
+        })?;
+    Ok(build_node(&programs, root_name))
+}
+
+pub fn part1(tower: &Tower) -> String {
+    tower.name.clone()
+}
+
+fn total_weight(tower: &Tower) -> usize {
+    let a: usize = tower.weight;
+    let b: usize = tower.children.iter().map(|child| total_weight(child)).sum();
+    a + b
+}
+
+fn find_correct_weight(weights: &Vec<usize>) -> usize {
+    for w in weights.iter() {
+        if weights.iter().filter(|w2| *w2 == w).count() > 1 {
+            return *w
+        }
+    }
+    unreachable!();
+}
+
+fn are_children_unbalanced(tower: &Tower) -> bool {
+    tower.children.iter().map(|child| total_weight(child)).collect::<BTreeSet<usize>>().len() > 1
+}
+
+fn find_unbalanced(tower: &Tower) -> usize {
+    let child_weights: Vec<usize> = tower.children.iter().map(|child| total_weight(child)).collect();
+    if child_weights.is_empty() { panic!() }
+
+    let correct_weight = find_correct_weight(&child_weights);
+    let ref wrong_child = tower.children.iter().find(|child| total_weight(child) != correct_weight).unwrap();
+    let offset_weight = correct_weight as isize - total_weight(wrong_child) as isize;
+
+    if are_children_unbalanced(wrong_child) {
+        find_unbalanced(wrong_child)
+    } else {
+        (wrong_child.weight as isize + offset_weight) as usize
+    }
+}
+
+pub fn part2(tower: &Tower) -> usize {
+    find_unbalanced(tower)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Tower;
+    type Answer1 = String;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "\
+pbga (66)
+xhth (57)
+ebii (61)
+havc (66)
+ktlj (57)
+fwft (72) -> ktlj, cntj, xhth
+qoyq (66)
+padx (45) -> pbga, havc, qoyq
+tknk (41) -> ugml, padx, fwft
+jptl (61)
+ugml (68) -> gyxo, ebii, jptl
+gyxo (61)
+cntj (57)";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), "tknk".to_string());
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 60);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("pbga (66)\nfwft (72) -> pbga, cntj").err().unwrap();
+        assert_eq!((err.line, err.column, err.message.as_str()), (2, 20, "no program is called cntj"));
+    }
+}
@@ -0,0 +1,131 @@
+use std::cmp::max;
+
+use lazy_static::lazy_static;
+use parsing::ParseError;
+use regex::Regex;
+use register_machine::{Flow, Machine, Registers};
+use solution::Solution;
+
+#[derive(Debug)]
+struct Condition {
+    register: String,
+    op: String,
+    value: i32
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    register: String,
+    op: String,
+    value: i32,
+    condition: Condition
+}
+
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    lazy_static!{
+        static ref RE: Regex = Regex::new("^([a-z]+) (inc|dec) ([0-9-]+) if ([a-z]+) (>|>=|<|<=|==|!=) ([0-9-]+)$").unwrap();
+    }
+
+    parsing::parse_lines(input, |line| {
+        let cap = RE.captures(line.text).ok_or_else(|| line.error(1, "expected e.g. \"b inc 5 if a > 1\""))?;
+        Ok(Instruction {
+            register: cap[1].to_string(),
+            op: cap[2].to_string(),
+            value: line.field(&cap[3]).parse()?,
+            condition: Condition {
+                register: cap[4].to_string(),
+                op: cap[5].to_string(),
+                value: line.field(&cap[6]).parse()?
+            }
+        })
+    })
+}
+
+fn meets_condition(registers: &Registers<String, i32>, cond: &Condition) -> bool {
+    let reg_value = registers.get(&cond.register);
+    if      cond.op == "<"  { reg_value <  cond.value }
+    else if cond.op == "<=" { reg_value <= cond.value }
+    else if cond.op == ">"  { reg_value >  cond.value }
+    else if cond.op == ">=" { reg_value >= cond.value }
+    else if cond.op == "==" { reg_value == cond.value }
+    else if cond.op == "!=" { reg_value != cond.value }
+    else { unreachable!() }
+}
+
+// There are no jumps in this dialect, every instruction is executed once in order
+impl register_machine::Instruction<Registers<String, i32>> for Instruction {
+    fn execute(&self, _pc: usize, registers: &mut Registers<String, i32>, _io: &mut ()) -> Flow {
+        if meets_condition(registers, &self.condition) {
+            let reg = registers.get_mut(self.register.clone());
+            if self.op == "inc" {
+                *reg += self.value;
+            } else if self.op == "dec" {
+                *reg -= self.value;
+            } else {
+                unreachable!();
+            }
+        }
+        Flow::Next
+    }
+}
+
+pub fn part1(instructions: &[Instruction]) -> i32 {
+    let mut machine = Machine::new(Registers::new());
+    machine.run(instructions, &mut ());
+
+    machine.registers.iter().map(|(_, val)| val).max().unwrap()
+}
+
+pub fn part2(instructions: &[Instruction]) -> i32 {
+    let mut machine = Machine::new(Registers::new());
+    let mut max_value = 0;
+
+    for inst in instructions.iter() {
+        machine.step(instructions, &mut ());
+        max_value = max(max_value, machine.registers.get(&inst.register));
+    }
+
+    max_value
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Instruction>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> i32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> i32 {
+        part2(input)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "\
+b inc 5 if a > 1
+a inc 1 if b < 5
+c dec -10 if a >= 1
+c inc -20 if c == 10";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 1);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 10);
+    }
+}
@@ -0,0 +1,66 @@
+use std::collections::BTreeSet;
+
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+    parsing::parse_lines(input, |line| line.parse())
+}
+
+pub fn part1(changes: &[i32]) -> i32 {
+    changes.iter().sum()
+}
+
+pub fn part2(changes: &[i32]) -> i32 {
+    let mut seen = BTreeSet::new();
+    let mut curr = 0;
+    loop {
+        for incr in changes {
+            seen.insert(curr);
+            curr += incr;
+            if seen.contains(&curr) {
+                return curr;
+            }
+        }
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> i32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> i32 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(3, part1(&parse("+1\n+1\n+1").unwrap()));
+        assert_eq!(0, part1(&parse("+1\n+1\n-2").unwrap()));
+        assert_eq!(-6, part1(&parse("-1\n-2\n-3").unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(0, part2(&parse("+1\n-1").unwrap()));
+        assert_eq!(10, part2(&parse("+3\n+3\n+4\n-2\n-4").unwrap()));
+        assert_eq!(5, part2(&parse("-6\n+3\n+8\n+5\n-6").unwrap()));
+        assert_eq!(14, part2(&parse("+7\n+7\n-2\n-7\n-4").unwrap()));
+    }
+}
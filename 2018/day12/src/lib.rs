@@ -0,0 +1,169 @@
+use std::collections::{BTreeSet};
+use std::mem;
+
+use lazy_static::lazy_static;
+use parsing::{Line, ParseError};
+use regex::Regex;
+use solution::Solution;
+
+// output is (plants, spread)
+pub fn parse(input: &str) -> Result<(BTreeSet<i32>, BTreeSet<u8>), ParseError> {
+    let mut plants = BTreeSet::new();
+    let mut spread = BTreeSet::new();
+    lazy_static!{
+        static ref PLANTS_RE: Regex = Regex::new("^initial state: ([#\\.]+)$").unwrap();
+        static ref SPREAD_RE: Regex = Regex::new("^([#\\.]{5}) => ([#\\.])$").unwrap();
+    }
+
+    let lines = parsing::lines(input);
+    let first = lines.first().copied().unwrap_or(Line { number: 1, text: "" });
+
+    let cap = PLANTS_RE.captures(first.text)
+        .ok_or_else(|| first.error(1, "expected e.g. \"initial state: #..#.#\""))?;
+    for (i, ch) in cap[1].chars().enumerate() {
+        if ch == '#' {
+            plants.insert(i as i32);
+        }
+    }
+
+    for line in lines.iter().skip(1).filter(|line| !line.is_blank()) {
+        let cap = SPREAD_RE.captures(line.text).ok_or_else(|| line.error(1, "expected e.g. \"...## => #\""))?;
+        if &cap[2] == "#" {
+            let mut config = 0u8;
+            for ch in cap[1].chars() {
+                config <<= 1;
+                if ch == '#' {
+                    config |= 1;
+                }
+            }
+            spread.insert(config);
+        }
+    }
+
+    Ok((plants, spread))
+}
+
+fn plant_next_gen(i: i32, plants: &BTreeSet<i32>, spread: &BTreeSet<u8>) -> bool {
+    let config = if plants.contains(&(i - 2)) { 16 } else { 0 }
+        | if plants.contains(&(i - 1)) { 8 } else { 0 }
+        | if plants.contains(&i) { 4 } else { 0 }
+        | if plants.contains(&(i + 1)) { 2 } else { 0 }
+        | if plants.contains(&(i + 2)) { 1 } else { 0 };
+    spread.contains(&config)
+}
+
+fn write_plants(plants: &BTreeSet<i32>) -> String {
+    let min = *plants.iter().next().unwrap();
+    let max = *plants.iter().rev().next().unwrap();
+    let mut out = String::new();
+    for i in min..=max {
+        if plants.contains(&i) {
+            out.push('#')
+        } else {
+            out.push('.')
+        }
+    }
+    out
+}
+
+fn step(plants: BTreeSet<i32>, spread: &BTreeSet<u8>) -> BTreeSet<i32> {
+    let min = plants.iter().next().unwrap() - 2;
+    let max = plants.iter().rev().next().unwrap() + 2;
+    let mut out = BTreeSet::new();
+    for i in min..=max {
+        if plant_next_gen(i, &plants, spread) {
+            out.insert(i);
+        }
+    }
+    out
+}
+
+pub fn part1((plants, spread): &(BTreeSet<i32>, BTreeSet<u8>)) -> i32 {
+    let mut plants = plants.clone();
+    for _ in 0..20 {
+        plants = step(plants, spread);
+    }
+    plants.iter().sum()
+}
+
+pub fn part2((plants, spread): &(BTreeSet<i32>, BTreeSet<u8>)) -> i64 {
+    let mut plants = plants.clone();
+    let mut seen = BTreeSet::new();
+    let mut i: i64 = 0;
+    while !seen.contains(&write_plants(&plants)) {
+        i += 1;
+        seen.insert(write_plants(&plants));
+
+        plants = step(plants, spread);
+    }
+    mem::drop(seen);
+
+    let mut cycle_len: i64 = 0;
+    let saved = plants.clone();
+    while {
+        i += 1;
+        cycle_len += 1;
+        plants = step(plants, spread);
+
+        write_plants(&saved) != write_plants(&plants)
+    } /*do*/ {}
+    let diff_per_cycle: i64 = (plants.iter().sum::<i32>() - saved.iter().sum::<i32>()) as i64;
+    mem::drop(saved);
+
+    let cycles_left = (50000000000 - i) / cycle_len;
+    let remaining = (50000000000 - i) % cycle_len;
+    for _ in 0..remaining {
+        plants = step(plants, spread);
+    }
+
+    plants.iter().sum::<i32>() as i64 + (diff_per_cycle * cycles_left)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (BTreeSet<i32>, BTreeSet<u8>);
+    type Answer1 = i32;
+    type Answer2 = i64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> i32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> i64 {
+        part2(input)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "\
+initial state: #..#.#..##......###...###
+
+...## => #
+..#.. => #
+.#... => #
+.#.#. => #
+.#.## => #
+.##.. => #
+.#### => #
+#.#.# => #
+#.### => #
+##.#. => #
+##.## => #
+###.. => #
+###.# => #
+####. => #";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 325);
+    }
+}
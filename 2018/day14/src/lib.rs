@@ -0,0 +1,100 @@
+use parsing::ParseError;
+use solution::Solution;
+
+// Part 1 reads the digits as a number of recipes, part 2 looks for them as a sequence
+pub fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+    parsing::single_line(input)?.map_chars(|ch| ch.to_digit(10).map(|d| d as usize))
+}
+
+fn init_recipes() -> Vec<usize> {
+    let mut recipes = Vec::new();
+    recipes.push(3);
+    recipes.push(7);
+    recipes
+}
+
+fn step(recipes: &mut Vec<usize>, pos: &mut [usize; 2]) {
+    let new_recipe = recipes[pos[0]] + recipes[pos[1]];
+    if new_recipe >= 10 {
+        recipes.push(new_recipe / 10);
+    }
+    recipes.push(new_recipe % 10);
+    for p in pos.iter_mut() {
+        *p = (*p + recipes[*p] + 1) % recipes.len();
+    }
+}
+
+pub fn part1(digits: &[usize]) -> String {
+    let start_after = digits.iter().fold(0, |n, d| n * 10 + d);
+    let mut recipes = init_recipes();
+    let mut pos = [0, 1];
+    while recipes.len() < start_after+10 {
+        step(&mut recipes, &mut pos);
+    }
+
+    let mut ret = String::new();
+    for recipe in recipes[start_after..start_after+10].iter() {
+        ret.push_str(&format!("{}", recipe));
+    }
+    ret
+}
+
+pub fn part2(seq: &[usize]) -> usize {
+    let mut recipes = init_recipes();
+    let mut pos = [0, 1];
+    let mut checked_through = 0;
+    loop {
+        step(&mut recipes, &mut pos);
+        if recipes.len() >= seq.len() {
+            while checked_through < recipes.len() - seq.len() {
+                if seq == &recipes[checked_through .. checked_through+seq.len()] {
+                    return checked_through;
+                }
+                checked_through += 1;
+            }
+        }
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<usize>;
+    type Answer1 = String;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse("9").unwrap()), "5158916779".to_string());
+        assert_eq!(part1(&parse("5").unwrap()), "0124515891".to_string());
+        assert_eq!(part1(&parse("18").unwrap()), "9251071085".to_string());
+        assert_eq!(part1(&parse("2018").unwrap()), "5941429882".to_string());
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse("51589").unwrap()), 9);
+        assert_eq!(part2(&parse("01245").unwrap()), 5);
+        assert_eq!(part2(&parse("92510").unwrap()), 18);
+        assert_eq!(part2(&parse("59414").unwrap()), 2018);
+    }
+
+}
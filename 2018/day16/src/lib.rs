@@ -1,3 +1,11 @@
+use std::collections::BTreeSet;
+
+use lazy_static::lazy_static;
+use parsing::{Line, ParseError};
+use regex::Regex;
+use register_machine::{Flow, Machine};
+use solution::Solution;
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Op {
     Addr, Addi, // rC = rA + (r/v)B
@@ -33,3 +41,168 @@ pub fn execute_op(registers: &mut [usize], op: Op, a: usize, b: usize, c: usize)
         Op::Divi => registers[c] = registers[a] / b
     }
 }
+
+// An opcode with its A, B and C operands
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Instruction(pub Op, pub usize, pub usize, pub usize);
+
+// The device's four registers, with the pc kept apart from them until day 19 binds it to one
+impl register_machine::Instruction<[usize; 4]> for Instruction {
+    fn execute(&self, _pc: usize, registers: &mut [usize; 4], _io: &mut ()) -> Flow {
+        let Instruction(op, a, b, c) = *self;
+        execute_op(registers, op, a, b, c);
+        Flow::Next
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct OpcodeSample {
+    before: [usize; 4],
+    instruction: [usize; 4],
+    after: [usize; 4]
+}
+
+fn registers(line: Line, re: &Regex, example: &str) -> Result<[usize; 4], ParseError> {
+    if !re.is_match(line.text) {
+        return Err(line.error(1, format!("expected e.g. {:?}", example)));
+    }
+    line.numbers_n()
+}
+
+pub fn parse(input: &str) -> Result<(Vec<OpcodeSample>, Vec<[usize; 4]>), ParseError> {
+    lazy_static!{
+        static ref BEFORE_RE: Regex = Regex::new("^Before: \\[(\\d+), (\\d+), (\\d+), (\\d+)\\]$").unwrap();
+        static ref INSTRUCTION_RE: Regex = Regex::new("^(\\d+) (\\d+) (\\d+) (\\d+)$").unwrap();
+        static ref AFTER_RE: Regex = Regex::new("^After:  \\[(\\d+), (\\d+), (\\d+), (\\d+)\\]$").unwrap();
+    }
+    let instruction = |line: Line| -> Result<[usize; 4], ParseError> {
+        let instruction = registers(line, &INSTRUCTION_RE, "9 2 1 2")?;
+        if instruction[0] >= all_ops().len() {
+            return Err(line.error(1, format!("expected an opcode below {}", all_ops().len())));
+        }
+        Ok(instruction)
+    };
+
+    let mut samples = Vec::new();
+    let mut program = Vec::new();
+    let mut lines = parsing::lines(input).into_iter();
+    while let Some(line) = lines.next() {
+        if BEFORE_RE.is_match(line.text) {
+            let before = line.numbers_n()?;
+            let line = lines.next().unwrap_or(Line { number: line.number + 1, text: "" });
+            let instruction = instruction(line)?;
+            let line = lines.next().unwrap_or(Line { number: line.number + 1, text: "" });
+            let after = registers(line, &AFTER_RE, "After:  [3, 2, 2, 1]")?;
+            samples.push(OpcodeSample {
+                before: before,
+                instruction: instruction,
+                after: after
+            });
+        } else if INSTRUCTION_RE.is_match(line.text) {
+            program.push(instruction(line)?);
+        } else if !line.is_blank() {
+            return Err(line.error(1, "expected e.g. \"Before: [3, 2, 1, 1]\" or \"9 2 1 2\""));
+        }
+    }
+    Ok((samples, program))
+}
+
+fn all_ops() -> BTreeSet<Op> {
+    vec![Op::Addr, Op::Addi, Op::Mulr, Op::Muli, Op::Banr, Op::Bani, Op::Borr,
+         Op::Bori, Op::Setr, Op::Seti, Op::Gtir, Op::Gtri, Op::Gtrr, Op::Eqir,
+         Op::Eqri, Op::Eqrr].into_iter().collect()
+}
+
+fn possible_ops(before: [usize; 4], after: [usize; 4], [_, a, b, c]: [usize; 4]) -> BTreeSet<Op> {
+    all_ops().into_iter().filter(|op| {
+        let mut machine = Machine::new(before);
+        machine.step(&[Instruction(*op, a, b, c)], &mut ());
+        machine.registers == after
+    }).collect()
+}
+
+pub fn part1((samples, _): &(Vec<OpcodeSample>, Vec<[usize; 4]>)) -> usize {
+    samples.iter()
+        .filter(|sample| possible_ops(sample.before, sample.after, sample.instruction).len() >= 3)
+        .count()
+}
+
+fn determine_opcodes(samples: &Vec<OpcodeSample>) -> Vec<Op> {
+    let mut opcode_possibilities = (0..all_ops().len())
+        .map(|_| all_ops()).collect::<Vec<_>>();
+    for sample in samples.iter() {
+        let ops = possible_ops(sample.before, sample.after, sample.instruction);
+        opcode_possibilities[sample.instruction[0]] =
+            opcode_possibilities[sample.instruction[0]].intersection(&ops).into_iter().cloned().collect();
+    }
+    let mut opcode_options = vec![None; all_ops().len()];
+    while opcode_possibilities.iter().any(|ops| ops.len() > 0) {
+        for opcode in 0..all_ops().len() {
+            if opcode_possibilities[opcode].len() == 0 {
+                assert!(opcode_options[opcode].is_some());
+            } else if opcode_possibilities[opcode].len() == 1 {
+                let op = *opcode_possibilities[opcode].iter().next().unwrap();
+                opcode_options[opcode] = Some(op);
+                for ops in opcode_possibilities.iter_mut() {
+                    ops.remove(&op);
+                }
+            }
+        }
+    }
+    opcode_options.into_iter().map(|opt| opt.unwrap()).collect()
+}
+
+pub fn part2((samples, program): &(Vec<OpcodeSample>, Vec<[usize; 4]>)) -> usize {
+    let opcodes = determine_opcodes(samples);
+    let program: Vec<Instruction> = program.iter()
+        .map(|&[opcode, a, b, c]| Instruction(opcodes[opcode], a, b, c))
+        .collect();
+
+    let mut machine = Machine::new([0, 0, 0, 0]);
+    machine.run(&program, &mut ());
+    machine.registers[0]
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<OpcodeSample>, Vec<[usize; 4]>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_possible_ops() {
+        let ex = "\
+Before: [3, 2, 1, 1]
+9 2 1 2
+After:  [3, 2, 2, 1]";
+        let sample = parse(ex).unwrap().0[0].clone();
+        assert_eq!(possible_ops(sample.before, sample.after, sample.instruction),
+                   [Op::Mulr, Op::Addi, Op::Seti].into_iter().cloned().collect());
+    }
+
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("Before: [3, 2, 1, 1]\n16 2 1 2\nAfter:  [3, 2, 2, 1]").err().unwrap();
+        assert_eq!((err.line, err.column, err.message.as_str()), (2, 1, "expected an opcode below 16"));
+        assert_eq!(parse("Before: [3, 2, 1, 1]\n9 2 1 2").err().unwrap().line, 3);
+    }
+}
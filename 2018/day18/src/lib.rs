@@ -0,0 +1,146 @@
+use std::collections::BTreeSet;
+use std::mem;
+
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    parsing::grid(&parsing::lines(input), |ch| ".|#".contains(ch).then_some(ch))
+}
+
+#[allow(dead_code)]
+fn map_to_string(map: &Vec<Vec<char>>) -> String {
+    let mut ret = String::new();
+    for row in map.iter() {
+        for ch in row.iter() {
+            ret.push(*ch);
+        }
+        ret.push('\n');
+    }
+    ret
+}
+
+fn adjacent(map: &Vec<Vec<char>>, x: usize, y: usize) -> Vec<char> {
+    let height = map.len();
+    let width = map[0].len();
+    let mut ret = Vec::new();
+    if y > 0 {
+        if x > 0 { ret.push(map[y - 1][x - 1]); }
+        ret.push(map[y - 1][x]);
+        if x < width - 1 { ret.push(map[y - 1][x + 1]); }
+    }
+    if x > 0 { ret.push(map[y][x - 1]); }
+    if x < width - 1 { ret.push(map[y][x + 1]); }
+    if y < height - 1 {
+        if x > 0 { ret.push(map[y + 1][x - 1]); }
+        ret.push(map[y + 1][x]);
+        if x < width - 1 { ret.push(map[y + 1][x + 1]); }
+    }
+    ret
+}
+
+fn iterate(old_map: &Vec<Vec<char>>) -> Vec<Vec<char>> {
+    let height = old_map.len();
+    let width = old_map[0].len();
+    let mut map = vec![vec![' '; width]; height];
+    for y in 0..height {
+        for x in 0..width {
+            let adj = adjacent(old_map, x, y);
+            map[y][x] = match old_map[y][x] {
+                '.' => if adj.iter().filter(|c| **c == '|').count() >= 3 { '|' } else { '.' },
+                '|' => if adj.iter().filter(|c| **c == '#').count() >= 3 { '#' } else { '|' },
+                '#' => if adj.iter().filter(|c| **c == '#').count() >= 1 && adj.iter().filter(|c| **c == '|').count() >= 1 { '#' } else { '.' },
+                _ => unreachable!()
+            };
+        }
+    }
+    map
+}
+
+fn resource_value(map: &Vec<Vec<char>>) -> usize {
+    let num_wooded = map.iter().flat_map(|row| row.iter()).filter(|c| **c == '|').count();
+    let num_lumber = map.iter().flat_map(|row| row.iter()).filter(|c| **c == '#').count();
+    num_wooded * num_lumber
+}
+
+pub fn part1(map: &[Vec<char>]) -> usize {
+    let mut map = map.to_vec();
+    for _ in 0..10 {
+        map = iterate(&map);
+    }
+    resource_value(&map)
+}
+
+pub fn part2(map: &[Vec<char>]) -> usize {
+    let mut map = map.to_vec();
+    let mut iters = 0;
+    let max = 1000000000;
+    let mut seen = BTreeSet::new();
+    while iters < max {
+        if !seen.insert(map.clone()) {
+            break;
+        }
+        iters += 1;
+        map = iterate(&map);
+    }
+    mem::drop(seen);
+
+    let mut cycle_len = 0;
+    let old_map = map.clone();
+    while {
+        iters += 1;
+        cycle_len += 1;
+        map = iterate(&map);
+
+        old_map != map && iters < max
+    } {}
+
+    let remaining = (max - iters) % cycle_len;
+    for _ in 0..remaining {
+        map = iterate(&map);
+    }
+
+    resource_value(&map)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<char>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "\
+.#.#...|#.
+.....#|##|
+.|..|...#.
+..|#.....#
+#.#|||#|#|
+...#.||...
+.|....|...
+||...#|.#|
+|.||||..|.
+...#.|..|.";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 1147);
+    }
+}
@@ -1,12 +1,34 @@
 use lazy_static::lazy_static;
+use parsing::{Line, ParseError};
 use regex::Regex;
+use register_machine::{Flow, Machine};
+use solution::Solution;
 
-pub use day16_2018::*;
+pub use day16_2018::{execute_op, Instruction, Op};
 
-pub type Instruction = (Op, usize, usize, usize);
+// The #ip directive binds the pc to one of the registers, which instructions can read and write
+// like any other
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BoundRegisters {
+    pub ip: usize,
+    pub values: [usize; 6]
+}
+
+impl register_machine::Instruction<BoundRegisters> for Instruction {
+    fn execute(&self, pc: usize, registers: &mut BoundRegisters, _io: &mut ()) -> Flow {
+        let Instruction(op, a, b, c) = *self;
+        registers.values[registers.ip] = pc;
+        execute_op(&mut registers.values, op, a, b, c);
+        Flow::JumpAbsolute(registers.values[registers.ip] as i64 + 1)
+    }
+}
 
-fn parse_op(op_str: &str) -> Op {
-    match op_str {
+pub fn new_machine(ip: usize, values: [usize; 6]) -> Machine<BoundRegisters> {
+    Machine::new(BoundRegisters { ip, values })
+}
+
+fn parse_op(op_str: &str) -> Option<Op> {
+    Some(match op_str {
         "addr" => Op::Addr,
         "addi" => Op::Addi,
         "mulr" => Op::Mulr,
@@ -26,24 +48,86 @@ fn parse_op(op_str: &str) -> Op {
         // Below here are needed for optimization
         "divr" => Op::Divr,
         "divi" => Op::Divi,
-        _ => unreachable!()
-    }
+        _ => return None
+    })
 }
 
 // (ip_register, instructions)
-pub fn parse_instructions(input: &str) -> (usize, Vec<Instruction>) {
+pub fn parse_instructions(input: &str) -> Result<(usize, Vec<Instruction>), ParseError> {
     lazy_static!{
-        static ref IP_RE: Regex = Regex::new("^#ip (\\d+)$").unwrap();
-        static ref INST_RE: Regex = Regex::new("^([a-z]+) (\\d+) (\\d+) (\\d+)$").unwrap();
+        static ref IP_RE: Regex = Regex::new("^#ip ([0-5])$").unwrap();
+        static ref INST_RE: Regex = Regex::new("^([a-z]+) (\\d+) (\\d+) ([0-5])$").unwrap();
     }
-    let mut lines = input.lines();
-    let cap = IP_RE.captures(lines.next().unwrap()).unwrap();
-    let ip = cap[1].parse().unwrap();
-    let instructions = lines.map(|line| {
-        let cap = INST_RE.captures(line).unwrap();
-        (parse_op(&cap[1]), cap[2].parse().unwrap(), cap[3].parse().unwrap(), cap[4].parse().unwrap())
+    let lines = parsing::lines(input);
+    let first = lines.first().copied().unwrap_or(Line { number: 1, text: "" });
+    let cap = IP_RE.captures(first.text).ok_or_else(|| first.error(1, "expected e.g. \"#ip 0\""))?;
+    let ip = first.field(&cap[1]).parse()?;
+    let instructions = lines[1..].iter().map(|line| {
+        let cap = INST_RE.captures(line.text).ok_or_else(|| line.error(1, "expected e.g. \"seti 5 0 1\""))?;
+        let op = parse_op(&cap[1]).ok_or_else(|| line.field(&cap[1]).error("expected an opcode like \"addr\""))?;
+        Ok(Instruction(op, line.field(&cap[2]).parse()?, line.field(&cap[3]).parse()?, line.field(&cap[4]).parse()?))
     })
-    .collect();
-    (ip, instructions)
+    .collect::<Result<_, _>>()?;
+    Ok((ip, instructions))
+}
+
+fn execute(ip: usize, program: &[Instruction], registers: [usize; 6]) -> [usize; 6] {
+    let mut machine = new_machine(ip, registers);
+    machine.run(program, &mut ());
+    machine.registers.values
+}
+
+pub fn part1((ip, program): &(usize, Vec<Instruction>)) -> usize {
+    execute(*ip, program, [0, 0, 0, 0, 0, 0])[0]
+}
+
+pub fn part2((ip, program): &(usize, Vec<Instruction>)) -> usize {
+    execute(*ip, program, [1, 0, 0, 0, 0, 0])[0]
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (usize, Vec<Instruction>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse_instructions(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "\
+#ip 0
+seti 5 0 1
+seti 6 0 2
+addi 0 1 0
+addr 1 2 3
+setr 1 0 0
+seti 8 0 4
+seti 9 0 5";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse_instructions(EX).unwrap()), 6);
+    }
+
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_instructions("#ip 0\nseti 5 0 1\nsett 6 0 2").err().unwrap();
+        assert_eq!((err.line, err.column, err.message.as_str()), (3, 1, "expected an opcode like \"addr\""));
+    }
+}
@@ -0,0 +1,63 @@
+use std::collections::BTreeSet;
+
+use day19_2018::{new_machine, parse_instructions, Instruction, Op};
+use solution::Solution;
+
+// The prompt asks to find the value of r0 which minimizes the instructions executed to get to the
+// halt point. This eqrr instruction is what causes the halt so we can get the value directly from
+// what is being compared to r0.
+fn find_halt_check(program: &[Instruction]) -> usize {
+    program.iter().position(|&Instruction(op, _, b, _)| op == Op::Eqrr && b == 0).unwrap()
+}
+
+pub fn part1((ip, program): &(usize, Vec<Instruction>)) -> usize {
+    let halt_check = find_halt_check(program);
+    let mut machine = new_machine(*ip, [0, 0, 0, 0, 0, 0]);
+    while machine.pc as usize != halt_check {
+        machine.step(program, &mut ());
+    }
+    let Instruction(_, a, _, _) = program[halt_check];
+    machine.registers.values[a]
+}
+
+// NOTE - must be run with input_optimized, see README
+pub fn part2((ip, program): &(usize, Vec<Instruction>)) -> usize {
+    let mut program = program.clone();
+    let halt_check = find_halt_check(&program);
+    let Instruction(_, a, b, c) = program[halt_check];
+    // Never halt, just observe every value that would have been compared
+    program[halt_check] = Instruction(Op::Seti, 0, b, c);
+    let mut machine = new_machine(*ip, [0, 0, 0, 0, 0, 0]);
+    let mut seen = BTreeSet::new();
+    let mut last_val = 0;
+    loop {
+        if machine.pc as usize == halt_check {
+            let val = machine.registers.values[a];
+            if !seen.insert(val) {
+                return last_val;
+            }
+            last_val = val;
+        }
+        machine.step(&program, &mut ());
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (usize, Vec<Instruction>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse_instructions(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
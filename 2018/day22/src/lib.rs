@@ -0,0 +1,153 @@
+use std::collections::{BTreeMap, VecDeque};
+
+use lazy_static::lazy_static;
+use parsing::{Line, ParseError};
+use regex::Regex;
+use solution::Solution;
+
+// Returns (depth, target_pos)
+pub fn parse(input: &str) -> Result<(usize, (usize, usize)), ParseError> {
+    lazy_static!{
+        static ref DEPTH_RE: Regex = Regex::new("^depth: (\\d+)$").unwrap();
+        static ref TARGET_RE: Regex = Regex::new("^target: (\\d+),(\\d+)").unwrap();
+    }
+    let lines = parsing::lines(input);
+    let (depth_line, target_line) = match lines[..] {
+        [depth_line, target_line] => (depth_line, target_line),
+        [_, _, extra, ..] => return Err(extra.error(1, "expected only the depth and the target")),
+        ref lines => return Err(Line { number: lines.len() + 1, text: "" }.error(1, "expected the depth and the target")),
+    };
+    let cap = DEPTH_RE.captures(depth_line.text).ok_or_else(|| depth_line.error(1, "expected e.g. \"depth: 510\""))?;
+    let depth = depth_line.field(&cap[1]).parse()?;
+    let cap = TARGET_RE.captures(target_line.text).ok_or_else(|| target_line.error(1, "expected e.g. \"target: 10,10\""))?;
+    let target_pos = (target_line.field(&cap[1]).parse()?, target_line.field(&cap[2]).parse()?);
+    Ok((depth, target_pos))
+}
+
+fn erosion_level(depth: usize, geologic_index: usize) -> usize {
+    (geologic_index + depth) % 20183
+}
+
+fn risk_level(depth: usize, geologic_index: usize) -> usize {
+    erosion_level(depth, geologic_index) % 3
+}
+
+fn build_geologic_index(depth: usize, target: (usize, usize), width: usize, height: usize) -> Vec<Vec<usize>> {
+    let mut geologic_index = vec![vec![0usize; width]; height];
+    for y in 0 .. height {
+        for x in 0 .. width {
+            geologic_index[y][x] = if (x, y) == (0, 0) {
+                0
+            } else if (x, y) == target {
+                0 
+            } else if y == 0 {
+                x * 16807
+            } else if x == 0 {
+                y * 48271
+            } else {
+                erosion_level(depth, geologic_index[y-1][x]) *
+                    erosion_level(depth, geologic_index[y][x-1])
+            };
+        }
+    }
+    geologic_index
+}
+
+fn build_map(depth: usize, mut geologic_index: Vec<Vec<usize>>) -> Vec<Vec<usize>> {
+    for row in geologic_index.iter_mut() {
+        for sq in row.iter_mut() {
+            *sq = risk_level(depth, *sq);
+        }
+    }
+    geologic_index
+}
+
+pub fn part1((depth, target): (usize, (usize, usize))) -> usize {
+    let geologic_index = build_geologic_index(depth, target, target.0 + 1, target.1 + 1);
+    let map = build_map(depth, geologic_index);
+
+    map.iter().map(|row| row.iter().sum::<usize>()).sum::<usize>()
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Tool {
+    Torch,
+    ClimbingGear,
+    Neither
+}
+
+fn tool_allowed(square: usize, tool: Tool) -> bool {
+    match square {
+        0 => tool == Tool::Torch || tool == Tool::ClimbingGear,
+        1 => tool == Tool::ClimbingGear || tool == Tool::Neither,
+        2 => tool == Tool::Torch || tool == Tool::Neither,
+        _ => unreachable!()
+    }
+}
+
+pub fn part2((depth, target): (usize, (usize, usize))) -> usize {
+    let extra = 100; // Arbitrary extra amount, can we calculate the exact right amount to use?
+    let width = target.0 + extra;
+    let height = target.1 + extra;
+    let geologic_index = build_geologic_index(depth, target, width, height);
+    let map = build_map(depth, geologic_index);
+
+    let mut visits = BTreeMap::new();
+    visits.insert(Tool::Torch, vec![vec![std::usize::MAX; width]; height]);
+    visits.insert(Tool::ClimbingGear, vec![vec![std::usize::MAX; width]; height]);
+    visits.insert(Tool::Neither, vec![vec![std::usize::MAX; width]; height]);
+
+    let mut queue = VecDeque::new();
+    queue.push_back((0, 0, Tool::Torch, 0));
+
+    while let Some((x, y, tool, dist)) = queue.pop_front() {
+        if !tool_allowed(map[y][x], tool) { continue }
+        if visits[&tool][y][x] <= dist { continue }
+        visits.get_mut(&tool).unwrap()[y][x] = dist;
+
+        queue.push_back((x, y, Tool::Torch, dist+7));
+        queue.push_back((x, y, Tool::ClimbingGear, dist+7));
+        queue.push_back((x, y, Tool::Neither, dist+7));
+        if x > 0          { queue.push_back((x-1, y, tool, dist+1)); }
+        if x < width - 1  { queue.push_back((x+1, y, tool, dist+1)); }
+        if y > 0          { queue.push_back((x, y-1, tool, dist+1)); }
+        if y < height - 1 { queue.push_back((x, y+1, tool, dist+1)); }
+    }
+    visits[&Tool::Torch][target.1][target.0]
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (usize, (usize, usize));
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(*input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(*input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "depth: 510\ntarget: 10,10\n";
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(parse(EX).unwrap()), 114);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(parse(EX).unwrap()), 45);
+    }
+}
@@ -0,0 +1,116 @@
+use std::cmp::max;
+
+use lazy_static::lazy_static;
+use parsing::ParseError;
+use regex::Regex;
+use solution::Solution;
+
+pub struct Claim {
+    id: usize,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize
+}
+
+pub fn parse(input: &str) -> Result<Vec<Claim>, ParseError> {
+    lazy_static! {
+        // E.g. #1 @ 1,3: 4x4
+        static ref RE: Regex = Regex::new("^#(\\d+) @ (\\d+),(\\d+): (\\d+)x(\\d+)$").unwrap();
+    }
+    parsing::parse_lines(input, |line| {
+        let caps = RE.captures(line.text).ok_or_else(|| line.error(1, "expected e.g. \"#1 @ 1,3: 4x4\""))?;
+        Ok(Claim {
+            id: line.field(&caps[1]).parse()?,
+            x: line.field(&caps[2]).parse()?,
+            y: line.field(&caps[3]).parse()?,
+            width: line.field(&caps[4]).parse()?,
+            height: line.field(&caps[5]).parse()?
+        })
+    })
+}
+
+fn make_fabric(claims: &[Claim]) -> (Vec<usize>, usize) {
+    let fabric_size = claims.iter()
+        .map(|c| max(c.x + c.width, c.y + c.height))
+        .max().unwrap();
+    (vec![0usize; fabric_size*fabric_size], fabric_size)
+}
+
+fn fill_claims(fabric: &mut Vec<usize>, fabric_size: usize, claims: &[Claim]) {
+    for claim in claims {
+        for y in claim.y .. (claim.y + claim.height) {
+            for x in claim.x .. (claim.x + claim.width) {
+                fabric[y * fabric_size + x] += 1;
+            }
+        }
+    }
+}
+
+pub fn part1(claims: &[Claim]) -> usize {
+    let (mut fabric, fabric_size) = make_fabric(claims);
+    fill_claims(&mut fabric, fabric_size, claims);
+    fabric.iter()
+        .filter(|sq| **sq > 1)
+        .count()
+}
+
+fn is_overlapping(fabric: &Vec<usize>, fabric_size: usize, claim: &Claim) -> bool {
+    for y in claim.y .. (claim.y + claim.height) {
+        for x in claim.x .. (claim.x + claim.width) {
+            if fabric[y * fabric_size + x] != 1 {
+                return false
+            }
+        }
+    }
+    return true
+}
+
+pub fn part2(claims: &[Claim]) -> usize {
+    let (mut fabric, fabric_size) = make_fabric(claims);
+    fill_claims(&mut fabric, fabric_size, claims);
+    claims.iter()
+        .filter(|claim| is_overlapping(&fabric, fabric_size, claim))
+        .next().unwrap().id
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Claim>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "\
+#1 @ 1,3: 4x4
+#2 @ 3,1: 4x4
+#3 @ 5,5: 2x2";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 4);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 3);
+    }
+
+}
@@ -0,0 +1,138 @@
+use std::collections::BTreeMap;
+
+use lazy_static::lazy_static;
+use parsing::ParseError;
+use regex::Regex;
+use solution::Solution;
+
+// Guard ID to total number of asleep minutes by the minute number
+pub fn parse(input: &str) -> Result<BTreeMap<usize, [usize; 60]>, ParseError> {
+    let mut lines = parsing::lines(input);
+    lines.sort_unstable_by_key(|line| line.text);
+
+    lazy_static! {
+        static ref SHIFT_CHANGE_RE: Regex =
+            Regex::new("^\\[\\d{4}-\\d{2}-\\d{2} \\d{2}:\\d{2}\\] Guard #(\\d+) begins shift$").unwrap();
+        static ref FALLS_ASLEEP_RE: Regex =
+            Regex::new("^\\[\\d{4}-\\d{2}-\\d{2} \\d{2}:([0-5]\\d)\\] falls asleep$").unwrap();
+        static ref WAKES_UP_RE: Regex =
+            Regex::new("^\\[\\d{4}-\\d{2}-\\d{2} \\d{2}:([0-5]\\d)\\] wakes up$").unwrap();
+    }
+
+    let mut curr_guard = None;
+    let mut is_asleep = false;
+    let mut last_minute = 0;
+    let mut asleep_minutes = BTreeMap::<usize, [usize; 60]>::new();
+
+    for line in lines {
+        if let Some(cap) = SHIFT_CHANGE_RE.captures(line.text) {
+            let next_guard = line.field(&cap[1]).parse()?;
+            if let (Some(guard), true) = (curr_guard, is_asleep) { // last guard was asleep to the end of their shift
+                for minute in last_minute .. 60 {
+                    asleep_minutes.get_mut(&guard).unwrap()[minute] += 1;
+                }
+            }
+
+            asleep_minutes.entry(next_guard).or_insert([0; 60]);
+            curr_guard = Some(next_guard);
+            is_asleep = false;
+            last_minute = 0;
+        } else if let Some(cap) = FALLS_ASLEEP_RE.captures(line.text) {
+            curr_guard.ok_or_else(|| line.error(20, "no guard is on shift yet"))?;
+            if is_asleep {
+                return Err(line.error(20, "the guard is already asleep"));
+            }
+            is_asleep = true;
+            last_minute = line.field(&cap[1]).parse()?;
+        } else if let Some(cap) = WAKES_UP_RE.captures(line.text) {
+            let guard = curr_guard.ok_or_else(|| line.error(20, "no guard is on shift yet"))?;
+            if !is_asleep {
+                return Err(line.error(20, "the guard is already awake"));
+            }
+            let next_minute = line.field(&cap[1]).parse()?;
+            for minute in last_minute .. next_minute {
+                asleep_minutes.get_mut(&guard).unwrap()[minute] += 1;
+            }
+
+            is_asleep = false;
+            last_minute = next_minute;
+        } else {
+            return Err(line.error(1, "expected e.g. \"[1518-11-01 00:05] falls asleep\""));
+        }
+    }
+
+    Ok(asleep_minutes)
+}
+
+pub fn part1(asleep_minutes: &BTreeMap<usize, [usize; 60]>) -> usize {
+    let (best_guard, guard_minutes) = asleep_minutes.iter().max_by_key(|(_,counts)| counts.iter().sum::<usize>()).unwrap();
+    let best_minute = guard_minutes.iter().enumerate().max_by_key(|(_,count)| *count).unwrap().0;
+    best_guard * best_minute
+}
+
+pub fn part2(asleep_minutes: &BTreeMap<usize, [usize; 60]>) -> usize {
+    let (best_guard, guard_minutes) = asleep_minutes.iter().max_by_key(|(_,counts)| counts.iter().max()).unwrap();
+    let best_minute = guard_minutes.iter().enumerate().max_by_key(|(_,count)| *count).unwrap().0;
+    best_guard * best_minute
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = BTreeMap<usize, [usize; 60]>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "\
+[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:25] wakes up
+[1518-11-01 00:30] falls asleep
+[1518-11-01 00:55] wakes up
+[1518-11-01 23:58] Guard #99 begins shift
+[1518-11-02 00:40] falls asleep
+[1518-11-02 00:50] wakes up
+[1518-11-03 00:05] Guard #10 begins shift
+[1518-11-03 00:24] falls asleep
+[1518-11-03 00:29] wakes up
+[1518-11-04 00:02] Guard #99 begins shift
+[1518-11-04 00:36] falls asleep
+[1518-11-04 00:46] wakes up
+[1518-11-05 00:03] Guard #99 begins shift
+[1518-11-05 00:45] falls asleep
+[1518-11-05 00:55] wakes up";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 240);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 4455);
+    }
+
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("[1518-11-01 00:05] wakes up\n[1518-11-01 00:00] Guard #10 begins shift").err().unwrap();
+        assert_eq!((err.line, err.column, err.message.as_str()), (1, 20, "the guard is already awake"));
+    }
+}
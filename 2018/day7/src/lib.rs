@@ -0,0 +1,124 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use lazy_static::lazy_static;
+use parsing::ParseError;
+use regex::Regex;
+use solution::Solution;
+
+pub fn parse(input: &str) -> Result<Vec<(char, char)>, ParseError> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new("^Step ([A-Z]) must be finished before step ([A-Z]) can begin\\.$").unwrap();
+    }
+
+    parsing::parse_lines(input, |line| {
+        let cap = RE.captures(line.text)
+            .ok_or_else(|| line.error(1, "expected e.g. \"Step C must be finished before step A can begin.\""))?;
+        Ok((line.field(&cap[1]).parse()?, line.field(&cap[2]).parse()?))
+    })
+}
+
+fn all_nodes(edges: &[(char, char)]) -> BTreeSet<char> {
+    edges.iter().map(|(c,_)| *c).chain(edges.iter().map(|(_,c)| *c)).collect()
+}
+
+pub fn part1(edges: &[(char, char)]) -> String {
+    let mut edges = edges.to_vec();
+    let mut nodes = all_nodes(&edges);
+    let mut ret = String::new();
+
+    while !nodes.is_empty() {
+        let node = nodes.iter()
+            .map(|ch| *ch)
+            .filter(|ch| edges.iter().all(|edge| edge.1 != *ch))
+            .next().unwrap();
+        ret.push(node);
+        nodes.remove(&node);
+        edges.retain(|edge| edge.0 != node);
+    }
+
+    ret
+}
+
+fn step_time(node: char, base_time: usize) -> usize {
+    (node as usize - 'A' as usize) + base_time + 1
+}
+
+fn calc_part2(edges: &[(char, char)], num_workers: usize, base_time: usize) -> usize {
+    let mut edges = edges.to_vec();
+    let mut nodes = all_nodes(&edges);
+    let mut workers: BTreeMap<char, usize> = BTreeMap::new();
+    let mut time = 0;
+
+    while !nodes.is_empty() || !workers.is_empty() {
+        let available_node = nodes.iter()
+            .map(|node| *node)
+            .filter(|node| !workers.contains_key(node))
+            .filter(|node| edges.iter().all(|edge| edge.1 != *node))
+            .next();
+        if workers.len() < num_workers && available_node.is_some() {
+            let node = available_node.unwrap();
+            workers.insert(node, time + step_time(node, base_time));
+        } else {
+            assert!(!workers.is_empty());
+            let (node, next_time) = workers.iter()
+                .map(|(n,t)| (*n, *t)).min_by_key(|(_,t)| *t).unwrap();
+            assert!(next_time >= time);
+            time = next_time;
+            workers.remove(&node);
+            nodes.remove(&node);
+            edges.retain(|edge| edge.0 != node);
+        }
+    }
+
+    time
+}
+
+pub fn part2(edges: &[(char, char)]) -> usize {
+    calc_part2(edges, 5, 60)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<(char, char)>;
+    type Answer1 = String;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "\
+Step C must be finished before step A can begin.
+Step C must be finished before step F can begin.
+Step A must be finished before step B can begin.
+Step A must be finished before step D can begin.
+Step B must be finished before step E can begin.
+Step D must be finished before step E can begin.
+Step F must be finished before step E can begin.";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), "CABDFE".to_string());
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(calc_part2(&parse(EX).unwrap(), 2, 0), 15);
+    }
+
+}
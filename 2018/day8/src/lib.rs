@@ -0,0 +1,113 @@
+use parsing::{Field, Line, ParseError};
+use solution::Solution;
+
+pub struct Node {
+    children: Vec<Box<Node>>,
+    metadata: Vec<usize>
+}
+
+pub fn parse(input: &str) -> Result<Node, ParseError> {
+    let line = parsing::single_line(input)?;
+    let mut iter = line.fields().into_iter();
+    let ret = parse_iter(&mut iter, &line)?;
+    match iter.next() {
+        Some(extra) => Err(extra.error("expected the tree to end here")),
+        None => Ok(ret)
+    }
+}
+
+fn next_number(input: &mut dyn Iterator<Item=Field>, line: &Line) -> Result<usize, ParseError> {
+    input.next()
+        .ok_or_else(|| line.error(line.text.len() + 1, "expected more numbers"))?
+        .parse()
+}
+
+fn parse_iter(input: &mut dyn Iterator<Item=Field>, line: &Line) -> Result<Node, ParseError> {
+    let num_children = next_number(input, line)?;
+    let num_metadata = next_number(input, line)?;
+    let mut children = Vec::new();
+    for _ in 0..num_children {
+        children.push(Box::new(parse_iter(input, line)?));
+    }
+    let mut metadata = Vec::new();
+    for _ in 0..num_metadata {
+        metadata.push(next_number(input, line)?);
+    }
+    Ok(Node {
+        children: children,
+        metadata: metadata
+    })
+}
+
+fn sum_part1(node: &Node) -> usize {
+    let mut sum = node.metadata.iter().sum();
+    for child in node.children.iter() {
+        sum += sum_part1(child);
+    }
+    sum
+}
+
+pub fn part1(tree: &Node) -> usize {
+    sum_part1(tree)
+}
+
+fn sum_part2(node: &Node) -> usize {
+    if node.children.is_empty() {
+        node.metadata.iter().sum()
+    } else {
+        node.metadata.iter()
+            .filter(|m| **m > 0 && **m <= node.children.len())
+            .map(|m| sum_part2(&node.children[*m - 1]))
+            .sum()
+    }
+}
+
+pub fn part2(tree: &Node) -> usize {
+    sum_part2(tree)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Node;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 138);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 66);
+    }
+
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("1 1 0 1 99").err().unwrap();
+        assert_eq!((err.column, err.message.as_str()), (11, "expected more numbers"));
+        assert_eq!(parse("0 1 99 2").err().unwrap().column, 8);
+    }
+}
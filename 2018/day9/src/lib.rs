@@ -0,0 +1,109 @@
+use lazy_static::lazy_static;
+use linked_list::{LinkedList, Cursor};
+use parsing::ParseError;
+use regex::Regex;
+use solution::Solution;
+
+// (players, last marble)
+pub fn parse(input: &str) -> Result<(usize, usize), ParseError> {
+    lazy_static!{
+        static ref RE: Regex = Regex::new("^(\\d+) players; last marble is worth (\\d+) points").unwrap();
+    }
+    let line = parsing::single_line(input)?;
+    let cap = RE.captures(line.text)
+        .ok_or_else(|| line.error(1, "expected e.g. \"9 players; last marble is worth 25 points\""))?;
+    let num_players = line.field(&cap[1]).parse()?;
+    if num_players == 0 {
+        return Err(line.error(1, "expected at least one player"));
+    }
+    Ok((num_players, line.field(&cap[2]).parse()?))
+}
+
+fn next_circular(cursor: &mut Cursor<usize>) -> usize {
+    if let Some(elem) = cursor.next() {
+        *elem
+    } else {
+        *cursor.next().unwrap()
+    }
+}
+
+fn prev_circular(cursor: &mut Cursor<usize>) -> usize {
+    if let Some(elem) = cursor.prev() {
+        *elem
+    } else {
+        *cursor.prev().unwrap()
+    }
+}
+
+fn remove_circular(cursor: &mut Cursor<usize>) -> usize {
+    if let Some(elem) = cursor.remove() {
+        elem
+    } else {
+        cursor.next();
+        cursor.remove().unwrap()
+    }
+}
+
+fn calc(num_players: usize, last_marble: usize) -> usize {
+    let mut scores = vec![0; num_players];
+    let mut player = num_players - 1;
+    let mut circle = LinkedList::new();
+    circle.push_back(0);
+    let mut cur = circle.cursor();
+    for marble in 1..=last_marble {
+        player = (player + 1) % num_players;
+
+        if marble % 23 == 0 {
+            for _ in 0..7 { prev_circular(&mut cur); }
+            scores[player] += marble + remove_circular(&mut cur);
+        } else {
+            for _ in 0..2 { next_circular(&mut cur); }
+            cur.insert(marble);
+        }
+    }
+    *scores.iter().max().unwrap()
+}
+
+pub fn part1((num_players, last_marble): (usize, usize)) -> usize {
+    calc(num_players, last_marble)
+}
+
+pub fn part2((num_players, last_marble): (usize, usize)) -> usize {
+    calc(num_players, last_marble * 100)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (usize, usize);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(*input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(*input)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(parse("9 players; last marble is worth 25 points").unwrap()), 32);
+        assert_eq!(part1(parse("10 players; last marble is worth 1618 points").unwrap()), 8317);
+        assert_eq!(part1(parse("13 players; last marble is worth 7999 points").unwrap()), 146373);
+        assert_eq!(part1(parse("17 players; last marble is worth 1104 points").unwrap()), 2764);
+        assert_eq!(part1(parse("21 players; last marble is worth 6111 points").unwrap()), 54718);
+        assert_eq!(part1(parse("30 players; last marble is worth 5807 points").unwrap()), 37305);
+    }
+}
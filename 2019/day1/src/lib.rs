@@ -0,0 +1,69 @@
+use parsing::{parse_lines, ParseError};
+use solution::Solution;
+
+pub fn parse_input(input: &str) -> Result<Vec<i32>, ParseError> {
+    parse_lines(input, |line| line.parse())
+}
+
+fn fuel_needed(module_mass: i32) -> i32 {
+    let fuel = (module_mass / 3) - 2;
+    if fuel >= 0 { fuel } else { 0 }
+}
+
+fn fuel_needed_including_fuel(module_mass: i32) -> i32 {
+    let mut fuel = fuel_needed(module_mass);
+    let mut tot_fuel = fuel;
+    while fuel > 0 {
+        fuel = fuel_needed(fuel);
+        tot_fuel += fuel;
+    }
+    tot_fuel
+}
+
+pub fn part1(masses: &[i32]) -> i32 {
+    masses.iter().copied().map(|mass| fuel_needed(mass)).sum::<i32>()
+}
+
+pub fn part2(masses: &[i32]) -> i32 {
+    masses.iter().copied().map(|mass| fuel_needed_including_fuel(mass)).sum::<i32>()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse_input(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> i32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> i32 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(fuel_needed(12), 2);
+        assert_eq!(fuel_needed(14), 2);
+        assert_eq!(fuel_needed(1969), 654);
+        assert_eq!(fuel_needed(100756), 33583);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(fuel_needed_including_fuel(14), 2);
+        assert_eq!(fuel_needed_including_fuel(1969), 966);
+        assert_eq!(fuel_needed_including_fuel(100756), 50346);
+    }
+}
@@ -0,0 +1,204 @@
+use std::cmp::Ordering;
+
+use lazy_static::lazy_static;
+use num_integer::lcm;
+use parsing::{lines, ParseError};
+use regex::Regex;
+use solution::Solution;
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct Moon {
+    pos: [i64; 3],
+    vel: [i64; 3]
+}
+
+pub fn parse(input: &str) -> Result<Vec<Moon>, ParseError> {
+    lazy_static!{
+        static ref RE: Regex = Regex::new("^<x=([0-9-]+), y=([0-9-]+), z=([0-9-]+)>$").unwrap();
+    }
+
+    lines(input).into_iter().filter(|line| !line.is_blank()).map(|line| {
+        let cap = RE.captures(line.text).ok_or_else(|| line.error(1, "expected a position like \"<x=-1, y=0, z=2>\""))?;
+        let pos = [
+            line.field(&cap[1]).parse()?,
+            line.field(&cap[2]).parse()?,
+            line.field(&cap[3]).parse()?
+        ];
+        Ok(Moon {
+            pos: pos,
+            vel: [0, 0, 0]
+        })
+    })
+    .collect()
+}
+
+fn get_mut_pair<T>(v: &mut Vec<T>, i: usize, j: usize) -> (&mut T, &mut T) {
+    assert!(i < j);
+    let (a, b) = v.split_at_mut(j);
+    (&mut a[i], &mut b[0])
+}
+
+fn step_velocities(moons: &mut Vec<Moon>) {
+    for i in 0 .. moons.len() {
+        for j in i+1 .. moons.len() {
+            let (m1, m2) = get_mut_pair(moons, i, j);
+            for axis in 0 .. 3 {
+                match m1.pos[axis].cmp(&m2.pos[axis]) {
+                    Ordering::Less => {
+                        m1.vel[axis] += 1;
+                        m2.vel[axis] -= 1;
+                    },
+                    Ordering::Greater => {
+                        m1.vel[axis] -= 1;
+                        m2.vel[axis] += 1;
+                    },
+                    Ordering::Equal => ()
+                }
+            }
+        }
+    }
+}
+
+fn step_positions(moons: &mut Vec<Moon>) {
+    for moon in moons.iter_mut() {
+        for axis in 0 .. 3 {
+            moon.pos[axis] += moon.vel[axis];
+        }
+    }
+}
+
+fn step(moons: &mut Vec<Moon>) {
+    step_velocities(moons);
+    step_positions(moons);
+}
+
+fn potential_energy(moon: &Moon) -> u64 {
+    (0 .. 3).map(|axis| moon.pos[axis].abs() as u64).sum()
+}
+
+fn kinetic_energy(moon: &Moon) -> u64 {
+    (0 .. 3).map(|axis| moon.vel[axis].abs() as u64).sum()
+}
+
+fn energy(moon: &Moon) -> u64 {
+    potential_energy(moon) * kinetic_energy(moon)
+}
+
+fn total_energy(moons: &Vec<Moon>) -> u64 {
+    moons.iter().map(|moon| energy(moon)).sum()
+}
+
+fn axis_repeats(moons1: &Vec<Moon>, moons2: &Vec<Moon>, axis: usize) -> bool {
+    moons1.iter().zip(moons2.iter()).all(|(moon1, moon2)| {
+        moon1.pos[axis] == moon2.pos[axis] && moon1.vel[axis] == moon2.vel[axis]
+    })
+}
+
+fn steps_before_repeat(moons: &mut Vec<Moon>) -> u64 {
+    let initial_moons = moons.clone();
+    let mut steps_for_axis_repeat = [0; 3];
+    for steps in 1 .. {
+        if steps_for_axis_repeat.iter().all(|steps_for_repeat| *steps_for_repeat != 0) {
+            break;
+        }
+        step(moons);
+        for axis in 0 .. 3 {
+            if steps_for_axis_repeat[axis] == 0 && axis_repeats(&initial_moons, moons, axis) {
+                steps_for_axis_repeat[axis] = steps;
+            }
+        }
+    }
+    lcm(steps_for_axis_repeat[0], lcm(steps_for_axis_repeat[1], steps_for_axis_repeat[2]))
+}
+
+pub fn part1(moons: &[Moon]) -> u64 {
+    let mut moons = moons.to_vec();
+    for _ in 0 .. 1000 {
+        step(&mut moons);
+    }
+    total_energy(&moons)
+}
+
+pub fn part2(moons: &[Moon]) -> u64 {
+    steps_before_repeat(&mut moons.to_vec())
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Moon>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX1: &str = "
+<x=-1, y=0, z=2>
+<x=2, y=-10, z=-7>
+<x=4, y=-8, z=8>
+<x=3, y=5, z=-1>";
+
+    const EX2: &str = "
+<x=-8, y=-10, z=0>
+<x=5, y=5, z=10>
+<x=2, y=-7, z=3>
+<x=9, y=-8, z=-3>";
+
+    #[test]
+    fn test_part1() {
+        let mut moons = parse(EX1).unwrap();
+        for _ in 0 .. 10 {
+            step(&mut moons);
+        }
+        let ex_end = vec![
+            Moon { pos: [2,  1, -3], vel: [-3, -2,  1] },
+            Moon { pos: [1, -8,  0], vel: [-1,  1,  3] },
+            Moon { pos: [3, -6,  1], vel: [ 3,  2, -3] },
+            Moon { pos: [2,  0,  4], vel: [ 1, -1, -1] }
+        ];
+        assert_eq!(ex_end, moons);
+        assert_eq!(179, total_energy(&moons));
+
+        let mut moons = parse(EX2).unwrap();
+        for _ in 0 .. 100 {
+            step(&mut moons);
+        }
+        let ex_end = vec![
+            Moon { pos: [  8, -12, -9], vel: [-7,   3,  0] },
+            Moon { pos: [ 13,  16, -3], vel: [ 3, -11, -5] },
+            Moon { pos: [-29, -11, -1], vel: [-3,   7,  4] },
+            Moon { pos: [ 16, -13, 23], vel: [ 7,   1,  1] }
+        ];
+        assert_eq!(ex_end, moons);
+        assert_eq!(1940, total_energy(&moons));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(2772, steps_before_repeat(&mut parse(EX1).unwrap()));
+        assert_eq!(4686774924, steps_before_repeat(&mut parse(EX2).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("<x=-1, y=0, z=2>\n<x=2, y=-10, z=7").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("<x=-1, y=0, z=2>\n<x=2, y=-10, z=9999999999999999999>").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 16));
+    }
+}
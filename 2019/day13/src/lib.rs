@@ -0,0 +1,101 @@
+use std::cmp::Ordering;
+use std::sync::mpsc::channel;
+use std::thread;
+
+use intcode::*;
+use solution::Solution;
+
+#[derive(Eq, PartialEq)]
+enum Tile {
+    Empty,
+    Wall,
+    Block,
+    HorizontalPaddle,
+    Ball
+}
+
+impl From<i64> for Tile {
+    fn from(id: i64) -> Tile {
+        match id {
+            0 => Tile::Empty,
+            1 => Tile::Wall,
+            2 => Tile::Block,
+            3 => Tile::HorizontalPaddle,
+            4 => Tile::Ball,
+            _ => panic!()
+        }
+    }
+}
+
+fn play_game(mem: &Mem) -> i64 {
+    let (tx_in, rx_in) = channel();
+    let (tx_out, rx_out) = channel();
+    let mut mem = mem.clone();
+    mem[0] = 2;
+
+    thread::spawn(move || run(&mut mem, &rx_in, tx_out));
+
+    let mut x_paddle = 0;
+    let mut x_ball;
+    let mut score = 0;
+    while let Ok(x) = rx_out.recv() {
+        let _y = rx_out.recv().unwrap();
+        let id = rx_out.recv().unwrap();
+        if x == -1 {
+            score = id;
+        } else {
+            match Tile::from(id) {
+                Tile::Ball => {
+                    x_ball = x;
+                    let joystick = match x_paddle.cmp(&x_ball) {
+                        Ordering::Less => 1,
+                        Ordering::Equal => 0,
+                        Ordering::Greater => -1
+                    };
+                    tx_in.send(joystick).unwrap();
+                },
+                Tile::HorizontalPaddle => x_paddle = x,
+                _ => ()
+            }
+        }
+    }
+
+    score
+}
+
+pub fn part1(mem: &Mem) -> usize {
+    let (tx_out, rx_out) = channel();
+    run(&mut mem.clone(), &channel().1, tx_out);
+    let mut num_blocks = 0;
+    while let Ok(_x) = rx_out.recv() {
+        let _y = rx_out.recv().unwrap();
+        if let Tile::Block = rx_out.recv().unwrap().into() {
+            num_blocks += 1;
+        }
+    }
+    num_blocks
+}
+
+pub fn part2(mem: &Mem) -> i64 {
+    play_game(mem)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Mem;
+    type Answer1 = usize;
+    type Answer2 = i64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> i64 {
+        part2(input)
+    }
+}
@@ -0,0 +1,226 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::ops;
+use std::sync::mpsc::channel;
+use std::thread;
+
+use frames::{Animation, Frame};
+use intcode::*;
+use params::Params;
+use solution::Solution;
+
+#[derive(Clone, Copy)]
+enum Dir {
+    North,
+    South,
+    East,
+    West
+}
+
+const DIRS: [Dir; 4] = [Dir::North, Dir::South, Dir::East, Dir::West];
+
+impl Dir {
+    fn to_command(&self) -> i64 {
+        match self {
+            Dir::North => 1,
+            Dir::South => 2,
+            Dir::East => 3,
+            Dir::West => 4
+        }
+    }
+}
+
+#[derive(Copy, Clone, Hash, Eq, PartialEq)]
+struct Pos { x: i32, y: i32 }
+
+impl Pos {
+    fn origin() -> Pos {
+        Pos { x: 0, y: 0 }
+    }
+
+    fn new(x: i32, y: i32) -> Pos {
+        Pos { x: x, y: y }
+    }
+}
+
+impl ops::Add<Dir> for Pos {
+    type Output = Pos;
+    fn add(self, dir: Dir) -> Pos {
+        match dir {
+            Dir::North => Pos { x: self.x, y: self.y - 1 },
+            Dir::South => Pos { x: self.x, y: self.y + 1 },
+            Dir::East => Pos { x: self.x + 1, y: self.y },
+            Dir::West => Pos { x: self.x - 1, y: self.y }
+        }
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum Square {
+    Wall,
+    Oxygen,
+    Ground
+}
+
+fn dist_to_next_unexplored(start_pos: Pos, map: &HashMap<Pos, Square>) -> Option<usize> {
+    let mut queue = VecDeque::new();
+    let mut seen = HashSet::new();
+    queue.push_back((start_pos, 0));
+    while let Some((pos, dist)) = queue.pop_front() {
+        if !seen.insert(pos) {
+            continue;
+        }
+        match map.get(&pos) {
+            None => return Some(dist),
+            Some(Square::Wall) => continue,
+            Some(Square::Ground) => (),
+            Some(Square::Oxygen) => ()
+        }
+        for dir in DIRS.iter() {
+            queue.push_back((pos + *dir, dist + 1));
+        }
+    }
+    None
+}
+
+fn dir_to_next_unexplored(pos: Pos, map: &HashMap<Pos, Square>) -> Option<Dir> {
+    DIRS.iter().filter(|dir| map.get(&(pos + **dir)).unwrap_or(&Square::Ground) != &Square::Wall)
+        .map(|dir| (dir, dist_to_next_unexplored(pos + *dir, map)))
+        .filter(|(_, dist)| dist.is_some())
+        .map(|(dir, dist)| (dir, dist.unwrap()))
+        .min_by_key(|(_, dist)| *dist)
+        .map(|(dir, _)| *dir)
+}
+
+fn build_map(mem: &Mem, animation: &mut Animation) -> HashMap<Pos, Square> {
+    let (tx_in, rx_in) = channel();
+    let (tx_out, rx_out) = channel();
+
+    let mut mem = mem.clone();
+    thread::spawn(move || run(&mut mem, &rx_in, tx_out));
+
+    let mut map = HashMap::new();
+    let mut pos = Pos::origin();
+    while let Some(dir) = dir_to_next_unexplored(pos, &map) {
+        tx_in.send(dir.to_command()).unwrap();
+        match rx_out.recv().unwrap() {
+            0 => {
+                map.insert(pos + dir, Square::Wall);
+            },
+            1 => {
+                map.insert(pos + dir, Square::Ground);
+                pos = pos + dir;
+            },
+            2 => {
+                map.insert(pos + dir, Square::Oxygen);
+                pos = pos + dir;
+            },
+            _ => panic!()
+        }
+        animation.frame(|| to_frame(&map, Some(pos)));
+    }
+    map
+}
+
+// The map so far, with the droid (or anything else of interest) marked D
+fn to_frame(map: &HashMap<Pos, Square>, droid: Option<Pos>) -> Frame {
+    let positions = || map.keys().chain(droid.iter());
+    let min_x = positions().map(|pos| pos.x).min().unwrap();
+    let max_x = positions().map(|pos| pos.x).max().unwrap();
+    let min_y = positions().map(|pos| pos.y).min().unwrap();
+    let max_y = positions().map(|pos| pos.y).max().unwrap();
+
+    let mut frame = Frame::new((max_x - min_x + 1) as usize, (max_y - min_y + 1) as usize, ' ');
+    for y in min_y..max_y+1 {
+        for x in min_x..max_x+1 {
+            let pos = Pos::new(x, y);
+            let ch = match map.get(&pos) {
+                _ if droid == Some(pos) => 'D',
+                None => ' ',
+                Some(Square::Wall) => '#',
+                Some(Square::Ground) => '.',
+                Some(Square::Oxygen) => 'O'
+            };
+            frame.set((x - min_x) as usize, (y - min_y) as usize, ch);
+        }
+    }
+    frame
+}
+
+pub fn part1(mem: &Mem) -> usize {
+    let map = build_map(mem, &mut Animation::off());
+
+    let mut queue = VecDeque::new();
+    let mut seen = HashSet::new();
+    queue.push_back((Pos::origin(), 0));
+    while let Some((pos, dist)) = queue.pop_front() {
+        if !seen.insert(pos) {
+            continue;
+        }
+        match map.get(&pos) {
+            Some(Square::Wall) => continue,
+            Some(Square::Ground) => (),
+            Some(Square::Oxygen) => return dist,
+            None => panic!()
+        }
+        for dir in DIRS.iter() {
+            queue.push_back((pos + *dir, dist + 1));
+        }
+    }
+    panic!();
+}
+
+pub fn part2(mem: &Mem, animation: &mut Animation) -> usize {
+    let mut steps = 0;
+    let mut map = build_map(mem, animation);
+    while map.values().any(|sq| *sq == Square::Ground) {
+        let mut nextmap = map.clone();
+        for (pos, sq) in map.iter() {
+            match sq {
+                Square::Oxygen => (),
+                _ => continue
+            }
+            for dir in DIRS.iter() {
+                match map.get(&(*pos + *dir)) {
+                    Some(Square::Wall) => (),
+                    Some(_) => *nextmap.get_mut(&(*pos + *dir)).unwrap() = Square::Oxygen,
+                    None => panic!()
+                }
+            }
+        }
+        map = nextmap;
+        steps += 1;
+        animation.frame(|| to_frame(&map, None));
+    }
+    steps
+}
+
+pub struct Droid {
+    pub mem: Mem,
+    pub animation: RefCell<Animation>,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Droid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        let mem = parse(puzzle_input).map_err(|e| e.to_string())?;
+        Ok(Droid { mem, animation: RefCell::new(Animation::off()) })
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(&input.mem)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(&input.mem, &mut input.animation.borrow_mut())
+    }
+
+    fn configure(input: &mut Self::Input, params: &Params) {
+        input.animation = RefCell::new(Animation::from_params(params));
+    }
+}
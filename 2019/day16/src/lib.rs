@@ -0,0 +1,143 @@
+use parsing::{single_line, ParseError};
+use solution::Solution;
+
+const PAT: [i32; 4] = [0, 1, 0, -1];
+
+pub fn parse(signal: &str) -> Result<Vec<u8>, ParseError> {
+    single_line(signal)?.as_field().trim().map_chars(|ch| ch.to_digit(10).map(|d| d as u8))
+}
+
+fn fft_slow(signal: Vec<u8>, offset: usize) -> Vec<u8> {
+    (0 .. signal.len()).into_iter().map(|i| {
+        let mut val = 0;
+        for j in i .. signal.len() {
+            val += signal[j] as i32 * PAT[(((j + offset + 1) / (i + offset + 1)) % 4)];
+        }
+        (val.abs() % 10) as u8
+    }).collect()
+}
+
+// When the offset is more than half the total length, the pattern ends up as an all ones
+// upper-triangular matrix, like:
+//
+// 1 1 1 1 
+// 0 1 1 1
+// 0 0 1 1
+// 0 0 0 1
+//
+// So we can just sum the elements in reverse order
+fn fft_fast(signal: Vec<u8>) -> Vec<u8> {
+    let mut output = Vec::with_capacity(signal.len());
+
+    let mut sum = 0;
+    for val in signal.iter().rev() {
+        sum += *val as i32;
+        output.push((sum.abs() % 10) as u8);
+    }
+    output.reverse();
+    output
+}
+
+fn fft(signal: Vec<u8>, offset: usize) -> Vec<u8> {
+    if signal.len() >= offset {
+        fft_slow(signal, offset)
+    } else {
+        fft_fast(signal)
+    }
+}
+
+fn signal_offset(signal: &[u8]) -> usize {
+    let signal = &signal[0..7];
+    let mut offset = 0;
+    for val in signal {
+        offset = offset * 10 + (*val as usize);
+    }
+    offset
+}
+
+fn iter_fft(mut signal: Vec<u8>, iterations: usize, offset: usize) -> Vec<u8> {
+    for _ in 0 .. iterations {
+        signal = fft(signal, offset);
+    }
+    signal
+}
+
+pub fn part1(signal: &[u8]) -> String {
+    iter_fft(signal.to_vec(), 100, 0)[0..8].iter().map(|i| format!("{}", i)).collect()
+}
+
+pub fn part2(signal_once: &[u8]) -> String {
+    let offset = signal_offset(signal_once);
+    let mut signal = Vec::with_capacity(signal_once.len() * 10_000);
+    for _ in 0..10_000 {
+        for val in signal_once.iter() {
+            signal.push(*val);
+        }
+    }
+    // Only digits at or to the right of a particular digit can affect it's value
+    let signal = signal.split_off(offset);
+    iter_fft(signal, 100, offset)[0..8].iter().map(|i| format!("{}", i)).collect()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<u8>;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(iter_fft(parse("12345678").unwrap(), 1, 0), parse("48226158").unwrap());
+        assert_eq!(iter_fft(parse("12345678").unwrap(), 2, 0), parse("34040438").unwrap());
+        assert_eq!(iter_fft(parse("12345678").unwrap(), 3, 0), parse("03415518").unwrap());
+        assert_eq!(iter_fft(parse("12345678").unwrap(), 4, 0), parse("01029498").unwrap());
+
+        assert_eq!(part1(&parse("80871224585914546619083218645595").unwrap()), "24176176");
+        assert_eq!(part1(&parse("19617804207202209144916044189917").unwrap()), "73745418");
+        assert_eq!(part1(&parse("69317163492948606335995924319873").unwrap()), "52432133");
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(iter_fft(parse("2345678").unwrap(), 1, 1), parse("8226158").unwrap());
+        assert_eq!(iter_fft(parse("45678").unwrap(), 1, 3), parse("26158").unwrap());
+    }
+
+    #[test]
+    fn test_part2_ex1() {
+        assert_eq!(part2(&parse("03036732577212944063491565474664").unwrap()), "84462026");
+    }
+
+    #[test]
+    fn test_part2_ex2() {
+        assert_eq!(part2(&parse("02935109699940807407585447034323").unwrap()), "78725270");
+    }
+
+    #[test]
+    fn test_part2_ex3() {
+        assert_eq!(part2(&parse("03081770884921959731165446850517").unwrap()), "53553731");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(parse("1234x678").err().unwrap().column, 5);
+    }
+}
@@ -0,0 +1,294 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, BTreeSet, HashSet, VecDeque};
+
+use parsing::{grid, lines, Line, ParseError};
+use solution::Solution;
+
+#[derive(Clone)]
+enum Square {
+    Open,
+    Key(String),
+    Door(String),
+    Wall
+}
+
+#[derive(Clone)]
+pub struct Map {
+    width: usize,
+    height: usize,
+    entrances: Vec<(usize, usize)>,
+    keys: BTreeSet<String>,
+    data: Vec<Square>
+}
+
+impl Map {
+    fn at(&self, x: usize, y: usize) -> &Square {
+        assert!(x < self.width && y < self.height);
+        &self.data[self.width * y + x]
+    }
+
+    fn at_mut(&mut self, x: usize, y: usize) -> &mut Square {
+        assert!(x < self.width && y < self.height);
+        &mut self.data[self.width * y + x]
+    }
+}
+
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    let lines: Vec<Line> = lines(input).into_iter().filter(|line| !line.is_blank()).collect();
+    let rows = grid(&lines, |ch| match ch {
+        '.' | '#' | '@' => Some(ch),
+        _ if ch.is_ascii_alphabetic() => Some(ch),
+        _ => None
+    })?;
+    let width = rows.first().map_or(0, |row| row.len());
+    let mut entrance = None;
+    let mut keys = BTreeSet::new();
+    let mut data = Vec::new();
+    for (y, row) in rows.iter().enumerate() {
+        for (x, &ch) in row.iter().enumerate() {
+            let sq = match ch {
+                '.' => Square::Open,
+                '#' => Square::Wall,
+                '@' => {
+                    if entrance.is_some() {
+                        return Err(lines[y].error(x + 1, "expected only one entrance"));
+                    }
+                    entrance = Some((x, y));
+                    Square::Open
+                },
+                key if key.is_ascii_lowercase() => {
+                    keys.insert(key.to_string());
+                    Square::Key(key.to_string())
+                },
+                door => Square::Door(door.to_lowercase().to_string())
+            };
+            data.push(sq);
+        }
+    }
+    let entrance = entrance.ok_or_else(|| {
+        Line { number: lines.last().map_or(1, |line| line.number + 1), text: "" }.error(1, "expected an entrance \"@\"")
+    })?;
+    Ok(Map {
+        width: width,
+        height: rows.len(),
+        entrances: vec![entrance],
+        keys: keys,
+        data: data
+    })
+}
+
+fn calc_next_moves(start_positions: Vec<(usize, usize)>,
+    start_keys: &BTreeSet<String>, start_dist: usize, map: &Map,
+    traverse_queue: &mut BinaryHeap<Reverse<(usize, Vec<(usize, usize)>, BTreeSet<String>)>>) {
+    for i in 0..start_positions.len() {
+        let mut fill_queue = VecDeque::new();
+        fill_queue.push_back((start_positions[i], start_dist));
+        let mut seen = HashSet::new();
+        while let Some(((x, y), dist)) = fill_queue.pop_front() {
+            if !seen.insert((x, y)) { continue }
+            match map.at(x, y) {
+                Square::Wall => continue,
+                Square::Open => (),
+                Square::Key(key) => if !start_keys.contains(key) {
+                    let mut keys = start_keys.clone();
+                    keys.insert(key.clone());
+                    let mut positions = start_positions.clone();
+                    positions[i] = (x, y);
+                    traverse_queue.push(Reverse((dist, positions, keys)));
+                    continue
+                },
+                Square::Door(key) => if !start_keys.contains(key) { continue }
+            }
+            if x > 0              { fill_queue.push_back(((x - 1, y), dist + 1)) }
+            if x < map.width - 1  { fill_queue.push_back(((x + 1, y), dist + 1)) }
+            if y > 0              { fill_queue.push_back(((x, y - 1), dist + 1)) }
+            if y < map.height - 1 { fill_queue.push_back(((x, y + 1), dist + 1)) }
+        }
+    }
+}
+
+fn calc_best_path(map: &Map) -> usize {
+    let mut queue = BinaryHeap::new();
+    queue.push(Reverse((0, map.entrances.clone(), BTreeSet::new())));
+    let mut seen = HashSet::new();
+
+    while let Some(Reverse((dist, positions, keys))) = queue.pop() {
+        if !seen.insert((positions.clone(), keys.clone())) { continue }
+        if keys == map.keys { return dist }
+        calc_next_moves(positions, &keys, dist, &map, &mut queue);
+    }
+    panic!("failed to find a path to all keys")
+}
+
+fn modify_map_for_part2(map: &mut Map) {
+    assert_eq!(map.entrances.len(), 1);
+    let (x, y) = map.entrances[0];
+    map.entrances = vec![
+        (x - 1, y - 1),
+        (x - 1, y + 1),
+        (x + 1, y - 1),
+        (x + 1, y + 1)
+    ];
+    *map.at_mut(x, y)     = Square::Wall;
+    *map.at_mut(x - 1, y) = Square::Wall;
+    *map.at_mut(x + 1, y) = Square::Wall;
+    *map.at_mut(x, y - 1) = Square::Wall;
+    *map.at_mut(x, y + 1) = Square::Wall;
+}
+
+pub fn part1(map: &Map) -> usize {
+    calc_best_path(map)
+}
+
+pub fn part2(map: &Map) -> usize {
+    let mut map = map.clone();
+    modify_map_for_part2(&mut map);
+    calc_best_path(&map)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1_ex1() {
+        let ex = "
+#########
+#b.A.@.a#
+#########";
+        assert_eq!(part1(&parse(ex).unwrap()), 8);
+    }
+
+    #[test]
+    fn test_part1_ex2() {
+        let ex = "
+########################
+#f.D.E.e.C.b.A.@.a.B.c.#
+######################.#
+#d.....................#
+########################";
+        assert_eq!(part1(&parse(ex).unwrap()), 86);
+    }
+
+    #[test]
+    fn test_part1_ex3() {
+        let ex = "
+########################
+#...............b.C.D.f#
+#.######################
+#.....@.a.B.c.d.A.e.F.g#
+########################";
+        assert_eq!(part1(&parse(ex).unwrap()), 132);
+    }
+
+    #[test]
+    fn test_part1_ex4() {
+        let ex = "
+#################
+#i.G..c...e..H.p#
+########.########
+#j.A..b...f..D.o#
+########@########
+#k.E..a...g..B.n#
+########.########
+#l.F..d...h..C.m#
+#################";
+        assert_eq!(part1(&parse(ex).unwrap()), 136);
+    }
+
+    #[test]
+    fn test_part1_ex5() {
+        let ex = "
+########################
+#@..............ac.GI.b#
+###d#e#f################
+###A#B#C################
+###g#h#i################
+########################";
+        assert_eq!(part1(&parse(ex).unwrap()), 81);
+    }
+
+
+    #[test]
+    fn test_part2_ex1() {
+        let ex = "
+#######
+#a.#Cd#
+##...##
+##.@.##
+##...##
+#cB#Ab#
+#######";
+        assert_eq!(part2(&parse(ex).unwrap()), 8);
+    }
+
+    #[test]
+    fn test_part2_ex2() {
+        let ex = "
+###############
+#d.ABC.#.....a#
+######...######
+######.@.######
+######...######
+#b.....#.....c#
+###############";
+        assert_eq!(part2(&parse(ex).unwrap()), 24);
+    }
+
+    #[test]
+    fn test_part2_ex3() {
+        let ex = "
+#############
+#DcBa.#.GhKl#
+#.###...#I###
+#e#d#.@.#j#k#
+###C#...###J#
+#fEbA.#.FgHi#
+#############";
+        assert_eq!(part2(&parse(ex).unwrap()), 32);
+    }
+
+    #[test]
+    fn test_part2_ex4() {
+        let ex = "
+#############
+#g#f.D#..h#l#
+#F###e#E###.#
+#dCba...BcIJ#
+#####.@.#####
+#nK.L...G...#
+#M###N#H###.#
+#o#m..#i#jk.#
+#############";
+        assert_eq!(part2(&parse(ex).unwrap()), 72);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("#########\n#b.A.@.@#\n#########").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 8));
+        let err = parse("#########\n#b.A...a#\n#########").err().unwrap();
+        assert_eq!((err.line, err.column), (4, 1));
+        assert_eq!(parse("#########\n#b.A.@.a!\n").err().unwrap().column, 9);
+    }
+}
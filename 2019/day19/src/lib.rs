@@ -0,0 +1,101 @@
+use std::sync::mpsc::channel;
+
+use intcode::*;
+use solution::Solution;
+
+fn is_tractor(mut mem: Mem, x: usize, y: usize) -> bool {
+    let (tx_in, rx_in) = channel();
+    let (tx_out, rx_out) = channel();
+
+    tx_in.send(x as i64).unwrap();
+    tx_in.send(y as i64).unwrap();
+
+    run(&mut mem, &rx_in, tx_out);
+
+    match rx_out.recv().unwrap() {
+        0 => false,
+        1 => true,
+        _ => panic!()
+    }
+}
+
+struct TopEdgeIter {
+    x: usize,
+    y: usize,
+    mem: Mem
+}
+
+impl TopEdgeIter {
+    fn new(mem: Mem, start_y: usize) -> Self {
+        let mut found = false;
+        for x in 0 .. {
+            if is_tractor(mem.clone(), x, start_y) {
+                found = true
+            } else if found {
+                return Self {
+                    x: x - 1,
+                    y: start_y,
+                    mem: mem
+                }
+            }
+        }
+        unreachable!()
+    }
+}
+
+impl Iterator for TopEdgeIter {
+    type Item = (usize, usize);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.x += 1;
+        while !is_tractor(self.mem.clone(), self.x, self.y) {
+            self.y += 1;
+        }
+        Some((self.x, self.y))
+    }
+}
+
+pub fn part1(mem: &Mem) -> usize {
+    let mut sum = 0;
+    for y in 0 .. 50 {
+        for x in 0 .. 50 {
+            if is_tractor(mem.clone(), x, y) {
+                sum += 1
+            }
+        }
+    }
+    sum
+}
+
+pub fn part2(mem: &Mem) -> usize {
+    let side_len = 100;
+    let mut top_edge = TopEdgeIter::new(mem.clone(), side_len);
+    loop {
+        let (right_x, top_y) = top_edge.next().unwrap();
+        if right_x + 1 < side_len { continue }
+        let left_x = right_x + 1 - side_len;
+        let bottom_y = top_y + side_len - 1;
+        if is_tractor(mem.clone(), left_x, bottom_y) {
+            return left_x * 10000 + top_y;
+        }
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Mem;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
@@ -0,0 +1,47 @@
+use std::sync::mpsc::channel;
+
+use intcode::*;
+use solution::Solution;
+
+pub fn part1(mem: &Mem) -> i64 {
+    let mut mem = mem.clone();
+    mem[1] = 12;
+    mem[2] = 2;
+    run(&mut mem, &channel().1, channel().0);
+    mem[0]
+}
+
+pub fn part2(mem: &Mem) -> i64 {
+    for noun in 0..100 {
+        for verb in 0..100 {
+            let mut mem = mem.clone();
+            mem[1] = noun;
+            mem[2] = verb;
+            run(&mut mem, &channel().1, channel().0);
+            if mem[0] == 19690720 {
+                return 100 * noun + verb;
+            }
+        }
+    }
+    panic!();
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Mem;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> i64 {
+        part2(input)
+    }
+}
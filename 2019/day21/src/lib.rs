@@ -0,0 +1,77 @@
+use std::sync::mpsc::channel;
+use std::thread;
+
+use intcode::*;
+use solution::Solution;
+
+fn springdroid(mut mem: Mem, commands: &str) -> i64 {
+    let (tx_in, rx_in) = channel();
+    let (tx_out, rx_out) = channel();
+
+    thread::spawn(move || run(&mut mem, &rx_in, tx_out));
+
+    send_line(&tx_in, commands);
+
+    let mut debug_output = String::new();
+    while let Ok(val) = rx_out.recv() {
+        if val >= 128 {
+            return val
+        } else {
+            debug_output.push(val as u8 as char);
+        }
+    }
+    eprintln!("{}", debug_output);
+    panic!()
+}
+
+// Jump if: we would jump over a hole, and can land safely.
+pub fn part1(mem: &Mem) -> i64 {
+    springdroid(mem.clone(),
+    // !(A && B && C) && D
+"OR A J
+AND B J
+AND C J
+NOT J J
+AND D J
+WALK")
+}
+
+// Jump if: we would jump over a hole, and can land safely, and we can leave safely after.
+pub fn part2(mem: &Mem) -> i64 {
+    springdroid(mem.clone(),
+    // !(A && B && C) && D && (H || E)
+    // NOTE - It may seem like E should be (E && (F || I)) but in any case where (!F && !H && !I)
+    // but is still traversable at all, we would have already jumped:
+    //    ##xx###.#..x#
+    // > ##xx###.#..x#
+    //    @ABCDEFGHI
+"OR A J
+AND B J
+AND C J
+NOT J J
+AND D J
+OR H T
+OR E T
+AND T J
+RUN")
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Mem;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> i64 {
+        part2(input)
+    }
+}
@@ -0,0 +1,262 @@
+use lazy_static::lazy_static;
+use params::Params;
+use parsing::{lines, ParseError};
+use regex::Regex;
+use solution::Solution;
+
+pub type Card = u16;
+type Deck = Vec<Card>;
+
+pub enum Technique {
+    Cut(isize),
+    DealWithIncrement(usize),
+    DealIntoNewStack
+}
+
+// The real input's deck sizes and positions, which the examples change
+pub const DECK_SIZE: usize = 10007;
+pub const CARD: Card = 2019;
+pub const BIG_DECK_SIZE: i128 = 119315717514047;
+pub const SHUFFLES: i128 = 101741582076661;
+pub const POSITION: i128 = 2020;
+
+lazy_static! {
+    static ref CUT_RE: Regex = Regex::new("^cut (-?[0-9]+)$").unwrap();
+    static ref DEAL_WITH_INCRREMENT_RE: Regex =
+        Regex::new("^deal with increment ([0-9]+)$").unwrap();
+    static ref DEAL_INTO_NEW_STACK_RE: Regex =
+        Regex::new("^deal into new stack$").unwrap();
+}
+
+fn new_deck(n: usize) -> Deck {
+    (0 .. n as Card).collect()
+}
+
+fn deal_into_new_stack(mut deck: Deck) -> Deck {
+    deck.reverse();
+    deck
+}
+
+fn cut(n: isize, mut deck: Deck) -> Deck {
+    assert!((n.abs() as usize) < deck.len());
+    let n = if n >= 0 { n as usize } else { deck.len() - n.abs() as usize };
+    let mut prefix = deck.drain(0 .. n).collect();
+    deck.append(&mut prefix);
+    deck
+}
+
+fn deal_with_increment(n: usize, deck: Deck) -> Deck {
+    assert!(n <= deck.len());
+    let deck_len = deck.len();
+    let mut deck2 = vec![0; deck_len];
+    let mut offset = 0;
+    for card in deck {
+        deck2[offset] = card;
+        offset = (offset + n) % deck_len;
+    }
+    deck2
+}
+
+pub fn parse(input: &str) -> Result<Vec<Technique>, ParseError> {
+    lines(input).into_iter().filter(|line| !line.is_blank()).map(|line| {
+        if let Some(cap) = CUT_RE.captures(line.text) {
+            Ok(Technique::Cut(line.field(&cap[1]).parse()?))
+        } else if let Some(cap) = DEAL_WITH_INCRREMENT_RE.captures(line.text) {
+            Ok(Technique::DealWithIncrement(line.field(&cap[1]).parse()?))
+        } else if DEAL_INTO_NEW_STACK_RE.is_match(line.text) {
+            Ok(Technique::DealIntoNewStack)
+        } else {
+            Err(line.error(1, "expected \"cut N\", \"deal with increment N\" or \"deal into new stack\""))
+        }
+    }).collect()
+}
+
+fn shuffle(techniques: &[Technique], mut deck: Deck) -> Deck {
+    for technique in techniques {
+        deck = match *technique {
+            Technique::Cut(offset) => cut(offset, deck),
+            Technique::DealWithIncrement(offset) => deal_with_increment(offset, deck),
+            Technique::DealIntoNewStack => deal_into_new_stack(deck)
+        };
+    }
+    deck
+}
+
+pub fn part1(techniques: &[Technique], deck_size: usize, card: Card) -> usize {
+    let deck = shuffle(techniques, new_deck(deck_size));
+    for (i, c) in deck.into_iter().enumerate() {
+        if c == card { return i }
+    }
+    panic!()
+}
+
+// I could not figure this out on my own. For more details on what this is doing, see:
+// https://przybyl.io/solution-explanation-to-day-22-of-advent-of-code-2019.html
+// I don't pretend to understand it well.
+pub fn part2(techniques: &[Technique], n: i128, reps: i128, pos: i128) -> i128 {
+    // a mod m
+    fn modulo(a: i128, m: i128) -> i128 {
+        ((a % m) + m) % m
+    }
+    // a^-1 mod m
+    fn modinv(a: i128, m: i128) -> i128 {
+        let mut mn = (m, a);
+        let mut xy = (0, 1);
+        while mn.1 != 0 {
+            xy = (xy.1, xy.0 - (mn.0 / mn.1) * xy.1);
+            mn = (mn.1, mn.0 % mn.1);
+        }
+        while xy.0 < 0 { xy.0 += m };
+        xy.0
+    }
+    // a^b mod m
+    fn modpow(mut a: i128, mut b: i128, m: i128) -> i128 {
+        let mut y = 1;
+        while b > 1 {
+            if b % 2 == 0 {
+                a = modulo(a * a, m);
+                b = b / 2;
+            } else {
+                y = modulo(a * y, m);
+                a = modulo(a * a, m);
+                b = (b - 1) / 2;
+            }
+        }
+        modulo(a * y, m)
+    }
+    let mut f = (1, 0);
+    for technique in techniques.iter().rev() {
+        match *technique {
+            Technique::Cut(offset) => {
+                let offset = modulo(offset as i128, n);
+                f = (modulo(f.0, n), modulo(f.1 + offset, n));
+            },
+            Technique::DealWithIncrement(offset) => {
+                let offset = modinv(modulo(offset as i128, n), n);
+                f = (modulo(offset * f.0, n), modulo(offset * f.1, n));
+            },
+            Technique::DealIntoNewStack => {
+                f = (modulo(-f.0, n), modulo(-f.1 - 1 + n, n));
+            }
+        }
+    }
+
+    let ak = modpow(f.0, reps, n);
+    let geo = if f.0 != 1 { modulo((ak - 1) * modinv(f.0 - 1, n), n) } else { reps };
+    modulo(ak * pos + geo * f.1, n)
+}
+
+pub struct Shuffle {
+    pub techniques: Vec<Technique>,
+    pub deck_size: usize,
+    pub card: Card,
+    pub big_deck_size: i128,
+    pub shuffles: i128,
+    pub position: i128,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Shuffle;
+    type Answer1 = usize;
+    type Answer2 = i128;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        let techniques = parse(puzzle_input).map_err(|e| e.to_string())?;
+        Ok(Shuffle {
+            techniques,
+            deck_size: DECK_SIZE,
+            card: CARD,
+            big_deck_size: BIG_DECK_SIZE,
+            shuffles: SHUFFLES,
+            position: POSITION,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(&input.techniques, input.deck_size, input.card)
+    }
+
+    fn part2(input: &Self::Input) -> i128 {
+        part2(&input.techniques, input.big_deck_size, input.shuffles, input.position)
+    }
+
+    fn configure(input: &mut Self::Input, params: &Params) {
+        input.deck_size = params.get("deck_size", DECK_SIZE);
+        input.card = params.get("card", CARD);
+        input.big_deck_size = params.get("big_deck_size", BIG_DECK_SIZE);
+        input.shuffles = params.get("shuffles", SHUFFLES);
+        input.position = params.get("position", POSITION);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1_deal_into_new_stack() {
+        assert_eq!(deal_into_new_stack(new_deck(10)),
+            vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn test_part1_cut() {
+        assert_eq!(cut(3, new_deck(10)),
+            vec![3, 4, 5, 6, 7, 8, 9, 0, 1, 2]);
+        assert_eq!(cut(-4, new_deck(10)),
+            vec![6, 7, 8, 9, 0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_part1_deal_with_increment() {
+        assert_eq!(deal_with_increment(3, new_deck(10)),
+            vec![0, 7, 4, 1, 8, 5, 2, 9, 6, 3]);
+    }
+
+    #[test]
+    fn test_part1_ex1() {
+        let ex = "
+deal with increment 7
+deal into new stack
+deal into new stack";
+        assert_eq!(shuffle(&parse(ex).unwrap(), new_deck(10)),
+            vec![0, 3, 6, 9, 2, 5, 8, 1, 4, 7]);
+    }
+
+    #[test]
+    fn test_part1_ex2() {
+        let ex = "
+cut 6
+deal with increment 7
+deal into new stack";
+        assert_eq!(shuffle(&parse(ex).unwrap(), new_deck(10)),
+            vec![3, 0, 7, 4, 1, 8, 5, 2, 9, 6]);
+    }
+
+    #[test]
+    fn test_part1_ex3() {
+        let ex = "
+deal into new stack
+cut -2
+deal with increment 7
+cut 8
+cut -4
+deal with increment 7
+cut 3
+deal with increment 9
+deal with increment 3
+cut -1";
+        assert_eq!(shuffle(&parse(ex).unwrap(), new_deck(10)),
+            vec![9, 2, 5, 8, 1, 4, 7, 0, 3, 6]);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("cut 6\ndeal with increment -7").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("cut 6\ndeal with increment 99999999999999999999").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 21));
+    }
+}
@@ -0,0 +1,141 @@
+use std::collections::{HashMap, HashSet};
+
+use parsing::{lines, Line, ParseError};
+use solution::Solution;
+
+type Pos = (i32, i32);
+pub enum Dir { Left, Right, Up, Down }
+impl Into<Pos> for &Dir {
+    fn into(self) -> Pos {
+        match self {
+            Dir::Left => (-1, 0),
+            Dir::Right => (1, 0),
+            Dir::Up => (0, -1),
+            Dir::Down => (0, 1)
+        }
+    }
+}
+pub type Path = Vec<(Dir, i32)>;
+
+fn parse_path(line: Line) -> Result<Path, ParseError> {
+    let mut path = Vec::new();
+    for segment in line.split(",") {
+        let dir = match segment.text.chars().next() {
+            Some('L') => Dir::Left,
+            Some('R') => Dir::Right,
+            Some('U') => Dir::Up,
+            Some('D') => Dir::Down,
+            _ => return Err(segment.error("expected a direction L, R, U or D"))
+        };
+        let steps = line.field(&segment.text[1..]).parse()?;
+        path.push((dir, steps));
+    }
+    Ok(path)
+}
+
+pub fn parse(input: &str) -> Result<[Path; 2], ParseError> {
+    let lines = lines(input);
+    match lines.as_slice() {
+        [line1, line2] => Ok([parse_path(*line1)?, parse_path(*line2)?]),
+        [_, _, extra, ..] => Err(extra.error(1, "expected only two wires")),
+        _ => Err(Line { number: lines.len() + 1, text: "" }.error(1, "expected two wires")),
+    }
+}
+fn points_touched(path: &Path) -> HashSet<Pos> {
+    let mut ret = HashSet::new();
+    let mut pos = (0, 0);
+    ret.insert(pos);
+    for (dir, steps) in path {
+        let offset: Pos = dir.into();
+        for _ in 0 .. *steps {
+            pos.0 += offset.0;
+            pos.1 += offset.1;
+            ret.insert(pos);
+        }
+    }
+    ret
+}
+
+fn signal_delays(path: &Path) -> HashMap<Pos, i32> {
+    let mut ret = HashMap::new();
+    let mut pos = (0, 0);
+    let mut dist = 0;
+    ret.insert(pos, dist);
+    for (dir, steps) in path {
+        let offset: Pos = dir.into();
+        for _ in 0 .. *steps {
+            pos.0 += offset.0;
+            pos.1 += offset.1;
+            dist += 1;
+            if !ret.contains_key(&pos) { ret.insert(pos, dist); }
+        }
+    }
+    ret
+}
+
+pub fn part1([path1, path2]: &[Path; 2]) -> i32 {
+    let [points1, points2] = [points_touched(path1), points_touched(path2)];
+
+    points1.intersection(&points2)
+        .map(|p| p.0.abs() + p.1.abs())
+        .filter(|d| *d != 0)
+        .min().unwrap()
+}
+
+pub fn part2([path1, path2]: &[Path; 2]) -> i32 {
+    let [points1, points2] = [points_touched(path1), points_touched(path2)];
+    let [signal_delays1, signal_delays2] = [signal_delays(path1), signal_delays(path2)];
+
+    points1.intersection(&points2)
+        .map(|p| signal_delays1.get(p).unwrap() + signal_delays2.get(p).unwrap())
+        .filter(|d| *d != 0)
+        .min().unwrap()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = [Path; 2];
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> i32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> i32 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse("R8,U5,L5,D3\nU7,R6,D4,L4").unwrap()), 6);
+        assert_eq!(part1(&parse("R75,D30,R83,U83,L12,D49,R71,U7,L72\nU62,R66,U55,R34,D71,R55,D58,R83").unwrap()), 159);
+        assert_eq!(part1(&parse("R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51\nU98,R91,D20,R16,D67,R40,U7,R15,U6,R7").unwrap()), 135);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse("R8,U5,L5,D3\nU7,R6,D4,L4").unwrap()), 30);
+        assert_eq!(part2(&parse("R75,D30,R83,U83,L12,D49,R71,U7,L72\nU62,R66,U55,R34,D71,R55,D58,R83").unwrap()), 610);
+        assert_eq!(part2(&parse("R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51\nU98,R91,D20,R16,D67,R40,U7,R15,U6,R7").unwrap()), 410);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("R8,U5,L5,D3\nU7,X6,D4,L4").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 4));
+        let err = parse("R8,U5,L5,D3\nU7,R6,D4,L").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 11));
+        assert_eq!(parse("R8,U5,L5,D3").err().unwrap().line, 2);
+    }
+}
@@ -0,0 +1,119 @@
+use std::ops::RangeInclusive;
+
+use parsing::{single_line, ParseError};
+use solution::Solution;
+
+const NON_DIGIT: u8 = std::u8::MAX;
+
+pub fn parse(input: &str) -> Result<RangeInclusive<u32>, ParseError> {
+    let (start, end) = single_line(input)?.as_field().trim().split_once("-")?;
+    Ok(start.parse()? ..= end.parse()?)
+}
+
+fn digits(mut num: u32) -> Vec<u8> {
+    let mut digits = Vec::new();
+    while num > 0 {
+        digits.push((num % 10) as u8);
+        num /= 10;
+    }
+    digits.reverse();
+    digits
+}
+
+fn has_adjacent_matching(digits: &[u8]) -> bool {
+    let mut last_digit = NON_DIGIT;
+    for digit in digits {
+        if last_digit == *digit { return true; }
+        last_digit = *digit;
+    }
+    false
+}
+
+fn has_adjacent_matching_strictly(digits: &[u8]) -> bool {
+    let mut last_digit = NON_DIGIT;
+    let mut group_size = 0;
+    for digit in digits {
+        if last_digit == *digit {
+            group_size += 1;
+        } else if group_size == 2 {
+            return true;
+        } else {
+            last_digit = *digit;
+            group_size = 1;
+        }
+    }
+    group_size == 2
+}
+
+fn monotonically_increasing(digits: &[u8]) -> bool {
+    let mut last_digit = 0;
+    for digit in digits {
+        if last_digit > *digit { return false; }
+        last_digit = *digit;
+    }
+    true
+}
+
+pub fn part1(range: &RangeInclusive<u32>) -> usize {
+    range.clone().filter(|num| {
+        let digits = digits(*num);
+        has_adjacent_matching(&digits) && monotonically_increasing(&digits)
+    }).count()
+}
+
+pub fn part2(range: &RangeInclusive<u32>) -> usize {
+    range.clone().filter(|num| {
+        let digits = digits(*num);
+        has_adjacent_matching_strictly(&digits) && monotonically_increasing(&digits)
+    }).count()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = RangeInclusive<u32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert!(has_adjacent_matching(&digits(111111)));
+        assert!(has_adjacent_matching(&digits(223450)));
+        assert!(!has_adjacent_matching(&digits(123789)));
+
+        assert!(monotonically_increasing(&digits(111111)));
+        assert!(!monotonically_increasing(&digits(223450)));
+        assert!(monotonically_increasing(&digits(123789)));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert!(has_adjacent_matching_strictly(&digits(112233)));
+        assert!(!has_adjacent_matching_strictly(&digits(123444)));
+        assert!(has_adjacent_matching_strictly(&digits(111122)));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("100-200\n").unwrap(), 100 ..= 200);
+        assert_eq!(parse("100 200").err().unwrap().column, 1);
+        assert_eq!(parse("100-2x0").err().unwrap().column, 5);
+    }
+}
@@ -0,0 +1,50 @@
+use std::sync::mpsc::channel;
+
+use intcode::*;
+use solution::Solution;
+
+pub fn part1(mem: &Mem) -> i64 {
+    let (tx_in, rx_in) = channel();
+    let (tx_out, rx_out) = channel();
+    tx_in.send(1).unwrap();
+    run(&mut mem.clone(), &rx_in, tx_out);
+    let mut outputs = Vec::new();
+    while let Ok(val) = rx_out.recv() {
+        outputs.push(val);
+    }
+    assert!(outputs.len() > 1);
+    for val in &outputs[0..outputs.len()-1] {
+        assert_eq!(0, *val);
+    }
+    outputs[outputs.len()-1]
+}
+
+pub fn part2(mem: &Mem) -> i64 {
+    let (tx_in, rx_in) = channel();
+    let (tx_out, rx_out) = channel();
+    tx_in.send(5).unwrap();
+    run(&mut mem.clone(), &rx_in, tx_out);
+    let output = rx_out.recv().unwrap();
+    assert!(rx_out.recv().is_err());
+    output
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Mem;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> i64 {
+        part2(input)
+    }
+}
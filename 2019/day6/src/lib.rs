@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use parsing::{lines, ParseError};
+use solution::Solution;
+
+// planet -> what it orbits
+pub fn parse(input: &str) -> Result<HashMap<String, String>, ParseError> {
+    lines(input).into_iter().filter(|line| !line.is_blank()).map(|line| {
+        match line.split(")")[..] {
+            [cen, obj] => Ok((obj.text.to_string(), cen.text.to_string())),
+            _ => Err(line.error(1, "expected an orbit like \"COM)B\""))
+        }
+    }).collect()
+}
+
+pub fn part1(orbits: &HashMap<String, String>) -> usize {
+    let mut cnt = 0;
+    for (_, mut cen) in orbits.iter() {
+        cnt += 1;
+        while let Some(new_cen) = orbits.get(cen) {
+            cnt += 1;
+            cen = new_cen;
+        }
+    }
+    cnt
+}
+
+pub fn part2(orbits: &HashMap<String, String>) -> usize {
+    let mut src = orbits.get("YOU").unwrap();
+    let mut dst = orbits.get("SAN").unwrap();
+    let mut src_trans = HashMap::<&str,usize>::new();
+    src_trans.insert(src, 0);
+    let mut n = 0;
+    while let Some(cen) = orbits.get(src) {
+        n += 1;
+        src_trans.insert(cen, n);
+        src = cen;
+    }
+    let mut n = 0;
+    loop {
+        if let Some(src_n) = src_trans.get(dst.as_str()) {
+            return n + src_n;
+        } else if let Some(cen) = orbits.get(dst) {
+            n += 1;
+            dst = cen;
+        } else {
+            unreachable!(); // It's a DAG so we'll always meet at least at the center
+        }
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = HashMap<String, String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let ex = "
+COM)B
+B)C
+C)D
+D)E
+E)F
+B)G
+G)H
+D)I
+E)J
+J)K
+K)L";
+        assert_eq!(part1(&parse(ex).unwrap()), 42);
+    }
+
+    #[test]
+    fn test_part2() {
+        let ex = "
+COM)B
+B)C
+C)D
+D)E
+E)F
+B)G
+G)H
+D)I
+E)J
+J)K
+K)L
+K)YOU
+I)SAN";
+        assert_eq!(part2(&parse(ex).unwrap()), 4);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("COM)B\nB)C)D").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
@@ -0,0 +1,117 @@
+use intcode::*;
+use solution::Solution;
+
+// Amplifiers wired in a chain, each taking turns on this thread until it has passed on its output
+struct Amplifiers {
+    machines: Vec<Machine>,
+}
+
+impl Amplifiers {
+    fn new(mem: &Mem, phases: &[i64]) -> Amplifiers {
+        let machines = phases.iter().map(|&phase| {
+            let mut machine = Machine::new(mem.clone());
+            machine.push_input(phase);
+            machine
+        }).collect();
+        Amplifiers { machines }
+    }
+
+    // Sends the signal through every amplifier, or gives None once the first one has halted
+    fn pass(&mut self, signal: i64) -> Option<i64> {
+        let mut signal = signal;
+        for machine in self.machines.iter_mut() {
+            machine.push_input(signal);
+            signal = match machine.resume() {
+                Event::Output(val) => val,
+                Event::Halted => return None,
+                Event::NeedInput => panic!("amplifier wants more input than the signal"),
+            };
+        }
+        Some(signal)
+    }
+
+    // Feeds the last amplifier's output back into the first until they halt
+    fn feedback(&mut self, signal: i64) -> i64 {
+        let mut signal = signal;
+        while let Some(next) = self.pass(signal) {
+            signal = next;
+        }
+        signal
+    }
+}
+
+fn permutations(items: &[i64]) -> Vec<Vec<i64>> {
+    if items.is_empty() {
+        return vec![Vec::new()];
+    }
+    let mut ret = Vec::new();
+    for (i, &first) in items.iter().enumerate() {
+        let mut rest = items.to_vec();
+        rest.remove(i);
+        for mut perm in permutations(&rest) {
+            perm.insert(0, first);
+            ret.push(perm);
+        }
+    }
+    ret
+}
+
+pub fn part1(mem: &Mem) -> i64 {
+    permutations(&[0, 1, 2, 3, 4]).iter().map(|phases| {
+        Amplifiers::new(mem, phases).pass(0).unwrap()
+    }).max().unwrap()
+}
+
+pub fn part2(mem: &Mem) -> i64 {
+    permutations(&[5, 6, 7, 8, 9]).iter().map(|phases| {
+        Amplifiers::new(mem, phases).feedback(0)
+    }).max().unwrap()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Mem;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> i64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse("3,15,3,16,1002,16,10,16,1,16,15,15,4,15,99,0,0").unwrap()), 43210);
+        assert_eq!(part1(&parse("3,23,3,24,1002,24,10,24,1002,23,-1,23,101,5,23,23,1,24,23,23,4,23,99,0,0").unwrap()), 54321);
+        assert_eq!(part1(&parse("3,31,3,32,1002,32,10,32,1001,31,-2,31,1007,31,0,33,1002,33,7,33,1,33,31,31,1,32,31,31,4,31,99,0,0,0").unwrap()), 65210);
+    }
+
+    #[test]
+    fn test_amplifiers() {
+        assert_eq!(permutations(&[1, 2, 3]), vec![
+            vec![1, 2, 3], vec![1, 3, 2], vec![2, 1, 3], vec![2, 3, 1], vec![3, 1, 2], vec![3, 2, 1]]);
+        let mem_str = "3,26,1001,26,-4,26,3,27,1002,27,2,27,1,27,26,27,4,27,1001,28,-1,28,1005,28,6,99,0,0,5";
+        let mut amps = Amplifiers::new(&parse(mem_str).unwrap(), &[9, 8, 7, 6, 5]);
+        assert_eq!(amps.pass(0), Some(129));
+        assert_eq!(amps.feedback(129), 139629729);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse("3,26,1001,26,-4,26,3,27,1002,27,2,27,1,27,26,27,4,27,1001,28,-1,28,1005,28,6,99,0,0,5").unwrap()), 139629729);
+        assert_eq!(part2(&parse("3,52,1001,52,-5,52,3,53,1,52,56,54,1007,54,5,55,1005,55,26,1001,54,-5,54,1105,1,12,1,53,54,53,1008,54,0,55,1001,55,1,55,2,53,55,53,4,53,1001,56,-1,56,1005,56,6,99,0,0,0,0,10").unwrap()), 18216);
+    }
+}
@@ -0,0 +1,114 @@
+use params::Params;
+use parsing::{single_line, ParseError};
+use solution::Solution;
+
+pub struct Image {
+    width: usize,
+    height: usize,
+    layers: usize,
+    data: Vec<u8>
+}
+
+impl Image {
+    fn new(width: usize, height: usize, data: Vec<u8>) -> Image {
+        let layers = data.len() / width / height;
+        assert_eq!(data.len(), layers * width * height);
+        Image {
+            width: width,
+            height: height,
+            layers: layers,
+            data: data
+        }
+    }
+
+    fn layer_data(&self, layer: usize) -> &[u8] {
+        let first = layer * self.width * self.height;
+        let last = (layer + 1) * self.width * self.height;
+        &self.data[first..last]
+    }
+
+    fn count_digits(&self, layer: usize, digit: u8) -> usize {
+        self.layer_data(layer).iter().filter(|d| **d == digit).count()
+    }
+
+    fn pixel_value(&self, x: usize, y: usize) -> u8 {
+        let offset = y * self.width + x;
+        for layer in 0..self.layers {
+            let px = self.layer_data(layer)[offset];
+            if px != 2 {
+                return px;
+            }
+        }
+        2
+    }
+}
+
+// The real input's size, which the example shrinks
+pub const WIDTH: usize = 25;
+pub const HEIGHT: usize = 6;
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<u8>, ParseError> {
+    single_line(puzzle_input)?.map_chars(|ch| ch.to_digit(10).map(|d| d as u8))
+}
+
+pub fn part1(img: &Image) -> usize {
+    let mut layers = (0..img.layers).collect::<Vec<_>>();
+    layers.sort_by(|layer1, layer2| img.count_digits(*layer1, 0).cmp(&img.count_digits(*layer2, 0)));
+    let layer = layers[0];
+    img.count_digits(layer, 1) * img.count_digits(layer, 2)
+}
+
+pub fn part2(img: &Image) -> String {
+    let mut pixels = String::new();
+    for y in 0..img.height {
+        for x in 0..img.width {
+            let px = match img.pixel_value(x, y) {
+                0 => '.',
+                1 => '#',
+                _ => panic!()
+            };
+            pixels.push(px);
+        }
+        pixels.push('\n');
+    }
+
+    ascii_bitmap::decode(&pixels).unwrap()
+}
+
+pub struct Transmission {
+    pub data: Vec<u8>,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Transmission {
+    fn image(&self) -> Image {
+        Image::new(self.width, self.height, self.data.clone())
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Transmission;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        let data = parse(puzzle_input).map_err(|e| e.to_string())?;
+        Ok(Transmission { data, width: WIDTH, height: HEIGHT })
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(&input.image())
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        part2(&input.image())
+    }
+
+    fn configure(input: &mut Self::Input, params: &Params) {
+        input.width = params.get("width", WIDTH);
+        input.height = params.get("height", HEIGHT);
+    }
+}
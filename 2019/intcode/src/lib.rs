@@ -1,7 +1,9 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::ops::{Index, IndexMut};
 use std::sync::mpsc::{Receiver, RecvError, Sender};
 
+use parsing::{single_line, ParseError};
+
 #[derive(PartialEq, Debug, Clone)]
 pub struct Mem(HashMap<usize, i64>);
 
@@ -28,11 +30,11 @@ impl IndexMut<usize> for Mem {
     }
 }
 
-pub fn parse(mem_str: &str) -> Mem {
-    Mem(mem_str.trim().split(",")
-        .map(|chunk| chunk.parse().unwrap())
-        .enumerate()
-        .collect())
+pub fn parse(mem_str: &str) -> Result<Mem, ParseError> {
+    let values = single_line(mem_str)?.split(",").into_iter()
+        .map(|chunk| chunk.trim().parse())
+        .collect::<Result<Vec<i64>, _>>()?;
+    Ok(Mem(values.into_iter().enumerate().collect()))
 }
 
 enum ParamMode {
@@ -77,70 +79,115 @@ fn inst(mem: &Mem, pc: usize) -> i64 {
     mem[pc] % 100
 }
 
+// What a Machine stopped for when it was resumed
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Event {
+    Output(i64),
+    NeedInput,
+    Halted,
+}
+
+// A program that runs until it has output or needs input it hasn't been given, so several can take
+// turns on one thread
+#[derive(Debug, Clone)]
+pub struct Machine {
+    mem: Mem,
+    pc: usize,
+    relative_base: i64,
+    inputs: VecDeque<i64>,
+}
+
+impl Machine {
+    pub fn new(mem: Mem) -> Machine {
+        Machine { mem, pc: 0, relative_base: 0, inputs: VecDeque::new() }
+    }
+
+    pub fn push_input(&mut self, val: i64) {
+        self.inputs.push_back(val);
+    }
+
+    pub fn resume(&mut self) -> Event {
+        let mem = &mut self.mem;
+        let relative_base = &mut self.relative_base;
+        let pc = &mut self.pc;
+        loop {
+            match inst(mem, *pc) {
+                1 => { // add
+                    let a = param(mem, *pc, 1, relative_base);
+                    let b = param(mem, *pc, 2, relative_base);
+                    *param_mut(mem, *pc, 3, relative_base) = a + b;
+                    *pc += 4;
+                },
+                2 => { // mul
+                    let a = param(mem, *pc, 1, relative_base);
+                    let b = param(mem, *pc, 2, relative_base);
+                    *param_mut(mem, *pc, 3, relative_base) = a * b;
+                    *pc += 4;
+                },
+                3 => { // read input
+                    match self.inputs.pop_front() {
+                        Some(val) => *param_mut(mem, *pc, 1, relative_base) = val,
+                        None => return Event::NeedInput // Try again once there's input
+                    };
+                    *pc += 2;
+                },
+                4 => { // write output
+                    let val = param(mem, *pc, 1, relative_base);
+                    *pc += 2;
+                    return Event::Output(val);
+                },
+                5 => { // jump if nonzero
+                    if param(mem, *pc, 1, relative_base) != 0 {
+                        *pc = param(mem, *pc, 2, relative_base) as usize;
+                    } else {
+                        *pc += 3;
+                    }
+                },
+                6 => { // jump if zero
+                    if param(mem, *pc, 1, relative_base) == 0 {
+                        *pc = param(mem, *pc, 2, relative_base) as usize;
+                    } else {
+                        *pc += 3;
+                    }
+                },
+                7 => { // less than
+                    let a = param(mem, *pc, 1, relative_base);
+                    let b = param(mem, *pc, 2, relative_base);
+                    *param_mut(mem, *pc, 3, relative_base) = if a < b { 1 } else { 0 };
+                    *pc += 4;
+                },
+                8 => { // equals
+                    let a = param(mem, *pc, 1, relative_base);
+                    let b = param(mem, *pc, 2, relative_base);
+                    *param_mut(mem, *pc, 3, relative_base) = if a == b { 1 } else { 0 };
+                    *pc += 4;
+                },
+                9 => { // Relative base offset
+                    *relative_base += param(mem, *pc, 1, relative_base);
+                    *pc += 2;
+                }
+                99 => return Event::Halted, // halt
+                opcode => unimplemented!("unknown opcode {}", opcode)
+            }
+        }
+    }
+}
+
 pub fn run(mem: &mut Mem, input: &dyn Input, output: Sender<i64>) {
-    let mut pc = 0;
-    let mut relative_base = 0;
+    let mut machine = Machine::new(std::mem::replace(mem, Mem(HashMap::new())));
     loop {
-        match inst(mem, pc) {
-            1 => { // add
-                let a = param(mem, pc, 1, &relative_base);
-                let b = param(mem, pc, 2, &relative_base);
-                *param_mut(mem, pc, 3, &relative_base) = a + b;
-                pc += 4;
-            },
-            2 => { // mul
-                let a = param(mem, pc, 1, &relative_base);
-                let b = param(mem, pc, 2, &relative_base);
-                *param_mut(mem, pc, 3, &relative_base) = a * b;
-                pc += 4;
+        match machine.resume() {
+            Event::NeedInput => match input.recv() {
+                Ok(val) => machine.push_input(val),
+                Err(_) => break // We've stopped writing inputs so stop the program
             },
-            3 => { // read input
-                match input.recv() {
-                    Ok(val) => *param_mut(mem, pc, 1, &relative_base) = val,
-                    Err(_) => return // We've stopped writing inputs so stop the program
-                };
-                pc += 2;
+            Event::Output(val) => if output.send(val).is_err() {
+                break // We've stopped reading outputs so stop the program
             },
-            4 => { // write output
-                if output.send(param(mem, pc, 1, &relative_base)).is_err() {
-                    return // We've stopped reading outputs so stop the program
-                }
-                pc += 2;
-            },
-            5 => { // jump if nonzero
-                if param(mem, pc, 1, &relative_base) != 0 {
-                    pc = param(mem, pc, 2, &relative_base) as usize;
-                } else {
-                    pc += 3;
-                }
-            },
-            6 => { // jump if zero
-                if param(mem, pc, 1, &relative_base) == 0 {
-                    pc = param(mem, pc, 2, &relative_base) as usize;
-                } else {
-                    pc += 3;
-                }
-            },
-            7 => { // less than
-                let a = param(mem, pc, 1, &relative_base);
-                let b = param(mem, pc, 2, &relative_base);
-                *param_mut(mem, pc, 3, &relative_base) = if a < b { 1 } else { 0 };
-                pc += 4;
-            },
-            8 => { // equals
-                let a = param(mem, pc, 1, &relative_base);
-                let b = param(mem, pc, 2, &relative_base);
-                *param_mut(mem, pc, 3, &relative_base) = if a == b { 1 } else { 0 };
-                pc += 4;
-            },
-            9 => { // Relative base offset
-                relative_base += param(mem, pc, 1, &relative_base);
-                pc += 2;
-            }
-            99 => break, // halt
-            opcode => unimplemented!("unknown opcode {}", opcode)
+            Event::Halted => break,
         }
     }
+    *mem = machine.mem;
 }
 
 pub fn send_line(tx: &Sender<i64>, line: &str) {
@@ -172,7 +219,7 @@ mod tests {
     use super::*;
 
     fn run_no_io(mem_str: &str) -> Mem {
-        let mut mem = parse(mem_str);
+        let mut mem = parse(mem_str).unwrap();
         run(&mut mem, &channel().1, channel().0);
         mem
     }
@@ -181,7 +228,7 @@ mod tests {
         let (tx_in, rx_in) = channel();
         let (tx_out, rx_out) = channel();
         tx_in.send(input).unwrap();
-        run(&mut parse(mem_str), &rx_in, tx_out);
+        run(&mut parse(mem_str).unwrap(), &rx_in, tx_out);
         let output = rx_out.recv().unwrap();
         assert!(rx_out.recv().is_err());
         output
@@ -199,7 +246,7 @@ mod tests {
 
     #[test]
     fn test_day5_part1() {
-        assert_eq!(run_no_io("1002,4,3,4,33"), parse("1002,4,3,4,99"));
+        assert_eq!(run_no_io("1002,4,3,4,33"), parse("1002,4,3,4,99").unwrap());
     }
 
     #[test]
@@ -247,7 +294,7 @@ mod tests {
     fn test_day9_part1() {
         let ex_quine = "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99";
         let (tx_out, rx_out) = channel();
-        run(&mut parse(ex_quine), &channel().1, tx_out);
+        run(&mut parse(ex_quine).unwrap(), &channel().1, tx_out);
         let mut quine_out = String::new();
         let mut first = true;
         while let Ok(v) = rx_out.recv() {
@@ -258,14 +305,33 @@ mod tests {
 
         let ex_16digit = "1102,34915192,34915192,7,4,7,99,0";
         let (tx_out, rx_out) = channel();
-        run(&mut parse(ex_16digit), &channel().1, tx_out);
+        run(&mut parse(ex_16digit).unwrap(), &channel().1, tx_out);
         assert_eq!(format!("{}", rx_out.recv().unwrap()).len(), 16);
         assert!(rx_out.recv().is_err());
 
         let ex_middle = "104,1125899906842624,99";
         let (tx_out, rx_out) = channel();
-        run(&mut parse(ex_middle), &channel().1, tx_out);
+        run(&mut parse(ex_middle).unwrap(), &channel().1, tx_out);
         assert_eq!(1125899906842624, rx_out.recv().unwrap());
         assert!(rx_out.recv().is_err());
     }
+
+    #[test]
+    fn test_machine() {
+        // Outputs double its input until it reads a zero
+        let mut machine = Machine::new(parse("3,15,1006,15,14,1002,15,2,15,4,15,1105,1,0,99,0").unwrap());
+        assert_eq!(machine.resume(), Event::NeedInput);
+        machine.push_input(21);
+        assert_eq!(machine.resume(), Event::Output(42));
+        assert_eq!(machine.resume(), Event::NeedInput);
+        machine.push_input(0);
+        assert_eq!(machine.resume(), Event::Halted);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("1,0,x0,0,99").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 5));
+        assert_eq!(parse("1,0,0,0,99\n\n2").err().unwrap().line, 2);
+    }
 }
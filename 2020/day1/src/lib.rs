@@ -0,0 +1,74 @@
+use std::collections::HashSet;
+
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse(puzzle_input: &str) -> Result<HashSet<u64>, ParseError> {
+    parsing::lines(puzzle_input).into_iter().map(|line| line.parse()).collect()
+}
+
+pub fn part1(entries: &HashSet<u64>) -> u64 {
+    for v1 in entries.iter() {
+        if *v1 > 2020 { continue }
+        let v2 = 2020 - v1;
+        if entries.contains(&v2) {
+            return v1 * v2
+        }
+    }
+    panic!()
+}
+
+pub fn part2(entries: &HashSet<u64>) -> u64 {
+    for v1 in entries.iter() {
+        for v2 in entries.iter() {
+            if v1 == v2 || v1 + v2 > 2020 { continue }
+            let v3 = 2020 - v1 - v2;
+            if entries.contains(&v3) {
+                return v1 * v2 * v3;
+            }
+        }
+    }
+    panic!()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = HashSet<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "1721
+979
+366
+299
+675
+1456";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(514579, part1(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(241861950, part2(&parse(EX).unwrap()));
+    }
+}
@@ -0,0 +1,190 @@
+use std::fmt;
+
+use parsing::ParseError;
+use solution::Solution;
+
+#[derive(Copy, Clone, PartialEq)]
+enum Cell {
+    Floor,
+    Seat(bool)
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let ch = match self {
+            Cell::Floor => '.',
+            Cell::Seat(false) => 'L',
+            Cell::Seat(true) => '#'
+        };
+        write!(f, "{}", ch)
+    }
+}
+
+impl Cell {
+    fn parse(ch: char) -> Option<Cell> {
+        match ch {
+            'L' => Some(Cell::Seat(false)),
+            '#' => Some(Cell::Seat(true)),
+            '.' => Some(Cell::Floor),
+            _ => None
+        }
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub struct Map {
+    height: usize,
+    width: usize,
+    cells: Vec<Cell>
+}
+
+impl Map {
+    fn parse(puzzle_input: &str) -> Result<Map, ParseError> {
+        let rows = parsing::grid(&parsing::lines(puzzle_input), Cell::parse)?;
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        let cells = rows.into_iter().flatten().collect();
+        Ok(Map { height: height, width: width, cells: cells })
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in 0..self.height {
+            if y > 0 { write!(f, "\n")? }
+            for x in 0..self.width {
+                write!(f, "{}", self.get(x, y))?
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Map {
+    fn get(&self, x: usize, y: usize) -> Cell {
+        assert!(x < self.width);
+        assert!(y < self.height);
+        self.cells[y*self.width + x]
+    }
+
+    fn get_mut(&mut self, x: usize, y: usize) -> &mut Cell {
+        assert!(x < self.width);
+        assert!(y < self.height);
+        &mut self.cells[y*self.width + x]
+    }
+
+    fn get_offset(&self, xin: usize, xoff: isize, yin: usize, yoff: isize) -> Option<Cell> {
+        let x = xoff + xin as isize;
+        let y = yoff + yin as isize;
+        if x < 0 || x as usize >= self.width { return None }
+        if y < 0 || y as usize >= self.height { return None }
+        Some(self.get(x as usize, y as usize))
+    }
+}
+
+const NEIGHBOR_DIRS: &[(isize, isize)] = &[(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)];
+
+pub fn part1(map: &Map) -> usize {
+    fn num_occupied_neighbors(map: &Map, x: usize, y: usize) -> usize {
+        NEIGHBOR_DIRS.iter()
+            .filter(|&&(xoff, yoff)| map.get_offset(x, xoff, y, yoff) == Some(Cell::Seat(true)))
+            .count()
+    }
+
+    let mut map = map.clone();
+    loop {
+        let mut next_map = map.clone();
+        for y in 0..map.height {
+            for x in 0..map.width {
+                match (map.get(x, y), num_occupied_neighbors(&map, x, y)) {
+                    (Cell::Seat(false), n) if n == 0 => { *next_map.get_mut(x, y) = Cell::Seat(true) },
+                    (Cell::Seat(true), n) if n >= 4 => { *next_map.get_mut(x, y) = Cell::Seat(false) },
+                    _ => ()
+                }
+            }
+        }
+        if map == next_map { break }
+        map = next_map;
+    }
+    map.cells.iter().filter(|&&cell| Cell::Seat(true) == cell).count()
+}
+
+pub fn part2(map: &Map) -> usize {
+    fn num_occupied_neighbors(map: &Map, x: usize, y: usize) -> usize {
+        NEIGHBOR_DIRS.iter()
+            .filter(|&&(xoff, yoff)| {
+                for i in 1.. {
+                    match map.get_offset(x, i*xoff, y, i*yoff) {
+                        Some(Cell::Seat(true)) => return true,
+                        Some(Cell::Seat(false)) | None => return false,
+                        Some(Cell::Floor) => ()
+                    }
+                }
+                unreachable!()
+            })
+            .count()
+    }
+
+    let mut map = map.clone();
+    loop {
+        let mut next_map = map.clone();
+        for y in 0..map.height {
+            for x in 0..map.width {
+                match (map.get(x, y), num_occupied_neighbors(&map, x, y)) {
+                    (Cell::Seat(false), n) if n == 0 => { *next_map.get_mut(x, y) = Cell::Seat(true) },
+                    (Cell::Seat(true), n) if n >= 5 => { *next_map.get_mut(x, y) = Cell::Seat(false) },
+                    _ => ()
+                }
+            }
+        }
+        if map == next_map { break }
+        map = next_map;
+    }
+    map.cells.iter().filter(|&&cell| Cell::Seat(true) == cell).count()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        Map::parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(37, part1(&Map::parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(26, part2(&Map::parse(EX).unwrap()));
+    }
+}
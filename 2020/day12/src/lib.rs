@@ -0,0 +1,185 @@
+use lazy_static::lazy_static;
+use parsing::ParseError;
+use regex::Regex;
+use solution::Solution;
+
+#[derive(Copy, Clone, PartialEq)]
+enum Dir {
+    North,
+    South,
+    East,
+    West,
+    Left,
+    Right,
+    Forward
+}
+
+impl Dir {
+    fn rotate_left(&self) -> Dir {
+        match self {
+            Dir::North => Dir::West,
+            Dir::South => Dir::East,
+            Dir::East => Dir::North,
+            Dir::West => Dir::South,
+            _ => panic!()
+        }
+    }
+
+    fn rotate_right(&self) -> Dir {
+        match self {
+            Dir::North => Dir::East,
+            Dir::South => Dir::West,
+            Dir::East => Dir::South,
+            Dir::West => Dir::North,
+            _ => panic!()
+        }
+    }
+}
+
+pub struct Action {
+    dir: Dir,
+    val: i32
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<Action>, ParseError> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new("^([NSEWLRF])([0-9]+)$").unwrap();
+    }
+    parsing::parse_lines(puzzle_input, |line| {
+        let cap = RE.captures(line.text).ok_or_else(|| line.error(1, "expected an action like \"F10\""))?;
+        let dir = match &cap[1] {
+            "N" => Dir::North,
+            "S" => Dir::South,
+            "E" => Dir::East,
+            "W" => Dir::West,
+            "L" => Dir::Left,
+            "R" => Dir::Right,
+            "F" => Dir::Forward,
+            _ => panic!()
+        };
+        let val_field = line.field(&cap[2]);
+        let val = val_field.parse()?;
+        if val == 0 {
+            return Err(val_field.error("expected a positive value"));
+        } else if (dir == Dir::Left || dir == Dir::Right) && (val % 90 != 0) {
+            return Err(val_field.error("expected a turn by a multiple of 90 degrees"));
+        }
+        Ok(Action { dir, val })
+    })
+}
+
+pub fn part1(actions: &Vec<Action>) -> u32 {
+    let mut east = 0;
+    let mut north = 0;
+    let mut facing = Dir::East;
+    for action in actions {
+        match action.dir {
+            Dir::North => { north += action.val },
+            Dir::South => { north -= action.val },
+            Dir::East => { east += action.val },
+            Dir::West => { east -= action.val },
+            Dir::Left => {
+                for _ in 0..action.val/90 {
+                    facing = facing.rotate_left();
+                }
+            },
+            Dir::Right => {
+                for _ in 0..action.val/90 {
+                    facing = facing.rotate_right();
+                }
+            },
+            Dir::Forward => {
+                match facing {
+                    Dir::North => { north += action.val },
+                    Dir::South => { north -= action.val },
+                    Dir::East => { east += action.val },
+                    Dir::West => { east -= action.val },
+                    _ => panic!()
+                }
+            }
+        }
+    }
+    (east.abs() + north.abs()) as u32
+}
+
+pub fn part2(actions: &Vec<Action>) -> u32 {
+    let mut east = 0;
+    let mut north = 0;
+    let mut wayp_east = 10;
+    let mut wayp_north = 1;
+    for action in actions {
+        match action.dir {
+            Dir::North => { wayp_north += action.val },
+            Dir::South => { wayp_north -= action.val },
+            Dir::East => { wayp_east += action.val },
+            Dir::West => { wayp_east -= action.val },
+            Dir::Left => {
+                for _ in 0..action.val/90 {
+                    let orig_wayp_north = wayp_north;
+                    wayp_north = wayp_east;
+                    wayp_east = -orig_wayp_north;
+                }
+            },
+            Dir::Right => {
+                for _ in 0..action.val/90 {
+                    let orig_wayp_north = wayp_north;
+                    wayp_north = -wayp_east;
+                    wayp_east = orig_wayp_north;
+                }
+            },
+            Dir::Forward => {
+                north += wayp_north * action.val;
+                east += wayp_east * action.val;
+            }
+        }
+    }
+    (east.abs() + north.abs()) as u32
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Action>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "F10
+N3
+F7
+R90
+F11";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(25, part1(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(286, part2(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("F10\nR45").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(parse("F10\nX3").err().unwrap().line, 2);
+    }
+}
@@ -0,0 +1,104 @@
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+pub type Bus = u16;
+
+pub fn parse(puzzle_input: &str) -> Result<(u64, Vec<(Bus, usize)>), ParseError> {
+    let lines = parsing::lines(puzzle_input);
+    if let Some(line) = lines.get(2) {
+        return Err(line.error(1, "expected only two lines"));
+    }
+    let missing = Line { number: lines.last().map_or(1, |line| line.number + 1), text: "" };
+    let earliest_departure = lines.first().copied().unwrap_or(missing).parse()?;
+    let buses_line = lines.get(1).copied().ok_or_else(|| missing.error(1, "expected the bus IDs"))?;
+    let mut buses = Vec::new();
+    for (i, bus_field) in buses_line.split(",").into_iter().enumerate() {
+        if bus_field.text != "x" {
+            buses.push((bus_field.parse()?, i));
+        }
+    }
+    Ok((earliest_departure, buses))
+}
+
+fn wait_time(t: u64, bus: Bus) -> Bus {
+    (bus - (t % bus as u64) as Bus) % bus
+}
+
+pub fn part1(earliest_departure: u64, buses: &Vec<(Bus, usize)>) -> u64 {
+    let mut best_bus = 0;
+    let mut best_wait = Bus::MAX;
+    for &(bus, _) in buses {
+        let wait = wait_time(earliest_departure, bus);
+        if wait < best_wait {
+            best_wait = wait;
+            best_bus = bus;
+        }
+    }
+    best_bus as u64 * best_wait as u64
+}
+
+pub fn part2(buses: &Vec<(Bus, usize)>) -> u64 {
+    let mut t = 0;
+    let mut mult = 1;
+    // NOTE - Luckily the bus IDs given are coprime, I'm pretty sure otherwise this wouldn't find the first occurence
+    for &(bus, offset) in buses {
+        while wait_time(t + offset as u64, bus) != 0 {
+            t += mult;
+        }
+        mult *= bus as u64;
+    }
+    assert!(buses.iter().all(|&(bus, offset)| wait_time(t + offset as u64, bus) == 0));
+    t
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (u64, Vec<(Bus, usize)>);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(&input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "939
+7,13,x,x,59,x,31,19";
+
+    #[test]
+    fn test_part1() {
+        let (earliest_departure, buses) = parse(EX).unwrap();
+        assert_eq!(295, part1(earliest_departure, &buses));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(1068781, part2(&parse(EX).unwrap().1));
+
+        assert_eq!(3417, part2(&parse("0\n17,x,13,19").unwrap().1));
+        assert_eq!(754018, part2(&parse("0\n67,7,59,61").unwrap().1));
+        assert_eq!(779210, part2(&parse("0\n67,x,7,59,61").unwrap().1));
+        assert_eq!(1261476, part2(&parse("0\n67,7,x,59,61").unwrap().1));
+        assert_eq!(1202161486, part2(&parse("0\n1789,37,47,1889").unwrap().1));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("939\n7,13,y").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 6));
+        assert_eq!(parse("939").err().unwrap().line, 2);
+    }
+}
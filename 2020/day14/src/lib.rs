@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+pub enum Cmd {
+    Mask([Option<bool>; 36]),
+    Write(u64, u64)
+}
+
+impl Cmd {
+    fn parse(line: Line) -> Result<Cmd, ParseError> {
+        let (first, second) = line.as_field().split_once(" = ")?;
+        if first.text == "mask" {
+            let bits = second.map_chars(|ch| match ch {
+                'X' => Some(None),
+                '0' => Some(Some(false)),
+                '1' => Some(Some(true)),
+                _ => None
+            })?;
+            if bits.len() != 36 {
+                return Err(second.error("expected a mask of 36 bits"));
+            }
+            let mut mask = [None; 36];
+            for (i, bit) in bits.into_iter().enumerate() {
+                mask[36 - i - 1] = bit;
+            }
+            Ok(Cmd::Mask(mask))
+        } else {
+            let addr_field = first.strip_prefix("mem[")?;
+            let addr_field = line.field(addr_field.text.strip_suffix(']').ok_or_else(|| first.error("expected \"mem[ADDRESS]\""))?);
+            let addr = addr_field.parse()?;
+            let val = second.parse()?;
+            if addr >= (1 << 36) {
+                return Err(addr_field.error("expected an address under 2^36"));
+            } else if val >= (1 << 36) {
+                return Err(second.error("expected a value under 2^36"));
+            }
+            Ok(Cmd::Write(addr, val))
+        }
+    }
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<Cmd>, ParseError> {
+    parsing::parse_lines(puzzle_input, Cmd::parse)
+}
+
+pub fn part1(cmds: &Vec<Cmd>) -> u64 {
+    let mut mem = HashMap::new();
+    let mut curmask = [Some(true); 36];
+    for cmd in cmds {
+        match cmd {
+            Cmd::Mask(mask) => {
+                curmask = mask.clone();
+            },
+            Cmd::Write(addr, mut val) => {
+                for i in 0..36 {
+                    match curmask[i] {
+                        Some(false) => val &= u64::MAX - (1 << i),
+                        Some(true) => val |= 1 << i,
+                        None => ()
+                    }
+                }
+                mem.insert(addr, val);
+            }
+        }
+    }
+    mem.values().sum()
+}
+
+pub fn part2(cmds: &Vec<Cmd>) -> u64 {
+    fn write_addrs(mem: &mut HashMap<u64, u64>, addr: u64, val: u64, mask: &[Option<bool>; 36], bitidx: u64) {
+        if bitidx == 36 {
+            mem.insert(addr, val);
+            return;
+        }
+        match mask[bitidx as usize] {
+            Some(false) => write_addrs(mem, addr, val, mask, bitidx + 1),
+            Some(true) => write_addrs(mem, addr | (1 << bitidx), val, mask, bitidx + 1),
+            None => {
+                write_addrs(mem, addr & (u64::MAX - (1 << bitidx)), val, mask, bitidx + 1);
+                write_addrs(mem, addr | (1 << bitidx), val, mask, bitidx + 1);
+            }
+        }
+    }
+
+    let mut mem = HashMap::new();
+    let mut curmask = [Some(true); 36];
+    for cmd in cmds {
+        match cmd {
+            Cmd::Mask(mask) => {
+                curmask = mask.clone();
+            },
+            Cmd::Write(addr, val) => {
+                write_addrs(&mut mem, *addr, *val, &curmask, 0);
+            }
+        }
+    }
+    mem.values().sum()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Cmd>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX1: &str = "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X
+mem[8] = 11
+mem[7] = 101
+mem[8] = 0";
+
+    const EX2: &str = "mask = 000000000000000000000000000000X1001X
+mem[42] = 100
+mask = 00000000000000000000000000000000X0XX
+mem[26] = 1";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(165, part1(&parse(EX1).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(208, part2(&parse(EX2).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X\nmem[8] = 1x").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 10));
+        let err = parse("mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX2X").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 42));
+    }
+}
@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use parsing::ParseError;
+use solution::Solution;
+
+// Numbers past this go in a HashMap instead of the table, so a huge starting number can't make it
+// allocate gigabytes. Numbers spoken after the start are always less than the turn, so 30 million
+// turns never need it. So do turns too late to fit in the table's u32s.
+const DENSE_LIMIT: usize = 1 << 26;
+
+// The numbers spoken each turn, from the first. Each number's last turn is kept in a table indexed
+// by the number, which only grows as far as the biggest number spoken so far, with 0 for never.
+struct MemoryGame<'a> {
+    starting_nums: &'a [usize],
+    turn: usize,
+    last: usize,
+    dense: Vec<u32>,
+    sparse: HashMap<usize, usize>,
+}
+
+impl MemoryGame<'_> {
+    fn new(starting_nums: &[usize]) -> MemoryGame<'_> {
+        MemoryGame { starting_nums, turn: 0, last: 0, dense: Vec::new(), sparse: HashMap::new() }
+    }
+
+    // Records that num was spoken on turn, returning when it was spoken before
+    fn speak(&mut self, num: usize, turn: usize) -> Option<usize> {
+        let dense_turn = match u32::try_from(turn) {
+            Ok(dense_turn) if num < DENSE_LIMIT => dense_turn,
+            // Turns only go up, so once they're too big the table is never written again and its
+            // entry for num moves to the HashMap the next time num is spoken
+            _ => {
+                let prev = self.sparse.insert(num, turn);
+                return prev.or_else(|| {
+                    let prev = std::mem::take(self.dense.get_mut(num)?);
+                    (prev != 0).then_some(prev as usize)
+                });
+            }
+        };
+        if num >= self.dense.len() {
+            self.dense.resize((num + 1).next_power_of_two().min(DENSE_LIMIT), 0);
+        }
+        let prev = std::mem::replace(&mut self.dense[num], dense_turn);
+        (prev != 0).then_some(prev as usize)
+    }
+}
+
+impl Iterator for MemoryGame<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        self.turn += 1;
+        let next = if self.turn <= self.starting_nums.len() {
+            if self.turn > 1 {
+                self.speak(self.last, self.turn - 1);
+            }
+            self.starting_nums[self.turn - 1]
+        } else {
+            match self.speak(self.last, self.turn - 1) {
+                Some(prev) => self.turn - 1 - prev,
+                None => 0,
+            }
+        };
+        self.last = next;
+        Some(next)
+    }
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<usize>, ParseError> {
+    parsing::single_line(puzzle_input)?.split(",").into_iter().map(|n| n.parse()).collect()
+}
+
+fn nth_spoken(starting_nums: &[usize], turn: usize) -> usize {
+    MemoryGame::new(starting_nums).nth(turn - 1).unwrap()
+}
+
+// The first turn num is spoken, if it's within max_turns. Only the tests ask this so far.
+#[cfg(test)]
+fn first_spoken(starting_nums: &[usize], num: usize, max_turns: usize) -> Option<usize> {
+    MemoryGame::new(starting_nums).take(max_turns).position(|spoken| spoken == num).map(|i| i + 1)
+}
+
+pub fn part1(starting_nums: &Vec<usize>) -> usize {
+    nth_spoken(starting_nums, 2020)
+}
+
+pub fn part2(starting_nums: &Vec<usize>) -> usize {
+    nth_spoken(starting_nums, 30_000_000)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(436, part1(&vec![0,3,6]));
+        assert_eq!(1, part1(&vec![1,3,2]));
+        assert_eq!(10, part1(&vec![2,1,3]));
+        assert_eq!(27, part1(&vec![1,2,3]));
+        assert_eq!(78, part1(&vec![2,3,1]));
+        assert_eq!(438, part1(&vec![3,2,1]));
+        assert_eq!(1836, part1(&vec![3,1,2]));
+    }
+
+    #[test]
+    fn test_memory_game() {
+        let spoken: Vec<usize> = MemoryGame::new(&[0, 3, 6]).take(10).collect();
+        assert_eq!(spoken, vec![0, 3, 6, 0, 3, 3, 1, 0, 4, 0]);
+        assert_eq!(first_spoken(&[0, 3, 6], 4, 100), Some(9));
+        assert_eq!(first_spoken(&[0, 3, 6], 5, 8), None);
+        // Too big for the table
+        assert_eq!(nth_spoken(&[1 << 30, 1 << 30], 3), 1);
+        // Too late for the table
+        let mut game = MemoryGame::new(&[]);
+        let late = u32::MAX as usize + 1;
+        assert_eq!(game.speak(5, 10), None);
+        assert_eq!(game.speak(5, late), Some(10));
+        assert_eq!(game.speak(5, late + 3), Some(late));
+        assert_eq!(game.speak(6, late + 4), None);
+    }
+
+    // NOTE - separate tests so they run in parallel to speed it up
+    #[test]
+    fn test_part2_1() {
+        assert_eq!(175594, part2(&vec![0,3,6]));
+    }
+    #[test]
+    fn test_part2_2() {
+        assert_eq!(2578, part2(&vec![1,3,2]));
+    }
+    #[test]
+    fn test_part2_3() {
+        assert_eq!(3544142, part2(&vec![2,1,3]));
+    }
+    #[test]
+    fn test_part2_4() {
+        assert_eq!(261214, part2(&vec![1,2,3]));
+    }
+    #[test]
+    fn test_part2_5() {
+        assert_eq!(6895259, part2(&vec![2,3,1]));
+    }
+    #[test]
+    fn test_part2_6() {
+        assert_eq!(18, part2(&vec![3,2,1]));
+    }
+    #[test]
+    fn test_part2_7() {
+        assert_eq!(362, part2(&vec![3,1,2]));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("0,3,6\n").unwrap(), vec![0, 3, 6]);
+        let err = parse("0,3,-6").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 5));
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::{HashMap, HashSet};
+use std::ops::RangeInclusive;
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+pub type Rules = HashMap<String, Vec<RangeInclusive<u32>>>;
+pub type Ticket = Vec<u32>;
+
+pub fn parse(puzzle_input: &str) -> Result<(Rules, Ticket, Vec<Ticket>), ParseError> {
+    fn parse_ticket(line: Line) -> Result<Ticket, ParseError> {
+        line.split(",").into_iter().map(|n| n.parse()).collect()
+    }
+
+    // The tickets' sections start with a header line
+    fn tickets<'a>(section: &'a [Line<'a>], header: &str) -> Result<&'a [Line<'a>], ParseError> {
+        if section[0].text != header {
+            return Err(section[0].error(1, format!("expected {:?}", header)));
+        }
+        Ok(&section[1..])
+    }
+
+    let sections = parsing::sections(puzzle_input);
+    if sections.len() != 3 {
+        let line = sections.get(3).map_or_else(
+            || Line { number: parsing::lines(puzzle_input).last().map_or(1, |line| line.number + 1), text: "" },
+            |section| section[0]);
+        return Err(line.error(1, "expected the rules, your ticket and the nearby tickets"));
+    }
+
+    let mut rules = Rules::new();
+    for &line in &sections[0] {
+        let (field, ranges_field) = line.as_field().split_once(": ")?;
+        let ranges = ranges_field.split(" or ").into_iter()
+            .map(|range_field| {
+                let (begin, end) = range_field.split_once("-")?;
+                Ok(begin.parse()? ..= end.parse()?)
+            }).collect::<Result<_, ParseError>>()?;
+        rules.insert(field.text.to_string(), ranges);
+    }
+
+    let your_ticket = match tickets(&sections[1], "your ticket:")? {
+        [line] => parse_ticket(*line)?,
+        lines => return Err(lines.get(1).unwrap_or(&sections[1][0]).error(1, "expected one ticket"))
+    };
+    let nearby_tickets = tickets(&sections[2], "nearby tickets:")?.iter()
+        .map(|&line| parse_ticket(line))
+        .collect::<Result<_, _>>()?;
+    Ok((rules, your_ticket, nearby_tickets))
+}
+
+fn valid_for_ranges(ranges: &Vec<RangeInclusive<u32>>, val: u32) -> bool {
+    ranges.iter().any(|range| range.contains(&val))
+}
+fn valid_for_some_rule(rules: &Rules, val: u32) -> bool {
+    rules.values().any(|ranges| valid_for_ranges(ranges, val))
+}
+
+pub fn part1(rules: &Rules, nearby_tickets: &Vec<Ticket>) -> u32 {
+    nearby_tickets.iter()
+        .flat_map(|ticket| ticket.iter())
+        .filter(|&&val| !valid_for_some_rule(rules, val))
+        .sum()
+}
+
+pub fn part2(rules: &Rules, your_ticket: &Ticket, nearby_tickets: &Vec<Ticket>) -> u64 {
+    let nearby_tickets: Vec<_> = nearby_tickets.iter()
+        .filter(|&ticket| ticket.iter().all(|&val| valid_for_some_rule(rules, val)))
+        .cloned()
+        .collect();
+
+    let mut allowed_offsets: Vec<(&str, HashSet<usize>)> = rules.iter()
+        .map(|(field, ranges)| {
+            let offsets: HashSet<usize> = (0..rules.len())
+                .filter(|&i| nearby_tickets.iter().all(|ticket| valid_for_ranges(ranges, ticket[i])))
+                .collect();
+            (field.as_str(), offsets)
+        }).collect();
+    allowed_offsets.sort_by_key(|(_, offsets)| offsets.len());
+    let mut used_offsets: HashSet<usize> = HashSet::new();
+    let mut correct_offsets: HashMap<&str, usize> = HashMap::new();
+    for (field, offsets) in allowed_offsets {
+        let offset = *offsets.iter().filter(|&i| !used_offsets.contains(i)).next().unwrap();
+        used_offsets.insert(offset);
+        correct_offsets.insert(field, offset);
+    }
+
+    correct_offsets.iter()
+        .filter(|(field, _)| field.starts_with("departure"))
+        .map(|(_, offset)| your_ticket[*offset] as u64)
+        .product()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Rules, Ticket, Vec<Ticket>);
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        part1(&input.0, &input.2)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(&input.0, &input.1, &input.2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX1: &str = "class: 1-3 or 5-7
+row: 6-11 or 33-44
+seat: 13-40 or 45-50
+
+your ticket:
+7,1,14
+
+nearby tickets:
+7,3,47
+40,4,50
+55,2,20
+38,6,12";
+
+    const EX2: &str = "class: 0-1 or 4-19
+row: 0-5 or 8-19
+seat: 0-13 or 16-19
+
+your ticket:
+11,12,13
+
+nearby tickets:
+3,9,18
+15,1,5
+5,14,9";
+
+    #[test]
+    fn test_part1() {
+        let (rules, _your_ticket, nearby_tickets) = parse(EX1).unwrap();
+        assert_eq!(71, part1(&rules, &nearby_tickets));
+    }
+
+    #[test]
+    fn test_part2() {
+        let (rules, your_ticket, nearby_tickets) = parse(EX2).unwrap();
+        assert_eq!(1, part2(&rules, &your_ticket, &nearby_tickets));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse(&EX1.replace("33-44", "33_44")).err().unwrap();
+        assert_eq!((err.line, err.column), (2, 14));
+        let err = parse(&EX1.replace("nearby", "near")).err().unwrap();
+        assert_eq!((err.line, err.column), (8, 1));
+        assert_eq!(parse(&EX1[..EX1.find("\n\nnearby").unwrap()]).err().unwrap().line, 7);
+    }
+}
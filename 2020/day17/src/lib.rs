@@ -0,0 +1,131 @@
+use std::collections::HashSet;
+
+use parsing::ParseError;
+use solution::Solution;
+
+type Point = [i32; 4];
+pub type Map = HashSet<Point>; // The active points on the infinite grid
+
+pub fn parse(puzzle_input: &str) -> Result<Map, ParseError> {
+    let cells = parsing::grid(&parsing::lines(puzzle_input), |ch| match ch {
+        '.' => Some(false),
+        '#' => Some(true),
+        _ => None
+    })?;
+    let mut active = Map::new();
+    for (y, row) in cells.iter().enumerate() {
+        for (x, &is_active) in row.iter().enumerate() {
+            if is_active {
+                active.insert([x as i32, y as i32, 0, 0]);
+            }
+        }
+    }
+    Ok(active)
+}
+
+fn points_of_interest(map: &Map, is_4d: bool) -> HashSet<Point> {
+    let mut points = HashSet::new();
+    for &[x, y, z, w] in map {
+        for xn in (x-1)..=(x+1) {
+            for yn in (y-1)..=(y+1) {
+                for zn in (z-1)..=(z+1) {
+                    for wn in (w-1)..=(w+1) {
+                        if !is_4d && wn != w { continue }
+                        points.insert([xn, yn, zn, wn]);
+                    }
+                }
+            }
+        }
+    }
+    points
+}
+
+fn count_active_neighbors(map: &Map, [x, y, z, w]: Point, is_4d: bool) -> usize {
+    let mut cnt = 0;
+    for xn in (x-1)..=(x+1) {
+        for yn in (y-1)..=(y+1) {
+            for zn in (z-1)..=(z+1) {
+                for wn in (w-1)..=(w+1) {
+                    if !is_4d && w != wn { continue }
+                    if x == xn && y == yn && z == zn && w == wn { continue }
+                    if map.contains(&[xn, yn, zn, wn]) {
+                        cnt += 1;
+                    }
+                }
+            }
+        }
+    }
+    cnt
+}
+
+fn cycle(prev_map: Map, is_4d: bool) -> Map {
+    let mut next_map = Map::new();
+    for p in points_of_interest(&prev_map, is_4d) {
+        let cnt = count_active_neighbors(&prev_map, p, is_4d);
+        let was_active = prev_map.contains(&p);
+        let is_active = match (was_active, cnt) {
+            (true, 2..=3) => true,
+            (false, 3) => true,
+            _ => false
+        };
+        if is_active {
+            next_map.insert(p);
+        }
+    }
+    next_map
+}
+
+pub fn part1(mut map: Map) -> usize {
+    for _ in 0..6 { map = cycle(map, false) }
+    map.len()
+}
+
+pub fn part2(mut map: Map) -> usize {
+    for _ in 0..6 { map = cycle(map, true) }
+    map.len()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input.clone())
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = ".#.
+..#
+###";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(112, part1(parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(848, part2(parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse(".#.\n..#\n#?#").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 2));
+    }
+}
@@ -0,0 +1,164 @@
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+#[derive(PartialEq)]
+pub enum Token {
+    Num(u64),
+    Plus,
+    Star,
+    LParen,
+    RParen
+}
+
+enum Op {
+    Add,
+    Mul
+}
+
+enum Precedence {
+    LeftToRight,
+    AddBeforeMul
+}
+
+// Also checks the tokens make an expression, so eval can't run off the end of them
+fn tokenize(line: Line) -> Result<Vec<Token>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut expect_value = true;
+    let mut depth = 0;
+    // NOTE - input doesn't have any numbers larger than 9 so we don't need to handle them
+    for (i, ch) in line.text.chars().enumerate() {
+        let token = match ch {
+            '0'..='9' => Token::Num(ch as u64 - '0' as u64),
+            '+' => Token::Plus,
+            '*' => Token::Star,
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            ' ' => continue,
+            _ => return Err(line.error(i + 1, format!("unexpected {:?}", ch)))
+        };
+        match (&token, expect_value) {
+            (Token::Num(_), true) => expect_value = false,
+            (Token::LParen, true) => depth += 1,
+            (Token::Plus | Token::Star, false) => expect_value = true,
+            (Token::RParen, false) if depth > 0 => depth -= 1,
+            (_, true) => return Err(line.error(i + 1, "expected a number or \"(\"")),
+            (_, false) => return Err(line.error(i + 1, "expected an operator or \")\"")),
+        }
+        tokens.push(token);
+    }
+    if expect_value || depth > 0 {
+        return Err(line.error(line.text.chars().count() + 1, "unexpected end of expression"));
+    }
+    Ok(tokens)
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<Vec<Token>>, ParseError> {
+    parsing::parse_lines(puzzle_input, tokenize)
+}
+
+fn eval(tokens: &[Token], prec: &Precedence) -> u64 {
+    fn value(tokens: &[Token], idx: &mut usize, prec: &Precedence) -> u64 {
+        match tokens[*idx] {
+            Token::Num(n) => { *idx += 1; n },
+            Token::LParen => { *idx += 1; inner(tokens, idx, prec, true) },
+            _ => panic!()
+        }
+    }
+
+    fn operation(tokens: &[Token], idx: &mut usize) -> Op {
+        match tokens[*idx] {
+            Token::Plus => { *idx += 1; Op::Add },
+            Token::Star => { *idx += 1; Op::Mul },
+            _ => panic!()
+        }
+    }
+
+    fn inner(tokens: &[Token], idx: &mut usize, prec: &Precedence, expect_rparen: bool) -> u64 {
+        let mut v1 = value(tokens, idx, prec);
+        loop {
+            if expect_rparen && Token::RParen == tokens[*idx] { *idx += 1; return v1; }
+            if !expect_rparen && *idx == tokens.len() { return v1; }
+
+            let op = operation(tokens, idx);
+            match (prec, &op) {
+                (Precedence::LeftToRight, _) | (Precedence::AddBeforeMul, Op::Add) => {
+                    let v2 = value(tokens, idx, prec);
+                    match op {
+                        Op::Add => v1 += v2,
+                        Op::Mul => v1 *= v2
+                    };
+                },
+                (Precedence::AddBeforeMul, Op::Mul) => {
+                    let v2 = inner(tokens, idx, prec, expect_rparen);
+                    return v1 * v2;
+                }
+            }
+        }
+    }
+
+    let mut idx = 0;
+    inner(tokens, &mut idx, prec, false)
+}
+
+pub fn part1(exprs: &[Vec<Token>]) -> u64 {
+    exprs.iter().map(|tokens| eval(tokens, &Precedence::LeftToRight)).sum()
+}
+
+pub fn part2(exprs: &[Vec<Token>]) -> u64 {
+    exprs.iter().map(|tokens| eval(tokens, &Precedence::AddBeforeMul)).sum()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<Token>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(71, part1(&parse("1 + 2 * 3 + 4 * 5 + 6").unwrap()));
+        assert_eq!(51, part1(&parse("1 + (2 * 3) + (4 * (5 + 6))").unwrap()));
+        assert_eq!(26, part1(&parse("2 * 3 + (4 * 5)").unwrap()));
+        assert_eq!(437, part1(&parse("5 + (8 * 3 + 9 + 3 * 4 * 3)").unwrap()));
+        assert_eq!(12240, part1(&parse("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))").unwrap()));
+        assert_eq!(13632, part1(&parse("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2").unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(231, part2(&parse("1 + 2 * 3 + 4 * 5 + 6").unwrap()));
+        assert_eq!(51, part2(&parse("1 + (2 * 3) + (4 * (5 + 6))").unwrap()));
+        assert_eq!(46, part2(&parse("2 * 3 + (4 * 5)").unwrap()));
+        assert_eq!(1445, part2(&parse("5 + (8 * 3 + 9 + 3 * 4 * 3)").unwrap()));
+        assert_eq!(669060, part2(&parse("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))").unwrap()));
+        assert_eq!(23340, part2(&parse("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2").unwrap()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("1 + 2\n1 + (2 * 3").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 11));
+        let err = parse("1 + * 2").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 5));
+        let err = parse("1 + 2)").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 6));
+    }
+}
@@ -0,0 +1,245 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use parsing::{Field, Line, ParseError};
+use solution::Solution;
+
+#[derive(Clone, Hash, Eq, PartialEq)]
+pub enum Rule {
+    Char(char),
+    Ref(usize),
+    Seq(Vec<Rule>),
+    Alt(Vec<Rule>)
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rule::Char(ch) => write!(f, "\"{}\"", ch),
+            Rule::Ref(id) => write!(f, "{}", id),
+            Rule::Seq(rules) => {
+                for (i, rule) in rules.iter().enumerate() {
+                    if i > 0 { write!(f, " ")?; }
+                    write!(f, "{}", rule)?;
+                }
+                Ok(())
+            },
+            Rule::Alt(rules) => {
+                for (i, rule) in rules.iter().enumerate() {
+                    if i > 0 { write!(f, " | ")?; }
+                    write!(f, "{}", rule)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+struct DisplayRules<'a>(&'a [Rule]);
+impl <'a> fmt::Display for DisplayRules<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, rule) in self.0.iter().enumerate() {
+            write!(f, "{}: {}\n", i, rule)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn parse(puzzle_input: &str) -> Result<(HashMap<usize, Rule>, Vec<String>), ParseError> {
+    // Remembers the references so they can be checked once all the rules are known
+    fn parse_rule<'a>(field: Field<'a>, refs: &mut Vec<(usize, Field<'a>)>) -> Result<Rule, ParseError> {
+        let s = field.text;
+        if s.contains(" | ") {
+            Ok(Rule::Alt(field.split(" | ").into_iter().map(|part| parse_rule(part, refs)).collect::<Result<_, _>>()?))
+        } else if s.contains(" ") {
+            Ok(Rule::Seq(field.split(" ").into_iter().map(|part| parse_rule(part, refs)).collect::<Result<_, _>>()?))
+        } else if s.starts_with("\"") {
+            let mut chars = s.chars().skip(1);
+            match (chars.next(), chars.next(), chars.next()) {
+                (Some(ch), Some('"'), None) if ch != '"' => Ok(Rule::Char(ch)),
+                _ => Err(field.error("expected a quoted character"))
+            }
+        } else {
+            let id = field.parse()?;
+            refs.push((id, field));
+            Ok(Rule::Ref(id))
+        }
+    }
+
+    let sections = parsing::sections(puzzle_input);
+    let (rule_lines, message_lines) = match &sections[..] {
+        [rule_lines, message_lines] => (rule_lines, message_lines),
+        _ => {
+            let line = sections.get(2).map_or_else(
+                || Line { number: parsing::lines(puzzle_input).last().map_or(1, |line| line.number + 1), text: "" },
+                |section| section[0]);
+            return Err(line.error(1, "expected the rules then the messages"));
+        }
+    };
+
+    let mut rules = HashMap::new();
+    let mut refs = Vec::new();
+    for line in rule_lines {
+        let (id, rule) = line.as_field().split_once(": ")?;
+        rules.insert(id.parse()?, parse_rule(rule, &mut refs)?);
+    }
+    if let Some((_, field)) = refs.iter().find(|(id, _)| !rules.contains_key(id)) {
+        return Err(field.error(format!("no rule {}", field.text)));
+    } else if !rules.contains_key(&0) {
+        return Err(rule_lines[0].error(1, "expected a rule 0"));
+    }
+    let messages = message_lines.iter().map(|line| line.text.to_string()).collect();
+    Ok((rules, messages))
+}
+
+// This takes ~30 seconds, maybe there's a faster way to do this based on the structure of the
+// rules given
+fn matches(rules: &HashMap<usize, Rule>, rule: &Rule, s: &str) -> bool {
+    fn matches_memo(rules: &HashMap<usize, Rule>,
+        rule: &Rule, s: &str,
+        memo: &mut HashMap<(Rule, String), bool>) -> bool {
+        if let Some(ret) = memo.get(&(rule.clone(), s.to_string())) { return *ret; }
+        let ret = match rule {
+            Rule::Char(ch) => ch.to_string() == s,
+            Rule::Ref(ref_id) => matches_memo(rules, &rules[ref_id], s, memo),
+            Rule::Seq(seq_rules) => {
+                if seq_rules.is_empty() {
+                    s.is_empty()
+                } else {
+                    let first_rule = &seq_rules[0];
+                    let remaining_rules = Rule::Seq(seq_rules[1..].iter().cloned().collect());
+                    (0..=s.len()).any(|i| matches_memo(rules, first_rule, &s[0..i], memo)
+                            && matches_memo(rules, &remaining_rules, &s[i..], memo))
+                }
+            },
+            Rule::Alt(alt_rules) => alt_rules.iter().any(|r| matches_memo(rules, r, s, memo))
+        };
+        memo.insert((rule.clone(), s.to_string()), ret);
+        ret
+    }
+    let mut memo = HashMap::new();
+    matches_memo(rules, rule, s, &mut memo)
+}
+
+pub fn part1(rules: &HashMap<usize, Rule>, messages: &[String]) -> usize {
+    messages.iter().filter(|m| matches(&rules, &rules[&0], &m)).count()
+}
+
+pub fn part2(rules: &HashMap<usize, Rule>, messages: &[String]) -> usize {
+    let mut rules = rules.clone();
+    rules.insert(8, Rule::Alt(vec![
+            Rule::Ref(42),
+            Rule::Seq(vec![Rule::Ref(42), Rule::Ref(8)])]));
+    rules.insert(11, Rule::Alt(vec![
+            Rule::Seq(vec![Rule::Ref(42), Rule::Ref(31)]),
+            Rule::Seq(vec![Rule::Ref(42), Rule::Ref(11), Rule::Ref(31)])]));
+    messages.iter().filter(|m| matches(&rules, &rules[&0], &m)).count()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (HashMap<usize, Rule>, Vec<String>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(&input.0, &input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX1: &str = r#"0: 4 1 5
+1: 2 3 | 3 2
+2: 4 4 | 5 5
+3: 4 5 | 5 4
+4: "a"
+5: "b"
+
+ababbb
+bababa
+abbbab
+aaabbb
+aaaabbb"#;
+
+    const EX2: &str = r#"42: 9 14 | 10 1
+9: 14 27 | 1 26
+10: 23 14 | 28 1
+1: "a"
+11: 42 31
+5: 1 14 | 15 1
+19: 14 1 | 14 14
+12: 24 14 | 19 1
+16: 15 1 | 14 14
+31: 14 17 | 1 13
+6: 14 14 | 1 14
+2: 1 24 | 14 4
+0: 8 11
+13: 14 3 | 1 12
+15: 1 | 14
+17: 14 2 | 1 7
+23: 25 1 | 22 14
+28: 16 1
+4: 1 1
+20: 14 14 | 1 15
+3: 5 14 | 16 1
+27: 1 6 | 14 18
+14: "b"
+21: 14 1 | 1 14
+25: 1 1 | 1 14
+22: 14 14
+8: 42
+26: 14 22 | 1 20
+18: 15 15
+7: 14 5 | 1 21
+24: 14 1
+
+abbbbbabbbaaaababbaabbbbabababbbabbbbbbabaaaa
+bbabbbbaabaabba
+babbbbaabbbbbabbbbbbaabaaabaaa
+aaabbbbbbaaaabaababaabababbabaaabbababababaaa
+bbbbbbbaaaabbbbaaabbabaaa
+bbbababbbbaaaaaaaabbababaaababaabab
+ababaaaaaabaaab
+ababaaaaabbbaba
+baabbaaaabbaaaababbaababb
+abbbbabbbbaaaababbbbbbaaaababb
+aaaaabbaabaaaaababaa
+aaaabbaaaabbaaa
+aaaabbaabbaaaaaaabbbabbbaaabbaabaaa
+babaaabbbaaabaababbaabababaaab
+aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba"#;
+
+    #[test]
+    fn test_part1() {
+        let (rules, messages) = parse(EX1).unwrap();
+        assert_eq!(2, part1(&rules, &messages));
+        let (rules, messages) = parse(EX2).unwrap();
+        assert_eq!(3, part1(&rules, &messages));
+    }
+
+    #[test]
+    fn test_part2() {
+        let (rules, messages) = parse(EX2).unwrap();
+        assert_eq!(12, part2(&rules, &messages));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse(&EX1.replace("3 | 3 2", "3 | 3 6")).err().unwrap();
+        assert_eq!((err.line, err.column), (2, 12));
+        let err = parse(&EX1.replace("\"b\"", "\"bb\"")).err().unwrap();
+        assert_eq!((err.line, err.column), (6, 4));
+    }
+}
@@ -0,0 +1,89 @@
+use lazy_static::lazy_static;
+use parsing::ParseError;
+use regex::Regex;
+use solution::Solution;
+
+pub struct Policy(usize, usize, char);
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<(Policy, String)>, ParseError> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new("^(\\d+)-(\\d+) ([a-z]): ([a-z]+)$").unwrap();
+    }
+    parsing::parse_lines(puzzle_input, |line| {
+        let cap = RE.captures(line.text).ok_or_else(|| line.error(1, "expected a policy and password like \"1-3 a: abcde\""))?;
+        let policy = Policy(
+            line.field(&cap[1]).parse()?,
+            line.field(&cap[2]).parse()?,
+            cap[3].chars().next().unwrap());
+        let password = cap[4].to_string();
+        Ok((policy, password))
+    })
+}
+
+pub fn part1(password_entries: &Vec<(Policy, String)>) -> usize {
+    fn is_valid((policy, password): &(Policy, String)) -> bool {
+        let cnt = password.chars().filter(|&ch| ch == policy.2).count();
+        cnt >= policy.0 && cnt <= policy.1
+    }
+    password_entries.iter().filter(|entry| is_valid(entry)).count()
+}
+
+pub fn part2(password_entries: &Vec<(Policy, String)>) -> usize {
+    fn valid_at_pos(pos: usize, letter: char, password: &str) -> bool {
+        password.chars().nth(pos - 1).unwrap() == letter
+    }
+
+    fn is_valid((policy, password): &(Policy, String)) -> bool {
+        let v1 = valid_at_pos(policy.0, policy.2, password);
+        let v2 = valid_at_pos(policy.1, policy.2, password);
+        v1 != v2
+    }
+    password_entries.iter().filter(|entry| is_valid(entry)).count()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<(Policy, String)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "1-3 a: abcde
+1-3 b: cdefg
+2-9 c: ccccccccc";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(2, part1(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(1, part2(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("1-3 a: abcde\n1-3 b cdefg").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("1-3 a: abcde\n1-99999999999999999999 b: cdefg").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+}
@@ -0,0 +1,117 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+#[derive(Debug)]
+pub struct Food {
+    ingredients: HashSet<String>,
+    allergens: HashSet<String>
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<Food>, ParseError> {
+    fn parse_line(line: Line) -> Result<Food, ParseError> {
+        let (ingredients, allergens) = line.as_field().split_once(" (contains ")?;
+        let allergens = allergens.text.strip_suffix(")")
+            .ok_or_else(|| line.error(line.text.chars().count() + 1, "expected \")\""))?;
+        let ingredients = ingredients.text.split(" ").map(|x| x.to_string()).collect();
+        let allergens = allergens.split(", ").map(|x| x.to_string()).collect();
+        Ok(Food { ingredients, allergens })
+    }
+
+    parsing::parse_lines(puzzle_input, parse_line)
+}
+
+fn calculate_ingredient_allergens(foods: &[Food]) -> HashMap<String, String> {
+    let mut possible_allergen_ingredients = HashMap::new();
+    for food in foods {
+        for allergen in &food.allergens {
+            let mut ingredients = possible_allergen_ingredients.remove(allergen).unwrap_or_else(|| food.ingredients.clone());
+            ingredients = &ingredients & &food.ingredients;
+            possible_allergen_ingredients.insert(allergen.clone(), ingredients);
+        }
+    }
+
+    let mut ingredient_allergens = HashMap::new();
+    while !possible_allergen_ingredients.is_empty() {
+        let allergen = possible_allergen_ingredients.iter()
+            .filter(|(_,ingredients)| ingredients.len() == 1)
+            .map(|(allergen,_)| allergen.clone())
+            .next().unwrap();
+        let ingredient = possible_allergen_ingredients.remove(&allergen).unwrap().into_iter().next().unwrap();
+        for ingredients in possible_allergen_ingredients.values_mut() {
+            ingredients.remove(&ingredient);
+        }
+        ingredient_allergens.insert(ingredient, allergen);
+    }
+
+    ingredient_allergens
+}
+
+pub fn part1(foods: &[Food]) -> usize {
+    let ingredient_allergens = calculate_ingredient_allergens(foods);
+
+    foods.iter().flat_map(|food| food.ingredients.iter())
+        .filter(|ingredient| !ingredient_allergens.contains_key(ingredient.as_str()))
+        .count()
+}
+
+pub fn part2(foods: &[Food]) -> String {
+    let mut ingredient_allergens: Vec<_> = calculate_ingredient_allergens(foods).into_iter().collect();
+    ingredient_allergens.sort_by_key(|(_,allergen)| allergen.clone());
+
+    let mut ret = String::new();
+    for (i, ingredient) in ingredient_allergens.into_iter().map(|(ingredient,_)| ingredient).enumerate() {
+        if i != 0 { ret.push(',') }
+        write!(ret, "{}", ingredient).unwrap();
+    }
+    ret
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Food>;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "mxmxvkd kfcds sqjhc nhms (contains dairy, fish)
+trh fvjkl sbzzf mxmxvkd (contains dairy)
+sqjhc fvjkl (contains soy)
+sqjhc mxmxvkd sbzzf (contains fish)";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(5, part1(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!("mxmxvkd,sqjhc,fvjkl".to_string(), part2(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("sqjhc fvjkl (contains soy)\nsqjhc fvjkl (contains soy").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 26));
+        assert_eq!(parse("a b (contains c)\na b").err().unwrap().line, 2);
+    }
+}
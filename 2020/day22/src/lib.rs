@@ -0,0 +1,174 @@
+use std::collections::{HashSet, VecDeque};
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+type Card = u8;
+pub type Deck = VecDeque<Card>;
+
+#[derive(Copy, Clone)]
+enum Winner {
+    Player1, Player2
+}
+
+pub fn parse(puzzle_input: &str) -> Result<[Deck; 2], ParseError> {
+    let sections = parsing::sections(puzzle_input);
+    if sections.len() != 2 {
+        let line = sections.get(2).map_or_else(
+            || Line { number: parsing::lines(puzzle_input).last().map_or(1, |line| line.number + 1), text: "" },
+            |section| section[0]);
+        return Err(line.error(1, "expected two players' decks"));
+    }
+
+    let mut seen = HashSet::new();
+    let mut decks = [Deck::new(), Deck::new()];
+    for (i, (lines, deck)) in sections.iter().zip(&mut decks).enumerate() {
+        let header = format!("Player {}:", i + 1);
+        if lines[0].text != header {
+            return Err(lines[0].error(1, format!("expected {:?}", header)));
+        }
+        for line in &lines[1..] {
+            let card = line.parse()?;
+            if !seen.insert(card) {
+                return Err(line.error(1, format!("card {} is in the decks twice", card)));
+            }
+            deck.push_back(card);
+        }
+    }
+    Ok(decks)
+}
+
+fn score(deck1: &Deck, deck2: &Deck) -> u64 {
+    fn score_deck(deck: &Deck) -> u64 {
+        deck.iter().rev().enumerate().map(|(i, c)| (1 + i as u64) * *c as u64).sum()
+    }
+    // NOTE - loser's score is 0
+    score_deck(deck1) + score_deck(deck2)
+}
+
+pub fn part1(decks: &[Deck; 2]) -> u64 {
+    let [mut deck1, mut deck2] = decks.clone();
+    loop {
+        if deck1.is_empty() || deck2.is_empty() { break }
+        let card1 = deck1.pop_front().unwrap();
+        let card2 = deck2.pop_front().unwrap();
+        if card1 > card2 {
+            deck1.push_back(card1);
+            deck1.push_back(card2);
+        } else {
+            deck2.push_back(card2);
+            deck2.push_back(card1);
+        }
+    }
+    score(&deck1, &deck2)
+}
+
+fn recursive_combat(deck1: &mut Deck, deck2: &mut Deck) -> Winner {
+    let mut seen = HashSet::new();
+    loop {
+        if deck1.is_empty() {
+            return Winner::Player2;
+        } else if deck2.is_empty() {
+            return Winner::Player1;
+        } else if !seen.insert((deck1.clone(), deck2.clone())) {
+            return Winner::Player1;
+        }
+        let card1 = deck1.pop_front().unwrap();
+        let card2 = deck2.pop_front().unwrap();
+        let winner = if deck1.len() >= card1 as usize && deck2.len() >= card2 as usize {
+            let mut subdeck1 = deck1.iter().take(card1 as usize).cloned().collect();
+            let mut subdeck2 = deck2.iter().take(card2 as usize).cloned().collect();
+            recursive_combat(&mut subdeck1, &mut subdeck2)
+        } else if card1 > card2 {
+            Winner::Player1
+        } else {
+            Winner::Player2
+        };
+
+        match winner {
+            Winner::Player1 => {
+                deck1.push_back(card1);
+                deck1.push_back(card2);
+            },
+            Winner::Player2 => {
+                deck2.push_back(card2);
+                deck2.push_back(card1);
+            }
+        }
+    }
+}
+
+pub fn part2(decks: &[Deck; 2]) -> u64 {
+    let [mut deck1, mut deck2] = decks.clone();
+    recursive_combat(&mut deck1, &mut deck2);
+    score(&deck1, &deck2)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = [Deck; 2];
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "Player 1:
+9
+2
+6
+3
+1
+
+Player 2:
+5
+8
+4
+7
+10";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(306, part1(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(291, part2(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_part2_non_infinite() {
+        part2(&parse("Player 1:
+43
+19
+
+Player 2:
+2
+29
+14").unwrap());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse(&EX.replace("\n10", "\n9")).err().unwrap();
+        assert_eq!((err.line, err.column), (13, 1));
+        let err = parse(&EX.replace("Player 2", "Player 3")).err().unwrap();
+        assert_eq!((err.line, err.column), (8, 1));
+    }
+}
@@ -0,0 +1,115 @@
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse(puzzle_input: &str) -> Result<(Vec<usize>, usize), ParseError> {
+    let line = parsing::single_line(puzzle_input)?;
+    let ord_cups: Vec<usize> = line.map_chars(|ch| Some(ch.to_digit(10)? as usize).filter(|&n| n > 0).map(|n| n - 1))?;
+    if let Some(cup) = (0..ord_cups.len()).find(|cup| !ord_cups.contains(cup)) {
+        return Err(line.error(1, format!("expected cup {} in the circle", cup + 1)));
+    }
+    let mut cups = vec![0usize; ord_cups.len()];
+    for i in 1..ord_cups.len() {
+        cups[ord_cups[i-1]] = ord_cups[i];
+    }
+    cups[ord_cups[ord_cups.len()-1]] = ord_cups[0];
+    Ok((cups, ord_cups[0]))
+}
+
+fn cycle(cups: &mut [usize], curr: usize) -> usize {
+    let mut picked_cups = [0usize; 3];
+    let mut next = cups[curr];
+    for i in 0..3 {
+        picked_cups[i] = next;
+        next = cups[next];
+    }
+    cups[curr] = next;
+
+    let mut dest = curr;
+    loop {
+        if dest == 0 { dest = cups.len() }
+        dest -= 1;
+        if !picked_cups.contains(&dest) { break }
+    }
+    cups[picked_cups[2]] = cups[dest];
+    cups[dest] = picked_cups[0];
+    next
+}
+
+pub fn part1(cups: &[usize], first: usize) -> String {
+    let mut cups = cups.to_vec();
+    let mut curr = first;
+    for _ in 0..100 {
+        curr = cycle(&mut cups, curr);
+    }
+    let mut ret = String::new();
+    let mut next = cups[0];
+    while next != 0 {
+        ret.push_str(&format!("{}", next + 1));
+        next = cups[next];
+    }
+    ret
+}
+
+pub fn part2(cups: &[usize], first: usize) -> u64 {
+    let mut cups = cups.to_vec();
+    let initial_len = cups.len();
+    let last = cups.iter().position(|&v| v == first).unwrap();
+    cups.resize(1_000_000, usize::MAX);
+    cups[last] = initial_len;
+    for i in initial_len+1..cups.len() {
+        cups[i-1] = i;
+    }
+    cups[999_999] = first;
+
+    let mut curr = first;
+    for _ in 0..10_000_000 {
+        curr = cycle(&mut cups, curr);
+    }
+    let v1 = cups[0];
+    let v2 = cups[v1];
+    (v1 as u64 + 1) * (v2 as u64 + 1)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<usize>, usize);
+    type Answer1 = String;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        part1(&input.0, input.1)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(&input.0, input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let (cups, first) = parse("389125467").unwrap();
+        assert_eq!("67384529".to_string(), part1(&cups, first));
+    }
+
+    #[test]
+    fn test_part2() {
+        let (cups, first) = parse("389125467").unwrap();
+        assert_eq!(149245887792, part2(&cups, first));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("3891x5467").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 5));
+        assert_eq!(parse("389").err().unwrap().message, "expected cup 1 in the circle");
+    }
+}
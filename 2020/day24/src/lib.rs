@@ -0,0 +1,202 @@
+use std::collections::HashSet;
+
+use parsing::ParseError;
+use solution::Solution;
+
+#[derive(Copy, Clone)]
+pub enum Dir {
+    E, SE, SW, W, NW, NE
+}
+
+// Axial coordinates, e.g. in the points around the origin:
+//   x-coord     y-coord
+//    0   1      -1  -1
+// -1   0   1   0   0   0
+//   -1   0       1   1
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+struct Coord {
+    x: i32,
+    y: i32
+}
+
+impl Dir {
+    fn to_coord(&self) -> Coord {
+        match self {
+            Dir::E  => Coord { x:  1, y:  0 },
+            Dir::SE => Coord { x:  0, y:  1 },
+            Dir::NE => Coord { x:  1, y: -1 },
+            Dir::W  => Coord { x: -1, y:  0 },
+            Dir::SW => Coord { x: -1, y:  1 },
+            Dir::NW => Coord { x:  0, y: -1 },
+        }
+    }
+}
+
+impl Coord {
+    fn origin() -> Coord {
+        Coord { x: 0, y: 0 }
+    }
+}
+
+impl std::ops::Add for Coord {
+    type Output = Coord;
+    fn add(self, other: Coord) -> Coord {
+        Coord { x: self.x + other.x, y: self.y + other.y }
+    }
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<Vec<Dir>>, ParseError> {
+    parsing::parse_lines(puzzle_input, |line| {
+        let mut dirs = Vec::new();
+        let mut carry = None;
+        for (i, ch) in line.text.chars().enumerate() {
+            let (dir, nextcarry) = match (ch, carry) {
+                ('e', None)      => (Some(Dir::E),  None),
+                ('e', Some('s')) => (Some(Dir::SE), None),
+                ('e', Some('n')) => (Some(Dir::NE), None),
+                ('w', None)      => (Some(Dir::W),  None),
+                ('w', Some('s')) => (Some(Dir::SW), None),
+                ('w', Some('n')) => (Some(Dir::NW), None),
+                ('s', None)      => (None, Some('s')),
+                ('n', None)      => (None, Some('n')),
+                _ => return Err(line.error(i + 1, format!("unexpected {:?}", ch)))
+            };
+            dir.map(|d| dirs.push(d));
+            carry = nextcarry;
+        }
+        if carry.is_some() {
+            return Err(line.error(line.text.chars().count() + 1, "expected \"e\" or \"w\""));
+        }
+        Ok(dirs)
+    })
+}
+
+fn flip(pos: Coord, black_tiles: &mut HashSet<Coord>) {
+    if black_tiles.contains(&pos) {
+        black_tiles.remove(&pos);
+    } else {
+        black_tiles.insert(pos);
+    }
+}
+
+fn initial_black_tiles(paths: &[Vec<Dir>]) -> HashSet<Coord> {
+    let mut black_tiles = HashSet::new();
+    for path in paths {
+        let mut pos = Coord::origin();
+        for dir in path {
+            pos = pos + dir.to_coord();
+        }
+        flip(pos, &mut black_tiles);
+    }
+    black_tiles
+}
+
+fn adjacent_tiles(pos: Coord) -> Vec<Coord> {
+    [Dir::E, Dir::SE, Dir::SW, Dir::W, Dir::NW, Dir::NE].iter()
+        .map(|dir| pos + dir.to_coord()).collect()
+}
+
+fn count_adjacent_black_tiles(pos: Coord, black_tiles: &HashSet<Coord>) -> usize {
+    adjacent_tiles(pos).into_iter().filter(|adj| black_tiles.contains(adj)).count()
+}
+
+fn cycle(prev: HashSet<Coord>) -> HashSet<Coord> {
+    let mut interesting_tiles = HashSet::new();
+    for &pos in &prev {
+        for adj in adjacent_tiles(pos) {
+            interesting_tiles.insert(adj);
+        }
+        interesting_tiles.insert(pos);
+    }
+    let mut next = HashSet::new();
+    for pos in interesting_tiles {
+        let was_black = prev.contains(&pos);
+        let adj_black = count_adjacent_black_tiles(pos, &prev);
+        let will_black = match (was_black, adj_black) {
+            (true, 1..=2) => true,
+            (true, _) => false,
+            (false, 2) => true,
+            (false, _) => false
+        };
+        if will_black { next.insert(pos); }
+    }
+
+    next
+}
+
+pub fn part1(paths: &[Vec<Dir>]) -> usize {
+    let black_tiles = initial_black_tiles(paths);
+    black_tiles.len()
+}
+
+pub fn part2(paths: &[Vec<Dir>]) -> usize {
+    let mut black_tiles = initial_black_tiles(paths);
+    for _ in 0..100 {
+        black_tiles = cycle(black_tiles);
+    }
+    black_tiles.len()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<Dir>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "sesenwnenenewseeswwswswwnenewsewsw
+neeenesenwnwwswnenewnwwsewnenwseswesw
+seswneswswsenwwnwse
+nwnwneseeswswnenewneswwnewseswneseene
+swweswneswnenwsewnwneneseenw
+eesenwseswswnenwswnwnwsewwnwsene
+sewnenenenesenwsewnenwwwse
+wenwwweseeeweswwwnwwe
+wsweesenenewnwwnwsenewsenwwsesesenwne
+neeswseenwwswnwswswnw
+nenwswwsewswnenenewsenwsenwnesesenew
+enewnwewneswsewnwswenweswnenwsenwsw
+sweneswneswneneenwnewenewwneswswnese
+swwesenesewenwneswnwwneseswwne
+enesenwswwswneneswsenwnewswseenwsese
+wnwnesenesenenwwnenwsewesewsesesew
+nenewswnwewswnenesenwnesewesw
+eneswnwswnwsenenwnwnwwseeswneewsenese
+neswnwewnwnwseenwseesewsenwsweewe
+wseweeenwnesenwwwswnew";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(10, part1(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(2208, part2(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("esew\nnwwswee\nnwwsx").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 5));
+        let err = parse("esew\nnwwsween").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 9));
+    }
+}
@@ -0,0 +1,82 @@
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+const MODULUS: u64 = 20201227;
+
+pub fn parse(puzzle_input: &str) -> Result<[u64; 2], ParseError> {
+    let lines = parsing::lines(puzzle_input);
+    if lines.len() != 2 {
+        let line = lines.get(2).copied()
+            .unwrap_or(Line { number: lines.last().map_or(1, |line| line.number + 1), text: "" });
+        return Err(line.error(1, "expected the card's and the door's public keys"));
+    }
+    let mut keys = [0; 2];
+    for (line, key) in lines.iter().zip(&mut keys) {
+        *key = line.parse()?;
+        // Otherwise no loop size gives it
+        if *key == 0 || *key >= MODULUS {
+            return Err(line.error(1, format!("expected a public key from 1 to {}", MODULUS - 1)));
+        }
+    }
+    Ok(keys)
+}
+
+fn transform(subject_num: u64, loop_size: u64) -> u64 {
+    let mut val = 1;
+    for _ in 0..loop_size {
+        val = (val * subject_num) % MODULUS;
+    }
+    val
+}
+
+fn invert_loop_size(pub_key: u64) -> u64 {
+    let mut val = 1;
+    for iters in 0.. {
+        if val == pub_key { return iters }
+        val = (val * 7) % MODULUS;
+    }
+    unreachable!()
+}
+
+pub fn part1(card_pub_key: u64, door_pub_key: u64) -> u64 {
+    let card_loop_size = invert_loop_size(card_pub_key);
+    let door_loop_size = invert_loop_size(door_pub_key);
+    let encr_key = transform(door_pub_key, card_loop_size);
+    debug_assert_eq!(encr_key, transform(card_pub_key, door_loop_size));
+    encr_key
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = [u64; 2];
+    type Answer1 = u64;
+    type Answer2 = &'static str;
+
+    const PARTS: usize = 1;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input[0], input[1])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(14897079, part1(5764801, 17807724));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(parse("5764801\n17807724\n").unwrap(), [5764801, 17807724]);
+        assert_eq!(parse("5764801\n20201227").err().unwrap().line, 2);
+        assert_eq!(parse("5764801").err().unwrap().line, 2);
+    }
+}
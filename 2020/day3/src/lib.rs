@@ -0,0 +1,92 @@
+use parsing::ParseError;
+use solution::Solution;
+
+pub struct Map {
+    width: usize,
+    height: usize,
+    trees: Vec<bool>
+}
+
+impl Map {
+    fn parse(puzzle_input: &str) -> Result<Map, ParseError> {
+        let rows = parsing::grid(&parsing::lines(puzzle_input), |ch| {
+            match ch {
+                '.' => Some(false),
+                '#' => Some(true),
+                _ => None
+            }
+        })?;
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        let trees = rows.into_iter().flatten().collect();
+        Ok(Map { width: width, height: height, trees: trees })
+    }
+
+    fn tree_at(&self, x: usize, y: usize) -> bool {
+        if y >= self.height { panic!() }
+        self.trees[(y * self.width) + (x % self.width)]
+    }
+
+    fn trees_on_slope(&self, x_offset: usize, y_offset: usize) -> usize {
+        (0 .. (self.height / y_offset)).filter(|&i| self.tree_at(i*x_offset, i*y_offset)).count()
+    }
+}
+
+pub fn part1(map: &Map) -> usize {
+    map.trees_on_slope(3, 1)
+}
+
+pub fn part2(map: &Map) -> usize {
+    map.trees_on_slope(1, 1) *
+        map.trees_on_slope(3, 1) *
+        map.trees_on_slope(5, 1) *
+        map.trees_on_slope(7, 1) *
+        map.trees_on_slope(1, 2)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        Map::parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(7, part1(&Map::parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(336, part2(&Map::parse(EX).unwrap()));
+    }
+}
@@ -0,0 +1,109 @@
+use parsing::ParseError;
+use solution::Solution;
+
+struct Seat {
+    row: usize,
+    col: usize
+}
+
+const TOTAL_SEATS: usize = 128*8;
+
+impl Seat {
+    fn parse(s: &str) -> Option<Seat> {
+        let mut row = 0;
+        let mut col = 0;
+        for ch in s.chars() {
+            match ch {
+                'F' => row *= 2,
+                'B' => row = row*2 + 1,
+                'L' => col *= 2,
+                'R' => col = col*2 + 1,
+                _ => return None
+            }
+        }
+        if row >= 128 || col >= 8 { return None }
+        Some(Seat { row: row, col: col })
+    }
+
+    fn id(&self) -> usize {
+        self.row*8 + self.col
+    }
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<usize>, ParseError> {
+    parsing::parse_lines(puzzle_input, |line| {
+        Seat::parse(line.text).map(|seat| seat.id())
+            .ok_or_else(|| line.error(1, "expected a seat like \"FBFBBFFRLR\""))
+    })
+}
+
+pub fn part1(seat_ids: &Vec<usize>) -> usize {
+    *seat_ids.iter().max().unwrap()
+}
+
+pub fn part2(seat_ids: &Vec<usize>) -> usize {
+    let mut filled_seats: [bool; TOTAL_SEATS] = [false; TOTAL_SEATS];
+    for id in seat_ids {
+        filled_seats[*id] = true;
+    }
+    for id in 1..TOTAL_SEATS {
+        if !filled_seats[id] && filled_seats[id+1] && filled_seats[id-1] {
+            return id
+        }
+    }
+    panic!()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seat_parsing() {
+        let seat = Seat::parse("FBFBBFFRLR").unwrap();
+        assert_eq!(44, seat.row);
+        assert_eq!(5, seat.col);
+        assert_eq!(357, seat.id());
+
+        let seat = Seat::parse("BFFFBBFRRR").unwrap();
+        assert_eq!(70, seat.row);
+        assert_eq!(7, seat.col);
+        assert_eq!(567, seat.id());
+
+        let seat = Seat::parse("FFFBBBFRRR").unwrap();
+        assert_eq!(14, seat.row);
+        assert_eq!(7, seat.col);
+        assert_eq!(119, seat.id());
+
+        let seat = Seat::parse("BBFFBBFRLL").unwrap();
+        assert_eq!(102, seat.row);
+        assert_eq!(4, seat.col);
+        assert_eq!(820, seat.id());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(parse("FBFBBFFRLR\nFBFBBFFRLRL").err().unwrap().line, 2);
+        assert_eq!(parse("FBFBBFFRLR\nFBFBXFFRLR").err().unwrap().line, 2);
+    }
+}
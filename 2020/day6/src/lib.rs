@@ -0,0 +1,87 @@
+use std::collections::HashSet;
+
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<Vec<HashSet<char>>>, ParseError> {
+    parsing::sections(puzzle_input).into_iter().map(|group| {
+        group.iter().map(|person| {
+            Ok(person.map_chars(|ch| ch.is_ascii_lowercase().then_some(ch))?.into_iter().collect())
+        }).collect()
+    }).collect()
+}
+
+pub fn part1(groups: &Vec<Vec<HashSet<char>>>) -> usize {
+    groups.iter().map(|group| {
+        let mut union = HashSet::<char>::new();
+        for person in group {
+            union.extend(person.iter());
+        }
+        union.len()
+    }).sum()
+}
+
+pub fn part2(groups: &Vec<Vec<HashSet<char>>>) -> usize {
+    groups.iter().map(|group| {
+        let first = &group[0];
+        let rest = &group[1..];
+        first.iter().filter(|ch| rest.iter().all(|p| p.contains(ch))).count()
+    }).sum()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<HashSet<char>>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "abc
+
+a
+b
+c
+
+ab
+ac
+
+a
+a
+a
+a
+
+b";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(11, part1(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(6, part2(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("abc\n\na\nb C").err().unwrap();
+        assert_eq!((err.line, err.column), (4, 2));
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use lazy_static::lazy_static;
+use parsing::ParseError;
+use regex::Regex;
+use solution::Solution;
+
+pub type Rules = HashMap<String, Vec<(usize, String)>>;
+
+pub fn parse(puzzle_input: &str) -> Result<Rules, ParseError> {
+    lazy_static! {
+        static ref OUTER_RE: Regex = Regex::new("^([a-z]+ [a-z]+) bags contain (.*)\\.$").unwrap();
+        static ref INNER_RE: Regex = Regex::new("^(\\d+) ([a-z]+ [a-z]+) bags?$").unwrap();
+    }
+    parsing::lines(puzzle_input).into_iter().map(|line| {
+        let cap = OUTER_RE.captures(line.text)
+            .ok_or_else(|| line.error(1, "expected a rule like \"light red bags contain 1 bright white bag.\""))?;
+        let outer_bag = cap[1].to_string();
+        let mut inner_bags = Vec::new();
+        for inner in line.field(&cap[2]).split(", ") {
+            if inner.text == "no other bags" { break }
+            let cap = INNER_RE.captures(inner.text)
+                .ok_or_else(|| inner.error("expected a count of bags like \"2 muted yellow bags\""))?;
+            inner_bags.push((line.field(&cap[1]).parse()?, cap[2].to_string()));
+        }
+        Ok((outer_bag, inner_bags))
+    }).collect()
+}
+
+pub fn part1(rules: &Rules) -> usize {
+    fn contained_within(outer_bag: &str, inner_bag: &str, rules: &Rules) -> bool {
+        for (_, bag) in rules.get(outer_bag).unwrap() {
+            if bag == inner_bag || contained_within(bag, inner_bag, rules) {
+                return true
+            }
+        }
+        false
+    }
+
+    rules.keys()
+        .filter(|outer_bag| contained_within(outer_bag, "shiny gold", rules))
+        .count()
+}
+
+pub fn part2(rules: &Rules) -> usize {
+    fn num_contained(outer_bag: &str, rules: &Rules) -> usize {
+        let mut sum = 0;
+        for (cnt, bag) in rules.get(outer_bag).unwrap() {
+            sum += cnt * (1 + num_contained(bag, rules));
+        }
+        sum
+    }
+
+    num_contained("shiny gold", rules)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Rules;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX1: &str = "light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.";
+
+    const EX2: &str = "shiny gold bags contain 2 dark red bags.
+dark red bags contain 2 dark orange bags.
+dark orange bags contain 2 dark yellow bags.
+dark yellow bags contain 2 dark green bags.
+dark green bags contain 2 dark blue bags.
+dark blue bags contain 2 dark violet bags.
+dark violet bags contain no other bags.";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(4, part1(&parse(EX1).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(32, part2(&parse(EX1).unwrap()));
+        assert_eq!(126, part2(&parse(EX2).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("faded blue bags contain no other bags.\nlight red bags contain 1 bright white bag, 2 muted yellow.").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 44));
+    }
+}
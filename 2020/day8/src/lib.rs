@@ -0,0 +1,132 @@
+use parsing::ParseError;
+use register_machine::{Flow, Machine, Status};
+use solution::Solution;
+
+#[derive(Copy, Clone)]
+enum Op {
+    Acc, Jmp, Nop
+}
+#[derive(Copy, Clone)]
+pub struct Instruction {
+    op: Op,
+    arg: i32
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parsing::parse_lines(puzzle_input, |line| {
+        let (op, arg) = match line.fields()[..] {
+            [op, arg] => (op, arg),
+            _ => return Err(line.error(1, "expected an operation and argument like \"jmp +4\""))
+        };
+        let op = match op.text {
+            "acc" => Op::Acc,
+            "jmp" => Op::Jmp,
+            "nop" => Op::Nop,
+            _ => return Err(op.error("expected acc, jmp or nop"))
+        };
+        Ok(Instruction { op: op, arg: arg.parse()? })
+    })
+}
+
+enum ExecutionResult {
+    Terminated(i32),
+    InfiniteLoop(i32)
+}
+
+// The only register is the accumulator
+impl register_machine::Instruction<i32> for Instruction {
+    fn execute(&self, _pc: usize, acc: &mut i32, _io: &mut ()) -> Flow {
+        match self.op {
+            Op::Acc => { *acc += self.arg; Flow::Next },
+            Op::Jmp => Flow::JumpRelative(self.arg as i64),
+            Op::Nop => Flow::Next
+        }
+    }
+}
+
+fn execute(instructions: &Vec<Instruction>) -> ExecutionResult {
+    let mut machine = Machine::new(0);
+    match machine.run_until_loop(instructions, &mut ()) {
+        Status::Looped => ExecutionResult::InfiniteLoop(machine.registers),
+        Status::Halted if machine.pc as usize == instructions.len() => ExecutionResult::Terminated(machine.registers),
+        _ => panic!()
+    }
+}
+
+pub fn part1(instructions: &Vec<Instruction>) -> i32 {
+    match execute(instructions) {
+        ExecutionResult::Terminated(_) => panic!(),
+        ExecutionResult::InfiniteLoop(acc) => acc,
+    }
+}
+
+pub fn part2(orig_instructions: &Vec<Instruction>) -> i32 {
+    let mut instructions = orig_instructions.clone();
+    for i in 0..orig_instructions.len() {
+        instructions[i] = match orig_instructions[i] {
+            Instruction { op: Op::Jmp, arg } => Instruction { op: Op::Nop, arg: arg },
+            Instruction { op: Op::Nop, arg } => Instruction { op: Op::Jmp, arg: arg },
+            _ => continue
+        };
+
+        if let ExecutionResult::Terminated(acc) = execute(&instructions) {
+            return acc
+        }
+
+        instructions[i] = orig_instructions[i];
+    }
+    panic!()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Instruction>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> i32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> i32 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(5, part1(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(8, part2(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("nop +0\nadd +1").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("nop +0\nacc +x").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 5));
+    }
+}
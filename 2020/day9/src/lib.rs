@@ -0,0 +1,117 @@
+use std::collections::HashSet;
+
+use params::Params;
+use parsing::ParseError;
+use solution::Solution;
+
+// The real input's preamble, which the example shortens
+pub const PREAMBLE_LEN: usize = 25;
+
+pub struct Xmas {
+    pub nums: Vec<u64>,
+    pub preamble_len: usize,
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<u64>, ParseError> {
+    parsing::parse_lines(puzzle_input, |line| line.parse())
+}
+
+fn pair_sums_to(preamble_nums: &HashSet<u64>, target: u64) -> bool {
+    for &v1 in preamble_nums {
+        if target != v1*2 && v1 < target && preamble_nums.contains(&(target - v1)) {
+            return true
+        }
+    }
+    false
+}
+
+pub fn part1(nums: &[u64], preamble_len: usize) -> u64 {
+    let mut preamble_nums: HashSet<u64> =
+        nums[0..preamble_len].iter().cloned().collect();
+    for i in preamble_len .. nums.len() {
+        if !pair_sums_to(&preamble_nums, nums[i]) {
+            return nums[i]
+        }
+        preamble_nums.remove(&nums[i-preamble_len]);
+        preamble_nums.insert(nums[i]);
+    }
+    panic!()
+}
+
+pub fn part2(nums: &[u64], target: u64) -> u64 {
+    for i in 0..nums.len() {
+        let mut sum = nums[i];
+        for j in 1..(nums.len()-i) {
+            sum += nums[i+j];
+            if sum == target {
+                return nums[i..(i+j+1)].iter().min().unwrap() +
+                    nums[i..(i+j+1)].iter().max().unwrap();
+            } else if sum > target {
+                break
+            }
+        }
+    }
+    panic!()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Xmas;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        let nums = parse(puzzle_input).map_err(|e| e.to_string())?;
+        Ok(Xmas { nums, preamble_len: PREAMBLE_LEN })
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(&input.nums, input.preamble_len)
+    }
+
+    // Part 2 looks for the numbers summing to part 1's answer
+    fn part2(input: &Self::Input) -> u64 {
+        part2(&input.nums, part1(&input.nums, input.preamble_len))
+    }
+
+    fn configure(input: &mut Self::Input, params: &Params) {
+        input.preamble_len = params.get("preamble_len", PREAMBLE_LEN);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "35
+20
+15
+25
+47
+40
+62
+55
+65
+95
+102
+117
+150
+182
+127
+219
+299
+277
+309
+576";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(127, part1(&parse(EX).unwrap(), 5));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(62, part2(&parse(EX).unwrap(), 127));
+    }
+}
@@ -0,0 +1,81 @@
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<u32>, ParseError> {
+    parsing::parse_lines(puzzle_input, |line| line.parse())
+}
+
+pub fn part1(measurements: &[u32]) -> usize {
+    let mut cnt = 0;
+    for i in 1..measurements.len() {
+        if measurements[i-1] < measurements[i] {
+            cnt += 1;
+        }
+    }
+    cnt
+}
+
+pub fn part2(measurements: &[u32]) -> usize {
+    let mut cnt = 0;
+    let mut sum = measurements[0] + measurements[1] + measurements[2];
+    for i in 3..measurements.len() {
+        let prior_sum = sum;
+        sum = sum - measurements[i - 3] + measurements[i];
+        if prior_sum < sum {
+            cnt += 1;
+        }
+    }
+    cnt
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<u32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "199
+200
+208
+210
+200
+207
+240
+269
+260
+263";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(7, part1(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(5, part2(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("199\n200\n2O8").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 1));
+    }
+}
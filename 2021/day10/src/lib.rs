@@ -0,0 +1,124 @@
+use parsing::ParseError;
+use solution::Solution;
+
+// An incomplete line will return the autocomplete characters as Ok(str), a
+// corrupted line will return the first incorrect char as Err(char)
+fn detect_corruption(line: &str) -> Result<String, char> {
+    let mut stack = Vec::new();
+    for ch in line.chars() {
+        match ch {
+            '(' => stack.push(')'),
+            '[' => stack.push(']'),
+            '{' => stack.push('}'),
+            '<' => stack.push('>'),
+            ')' | ']' | '}' | '>' => {
+                if stack.is_empty() || *stack.last().unwrap() != ch {
+                    return Err(ch);
+                } else {
+                    stack.pop();
+                }
+            },
+            _ => unreachable!()
+        }
+    }
+    let mut ret = String::new();
+    while let Some(ch) = stack.pop() {
+        ret.push(ch);
+    }
+    Ok(ret)
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<String>, ParseError> {
+    parsing::parse_lines(puzzle_input, |line| {
+        line.map_chars(|ch| "()[]{}<>".contains(ch).then_some(ch))?;
+        Ok(line.text.to_string())
+    })
+}
+
+pub fn part1(lines: &[String]) -> u32 {
+    lines.iter()
+        .map(|line| detect_corruption(line))
+        .filter_map(|res| match res { Ok(_) => None, Err(ch) => Some(ch) })
+        .map(|ch| match ch {
+            ')' => 3,
+            ']' => 57,
+            '}' => 1197,
+            '>' => 25137,
+            _ => panic!()
+        })
+        .sum()
+}
+
+pub fn part2(lines: &[String]) -> u64 {
+    let mut scores: Vec<u64> = lines.iter()
+        .map(|line| detect_corruption(line))
+        .filter_map(|res| match res { Ok(s) => Some(s), Err(_) => None })
+        .map(|s| {
+            let mut score = 0;
+            for ch in s.chars() {
+                score = score * 5 + match ch {
+                    ')' => 1,
+                    ']' => 2,
+                    '}' => 3,
+                    '>' => 4,
+                    _ => panic!()
+                };
+            }
+            score
+        })
+        .collect();
+    scores.sort_unstable();
+    scores[scores.len() / 2]
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(26397, part1(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(288957, part2(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("[({(<(())[]>[[{[]{<()<>>\n[(()[<>])]({[<{<<[]>>x").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 22));
+    }
+}
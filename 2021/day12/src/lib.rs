@@ -0,0 +1,192 @@
+use std::collections::{BTreeSet, HashSet, VecDeque};
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+pub fn parse(puzzle_input: &str) -> Result<(Vec<String>, Vec<Vec<usize>>), ParseError> {
+    let edges = parsing::parse_lines(puzzle_input, |line| {
+        let (name1, name2) = line.as_field().split_once("-")?;
+        for name in [name1, name2] {
+            if name.text.is_empty() || !name.text.chars().all(|ch| ch.is_ascii_alphabetic()) {
+                return Err(name.error("expected a cave name"));
+            }
+        }
+        // Otherwise there'd be infinitely many paths going back and forth between them
+        if name1.text.chars().all(|ch| ch.is_ascii_uppercase()) && name2.text.chars().all(|ch| ch.is_ascii_uppercase()) {
+            return Err(line.error(1, "expected a small cave"));
+        }
+        Ok((name1.text.to_string(), name2.text.to_string()))
+    })?;
+    let cave_names: BTreeSet<_> = edges.iter().flat_map(|(name1, name2)| [name1.clone(), name2.clone()]).collect();
+    let cave_names: Vec<String> = cave_names.into_iter().collect();
+    for name in ["start", "end"] {
+        if cave_names.binary_search(&name.to_string()).is_err() {
+            let line = Line { number: parsing::lines(puzzle_input).last().map_or(1, |line| line.number + 1), text: "" };
+            return Err(line.error(1, format!("expected a path to {:?}", name)));
+        }
+    }
+    let mut adj_list = vec![Vec::new(); cave_names.len()];
+    for (name1, name2) in &edges {
+        let name1_idx = cave_names.binary_search(name1).unwrap();
+        let name2_idx = cave_names.binary_search(name2).unwrap();
+        adj_list[name1_idx].push(name2_idx);
+        adj_list[name2_idx].push(name1_idx);
+    }
+    Ok((cave_names, adj_list))
+}
+
+pub fn part1(cave_names: &[String], adj_list: &[Vec<usize>]) -> usize {
+    let start_room_id = cave_names.binary_search(&"start".to_string()).unwrap();
+    let end_room_id = cave_names.binary_search(&"end".to_string()).unwrap();
+    let small_rooms: HashSet<usize> = cave_names.iter().enumerate()
+        .filter_map(|(i, name)| if name.chars().next().unwrap().is_lowercase() { Some(i) } else { None })
+        .collect();
+
+    let mut all_paths = HashSet::new();
+    let mut queue = VecDeque::new();
+    let start_path = vec![start_room_id];
+    let start_path_rooms = start_path.iter().cloned().collect::<HashSet<_>>();
+    queue.push_back((start_path, start_path_rooms));
+    while let Some((path, path_rooms)) = queue.pop_front() {
+        let curr_room_id = *path.last().unwrap();
+        if curr_room_id == end_room_id {
+            all_paths.insert(path);
+            continue;
+        }
+        for &next_room_id in adj_list[curr_room_id].iter() {
+            if !small_rooms.contains(&next_room_id) || !path_rooms.contains(&next_room_id) {
+                let mut next_path = path.clone();
+                let mut next_path_rooms = path_rooms.clone();
+                next_path.push(next_room_id);
+                next_path_rooms.insert(next_room_id);
+                queue.push_back((next_path, next_path_rooms));
+            }
+        }
+    }
+    all_paths.len()
+}
+
+pub fn part2(cave_names: &[String], adj_list: &[Vec<usize>]) -> usize {
+    let start_room_id = cave_names.binary_search(&"start".to_string()).unwrap();
+    let end_room_id = cave_names.binary_search(&"end".to_string()).unwrap();
+    let small_rooms: HashSet<usize> = cave_names.iter().enumerate()
+        .filter_map(|(i, name)| if name.chars().next().unwrap().is_lowercase() { Some(i) } else { None })
+        .collect();
+
+    let mut all_paths = HashSet::new();
+    let mut queue = VecDeque::new();
+    let start_path = vec![start_room_id];
+    let start_path_rooms = start_path.iter().cloned().collect::<HashSet<_>>();
+    queue.push_back((start_path, start_path_rooms, false));
+    while let Some((path, path_rooms, small_revisited)) = queue.pop_front() {
+        let curr_room_id = *path.last().unwrap();
+        if curr_room_id == end_room_id {
+            all_paths.insert(path);
+            continue;
+        }
+        for &next_room_id in adj_list[curr_room_id].iter() {
+            if next_room_id == start_room_id { continue }
+            if !small_rooms.contains(&next_room_id) || !path_rooms.contains(&next_room_id) || !small_revisited {
+                let mut next_path = path.clone();
+                let mut next_path_rooms = path_rooms.clone();
+                next_path.push(next_room_id);
+                next_path_rooms.insert(next_room_id);
+                let small_revisited = small_revisited || (small_rooms.contains(&next_room_id) && path_rooms.contains(&next_room_id));
+                queue.push_back((next_path, next_path_rooms, small_revisited));
+            }
+        }
+    }
+    all_paths.len()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<String>, Vec<Vec<usize>>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(&input.0, &input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX1: &str = "start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end";
+
+    const EX2: &str = "dc-end
+HN-start
+start-kj
+dc-start
+dc-HN
+LN-dc
+HN-end
+kj-sa
+kj-HN
+kj-dc";
+
+    const EX3: &str = "fs-end
+he-DX
+fs-he
+start-DX
+pj-DX
+end-zg
+zg-sl
+zg-pj
+pj-he
+RW-he
+fs-DX
+pj-RW
+zg-RW
+start-pj
+he-WI
+zg-he
+pj-fs
+start-RW";
+
+    #[test]
+    fn test_part1() {
+        let (cave_names, adj_list) = parse(EX1).unwrap();
+        assert_eq!(10, part1(&cave_names, &adj_list));
+        let (cave_names, adj_list) = parse(EX2).unwrap();
+        assert_eq!(19, part1(&cave_names, &adj_list));
+        let (cave_names, adj_list) = parse(EX3).unwrap();
+        assert_eq!(226, part1(&cave_names, &adj_list));
+    }
+
+    #[test]
+    fn test_part2() {
+        let (cave_names, adj_list) = parse(EX1).unwrap();
+        assert_eq!(36, part2(&cave_names, &adj_list));
+        let (cave_names, adj_list) = parse(EX2).unwrap();
+        assert_eq!(103, part2(&cave_names, &adj_list));
+        let (cave_names, adj_list) = parse(EX3).unwrap();
+        assert_eq!(3509, part2(&cave_names, &adj_list));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("start-A\nstart-b\nA-c\nA+b").err().unwrap();
+        assert_eq!((err.line, err.column), (4, 1));
+        let err = parse("start-A\nA-B\nA-end").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(parse("start-A\nA-b").err().unwrap().line, 3);
+    }
+}
@@ -0,0 +1,182 @@
+use std::fmt::Write;
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+type Coord = (usize, usize);
+
+#[derive(Copy, Clone)]
+pub enum Fold {
+    X(usize),
+    Y(usize)
+}
+
+pub struct Grid {
+    width: usize,
+    height: usize,
+    dots: Vec<bool>
+}
+
+impl Grid {
+    fn from_coords(coords: &[Coord]) -> Grid {
+        let width = 1 + *coords.iter().map(|(x, _)| x).max().unwrap();
+        let height = 1 + *coords.iter().map(|(_, y)| y).max().unwrap();
+        let mut dots = vec![false; width * height];
+        for &(x, y) in coords {
+            dots[width * y + x] = true;
+        }
+        Grid { width, height, dots }
+    }
+
+    fn fold(&self, fold: Fold) -> Grid {
+        let (width, height) = match fold {
+            Fold::X(fold_x) => {
+                assert!(self.width - 1 == fold_x * 2);
+                assert!((0..self.height).all(|y| !self.dots[self.width * y + fold_x]));
+                (fold_x, self.height)
+            },
+            Fold::Y(fold_y) => {
+                assert!(self.height - 1 == fold_y * 2);
+                assert!((0..self.width).all(|x| !self.dots[self.width * fold_y + x]));
+                (self.width, fold_y)
+            }
+        };
+        let mut dots = vec![false; width * height];
+        match fold {
+            Fold::X(fold_x) => {
+                for y in 0..height {
+                    for x in 0..fold_x {
+                        dots[width * y + x] |= self.dots[self.width * y + x];
+                    }
+                    for x in fold_x+1..self.width {
+                        dots[width * y + (self.width - x - 1)] |= self.dots[self.width * y + x];
+                    }
+                }
+            },
+            Fold::Y(fold_y) => {
+                for x in 0..width {
+                    for y in 0..fold_y {
+                        dots[width * y + x] |= self.dots[self.width * y + x];
+                    }
+                    for y in fold_y+1..self.height {
+                        dots[width * (self.height - y - 1) + x] |= self.dots[self.width * y + x];
+                    }
+                }
+            }
+        };
+        Grid { width, height, dots }
+    }
+}
+
+pub fn parse(puzzle_input: &str) -> Result<(Vec<Coord>, Vec<Fold>), ParseError> {
+    let sections = parsing::sections(puzzle_input);
+    let (coord_lines, fold_lines) = match &sections[..] {
+        [coord_lines, fold_lines] => (coord_lines, fold_lines),
+        _ => {
+            let line = sections.get(2).map_or_else(
+                || Line { number: parsing::lines(puzzle_input).last().map_or(1, |line| line.number + 1), text: "" },
+                |section| section[0]);
+            return Err(line.error(1, "expected the dots then the folds"));
+        }
+    };
+    let coords = coord_lines.iter()
+        .map(|line| {
+            let (x, y) = line.as_field().split_once(",")?;
+            Ok((x.parse()?, y.parse()?))
+        })
+        .collect::<Result<_, ParseError>>()?;
+    let folds = fold_lines.iter()
+        .map(|line| {
+            let (axis, pos) = line.as_field().strip_prefix("fold along ")?.split_once("=")?;
+            match axis.text {
+                "x" => Ok(Fold::X(pos.parse()?)),
+                "y" => Ok(Fold::Y(pos.parse()?)),
+                _ => Err(axis.error("expected \"x\" or \"y\""))
+            }
+        })
+        .collect::<Result<_, _>>()?;
+    Ok((coords, folds))
+}
+
+pub fn part1(coords: &[Coord], folds: &[Fold]) -> usize {
+    let grid = Grid::from_coords(coords);
+    let grid = grid.fold(folds[0]);
+    grid.dots.iter().filter(|&&dot| dot).count()
+}
+
+pub fn part2(coords: &[Coord], folds: &[Fold]) -> String {
+    let mut grid = Grid::from_coords(coords);
+    for fold in folds {
+        grid = grid.fold(*fold);
+    }
+
+    let mut s = String::new();
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            write!(s, "{}", if grid.dots[y * grid.width + x] { '#' } else { '.' });
+        }
+            write!(s, "\n");
+    }
+    ascii_bitmap::decode(&s).unwrap()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<Coord>, Vec<Fold>);
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        part2(&input.0, &input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5";
+
+    #[test]
+    fn test_part1() {
+        let (coords, folds) = parse(&EX).unwrap();
+        assert_eq!(17, part1(&coords, &folds));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse(&EX.replace("fold along x", "fold along z")).err().unwrap();
+        assert_eq!((err.line, err.column), (21, 12));
+        let err = parse(&EX.replace("0,13", "0;13")).err().unwrap();
+        assert_eq!((err.line, err.column), (10, 1));
+    }
+}
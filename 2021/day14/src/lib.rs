@@ -0,0 +1,173 @@
+use std::collections::{HashMap, HashSet};
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+type Pair = [char; 2];
+
+// The key insight is that we don't care about the ordering, only how many of each pair
+#[derive(Clone)]
+pub struct Polymer {
+    pair_counts: HashMap<Pair, u64>,
+    last_char: char
+}
+
+impl Polymer {
+    fn from_str(s: &str) -> Self {
+        let mut pair_counts = HashMap::new();
+        let mut char_iter = s.chars();
+        let mut last_char = char_iter.next().unwrap();
+        for ch in char_iter {
+            pair_counts.entry([last_char, ch]).and_modify(|count| *count += 1).or_insert(1);
+            last_char = ch;
+        }
+        Polymer { pair_counts, last_char }
+    }
+
+    fn step(self, insertion_rules: &HashMap<Pair, Vec<Pair>>) -> Polymer {
+        let mut ret = Polymer { pair_counts: HashMap::new(), last_char: self.last_char };
+        for (input_pair, input_count) in self.pair_counts {
+            for output_pair in insertion_rules[&input_pair].iter() {
+                ret.pair_counts.entry(*output_pair)
+                    .and_modify(|output_count| *output_count += input_count)
+                    .or_insert(input_count);
+            }
+        }
+        ret
+    }
+
+    fn most_minus_least_common_elem(&self) -> u64 {
+        let mut char_counts = HashMap::new();
+        char_counts.insert(self.last_char, 1);
+        for (&[a, _], &pair_count) in self.pair_counts.iter() {
+            char_counts.entry(a)
+                .and_modify(|char_count| *char_count += pair_count)
+                .or_insert(pair_count);
+        }
+
+        let most_common = char_counts.values().max().unwrap();
+        let least_common = char_counts.values().min().unwrap();
+        most_common - least_common
+    }
+
+}
+
+pub fn parse(puzzle_input: &str) -> Result<(Polymer, HashMap<Pair, Vec<Pair>>), ParseError> {
+    let sections = parsing::sections(puzzle_input);
+    let end = Line { number: parsing::lines(puzzle_input).last().map_or(1, |line| line.number + 1), text: "" };
+    let (template_lines, rule_lines) = match &sections[..] {
+        [template_lines, rule_lines] if template_lines.len() == 1 => (template_lines, rule_lines),
+        [template_lines, _] => return Err(template_lines[1].error(1, "expected a blank line after the template")),
+        _ => return Err(sections.get(2).map_or(end, |section| section[0]).error(1, "expected the template then the rules"))
+    };
+    let element = |ch: char| ch.is_ascii_uppercase().then_some(ch);
+    let template = template_lines[0].map_chars(element)?;
+    let insertion_rules: HashMap<Pair, Vec<Pair>> = rule_lines.iter()
+        .map(|line| {
+            let (pair, insert) = line.as_field().split_once(" -> ")?;
+            let (a, b, c) = match (&pair.map_chars(element)?[..], &insert.map_chars(element)?[..]) {
+                (&[a, b], &[c]) => (a, b, c),
+                _ => return Err(line.error(1, "expected a rule like \"AB -> C\""))
+            };
+            Ok(([a, b], vec![[a, c], [c, b]]))
+        })
+        .collect::<Result<_, _>>()?;
+
+    // If not all pairs have a mapping, we'd need to insert noops, but they do
+    let all_elements: HashSet<char> = insertion_rules.iter()
+        .flat_map(|(inputs, outputs)| inputs.iter().chain(outputs.iter().flat_map(|pairs| pairs)))
+        .chain(&template)
+        .cloned()
+        .collect();
+    for &a in &all_elements {
+        for &b in &all_elements {
+            if !insertion_rules.contains_key(&[a, b]) {
+                return Err(end.error(1, format!("expected a rule for {}{}", a, b)));
+            }
+        }
+    }
+    let template: String = template.into_iter().collect();
+    Ok((Polymer::from_str(&template), insertion_rules))
+}
+
+pub fn part1(polymer: &Polymer, insertion_rules: &HashMap<Pair, Vec<Pair>>) -> u64 {
+    let mut polymer = polymer.clone();
+    for _ in 0..10 {
+        polymer = polymer.step(insertion_rules);
+    }
+    polymer.most_minus_least_common_elem()
+}
+
+pub fn part2(polymer: &Polymer, insertion_rules: &HashMap<Pair, Vec<Pair>>) -> u64 {
+    let mut polymer = polymer.clone();
+    for _ in 0..40 {
+        polymer = polymer.step(insertion_rules);
+    }
+    polymer.most_minus_least_common_elem()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Polymer, HashMap<Pair, Vec<Pair>>);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(&input.0, &input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C";
+
+    #[test]
+    fn test_part1() {
+        let (polymer, insertion_rules) = parse(EX).unwrap();
+        assert_eq!(1588, part1(&polymer, &insertion_rules));
+    }
+
+    #[test]
+    fn test_part2() {
+        let (polymer, insertion_rules) = parse(EX).unwrap();
+        assert_eq!(2188189693529, part2(&polymer, &insertion_rules));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse(&EX.replace("CN -> C", "CN -> c")).err().unwrap();
+        assert_eq!((err.line, err.column), (18, 7));
+        let err = parse(&EX.replace("CN -> C", "CN -> CC")).err().unwrap();
+        assert_eq!((err.line, err.column), (18, 1));
+        let err = parse(&EX.replace("CN -> C", "")).err().unwrap();
+        assert_eq!(err.message, "expected a rule for CN");
+    }
+}
@@ -0,0 +1,146 @@
+use std::cmp::Ordering;
+use std::collections::{HashSet, BinaryHeap};
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+pub struct Grid {
+    width: usize,
+    height: usize,
+    data: Vec<u32>
+}
+
+impl Grid {
+    fn idx(&self, x: usize, y: usize) -> usize {
+        assert!(x < self.width && y < self.height);
+        y * self.width + x
+    }
+
+    fn at(&self, x: usize, y: usize) -> u32 {
+        self.data[self.idx(x, y)]
+    }
+
+    fn at_mut(&mut self, x: usize, y: usize) -> &mut u32 {
+        let i = self.idx(x, y);
+        &mut self.data[i]
+    }
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Grid, ParseError> {
+    let rows = parsing::grid(&parsing::lines(puzzle_input), |ch| ch.to_digit(10))?;
+    if rows.is_empty() {
+        return Err(Line { number: 1, text: "" }.error(1, "expected a grid of risk levels"));
+    }
+    let width = rows[0].len();
+    let height = rows.len();
+    Ok(Grid { width, height, data: rows.concat() })
+}
+
+fn find_best_path(grid: &Grid) -> u32 {
+    #[derive(Eq, PartialEq)]
+    struct AggPath { x: usize, y: usize, risk: u32 }
+    impl Ord for AggPath {
+        fn cmp(&self, other: &Self) -> Ordering {
+            other.risk.cmp(&self.risk) // reverse ordering to make a min-heap
+        }
+    }
+    impl PartialOrd for AggPath {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+    let mut heap = BinaryHeap::new();
+    heap.push(AggPath { x: 0, y: 0, risk: 0 });
+    let mut seen = HashSet::new();
+    while let Some(AggPath { x, y, risk }) = heap.pop() {
+        if !seen.insert((x, y)) { continue }
+        if x == grid.width - 1 && y == grid.height - 1 {
+            return risk
+        }
+        let mut push_heap = |x, y| {
+            heap.push(AggPath { x, y, risk: risk + grid.at(x, y) });
+        };
+        if x > 0               { push_heap(x - 1, y) }
+        if x < grid.width - 1  { push_heap(x + 1, y) }
+        if y > 0               { push_heap(x, y - 1) }
+        if y < grid.height - 1 { push_heap(x, y + 1) }
+    }
+    unreachable!()
+}
+
+pub fn part1(grid: &Grid) -> u32 {
+    find_best_path(grid)
+}
+
+pub fn part2(orig_grid: &Grid) -> u32 {
+    let add_risk = |a, b, c| { ((a + b + c - 1) % 9) + 1 };
+    let width = orig_grid.width * 5;
+    let height = orig_grid.height * 5;
+    let mut grid = Grid { width, height, data: vec![0; width * height] };
+    for y_rep in 0..5 {
+        for x_rep in 0..5 {
+            for y in 0..orig_grid.height {
+                for x in 0..orig_grid.width {
+                    let y_new = y_rep * orig_grid.height + y;
+                    let x_new = x_rep * orig_grid.width + x;
+                    *grid.at_mut(x_new, y_new) =
+                        add_risk(y_rep as u32, x_rep as u32, orig_grid.at(x, y));
+                }
+            }
+        }
+    }
+    find_best_path(&grid)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Grid;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(40, part1(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(315, part2(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("1163751742\n138137367\n2136511328").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 10));
+        assert_eq!(parse("").err().unwrap().line, 1);
+    }
+}
@@ -0,0 +1,342 @@
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+// Remembers the line of hex it came from, so errors can point at the hex digit a bit is in
+struct BitString<'a>(Vec<u8>, Line<'a>);
+
+impl<'a> BitString<'a> {
+    fn from_hex(line: Line<'a>) -> Result<Self, ParseError> {
+        let digits = line.map_chars(|ch| ch.to_digit(16).map(|digit| digit as u8))?;
+        if digits.len() % 2 != 0 {
+            return Err(line.error(digits.len() + 1, "expected an even number of hex digits"));
+        }
+
+        let data = digits
+            .chunks(2)
+            .map(|pair| pair[0] << 4 | pair[1])
+            .collect();
+        Ok(BitString(data, line))
+    }
+
+    fn iter(&self) -> BitStringIter<'_> {
+        BitStringIter { data: &self.0, line: self.1, idx: 0 }
+    }
+}
+
+struct BitStringIter<'a> {
+    data: &'a [u8],
+    line: Line<'a>,
+    idx: usize
+}
+
+impl <'a> Iterator for BitStringIter<'a> {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<bool> {
+        if self.idx / 8 >= self.data.len() {
+            return None
+        }
+        let byte_idx = self.idx / 8;
+        let bit_idx = 7 - (self.idx % 8);
+        let ret = (self.data[byte_idx] & (1 << bit_idx)) != 0;
+        self.idx += 1;
+        Some(ret)
+    }
+}
+
+impl <'a> BitStringIter<'a> {
+    // At the hex digit holding the bit at idx
+    fn error_at(&self, idx: usize, message: impl Into<String>) -> ParseError {
+        self.line.error(idx / 4 + 1, message)
+    }
+
+    fn read_bit(&mut self) -> Result<bool, ParseError> {
+        self.next().ok_or_else(|| self.error_at(self.idx, "unexpected end of the transmission"))
+    }
+
+    fn read_u8(&mut self, nbits: usize) -> Result<u8, ParseError> {
+        assert!(nbits <= 8 && nbits > 0);
+        let mut ret = 0;
+        for _ in 0..nbits {
+            ret = (ret << 1) | if self.read_bit()? { 1 } else { 0 };
+        }
+        Ok(ret)
+    }
+
+    fn read_u16(&mut self, nbits: usize) -> Result<u16, ParseError> {
+        assert!(nbits <= 16 && nbits > 0);
+        let mut ret = 0;
+        for _ in 0..nbits {
+            ret = (ret << 1) | if self.read_bit()? { 1 } else { 0 };
+        }
+        Ok(ret)
+    }
+
+    fn check_padding(&mut self) -> Result<(), ParseError> {
+        while let Some(bit) = self.next() {
+            if bit {
+                return Err(self.error_at(self.idx - 1, "expected only zeros after the packet"));
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct Packet {
+    version: u8,
+    contents: PacketContents,
+}
+
+impl Packet {
+    fn parse(puzzle_input: &str) -> Result<Self, ParseError> {
+        let bits = BitString::from_hex(parsing::single_line(puzzle_input)?)?;
+        let mut bits = bits.iter();
+        let ret = Packet::parse_from_bits(&mut bits)?;
+        bits.check_padding()?;
+        Ok(ret)
+    }
+
+    fn parse_from_bits(bits: &mut BitStringIter) -> Result<Self, ParseError> {
+        let start_idx = bits.idx;
+        let version = bits.read_u8(3)?;
+        let type_id = bits.read_u8(3)?;
+        if type_id == 4 {
+            let mut value = 0u64;
+            loop {
+                let group = bits.read_u8(5)?;
+                if value >> 60 != 0 {
+                    return Err(bits.error_at(start_idx, "literal value doesn't fit in 64 bits"));
+                }
+                value = (value << 4) | (group & 0xf) as u64;
+                if (group & 0x10) == 0 {
+                    break
+                }
+            }
+            let contents = PacketContents::Literal(value);
+            Ok(Packet { version, contents })
+        } else {
+            let operator_type = OperatorType::from_type_id(type_id);
+            let length_type = LengthType::parse_from_bits(bits)?;
+            let length = match length_type {
+                LengthType::TotalBits => bits.read_u16(15)?,
+                LengthType::NumPackets => bits.read_u16(11)?,
+            } as usize;
+            let mut sub_packets = Vec::new();
+            match length_type {
+                LengthType::NumPackets => {
+                    for _ in 0..length {
+                        sub_packets.push(Packet::parse_from_bits(bits)?);
+                    }
+                },
+                LengthType::TotalBits => {
+                    let stop_idx = bits.idx + length;
+                    while bits.idx < stop_idx {
+                        sub_packets.push(Packet::parse_from_bits(bits)?);
+                    }
+                    if stop_idx != bits.idx {
+                        return Err(bits.error_at(start_idx, format!("expected sub-packets filling {} bits", length)));
+                    }
+                }
+            }
+            // So that part 2's operators always have values to work on
+            let expected = match operator_type {
+                OperatorType::Sum | OperatorType::Product => None,
+                OperatorType::Minimum | OperatorType::Maximum if sub_packets.is_empty() => Some("at least one sub-packet"),
+                OperatorType::Minimum | OperatorType::Maximum => None,
+                _ if sub_packets.len() != 2 => Some("two sub-packets to compare"),
+                _ => None
+            };
+            if let Some(expected) = expected {
+                return Err(bits.error_at(start_idx, format!("expected {}", expected)));
+            }
+            let contents = PacketContents::Operator(operator_type, sub_packets);
+            Ok(Packet { version, contents })
+        }
+    }
+}
+
+enum PacketContents {
+    Literal(u64),
+    Operator(OperatorType, Vec<Packet>)
+}
+
+enum OperatorType {
+    Sum,
+    Product,
+    Minimum,
+    Maximum,
+    GreaterThan,
+    LessThan,
+    EqualTo
+}
+
+impl OperatorType {
+    fn from_type_id(type_id: u8) -> OperatorType {
+        match type_id {
+            0 => OperatorType::Sum,
+            1 => OperatorType::Product,
+            2 => OperatorType::Minimum,
+            3 => OperatorType::Maximum,
+            5 => OperatorType::GreaterThan,
+            6 => OperatorType::LessThan,
+            7 => OperatorType::EqualTo,
+            _ => unreachable!()
+        }
+    }
+}
+
+enum LengthType {
+    TotalBits, NumPackets
+}
+
+impl LengthType {
+    fn parse_from_bits(bits: &mut BitStringIter) -> Result<LengthType, ParseError> {
+        if bits.read_bit()? {
+            Ok(LengthType::NumPackets)
+        } else {
+            Ok(LengthType::TotalBits)
+        }
+    }
+}
+
+pub fn part1(packet: &Packet) -> u64 {
+    fn sum_versions(packet: &Packet) -> u64 {
+        let mut sum = packet.version as u64;
+        if let PacketContents::Operator(_, ref sub_packets) = packet.contents {
+            for sub_packet in sub_packets {
+                sum += sum_versions(sub_packet);
+            }
+        }
+        sum
+    }
+
+    sum_versions(packet)
+}
+
+pub fn part2(packet: &Packet) -> u64 {
+    fn calc_with_operators(packet: &Packet) -> u64 {
+        match &packet.contents {
+            PacketContents::Literal(val) => *val,
+            PacketContents::Operator(OperatorType::Sum, sub_packets) => {
+                sub_packets.iter().map(|p| calc_with_operators(p)).sum()
+            },
+            PacketContents::Operator(OperatorType::Product, sub_packets) => {
+                sub_packets.iter().map(|p| calc_with_operators(p)).product()
+            },
+            PacketContents::Operator(OperatorType::Minimum, sub_packets) => {
+                sub_packets.iter().map(|p| calc_with_operators(p)).min().unwrap()
+            },
+            PacketContents::Operator(OperatorType::Maximum, sub_packets) => {
+                sub_packets.iter().map(|p| calc_with_operators(p)).max().unwrap()
+            },
+            PacketContents::Operator(cmp_op, sub_packets) => {
+                assert_eq!(2, sub_packets.len());
+                let val_0 = calc_with_operators(&sub_packets[0]);
+                let val_1 = calc_with_operators(&sub_packets[1]);
+                let cmp_result = match cmp_op {
+                    OperatorType::GreaterThan => val_0 > val_1,
+                    OperatorType::LessThan => val_0 < val_1,
+                    OperatorType::EqualTo => val_0 == val_1,
+                    _ => panic!(),
+                };
+                if cmp_result { 1 } else { 0 }
+            },
+        }
+    }
+
+    calc_with_operators(packet)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Packet;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        Packet::parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert!(matches!(
+                Packet::parse("D2FE28").unwrap(),
+                Packet {
+                    version: 6,
+                    contents: PacketContents::Literal(2021),
+                }));
+        assert!(matches!(
+                Packet::parse("38006F45291200").unwrap(),
+                Packet {
+                    version: 1,
+                    contents: PacketContents::Operator(OperatorType::LessThan, sub_packets),
+                } if matches!(sub_packets.as_slice(), &[
+                        Packet {
+                            version: 6,
+                            contents: PacketContents::Literal(10),
+                        },
+                        Packet {
+                            version: 2,
+                            contents: PacketContents::Literal(20),
+                        },
+                    ])));
+        assert!(matches!(
+                Packet::parse("EE00D40C823060").unwrap(),
+                Packet {
+                    version: 7,
+                    contents: PacketContents::Operator(OperatorType::Maximum, sub_packets),
+                } if matches!(sub_packets.as_slice(), &[
+                        Packet {
+                            version: 2,
+                            contents: PacketContents::Literal(1),
+                        },
+                        Packet {
+                            version: 4,
+                            contents: PacketContents::Literal(2),
+                        },
+                        Packet {
+                            version: 1,
+                            contents: PacketContents::Literal(3),
+                        },
+                    ])));
+        assert_eq!(16, part1(&Packet::parse("8A004A801A8002F478").unwrap()));
+        assert_eq!(12, part1(&Packet::parse("620080001611562C8802118E34").unwrap()));
+        assert_eq!(23, part1(&Packet::parse("C0015000016115A2E0802F182340").unwrap()));
+        assert_eq!(31, part1(&Packet::parse("A0016C880162017C3686B18A3D4780").unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(3, part2(&Packet::parse("C200B40A82").unwrap()));
+        assert_eq!(54, part2(&Packet::parse("04005AC33890").unwrap()));
+        assert_eq!(7, part2(&Packet::parse("880086C3E88112").unwrap()));
+        assert_eq!(9, part2(&Packet::parse("CE00C43D881120").unwrap()));
+        assert_eq!(1, part2(&Packet::parse("D8005AC2A8F0").unwrap()));
+        assert_eq!(0, part2(&Packet::parse("F600BC2D8F").unwrap()));
+        assert_eq!(0, part2(&Packet::parse("9C005AC2F8F0").unwrap()));
+        assert_eq!(1, part2(&Packet::parse("9C0141080250320F1802104A08").unwrap()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let column = |hex| Packet::parse(hex).err().unwrap().column;
+        assert_eq!(column("D2FG28"), 4);
+        assert_eq!(column("D2FE2"), 6);
+        assert_eq!(column("D2FE"), 5);
+        assert_eq!(column("D2FE29"), 6);
+    }
+}
@@ -0,0 +1,116 @@
+use std::ops::RangeInclusive;
+
+use lazy_static::lazy_static;
+use parsing::ParseError;
+use regex::Regex;
+use solution::Solution;
+
+pub fn parse(puzzle_input: &str) -> Result<(RangeInclusive<i32>, RangeInclusive<i32>), ParseError> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new("^target area: x=(\\d+)\\.\\.(\\d+), y=(-?\\d+)\\.\\.(-?\\d+)$").unwrap();
+    }
+    let line = parsing::single_line(puzzle_input)?;
+    let cap = RE.captures(line.text)
+        .ok_or_else(|| line.error(1, "expected \"target area: x=X1..X2, y=Y1..Y2\""))?;
+    let x1 = line.field(&cap[1]).parse()?;
+    let x2 = line.field(&cap[2]).parse()?;
+    let y1 = line.field(&cap[3]).parse()?;
+    let y2_field = line.field(&cap[4]);
+    let y2 = y2_field.parse()?;
+    if x1 > x2 || y1 > y2 {
+        return Err(line.error(1, "expected the ranges to go from low to high"));
+    } else if y2 >= 0 {
+        // The velocities tried only reach targets below the launcher
+        return Err(y2_field.error("expected the target area to be below the launcher"));
+    }
+    Ok((x1..=x2, y1..=y2))
+}
+
+fn reaches_target_area(mut dx: i32, mut dy: i32, xtarget: &RangeInclusive<i32>, ytarget: &RangeInclusive<i32>) -> bool {
+    let mut x = 0;
+    let mut y = 0;
+    while x <= *xtarget.end() && y >= *ytarget.start() {
+        if xtarget.contains(&x) && ytarget.contains(&y) {
+            return true;
+        }
+        x += dx;
+        y += dy;
+        if dx > 0 {
+            dx -= 1;
+        } else if dx < 0 {
+            dx += 1;
+        }
+        dy -= 1;
+    }
+    false
+}
+
+fn target_reaching_initial_velocities(xtarget: RangeInclusive<i32>, ytarget: RangeInclusive<i32>) -> Vec<(i32, i32)> {
+    let mut ret = Vec::new();
+    for dx in 1 ..= *xtarget.end() {
+        for dy in *ytarget.start() .. -*ytarget.start() {
+            if reaches_target_area(dx, dy, &xtarget, &ytarget) {
+                ret.push((dx, dy));
+            }
+        }
+    }
+    ret
+}
+
+pub fn part1(xtarget: &RangeInclusive<i32>, ytarget: &RangeInclusive<i32>) -> i32 {
+    target_reaching_initial_velocities(xtarget.clone(), ytarget.clone()).into_iter()
+        .filter(|(_, dy)| *dy > 0)
+        .map(|(_, dy)| dy * (dy + 1) / 2)
+        .max().unwrap()
+}
+
+pub fn part2(xtarget: &RangeInclusive<i32>, ytarget: &RangeInclusive<i32>) -> usize {
+    target_reaching_initial_velocities(xtarget.clone(), ytarget.clone()).len()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (RangeInclusive<i32>, RangeInclusive<i32>);
+    type Answer1 = i32;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> i32 {
+        part1(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(&input.0, &input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "target area: x=20..30, y=-10..-5";
+
+    #[test]
+    fn test_part1() {
+        let (xtarget, ytarget) = parse(EX).unwrap();
+        assert_eq!(45, part1(&xtarget, &ytarget));
+    }
+
+    #[test]
+    fn test_part2() {
+        let (xtarget, ytarget) = parse(EX).unwrap();
+        assert_eq!(112, part2(&xtarget, &ytarget));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("target area: x=20..30, y=-10..5").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 31));
+        let err = parse("target area: x=20..30 y=-10..-5").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 1));
+    }
+}
@@ -0,0 +1,101 @@
+use parsing::ParseError;
+use solution::Solution;
+
+pub struct Cmd(CmdType, u32);
+
+enum CmdType {
+    Forward, Down, Up
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<Cmd>, ParseError> {
+    parsing::parse_lines(puzzle_input, |line| {
+        let (typ, amt) = line.as_field().split_once(" ")?;
+        let typ = match typ.text {
+            "forward" => CmdType::Forward,
+            "down" => CmdType::Down,
+            "up" => CmdType::Up,
+            _ => return Err(typ.error("expected \"forward\", \"down\" or \"up\""))
+        };
+        Ok(Cmd(typ, amt.parse()?))
+    })
+}
+
+pub fn part1(commands: &[Cmd]) -> u32 {
+    let mut depth = 0;
+    let mut pos = 0;
+    for Cmd(typ, amt) in commands {
+        match typ {
+            CmdType::Forward => pos += amt,
+            CmdType::Down => depth += amt,
+            CmdType::Up => depth -= amt
+        }
+    }
+    depth * pos
+}
+
+pub fn part2(commands: &[Cmd]) -> u32 {
+    let mut depth = 0;
+    let mut pos = 0;
+    let mut aim = 0;
+    for Cmd(typ, amt) in commands {
+        match typ {
+            CmdType::Forward => {
+                pos += amt;
+                depth += amt * aim;
+            },
+            CmdType::Down => aim += amt,
+            CmdType::Up => aim -= amt
+        }
+    }
+    depth * pos
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Cmd>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(150, part1(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(900, part2(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("forward 5\nback 5").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("forward 5\ndown x").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 6));
+    }
+}
@@ -0,0 +1,212 @@
+use std::cmp::{min, max};
+use std::collections::HashSet;
+use std::fmt;
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+#[derive(Clone)]
+pub struct Image {
+    x_min: isize,
+    x_max: isize,
+    y_min: isize,
+    y_max: isize,
+    lit_infinite: bool,
+    lit_pixels: HashSet<[isize; 2]>, // [x, y]
+}
+
+impl Image {
+    fn parse(lines: &[Line]) -> Result<Image, ParseError> {
+        let grid = parsing::grid(lines, |ch| match ch {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+        let mut lit_pixels = HashSet::new();
+        let mut x_min = isize::MAX;
+        let mut x_max = isize::MIN;
+        let mut y_min = isize::MAX;
+        let mut y_max = isize::MIN;
+        for (y, row) in grid.iter().enumerate() {
+            let y = y as isize;
+            for (x, &lit) in row.iter().enumerate() {
+                if lit {
+                    let x = x as isize;
+                    x_min = min(x_min, x);
+                    x_max = max(x_max, x);
+                    y_min = min(y_min, y);
+                    y_max = max(y_max, y);
+                    lit_pixels.insert([x, y]);
+                }
+            }
+        }
+        if lit_pixels.is_empty() {
+            return Err(lines[0].error(1, "expected at least one lit pixel"));
+        }
+        Ok(Image { x_min, x_max, y_min, y_max, lit_infinite: false, lit_pixels })
+    }
+
+    fn enhance_pixel(&self, algorithm: &[bool], x: isize, y: isize) -> bool {
+        let mut idx = 0;
+        for y in y-1 ..= y+1 {
+            for x in x-1 ..= x+1 {
+                let lit = if x < self.x_min || y < self.y_min || x > self.x_max || y > self.y_max {
+                    self.lit_infinite
+                } else {
+                    self.lit_pixels.contains(&[x, y])
+                };
+
+                idx <<= 1;
+                if lit {
+                    idx |= 1;
+                }
+            }
+        }
+        algorithm[idx]
+    }
+
+    fn enhance(&self, algorithm: &[bool]) -> Image {
+        let mut lit_pixels = HashSet::new();
+        let mut x_min = isize::MAX;
+        let mut x_max = isize::MIN;
+        let mut y_min = isize::MAX;
+        let mut y_max = isize::MIN;
+        for y in self.y_min-2 ..= self.y_max+2 {
+            for x in self.x_min-2 ..= self.x_max+2 {
+                if self.enhance_pixel(algorithm, x, y) {
+                    x_min = min(x_min, x);
+                    x_max = max(x_max, x);
+                    y_min = min(y_min, y);
+                    y_max = max(y_max, y);
+                    lit_pixels.insert([x, y]);
+                }
+            }
+        }
+        let lit_infinite = algorithm[if self.lit_infinite { 255 } else { 0 }];
+        Image { x_min, x_max, y_min, y_max, lit_infinite, lit_pixels }
+    }
+}
+
+#[allow(dead_code)]
+impl fmt::Display for Image {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first_line = true;
+        let scrolloff = 2;
+        for y in self.y_min-scrolloff ..= self.y_max+scrolloff {
+            if first_line {
+                first_line = false;
+            } else {
+                write!(f, "\n")?;
+            }
+            for x in self.x_min-scrolloff ..= self.x_max+scrolloff {
+                let lit = if x < self.x_min || y < self.y_min || x > self.x_max || y > self.y_max {
+                    self.lit_infinite
+                } else {
+                    self.lit_pixels.contains(&[x, y])
+                };
+
+                write!(f, "{}", if lit { '#' } else { '.' })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn parse(puzzle_input: &str) -> Result<(Vec<bool>, Image), ParseError> {
+    let sections = parsing::sections(puzzle_input);
+    let (algorithm_lines, image_lines) = match &sections[..] {
+        [algorithm_lines, image_lines] => (algorithm_lines, image_lines),
+        _ => {
+            let line = sections.get(2).map_or_else(
+                || Line { number: parsing::lines(puzzle_input).last().map_or(1, |line| line.number + 1), text: "" },
+                |section| section[0]);
+            return Err(line.error(1, "expected the algorithm then the image"));
+        }
+    };
+    if algorithm_lines.len() != 1 {
+        return Err(algorithm_lines[1].error(1, "expected a blank line before the image"));
+    }
+    let line = algorithm_lines[0];
+    let algorithm = line.map_chars(|ch| match ch {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    if algorithm.len() != 512 {
+        return Err(line.error(1, format!("expected 512 characters but found {}", algorithm.len())));
+    }
+    let image = Image::parse(image_lines)?;
+    Ok((algorithm, image))
+}
+
+pub fn part1((algorithm, image): (&[bool], &Image)) -> usize {
+    let mut image = image.clone();
+    for _ in 0..2 {
+        image = image.enhance(algorithm);
+    }
+    image.lit_pixels.len()
+}
+
+pub fn part2((algorithm, image): (&[bool], &Image)) -> usize {
+    let mut image = image.clone();
+    for _ in 0..50 {
+        image = image.enhance(algorithm);
+    }
+    image.lit_pixels.len()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<bool>, Image);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1((&input.0, &input.1))
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2((&input.0, &input.1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
+
+#..#.
+#....
+##..#
+..#..
+..###";
+
+    #[test]
+    fn test_part1() {
+        let (algorithm, image) = parse(EX).unwrap();
+        assert_eq!(35, part1((&algorithm, &image)));
+    }
+
+    #[test]
+    fn test_part2() {
+        let (algorithm, image) = parse(EX).unwrap();
+        assert_eq!(3351, part2((&algorithm, &image)));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let algorithm = "#".repeat(512);
+        let err = parse(&format!("{}\n\n#.\n.x", algorithm)).err().unwrap();
+        assert_eq!((err.line, err.column), (4, 2));
+        let err = parse(&format!("{}.\n\n#.\n..", algorithm)).err().unwrap();
+        assert_eq!((err.line, err.column), (1, 1));
+        let err = parse(&algorithm).err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+pub struct PlayerState {
+    position: usize, // 1-indexed
+    score: usize,
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<PlayerState>, ParseError> {
+    let lines = parsing::lines(puzzle_input);
+    let mut ret = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        if i == 2 {
+            return Err(line.error(1, "expected only 2 players"));
+        }
+        let field = line.as_field().strip_prefix(&format!("Player {} starting position: ", i + 1))?;
+        let position = field.parse()?;
+        if !(1..=10).contains(&position) {
+            return Err(field.error("expected a position from 1 to 10"));
+        }
+        ret.push(PlayerState { position, score: 0 });
+    }
+    if ret.len() != 2 {
+        let line = Line { number: lines.last().map_or(1, |line| line.number + 1), text: "" };
+        return Err(line.error(1, "expected 2 players"));
+    }
+    Ok(ret)
+}
+
+pub fn part1(players: &[PlayerState]) -> u64 {
+    let mut die = 0;
+    let mut roll_cnt = 0;
+    let mut roll = || {
+        let ret = die + 1;
+        die = ret % 100;
+        roll_cnt += 1;
+        ret
+    };
+    let mut players = players.to_vec();
+    loop {
+        for i in 0..2 {
+            let offset = roll() + roll() + roll();
+            let position = (players[i].position + offset - 1) % 10 + 1;
+            let score = players[i].score + position;
+            players[i].position = position;
+            players[i].score = score;
+
+            if score >= 1000 {
+                return players[(i + 1) % 2].score as u64 * roll_cnt;
+            }
+        }
+    }
+}
+
+pub fn part2(players: &[PlayerState]) -> u64 {
+    // Returns how many universes each player won in
+    fn count_outcomes(players: Vec<PlayerState>,
+        memo: &mut HashMap<Vec<PlayerState>, Vec<u64>>) -> Vec<u64>
+    {
+        if players.iter().any(|p| p.score >= 21) {
+            return players.iter()
+                .map(|p| if p.score >= 21 { 1 } else { 0 })
+                .collect();
+        } else if let Some(ret) = memo.get(&players) {
+            return ret.clone();
+        }
+
+        let mut ret = vec![0u64; 2];
+        const ROLL_OUTCOMES: [u64; 10] = [0, 0, 0, 1, 3, 6, 7, 6, 3, 1];
+        for roll in 3..ROLL_OUTCOMES.len() {
+            let position = (players[0].position + roll - 1) % 10 + 1;
+            let score = players[0].score + position;
+            let players = vec![players[1], PlayerState { position, score }];
+            let outcomes = count_outcomes(players, memo);
+            ret[0] += ROLL_OUTCOMES[roll] * outcomes[1];
+            ret[1] += ROLL_OUTCOMES[roll] * outcomes[0];
+        }
+        memo.insert(players, ret.clone());
+        ret
+    }
+
+    let outcomes = count_outcomes(players.to_vec(), &mut HashMap::new());
+    *outcomes.iter().max().unwrap()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<PlayerState>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "Player 1 starting position: 4
+Player 2 starting position: 8";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(739785, part1(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(444356092776315, part2(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("Player 1 starting position: 4\nPlayer 1 starting position: 8").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("Player 1 starting position: 4\nPlayer 2 starting position: 11").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 29));
+        let err = parse("Player 1 starting position: 4").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
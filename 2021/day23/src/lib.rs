@@ -0,0 +1,340 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, BTreeMap, BTreeSet, HashSet};
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum Tile {
+    Wall,
+    Hallway(bool), // whether the hallway is just outside a room
+    Room(char),
+}
+
+pub struct Grid {
+    width: usize,
+    tiles: Vec<Tile>,
+    rooms: BTreeMap<char, BTreeSet<Pos>>,
+}
+
+impl Grid {
+    fn tile(&self, pos: Pos) -> Tile {
+        self.tiles[self.width * pos.y as usize + pos.x as usize]
+    }
+}
+
+#[derive(Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+struct Pos {
+    x: u8,
+    y: u8
+}
+
+impl Pos {
+    fn up(self) -> Self {
+        Pos { x: self.x, y: self.y - 1 }
+    }
+
+    fn down(self) -> Self {
+        Pos { x: self.x, y: self.y + 1 }
+    }
+
+    fn left(self) -> Self {
+        Pos { x: self.x - 1, y: self.y }
+    }
+
+    fn right(self) -> Self {
+        Pos { x: self.x + 1, y: self.y }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq)]
+pub struct State {
+    positions: BTreeMap<char, BTreeSet<Pos>>,
+    energy: u64,
+}
+
+impl State {
+    fn all_positions(&self) -> BTreeSet<Pos> {
+        let mut ret = BTreeSet::new();
+        for positions in self.positions.values() {
+            for pos in positions.iter() {
+                ret.insert(*pos);
+            }
+        }
+        ret
+    }
+}
+
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed order because BinaryHeap max-heap...
+        other.energy.cmp(&self.energy)
+    }
+}
+
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+type Burrow = (Grid, State);
+
+fn parse_diagram(lines: &[Line]) -> Result<Burrow, ParseError> {
+    let width = lines[0].text.len();
+    let mut tiles = Vec::new();
+    let mut rooms = BTreeMap::new();
+    let mut positions = BTreeMap::new();
+    for (y, line) in lines.iter().enumerate() {
+        if line.text.len() > width {
+            return Err(line.error(width + 1, "expected the rows to be no wider than the first"));
+        }
+        let mut room_chs = 'A'..='D';
+        for (x, ch) in line.text.chars().enumerate() {
+            let tile = match ch {
+                '#' | ' ' => Tile::Wall,
+                '.' => Tile::Hallway(false),
+                'A'..='D' => {
+                    let room_ch = room_chs.next()
+                        .filter(|_| y > 0)
+                        .ok_or_else(|| line.error(x + 1, "expected at most 4 rooms below the hallway"))?;
+                    if let Tile::Hallway(ref mut room_adjacent) = &mut tiles[width * (y - 1) + x] {
+                        *room_adjacent = true;
+                    }
+                    let pos = Pos { x: x as u8, y: y as u8 };
+                    positions.entry(ch).or_insert(BTreeSet::new()).insert(pos);
+                    rooms.entry(room_ch).or_insert(BTreeSet::new()).insert(pos);
+                    Tile::Room(room_ch)
+                },
+                _ => return Err(line.error(x + 1, format!("unexpected {:?}", ch))),
+            };
+            tiles.push(tile);
+        }
+        for _ in 0 .. (width - line.text.len()) {
+            tiles.push(Tile::Wall)
+        }
+    }
+    // Every room must fit exactly the amphipods that belong in it
+    for room_ch in 'A'..='D' {
+        let room_size = rooms.get(&room_ch).map_or(0, BTreeSet::len);
+        let amphipods = positions.get(&room_ch).map_or(0, BTreeSet::len);
+        if room_size == 0 || amphipods != room_size {
+            let line = lines[lines.len() - 1];
+            return Err(line.error(1, format!("expected {} amphipods of type {} but found {}", room_size, room_ch, amphipods)));
+        }
+    }
+    let grid = Grid { width, tiles, rooms };
+    let state = State { positions, energy: 0 };
+    Ok((grid, state))
+}
+
+// Parses the diagram as given, and unfolded for part 2 with two more rows in each room
+pub fn parse(puzzle_input: &str) -> Result<(Burrow, Burrow), ParseError> {
+    let lines = parsing::lines(puzzle_input);
+    if lines.len() != 5 {
+        let line = lines.get(5).copied()
+            .unwrap_or(Line { number: lines.last().map_or(1, |line| line.number + 1), text: "" });
+        return Err(line.error(1, "expected a diagram of 5 rows"));
+    }
+    let folded = parse_diagram(&lines)?;
+    let mut unfolded_lines = lines.clone();
+    let number = lines[3].number;
+    unfolded_lines.splice(3..3, ["  #D#C#B#A#", "  #D#B#A#C#"].iter().map(|&text| Line { number, text }));
+    let unfolded = parse_diagram(&unfolded_lines)?;
+    Ok((folded, unfolded))
+}
+
+fn move_cost(ch: char) -> u64 {
+    match ch {
+        'A' => 1,
+        'B' => 10,
+        'C' => 100,
+        'D' => 1000,
+        _ => panic!(),
+    }
+}
+
+fn is_final_state(grid: &Grid, state: &State) -> bool {
+    grid.rooms == state.positions
+}
+
+fn homogenous_room(grid: &Grid, state: &State, room_ch: char) -> bool {
+    for (ch, positions) in state.positions.iter() {
+        if *ch == room_ch { continue }
+        for pos in positions.iter() {
+            if Tile::Room(room_ch) == grid.tile(*pos) { return false }
+        }
+    }
+    true
+}
+
+fn try_move_out(grid: &Grid, state: &State, queue: &mut BinaryHeap<State>,
+    all_positions: &BTreeSet<Pos>, ch: char, orig_pos: Pos, pos: Pos,
+    energy_added: u64, seen: &mut HashSet<Pos>)
+{
+    if !seen.insert(pos) { return }
+    if let Tile::Hallway(false) = grid.tile(pos) {
+        let mut state = state.clone();
+        state.positions.get_mut(&ch).unwrap().remove(&orig_pos);
+        state.positions.get_mut(&ch).unwrap().insert(pos);
+        state.energy += energy_added;
+        queue.push(state);
+    }
+
+    for pos in [pos.left(), pos.right(), pos.up(), pos.down()] {
+        let can_move = match grid.tile(pos) {
+            Tile::Wall => false,
+            Tile::Hallway(_) => true,
+            room@Tile::Room(_) => room == grid.tile(orig_pos),
+        };
+        if can_move && !all_positions.contains(&pos) {
+            try_move_out(grid, state, queue, all_positions,
+                ch, orig_pos, pos, energy_added + move_cost(ch), seen);
+        }
+    }
+}
+
+fn try_move_in(grid: &Grid, state: &State, queue: &mut BinaryHeap<State>,
+    all_positions: &BTreeSet<Pos>, ch: char, orig_pos: Pos, pos: Pos,
+    energy_added: u64, seen: &mut HashSet<Pos>)
+{
+    if !seen.insert(pos) { return }
+    if Tile::Room(ch) == grid.tile(pos) &&
+        (Tile::Wall == grid.tile(pos.down()) || all_positions.contains(&pos.down()))
+    {
+        let mut state = state.clone();
+        state.positions.get_mut(&ch).unwrap().remove(&orig_pos);
+        state.positions.get_mut(&ch).unwrap().insert(pos);
+        state.energy += energy_added;
+        queue.push(state);
+        return;
+    }
+
+    for pos in [pos.left(), pos.right(), pos.up(), pos.down()] {
+        let can_move = match grid.tile(pos) {
+            Tile::Wall => false,
+            Tile::Hallway(_) => true,
+            Tile::Room(room_ch) => room_ch == ch,
+        };
+        if can_move && !all_positions.contains(&pos) {
+            try_move_in(grid, state, queue, all_positions,
+                ch, orig_pos, pos, energy_added + move_cost(ch), seen);
+        }
+    }
+}
+
+#[allow(dead_code)]
+fn state_to_string(grid: &Grid, state: &State) -> String {
+    let mut ret = String::new();
+    for y in 0 .. grid.tiles.len()/grid.width {
+        if y > 0 { ret.push('\n') }
+        'x:
+        for x in 0 .. grid.width {
+            let pos = Pos { x: x as u8, y: y as u8 };
+            match grid.tile(pos) {
+                Tile::Wall => ret.push('#'),
+                Tile::Hallway(_) | Tile::Room(_) => {
+                    for (ch, positions) in state.positions.iter() {
+                        if positions.contains(&pos) {
+                            ret.push(*ch);
+                            continue 'x;
+                        }
+                    }
+                    ret.push('.');
+                },
+            }
+        }
+    }
+    ret
+}
+
+fn find_lowest_energy_path(grid: &Grid, state: State) -> u64 {
+    let mut queue = BinaryHeap::new();
+    queue.push(state);
+    let mut seen = HashSet::new();
+
+    while let Some(state) = queue.pop() {
+        if !seen.insert(state.positions.clone()) { continue }
+        if is_final_state(grid, &state) {
+            return state.energy;
+        }
+
+        let all_positions = state.all_positions();
+        for (ch, positions) in state.positions.iter() {
+            for pos in positions.iter() {
+                if let Tile::Room(room_ch) = grid.tile(*pos) {
+                    if !homogenous_room(grid, &state, room_ch) {
+                        try_move_out(grid, &state, &mut queue, &all_positions,
+                            *ch, *pos, *pos, 0, &mut HashSet::new());
+                    }
+                } else {
+                    if homogenous_room(grid, &state, *ch) {
+                        try_move_in(grid, &state, &mut queue, &all_positions,
+                            *ch, *pos, *pos, 0, &mut HashSet::new());
+                    }
+                }
+            }
+        }
+    }
+
+    panic!("found no path to the solution")
+}
+
+pub fn part1((grid, state): &Burrow) -> u64 {
+    find_lowest_energy_path(grid, state.clone())
+}
+
+pub fn part2((grid, state): &Burrow) -> u64 {
+    find_lowest_energy_path(grid, state.clone())
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Burrow, Burrow);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(&input.0)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(&input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "#############
+#...........#
+###B#C#B#D###
+  #A#D#C#A#
+  #########";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(12521, part1(&parse(EX).unwrap().0));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(44169, part2(&parse(EX).unwrap().1));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("#############\n#...........#\n###B#C#B#D###\n  #A#D#C#A#").err().unwrap();
+        assert_eq!((err.line, err.column), (5, 1));
+        let err = parse("#############\n#...........#\n###B#C#B#D###\n  #A#D#X#A#\n  #########").err().unwrap();
+        assert_eq!((err.line, err.column), (4, 8));
+        let err = parse("#############\n#...........#\n###B#C#B#D###\n  #A#D#C#C#\n  #########").err().unwrap();
+        assert_eq!((err.line, err.column), (5, 1));
+    }
+}
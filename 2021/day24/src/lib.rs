@@ -0,0 +1,328 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use parsing::{Field, Line, ParseError};
+use register_machine::{Flow, Machine, RegisterFile, Status};
+use solution::Solution;
+
+type Operand = register_machine::Operand<char, i64>;
+
+#[derive(Copy, Clone, Hash, Eq, PartialEq)]
+pub enum Instruction {
+    Inp(char),
+    Add(char, Operand),
+    Mul(char, Operand),
+    Div(char, Operand),
+    Mod(char, Operand),
+    Eql(char, Operand),
+}
+
+impl Instruction {
+    // The block template also reads from placeholder registers, for the constants that vary
+    fn parse(line: Line, operand_registers: &str) -> Result<Instruction, ParseError> {
+        let register = |field: Field, registers: &str| {
+            let mut chars = field.text.chars();
+            match (chars.next(), chars.next()) {
+                (Some(ch), None) if registers.contains(ch) => Ok(ch),
+                _ => Err(field.error(format!("expected a register but found {:?}", field.text))),
+            }
+        };
+        let operand = |field: Field| match field.text.parse::<i64>() {
+            Ok(val) => Ok(Operand::Imm(val)),
+            Err(_) => register(field, operand_registers).map(Operand::Reg),
+        };
+
+        let fields = line.fields();
+        let expected_len = if fields.first().map(|f| f.text) == Some("inp") { 2 } else { 3 };
+        if fields.len() != expected_len {
+            return Err(line.error(1, format!("expected {} fields but found {}", expected_len, fields.len())));
+        }
+        let a = register(fields[1], REGISTERS)?;
+        Ok(match fields[0].text {
+            "inp" => Instruction::Inp(a),
+            "add" => Instruction::Add(a, operand(fields[2])?),
+            "mul" => Instruction::Mul(a, operand(fields[2])?),
+            "div" => Instruction::Div(a, operand(fields[2])?),
+            "mod" => Instruction::Mod(a, operand(fields[2])?),
+            "eql" => Instruction::Eql(a, operand(fields[2])?),
+            op => return Err(fields[0].error(format!("unknown instruction {:?}", op))),
+        })
+    }
+}
+
+// The io is the next digit of the model number, inp blocks until one is provided
+impl register_machine::Instruction<Mem, Option<i64>> for Instruction {
+    fn execute(&self, _pc: usize, mem: &mut Mem, input: &mut Option<i64>) -> Flow {
+        match *self {
+            Instruction::Inp(a) => match input.take() {
+                Some(val) => *mem.ref_mut(a) = val,
+                None => return Flow::Block
+            },
+            Instruction::Add(a, b) => *mem.ref_mut(a) += b.value(mem),
+            Instruction::Mul(a, b) => *mem.ref_mut(a) *= b.value(mem),
+            Instruction::Div(a, b) => *mem.ref_mut(a) /= b.value(mem),
+            Instruction::Mod(a, b) => *mem.ref_mut(a) %= b.value(mem),
+            Instruction::Eql(a, b) => {
+                let eql = mem.get(a) == b.value(mem);
+                *mem.ref_mut(a) = if eql { 1 } else { 0 };
+            },
+        };
+        Flow::Next
+    }
+}
+
+const REGISTERS: &str = "wxyz";
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parsing::parse_lines(puzzle_input, |line| Instruction::parse(line, REGISTERS))
+}
+
+#[derive(Clone, Hash, Eq, PartialEq)]
+struct Mem {
+    w: i64,
+    x: i64,
+    y: i64,
+    z: i64,
+}
+
+impl Mem {
+    fn new() -> Self {
+        Self { w: 0, x: 0, y: 0, z: 0 }
+    }
+
+    fn get(&self, ch: char) -> i64 {
+        match ch {
+            'w' => self.w,
+            'x' => self.x,
+            'y' => self.y,
+            'z' => self.z,
+            _ => panic!(),
+        }
+    }
+
+    fn ref_mut(&mut self, ch: char) -> &mut i64 {
+        match ch {
+            'w' => &mut self.w,
+            'x' => &mut self.x,
+            'y' => &mut self.y,
+            'z' => &mut self.z,
+            _ => panic!(),
+        }
+    }
+}
+
+impl RegisterFile<char> for Mem {
+    type Value = i64;
+    fn read(&self, reg: &char) -> i64 {
+        self.get(*reg)
+    }
+}
+
+// Every block of the program between inp instructions has this shape, differing only in the
+// constants D, A and B. Treating z as a stack of base 26 digits, each block computes:
+//
+//   x = (top of z) + A != w
+//   if D == 26 { pop z }
+//   if x { push w + B onto z }
+const BLOCK_TEMPLATE: &str = "\
+inp w
+mul x 0
+add x z
+mod x 26
+div z D
+add x A
+eql x w
+eql x 0
+mul y 0
+add y 25
+mul y x
+add y 1
+mul z y
+mul y 0
+add y w
+add y B
+mul y x
+add z y";
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct Block {
+    div: i64,
+    check: i64,
+    offset: i64,
+}
+
+fn summarise_block(block: &[Instruction], template: &[Instruction]) -> Result<Block, String> {
+    if block.len() != template.len() {
+        return Err(format!("Expected {} instructions but found {}", template.len(), block.len()));
+    }
+    let mut constants = HashMap::new();
+    for (i, (&tmpl, &inst)) in template.iter().zip(block).enumerate() {
+        let matches = match (tmpl, inst) {
+            (Instruction::Add(a, Operand::Reg(name)), Instruction::Add(b, Operand::Imm(val))) |
+            (Instruction::Div(a, Operand::Reg(name)), Instruction::Div(b, Operand::Imm(val)))
+                if name.is_ascii_uppercase() => a == b && constants.insert(name, val).is_none(),
+            _ => tmpl == inst,
+        };
+        if !matches {
+            return Err(format!("Instruction {} doesn't have the expected shape", i));
+        }
+    }
+    Ok(Block { div: constants[&'D'], check: constants[&'A'], offset: constants[&'B'] })
+}
+
+fn summarise(program: &[Instruction]) -> Result<Vec<Block>, String> {
+    let template = parsing::parse_lines(BLOCK_TEMPLATE, |line| Instruction::parse(line, "wxyzABD")).unwrap();
+    let starts = program.iter().enumerate()
+        .filter(|(_, inst)| matches!(inst, Instruction::Inp(_)))
+        .map(|(i, _)| i)
+        .chain(std::iter::once(program.len()))
+        .collect::<Vec<_>>();
+    if starts[0] != 0 {
+        return Err("Program doesn't start with inp".to_string());
+    }
+    starts.windows(2).enumerate()
+        .map(|(i, w)| summarise_block(&program[w[0]..w[1]], &template)
+            .map_err(|e| format!("Block {}: {}", i, e)))
+        .collect()
+}
+
+// digit[pop] = digit[push] + delta, which is the only way the pop block can avoid pushing again
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DigitPair {
+    push: usize,
+    pop: usize,
+    delta: i64,
+}
+
+impl fmt::Display for DigitPair {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "digit {} = digit {} {} {}", self.pop, self.push,
+            if self.delta < 0 { '-' } else { '+' }, self.delta.abs())
+    }
+}
+
+fn pair_digits(blocks: &[Block]) -> Result<Vec<DigitPair>, String> {
+    let mut stack = Vec::new();
+    let mut pairs = Vec::new();
+    for (i, block) in blocks.iter().enumerate() {
+        match block.div {
+            // Since a digit is at most 9, a check above that always fails and the block pushes
+            1 if block.check > 9 => stack.push((i, block.offset)),
+            1 => return Err(format!("Block {} might not push, check {} is too small", i, block.check)),
+            26 => {
+                let (push, offset) = stack.pop()
+                    .ok_or_else(|| format!("Block {} pops from an empty stack", i))?;
+                let delta = offset + block.check;
+                if delta.abs() > 8 {
+                    return Err(format!("No digits satisfy digit {} = digit {} + {}", i, push, delta));
+                }
+                pairs.push(DigitPair { push, pop: i, delta });
+            },
+            div => return Err(format!("Block {} divides z by {}", i, div)),
+        }
+    }
+    if !stack.is_empty() {
+        return Err(format!("{} blocks push without a matching pop, z can't reach 0", stack.len()));
+    }
+    Ok(pairs)
+}
+
+fn analyse(program: &[Instruction]) -> Result<Vec<DigitPair>, String> {
+    pair_digits(&summarise(program)?)
+}
+
+fn run_monad(program: &[Instruction], digits: &[i64]) -> i64 {
+    let mut machine = Machine::new(Mem::new());
+    for &digit in digits {
+        assert_eq!(machine.run(program, &mut None), Status::Blocked);
+        machine.step(program, &mut Some(digit));
+    }
+    assert_eq!(machine.run(program, &mut None), Status::Halted);
+    machine.registers.get('z')
+}
+
+fn find_model_num(program: &[Instruction], pairs: &[DigitPair], largest: bool) -> i64 {
+    let mut digits = vec![0; pairs.len() * 2];
+    for pair in pairs {
+        // Pick the push digit so that both it and the pop digit are in range
+        digits[pair.push] = if largest { 9.min(9 - pair.delta) } else { 1.max(1 - pair.delta) };
+        digits[pair.pop] = digits[pair.push] + pair.delta;
+    }
+    assert_eq!(run_monad(program, &digits), 0);
+    digits.iter().fold(0, |sum, &digit| sum * 10 + digit)
+}
+
+pub fn part1(program: &[Instruction], pairs: &[DigitPair]) -> i64 {
+    find_model_num(program, pairs, true)
+}
+
+pub fn part2(program: &[Instruction], pairs: &[DigitPair]) -> i64 {
+    find_model_num(program, pairs, false)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<Instruction>, Vec<DigitPair>);
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        let program = parse(puzzle_input).map_err(|e| e.to_string())?;
+        let pairs = analyse(&program).map_err(|e| format!("can't analyse the program: {}", e))?;
+        for pair in pairs.iter() {
+            eprintln!("{}", pair);
+        }
+        Ok((program, pairs))
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        part1(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input) -> i64 {
+        part2(&input.0, &input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(div: i64, check: i64, offset: i64) -> String {
+        BLOCK_TEMPLATE.replace("D", &div.to_string())
+            .replace("A", &check.to_string())
+            .replace("B", &offset.to_string())
+    }
+
+    #[test]
+    fn test_analyse() {
+        let program = parse(&[block(1, 12, 4), block(1, 11, 2), block(26, -5, 0), block(26, -1, 1)].join("\n")).unwrap();
+        let pairs = analyse(&program).unwrap();
+        assert_eq!(pairs, vec![
+            DigitPair { push: 1, pop: 2, delta: -3 },
+            DigitPair { push: 0, pop: 3, delta: 3 },
+        ]);
+        assert_eq!(pairs[0].to_string(), "digit 2 = digit 1 - 3");
+        assert_eq!(part1(&program, &pairs), 6969);
+        assert_eq!(part2(&program, &pairs), 1414);
+    }
+
+    #[test]
+    fn test_analyse_unbalanced() {
+        let program = parse(&[block(1, 12, 4), block(1, 11, 2), block(26, -5, 0)].join("\n")).unwrap();
+        assert!(analyse(&program).is_err());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("inp w\nadd x").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("inp w\nadd v 1").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 5));
+        let err = parse("inp w\nsub x 1").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("inp w\nadd x q").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 7));
+    }
+}
@@ -0,0 +1,125 @@
+use std::collections::HashSet;
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+#[derive(Clone)]
+pub struct Grid {
+    height: usize,
+    width: usize,
+    east: HashSet<(usize, usize)>,
+    south: HashSet<(usize, usize)>,
+}
+
+impl Grid {
+    fn parse(puzzle_input: &str) -> Result<Grid, ParseError> {
+        let rows = parsing::grid(&parsing::lines(puzzle_input), |ch| match ch {
+            '>' | 'v' | '.' => Some(ch),
+            _ => None,
+        })?;
+        if rows.is_empty() {
+            return Err(Line { number: 1, text: "" }.error(1, "expected a map of the sea cucumbers"));
+        }
+        let height = rows.len();
+        let width = rows[0].len();
+        let mut east = HashSet::new();
+        let mut south = HashSet::new();
+        for (y, row) in rows.iter().enumerate() {
+            for (x, &ch) in row.iter().enumerate() {
+                match ch {
+                    '>' => { east.insert((x, y)); },
+                    'v' => { south.insert((x, y)); },
+                    _ => (),
+                }
+            }
+        }
+        Ok(Grid { height, width, east, south })
+    }
+
+    fn step(self) -> (bool, Self) {
+        let height = self.height;
+        let width = self.width;
+        let mut east = HashSet::new();
+        let mut south = HashSet::new();
+        let mut changed = false;
+        for (x, y) in self.east.iter().copied() {
+            let x1 = (x + 1) % width;
+            if self.east.contains(&(x1, y)) || self.south.contains(&(x1, y)) {
+                east.insert((x, y));
+            } else {
+                changed = true;
+                east.insert((x1, y));
+            }
+        }
+        for (x, y) in self.south.iter().copied() {
+            let y1 = (y + 1) % height;
+            if east.contains(&(x, y1)) || self.south.contains(&(x, y1)) {
+                south.insert((x, y));
+            } else {
+                changed = true;
+                south.insert((x, y1));
+            }
+        }
+        (changed, Self { height, width, east, south })
+    }
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Grid, ParseError> {
+    Grid::parse(puzzle_input)
+}
+
+pub fn part1(grid: &Grid) -> usize {
+    let mut grid = grid.clone();
+    for i in 1.. {
+        let (changed, grid2) = grid.step();
+        if !changed { return i }
+        grid = grid2;
+    }
+    unreachable!();
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Grid;
+    type Answer1 = usize;
+    type Answer2 = &'static str;
+
+    const PARTS: usize = 1;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "v...>>.vv>
+.vv>>.vv..
+>>.>v>...v
+>>v>>.>.v.
+v>v.vv.v..
+>.>>..v...
+.vv..>.>v.
+v.v..>>v.v
+....v..v.>";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(58, part1(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("v..\n.>.\n..<").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 3));
+        let err = parse("v..\n.>").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+}
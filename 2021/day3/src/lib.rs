@@ -0,0 +1,116 @@
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse(puzzle_input: &str) -> Result<(usize, Vec<u32>), ParseError> {
+    let lines = parsing::lines(puzzle_input);
+    let bits = parsing::grid(&lines, |ch| ch.to_digit(2))?;
+    let n_bits = bits.first().map_or(0, |row| row.len());
+    if n_bits > u32::BITS as usize {
+        return Err(lines[0].error(u32::BITS as usize + 1, format!("expected at most {} bits", u32::BITS)));
+    }
+    let diags = bits.iter()
+        .map(|row| row.iter().fold(0, |num, &bit| (num << 1) | bit))
+        .collect();
+    Ok((n_bits, diags))
+}
+
+fn count_ones_in_bit_pos(diags: &[u32], bit_pos: usize) -> usize {
+    diags.iter().filter(|diag| (*diag & (1 << bit_pos)) != 0).count()
+}
+
+fn most_common_in_bit_pos(diags: &[u32], bit_pos: usize) -> u32 {
+    if count_ones_in_bit_pos(diags, bit_pos) * 2 >= diags.len() { 1 } else { 0 }
+}
+
+fn least_common_in_bit_pos(diags: &[u32], bit_pos: usize) -> u32 {
+    if count_ones_in_bit_pos(diags, bit_pos) * 2 < diags.len() { 1 } else { 0 }
+}
+
+pub fn part1(n_bits: usize, diags: &[u32]) -> u32 {
+    let mut gamma_rate = 0;
+    let mut epsilon_rate = 0;
+    for i in 0..n_bits {
+        if most_common_in_bit_pos(diags, i) == 1 {
+            gamma_rate |= 1 << i;
+        }
+        if least_common_in_bit_pos(diags, i) == 1 {
+            epsilon_rate |= 1 << i;
+        }
+    }
+    gamma_rate * epsilon_rate
+}
+
+fn select_with_bit_criteria(n_bits: usize, diags: &[u32], bit_criteria: fn(diags: &[u32], bit_pos: usize) -> u32) -> u32 {
+    let mut diags = diags.to_vec();
+    let mut bit_pos = n_bits - 1;
+    while diags.len() > 1 {
+        let desired = bit_criteria(&diags, bit_pos);
+        diags.retain(|diag| (*diag & (1 << bit_pos)) == (desired << bit_pos));
+        if bit_pos == 0 { break } else { bit_pos -= 1 }
+    }
+    *diags.iter().next().unwrap()
+}
+
+pub fn part2(n_bits: usize, diags: &[u32]) -> u32 {
+    let o2_rating = select_with_bit_criteria(n_bits, diags, most_common_in_bit_pos);
+    let co2_rating = select_with_bit_criteria(n_bits, diags, least_common_in_bit_pos);
+    o2_rating * co2_rating
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (usize, Vec<u32>);
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        part1(input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        part2(input.0, &input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010";
+
+    #[test]
+    fn test_part1() {
+        let (n_bits, diags) = parse(EX).unwrap();
+        assert_eq!(198, part1(n_bits, &diags));
+    }
+
+    #[test]
+    fn test_part2() {
+        let (n_bits, diags) = parse(EX).unwrap();
+        assert_eq!(230, part2(n_bits, &diags));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("00100\n11110\n1011").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 5));
+        let err = parse("00100\n11120").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 4));
+    }
+}
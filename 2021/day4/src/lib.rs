@@ -0,0 +1,171 @@
+use std::collections::{HashMap, HashSet};
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+#[derive(Clone)]
+pub struct BingoBoard {
+    nums: HashMap<u8, (usize, usize)>, // num -> (row, col)
+    marked_nums: HashSet<u8>,
+    marked_rows: [usize; BingoBoard::SIDE],
+    marked_cols: [usize; BingoBoard::SIDE],
+}
+
+impl BingoBoard {
+    const SIDE: usize = 5;
+
+    fn parse(lines: &[Line]) -> Result<BingoBoard, ParseError> {
+        if lines.len() != Self::SIDE {
+            let line = lines.get(Self::SIDE).copied()
+                .unwrap_or(Line { number: lines[lines.len() - 1].number + 1, text: "" });
+            return Err(line.error(1, format!("expected {} rows", Self::SIDE)));
+        }
+        let mut nums = HashMap::with_capacity(Self::SIDE * Self::SIDE);
+        for (row, line) in lines.iter().enumerate() {
+            let fields = line.fields();
+            if fields.len() != Self::SIDE {
+                return Err(line.error(1, format!("expected {} numbers", Self::SIDE)));
+            }
+            for (col, field) in fields.into_iter().enumerate() {
+                if nums.insert(field.parse()?, (row, col)).is_some() {
+                    return Err(field.error(format!("{} is on the board twice", field.text)));
+                }
+            }
+        }
+        Ok(BingoBoard {
+            nums,
+            marked_nums: HashSet::with_capacity(Self::SIDE * Self::SIDE),
+            marked_rows: [0; Self::SIDE],
+            marked_cols: [0; Self::SIDE],
+        })
+    }
+
+    // Return true if we are now a winner
+    fn mark(&mut self, num: u8) -> bool {
+        if !self.nums.contains_key(&num) { return false }
+        if self.marked_nums.insert(num) {
+            let (row, col) = self.nums[&num];
+            self.marked_rows[row] += 1;
+            self.marked_cols[col] += 1;
+            self.marked_rows[row] == Self::SIDE || self.marked_cols[col] == Self::SIDE
+        } else {
+            false
+        }
+    }
+
+    fn sum_unmarked(&self) -> u32 {
+        let mut sum = 0;
+        for num in self.nums.keys() {
+            if !self.marked_nums.contains(&num) {
+                sum += *num as u32;
+            }
+        }
+        sum
+    }
+}
+
+pub fn parse(puzzle_input: &str) -> Result<(Vec<u8>, Vec<BingoBoard>), ParseError> {
+    let sections = parsing::sections(puzzle_input);
+    let (nums_section, board_sections) = sections.split_first()
+        .ok_or_else(|| Line { number: 1, text: "" }.error(1, "expected the numbers drawn"))?;
+    if nums_section.len() != 1 {
+        return Err(nums_section[1].error(1, "expected a blank line before the boards"));
+    }
+    let nums = nums_section[0].split(",").into_iter().map(|s| s.parse()).collect::<Result<_, _>>()?;
+    let boards = board_sections.iter().map(|lines| BingoBoard::parse(lines)).collect::<Result<_, _>>()?;
+    Ok((nums, boards))
+}
+
+pub fn part1(nums: &[u8], mut boards: Vec<BingoBoard>) -> u32 {
+    for &num in nums {
+        for board in boards.iter_mut() {
+            if board.mark(num) {
+                return board.sum_unmarked() * num as u32;
+            }
+        }
+    }
+    panic!()
+}
+
+pub fn part2(nums: &[u8], mut boards: Vec<BingoBoard>) -> u32 {
+    let mut winning_boards = HashSet::new();
+    let cnt_boards = boards.len();
+    for &num in nums {
+        for (i, board) in boards.iter_mut().enumerate() {
+            if winning_boards.contains(&i) {
+                continue;
+            } else if board.mark(num) {
+                winning_boards.insert(i);
+                if winning_boards.len() == cnt_boards {
+                    return board.sum_unmarked() * num as u32;
+                }
+            }
+        }
+    }
+    panic!()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<u8>, Vec<BingoBoard>);
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        part1(&input.0, input.1.clone())
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        part2(&input.0, input.1.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7";
+
+    #[test]
+    fn test_part1() {
+        let (nums, boards) = parse(EX).unwrap();
+        assert_eq!(4512, part1(&nums, boards));
+    }
+
+    #[test]
+    fn test_part2() {
+        let (nums, boards) = parse(EX).unwrap();
+        assert_eq!(1924, part2(&nums, boards));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse(&EX.replacen("22 13 17 11  0", "22 13 17 11 13", 1)).err().unwrap();
+        assert_eq!((err.line, err.column), (3, 13));
+        let err = parse(&EX.replacen(" 1 12 20 15 19\n", "", 1)).err().unwrap();
+        assert_eq!((err.line, err.column), (7, 1));
+    }
+}
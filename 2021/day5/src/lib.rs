@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+
+use parsing::{Field, Line, ParseError};
+use solution::Solution;
+
+#[derive(Hash, Eq, PartialEq)]
+struct Point {
+    x: i32,
+    y: i32
+}
+
+impl Point {
+    fn parse(field: Field) -> Result<Point, ParseError> {
+        let (x, y) = field.split_once(",")?;
+        Ok(Point { x: x.parse()?, y: y.parse()? })
+    }
+}
+
+pub struct LineSegment(Point, Point);
+
+impl LineSegment {
+    fn parse(line: Line) -> Result<LineSegment, ParseError> {
+        let (p1, p2) = line.as_field().split_once(" -> ")?;
+        let p1 = Point::parse(p1)?;
+        let p2 = Point::parse(p2)?;
+        // Otherwise count_overlapping would step past the end
+        if p1.x != p2.x && p1.y != p2.y && (p1.x - p2.x).abs() != (p1.y - p2.y).abs() {
+            return Err(line.error(1, "expected a horizontal, vertical or diagonal line"));
+        }
+        Ok(LineSegment(p1, p2))
+    }
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<LineSegment>, ParseError> {
+    parsing::parse_lines(puzzle_input, LineSegment::parse)
+}
+
+fn count_overlapping<'a, I>(segments: I) -> usize
+where I: Iterator<Item=&'a LineSegment>
+{
+    let mut point_counts = HashMap::new();
+    for seg in segments {
+        let mut x = seg.0.x;
+        let mut y = seg.0.y;
+        let last_x = seg.1.x;
+        let last_y = seg.1.y;
+        let step_x = if last_x > x { 1 } else if last_x == x { 0 } else { -1 };
+        let step_y = if last_y > y { 1 } else if last_y == y { 0 } else { -1 };
+
+        loop {
+            point_counts.entry(Point { x, y })
+                .and_modify(|cnt| *cnt += 1)
+                .or_insert(1);
+
+            if x == last_x && y == last_y { break }
+            x += step_x;
+            y += step_y;
+        }
+    }
+
+    point_counts.values().filter(|&&cnt| cnt > 1).count()
+}
+
+pub fn part1(segments: &[LineSegment]) -> usize {
+    count_overlapping(segments.iter()
+        .filter(|&seg| seg.0.x == seg.1.x || seg.0.y == seg.1.y))
+}
+
+pub fn part2(segments: &[LineSegment]) -> usize {
+    count_overlapping(segments.iter())
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<LineSegment>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2";
+
+    #[test]
+    fn test_part1() {
+        let segments = parse(EX).unwrap();
+        assert_eq!(5, part1(&segments));
+    }
+
+    #[test]
+    fn test_part2() {
+        let segments = parse(EX).unwrap();
+        assert_eq!(12, part2(&segments));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("0,9 -> 5,9\n8,0 -> 0,x").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 10));
+        let err = parse("0,9 -> 5,9\n8,0 -> 0,7").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
@@ -0,0 +1,81 @@
+use parsing::ParseError;
+use solution::Solution;
+
+const MAX_DAYS: usize = 9;
+
+pub fn parse(puzzle_input: &str) -> Result<[u64; MAX_DAYS], ParseError> {
+    let mut fish_timers = [0; MAX_DAYS];
+    for s in parsing::single_line(puzzle_input)?.split(",") {
+        let timer: usize = s.parse()?;
+        if timer >= MAX_DAYS {
+            return Err(s.error(format!("expected a timer under {}", MAX_DAYS)));
+        }
+        fish_timers[timer] += 1;
+    }
+    Ok(fish_timers)
+}
+
+fn count_fish_after_days(fish_timers: &[u64; MAX_DAYS], days: usize) -> u64 {
+    let mut fish_timers = fish_timers.clone();
+    for _ in 0..days {
+        let fish_0 = fish_timers[0];
+        for i in 0..MAX_DAYS-1 {
+            fish_timers[i] = fish_timers[i + 1];
+        }
+        fish_timers[8] = fish_0;
+        fish_timers[6] += fish_0;
+    }
+
+    fish_timers.iter().sum()
+}
+
+pub fn part1(fish_timers: &[u64; MAX_DAYS]) -> u64 {
+    count_fish_after_days(fish_timers, 80)
+}
+
+pub fn part2(fish_timers: &[u64; MAX_DAYS]) -> u64 {
+    count_fish_after_days(fish_timers, 256)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = [u64; MAX_DAYS];
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "3,4,3,1,2";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(5934, part1(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(26984457539, part2(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("3,4,9,1,2").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 5));
+    }
+}
@@ -0,0 +1,78 @@
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<u32>, ParseError> {
+    parsing::single_line(puzzle_input)?.split(",").into_iter().map(|s| s.parse()).collect()
+}
+
+fn abs_dist(a: u32, b: u32) -> u32 {
+    if a <= b {
+        b - a
+    } else {
+        a - b
+    }
+}
+
+fn best_cost(positions: &[u32], cost_fn: fn(u32, u32) -> u32) -> u32 {
+    let min_pos = *positions.iter().min().unwrap();
+    let max_pos = *positions.iter().max().unwrap();
+
+    (min_pos .. max_pos+1)
+        .map(|dest_pos| positions.iter()
+            .map(|&pos| cost_fn(dest_pos, pos))
+            .sum())
+        .min()
+        .unwrap()
+}
+
+pub fn part1(positions: &[u32]) -> u32 {
+    best_cost(positions, abs_dist)
+}
+
+pub fn part2(positions: &[u32]) -> u32 {
+    let binomial_dist = |a, b| abs_dist(a, b) * (abs_dist(a, b) + 1) / 2;
+    best_cost(positions, binomial_dist)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "16,1,2,0,4,2,7,1,2,14";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(37, part1(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(168, part2(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("16,1,2,,0").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 8));
+    }
+}
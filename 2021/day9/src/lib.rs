@@ -0,0 +1,124 @@
+use std::collections::{HashSet, VecDeque};
+
+use parsing::ParseError;
+use solution::Solution;
+
+pub struct HeightMap {
+    width: usize,
+    height: usize,
+    data: Vec<u32>
+}
+
+impl HeightMap {
+    fn at(&self, x: usize, y: usize) -> u32 {
+        self.data[y * self.width + x]
+    }
+
+    fn is_low_point(&self, x: usize, y: usize) -> bool {
+        let val = self.at(x, y);
+        if x > 0               && val >= self.at(x - 1, y) { return false }
+        if x < self.width - 1  && val >= self.at(x + 1, y) { return false }
+        if y > 0               && val >= self.at(x, y - 1) { return false }
+        if y < self.height - 1 && val >= self.at(x, y + 1) { return false }
+        true
+    }
+
+    fn basin_size(&self, startx: usize, starty: usize) -> usize {
+        let mut basin_points = HashSet::new();
+        let mut queue = VecDeque::new();
+        queue.push_back((startx, starty));
+
+        while let Some((x, y)) = queue.pop_front() {
+            let val = self.at(x, y);
+            if val == 9 { continue } // hightest height
+            if !basin_points.insert((x, y)) { continue }
+
+            if x > 0               && val < self.at(x - 1, y) { queue.push_back((x - 1, y)) }
+            if x < self.width - 1  && val < self.at(x + 1, y) { queue.push_back((x + 1, y)) }
+            if y > 0               && val < self.at(x, y - 1) { queue.push_back((x, y - 1)) }
+            if y < self.height - 1 && val < self.at(x, y + 1) { queue.push_back((x, y + 1)) }
+        }
+
+        basin_points.len()
+    }
+}
+
+pub fn parse(puzzle_input: &str) -> Result<HeightMap, ParseError> {
+    let rows = parsing::grid(&parsing::lines(puzzle_input), |ch| ch.to_digit(10))?;
+    let width = rows.first().map_or(0, |row| row.len());
+    let height = rows.len();
+    Ok(HeightMap { width, height, data: rows.concat() })
+}
+
+pub fn part1(heightmap: &HeightMap) -> u32 {
+    let mut sum = 0;
+    for y in 0..heightmap.height {
+        for x in 0..heightmap.width {
+            if heightmap.is_low_point(x, y) {
+                sum += heightmap.at(x, y) + 1;
+            }
+        }
+    }
+    sum
+}
+
+pub fn part2(heightmap: &HeightMap) -> usize {
+    let mut basin_sizes = Vec::new();
+    for y in 0..heightmap.height {
+        for x in 0..heightmap.width {
+            if heightmap.is_low_point(x, y) {
+                basin_sizes.push(heightmap.basin_size(x, y));
+            }
+        }
+    }
+    basin_sizes.sort_unstable();
+    let n = basin_sizes.len();
+    basin_sizes[n - 3] * basin_sizes[n - 2] * basin_sizes[n - 1]
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = HeightMap;
+    type Answer1 = u32;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "2199943210
+3987894921
+9856789892
+8767896789
+9899965678";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(15, part1(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(1134, part2(&parse(EX).unwrap()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("2199943210\n398789492\n9856789892").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 10));
+    }
+}
@@ -0,0 +1,82 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<u64>, ParseError> {
+    parsing::sections(puzzle_input).into_iter()
+        .map(|lines| lines.iter().map(|line| line.parse::<u64>()).sum())
+        .collect()
+}
+
+
+pub fn part1(elves: &[u64]) -> u64 {
+    elves.iter().copied().max().unwrap()
+}
+
+pub fn part2(elves: &[u64]) -> u64 {
+    let mut heap = BinaryHeap::new();
+    for &cals in elves {
+        heap.push(Reverse(cals));
+        if heap.len() > 3 { heap.pop(); }
+    }
+    heap.iter().map(|Reverse(cals)| cals).sum()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000
+";
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse(EX).unwrap(), vec![6000, 4000, 11000, 24000, 10000]);
+        assert_eq!(parse(&EX.replace('\n', "\r\n")).unwrap(), vec![6000, 4000, 11000, 24000, 10000]);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 24000);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 45000);
+    }
+}
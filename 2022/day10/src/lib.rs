@@ -0,0 +1,294 @@
+use parsing::ParseError;
+use solution::Solution;
+
+pub enum Op {
+    Noop,
+    Addx(i64),
+}
+
+impl Op {
+    fn cycles(&self) -> usize {
+        match self {
+            Op::Noop => 1,
+            Op::Addx(_) => 2,
+        }
+    }
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<Op>, ParseError> {
+    const NOOP: &str = "noop";
+    const ADDX_PREFIX: &str = "addx ";
+    parsing::parse_lines(puzzle_input, |line| {
+        if let Some(offset) = line.text.strip_prefix(ADDX_PREFIX) {
+            Ok(Op::Addx(line.field(offset).parse()?))
+        } else if line.text == NOOP {
+            Ok(Op::Noop)
+        } else {
+            Err(line.error(1, "expected noop or addx"))
+        }
+    })
+}
+
+struct Cpu<'a> {
+    program: &'a Vec<Op>,
+    pc: usize,
+    x: i64,
+    cycles_to_retire: usize,
+}
+
+impl <'a> Cpu<'a> {
+    fn new(program: &'a Vec<Op>) -> Cpu<'a> {
+        Cpu { program, pc: 0, x: 1, cycles_to_retire: 0 }
+    }
+
+    // Returns the value of x 
+    fn step(&mut self) -> Option<i64> {
+        if self.pc >= self.program.len() {
+            return None;
+        }
+        if self.cycles_to_retire == 0 {
+            self.cycles_to_retire = self.program[self.pc].cycles();
+        }
+        let ret = Some(self.x);
+        self.cycles_to_retire -= 1;
+        if self.cycles_to_retire == 0 {
+            match self.program[self.pc] {
+                Op::Noop => (),
+                Op::Addx(offset) => self.x += offset,
+            }
+            self.pc += 1;
+        }
+        ret
+    }
+}
+
+pub fn part1(program: &Vec<Op>) -> i64 {
+    let mut cpu = Cpu::new(program);
+    let mut cycle = 0;
+    let mut signal_strength_sum = 0;
+    while let Some(x) = cpu.step() {
+        cycle += 1;
+        if cycle % 40 == 20 {
+            signal_strength_sum += x * cycle;
+        }
+    }
+    signal_strength_sum
+}
+
+fn part2_image(program: &Vec<Op>) -> String {
+    let mut cpu = Cpu::new(program);
+    let mut cycle = 0;
+    let mut image = String::with_capacity(240);
+    while let Some(x) = cpu.step() {
+        let ch = if (x - cycle).abs() <= 1 { '#' } else { '.' };
+        cycle += 1;
+        image.push(ch);
+        if cycle == 40 {
+            cycle = 0;
+            image.push('\n');
+        }
+    }
+    image
+}
+
+pub fn part2(program: &Vec<Op>) -> String {
+    ascii_bitmap::decode(&part2_image(program)).unwrap()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Op>;
+    type Answer1 = i64;
+    type Answer2 = String;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop
+";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 13140);
+    }
+
+    #[test]
+    fn test_part2() {
+        const IMAGE: &str = "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....
+";
+        assert_eq!(&part2_image(&parse(EX).unwrap()), IMAGE);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("noop\nsubx 3").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("noop\naddx three").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 6));
+    }
+}
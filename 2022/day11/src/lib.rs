@@ -0,0 +1,203 @@
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+#[derive(Copy, Clone)]
+enum Op {
+    Add(u64),
+    Mul(u64),
+    Square,
+}
+
+impl Op {
+    fn apply(&self, worry: u64) -> u64 {
+        match self {
+            Op::Add(other) => worry + other,
+            Op::Mul(other) => worry * other,
+            Op::Square => worry * worry,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Monkey {
+    items: Vec<u64>,
+    op: Op,
+    divisible_by: u64,
+    true_throw_to: usize,
+    false_throw_to: usize,
+}
+
+impl Monkey {
+    fn throw_to(&self, worry: u64) -> usize {
+        if worry % self.divisible_by == 0 {
+            self.true_throw_to
+        } else {
+            self.false_throw_to
+        }
+    }
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<Monkey>, ParseError> {
+    const STARTING_ITEMS_PREFIX: &str = "  Starting items: ";
+    const OPERATION_PREFIX: &str = "  Operation: new = old ";
+    const OP_ADD_PREFIX: &str = "+ ";
+    const OP_MUL_PREFIX: &str = "* ";
+    const TEST_DIVISIBLE_PREFIX: &str = "  Test: divisible by ";
+    const TRUE_THROW_TO_PREFIX: &str = "    If true: throw to monkey ";
+    const FALSE_THROW_TO_PREFIX: &str = "    If false: throw to monkey ";
+    let sections = parsing::sections(puzzle_input);
+    let monkeys = sections.iter().enumerate().map(|(i, lines)| {
+        if lines.len() != 6 {
+            let line = lines.get(6).copied().unwrap_or(Line { number: lines[lines.len() - 1].number + 1, text: "" });
+            return Err(line.error(1, "expected 6 lines for each monkey"));
+        }
+        let header = format!("Monkey {}:", i);
+        if lines[0].text != header {
+            return Err(lines[0].error(1, format!("expected {:?}", header)));
+        }
+        let items_field = lines[1].as_field().strip_prefix(STARTING_ITEMS_PREFIX)?;
+        let items = if items_field.text.is_empty() {
+            Vec::new()
+        } else {
+            items_field.split(", ").iter().map(|worry| worry.parse()).collect::<Result<_, _>>()?
+        };
+        let op_field = lines[2].as_field().strip_prefix(OPERATION_PREFIX)?;
+        let op = if op_field.text == "* old" {
+            Op::Square
+        } else if let Ok(other) = op_field.strip_prefix(OP_ADD_PREFIX) {
+            Op::Add(other.parse()?)
+        } else if let Ok(other) = op_field.strip_prefix(OP_MUL_PREFIX) {
+            Op::Mul(other.parse()?)
+        } else {
+            return Err(op_field.error("expected \"+ N\", \"* N\" or \"* old\""));
+        };
+        let divisible_by_field = lines[3].as_field().strip_prefix(TEST_DIVISIBLE_PREFIX)?;
+        let divisible_by = divisible_by_field.parse()?;
+        if divisible_by == 0 {
+            return Err(divisible_by_field.error("expected a positive number"));
+        }
+        let throw_to = |prefix: &str, line: Line| -> Result<usize, ParseError> {
+            let field = line.as_field().strip_prefix(prefix)?;
+            match field.parse()? {
+                to if to == i || to >= sections.len() => Err(field.error("expected another monkey to throw to")),
+                to => Ok(to),
+            }
+        };
+        let true_throw_to = throw_to(TRUE_THROW_TO_PREFIX, lines[4])?;
+        let false_throw_to = throw_to(FALSE_THROW_TO_PREFIX, lines[5])?;
+        Ok(Monkey { items, op, divisible_by, true_throw_to, false_throw_to })
+    }).collect::<Result<Vec<_>, _>>()?;
+    if monkeys.len() < 2 {
+        return Err(Line { number: parsing::lines(puzzle_input).len() + 1, text: "" }.error(1, "expected at least 2 monkeys"));
+    }
+    Ok(monkeys)
+}
+
+fn simulate(mut monkeys: Vec<Monkey>, num_rounds: usize, low_worry: bool) -> usize {
+    let mut inspect_cnt = vec![0; monkeys.len()];
+    let mut all_mod = 1;
+    for monkey in &monkeys {
+        all_mod *= monkey.divisible_by;
+    }
+    for _ in 0..num_rounds {
+        for monkey_id in 0..monkeys.len() {
+            let num_items = monkeys[monkey_id].items.len();
+            inspect_cnt[monkey_id] += num_items;
+            for item_id in 0..num_items {
+                let worry = monkeys[monkey_id].items[item_id];
+                let worry = monkeys[monkey_id].op.apply(worry);
+                let worry = if low_worry { worry / 3 } else { worry % all_mod };
+                let throw_to = monkeys[monkey_id].throw_to(worry);
+                monkeys[throw_to].items.push(worry);
+            }
+            monkeys[monkey_id].items.clear();
+        }
+    }
+    inspect_cnt.sort();
+    inspect_cnt[inspect_cnt.len() - 1] * inspect_cnt[inspect_cnt.len() - 2]
+}
+
+pub fn part1(monkeys: &[Monkey]) -> usize {
+    simulate(monkeys.to_vec(), 20, true)
+}
+
+pub fn part2(monkeys: &[Monkey]) -> usize {
+    simulate(monkeys.to_vec(), 10000, false)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Monkey>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1
+";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 10605);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 2713310158);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let monkey1 = "\n\nMonkey 1:\n  Starting items: 54\n  Operation: new = old + 6\n  Test: divisible by 19\n    If true: throw to monkey 0\n    If false: throw to monkey 0";
+        let err = parse(&EX.replace("old * 19", "old - 19")).err().unwrap();
+        assert_eq!((err.line, err.column), (3, 24));
+        let err = parse(&EX.replace("monkey 2", "monkey 4")).err().unwrap();
+        assert_eq!((err.line, err.column), (5, 30));
+        let err = parse(&EX.replace("Monkey 2:", "Monkey 3:")).err().unwrap();
+        assert_eq!((err.line, err.column), (15, 1));
+        let err = parse(&format!("Monkey 0:\n  Starting items: 79, 98{}", monkey1)).err().unwrap();
+        assert_eq!((err.line, err.column), (3, 1));
+    }
+}
@@ -0,0 +1,193 @@
+use std::collections::{VecDeque};
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+struct Pos {
+    x: usize,
+    y: usize,
+}
+
+impl Pos {
+    fn new(x: usize, y: usize) -> Pos {
+        Pos { x, y }
+    }
+}
+
+pub struct HeightMap {
+    width: usize,
+    height: usize,
+    start: Pos,
+    end: Pos,
+    grid: Vec<u8>,
+}
+
+impl HeightMap {
+    fn get(&self, pos: Pos) -> u8 {
+        self.grid[self.idx(pos)]
+    }
+
+    fn idx(&self, pos: Pos) -> usize {
+        self.width * pos.y + pos.x
+    }
+
+    fn parse(puzzle_input: &str) -> Result<HeightMap, ParseError> {
+        let lines = parsing::lines(puzzle_input);
+        let rows = parsing::grid(&lines, |ch| matches!(ch, 'S' | 'E' | 'a'..='z').then_some(ch))?;
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        let mut grid = Vec::with_capacity(width * height);
+        let mut start: Option<Pos> = None;
+        let mut end: Option<Pos> = None;
+        for (y, row) in rows.iter().enumerate() {
+            for (x, &ch) in row.iter().enumerate() {
+                match ch {
+                    'S' | 'E' => {
+                        let (pos, elevation) = if ch == 'S' { (&mut start, 0) } else { (&mut end, 25) };
+                        if pos.is_some() {
+                            return Err(lines[y].error(x + 1, format!("expected only one {}", ch)));
+                        }
+                        *pos = Some(Pos::new(x, y));
+                        grid.push(elevation);
+                    },
+                    _ => grid.push(ch as u8 - b'a'),
+                }
+            }
+        }
+        let missing = |ch| Line { number: lines.len() + 1, text: "" }.error(1, format!("expected an {}", ch));
+        let start = start.ok_or_else(|| missing('S'))?;
+        let end = end.ok_or_else(|| missing('E'))?;
+        Ok(HeightMap { width, height, start, end, grid })
+    }
+}
+
+// Find the shortest path from start to end
+pub fn part1(heightmap: &HeightMap) -> usize {
+    let mut queue = VecDeque::new();
+    let mut seen = vec![false; heightmap.width * heightmap.height];
+    queue.push_back((heightmap.start, 0));
+    while let Some((pos, steps)) = queue.pop_front() {
+        if seen[heightmap.idx(pos)] { continue }
+        seen[heightmap.idx(pos)] = true;
+        if pos == heightmap.end { return steps }
+        let elevation = heightmap.get(pos);
+
+        if pos.x > 0 {
+            let next_pos = Pos::new(pos.x - 1, pos.y);
+            if heightmap.get(next_pos) <= elevation + 1 {
+                queue.push_back((next_pos, steps + 1));
+            }
+        }
+        if pos.x < heightmap.width - 1 {
+            let next_pos = Pos::new(pos.x + 1, pos.y);
+            if heightmap.get(next_pos) <= elevation + 1 {
+                queue.push_back((next_pos, steps + 1));
+            }
+        }
+        if pos.y > 0 {
+            let next_pos = Pos::new(pos.x, pos.y - 1);
+            if heightmap.get(next_pos) <= elevation + 1 {
+                queue.push_back((next_pos, steps + 1));
+            }
+        }
+        if pos.y < heightmap.height - 1 {
+            let next_pos = Pos::new(pos.x, pos.y + 1);
+            if heightmap.get(next_pos) <= elevation + 1 {
+                queue.push_back((next_pos, steps + 1));
+            }
+        }
+    }
+    panic!()
+}
+
+// Find the shortest path from the end to any square at 0 elevation (working backwards)
+pub fn part2(heightmap: &HeightMap) -> usize {
+    let mut queue = VecDeque::new();
+    let mut seen = vec![false; heightmap.width * heightmap.height];
+    queue.push_back((heightmap.end, 0));
+    while let Some((pos, steps)) = queue.pop_front() {
+        if seen[heightmap.idx(pos)] { continue }
+        seen[heightmap.idx(pos)] = true;
+        let elevation = heightmap.get(pos);
+        if elevation == 0 { return steps }
+
+        if pos.x > 0 {
+            let next_pos = Pos::new(pos.x - 1, pos.y);
+            if heightmap.get(next_pos) + 1 >= elevation {
+                queue.push_back((next_pos, steps + 1));
+            }
+        }
+        if pos.x < heightmap.width - 1 {
+            let next_pos = Pos::new(pos.x + 1, pos.y);
+            if heightmap.get(next_pos) + 1 >= elevation {
+                queue.push_back((next_pos, steps + 1));
+            }
+        }
+        if pos.y > 0 {
+            let next_pos = Pos::new(pos.x, pos.y - 1);
+            if heightmap.get(next_pos) + 1 >= elevation {
+                queue.push_back((next_pos, steps + 1));
+            }
+        }
+        if pos.y < heightmap.height - 1 {
+            let next_pos = Pos::new(pos.x, pos.y + 1);
+            if heightmap.get(next_pos) + 1 >= elevation {
+                queue.push_back((next_pos, steps + 1));
+            }
+        }
+    }
+    panic!()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = HeightMap;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        HeightMap::parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi
+";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&HeightMap::parse(EX).unwrap()), 31);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&HeightMap::parse(EX).unwrap()), 29);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = HeightMap::parse("Sab\nSbE").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = HeightMap::parse("Sab\nab1").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 3));
+        let err = HeightMap::parse("Sab\nabc").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 1));
+    }
+}
@@ -0,0 +1,230 @@
+use std::cmp::Ordering;
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+pub enum Packet {
+    List(Vec<Packet>),
+    Int(u32),
+}
+
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Packet) -> Ordering {
+        match (self, other) {
+            (Packet::Int(a),   Packet::Int(b))   => a.cmp(b),
+            (Packet::List(_),  Packet::Int(b))   => self.cmp(&Packet::List(vec![Packet::Int(*b)])),
+            (Packet::Int(a),   Packet::List(_))  => Packet::List(vec![Packet::Int(*a)]).cmp(other),
+            (Packet::List(la), Packet::List(lb)) => {
+                let mut a_it = la.iter();
+                let mut b_it = lb.iter();
+                loop {
+                    match (a_it.next(), b_it.next()) {
+                        (Some(a), Some(b)) => {
+                            let cmp = a.cmp(b);
+                            if cmp != Ordering::Equal {
+                                return cmp
+                            }
+                        },
+                        (Some(_), None)    => return Ordering::Greater,
+                        (None,    Some(_)) => return Ordering::Less,
+                        (None,    None)    => return Ordering::Equal,
+                    }
+                }
+            },
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Packet) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+
+impl PartialEq for Packet {
+    fn eq(&self, other: &Packet) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl Eq for Packet {}
+
+
+
+impl Packet {
+    fn parse(line: Line) -> Result<Packet, ParseError> {
+        let mut stack: Vec<Vec<Packet>> = Vec::new();
+        let mut curr: Option<u32> = None;
+        let mut ret: Option<Packet> = None;
+        let mut prev = None;
+        for (i, ch) in line.text.chars().enumerate() {
+            let unexpected = || line.error(i + 1, format!("unexpected {:?}", ch));
+            if ret.is_some() {
+                return Err(line.error(i + 1, "expected the end of the line"));
+            }
+            match ch {
+                '[' => {
+                    if !matches!(prev, None | Some('[') | Some(',')) {
+                        return Err(unexpected());
+                    }
+                    stack.push(Vec::new());
+                },
+                ']' => {
+                    let mut list = stack.pop().ok_or_else(unexpected)?;
+                    if let Some(i) = curr {
+                        list.push(Packet::Int(i));
+                    } else if prev == Some(',') {
+                        return Err(unexpected());
+                    }
+                    curr = None;
+                    if stack.is_empty() {
+                        ret = Some(Packet::List(list));
+                    } else {
+                        stack.last_mut().unwrap().push(Packet::List(list));
+                    }
+                },
+                ',' => {
+                    if stack.is_empty() || !matches!(prev, Some(']') | Some('0'..='9')) {
+                        return Err(unexpected());
+                    }
+                    if let Some(i) = curr {
+                        stack.last_mut().unwrap().push(Packet::Int(i));
+                    } else {
+                        // Already handled when parsing the ']'
+                    }
+                    curr = None;
+                },
+                '0'..='9' => {
+                    if prev == Some(']') {
+                        return Err(unexpected());
+                    }
+                    curr = curr.unwrap_or(0).checked_mul(10)
+                        .and_then(|n| n.checked_add(ch as u32 - '0' as u32));
+                    if curr.is_none() {
+                        return Err(line.error(i + 1, "the number is too large"));
+                    }
+                },
+                _ => return Err(unexpected()),
+            }
+            prev = Some(ch);
+        }
+        if !stack.is_empty() {
+            return Err(line.error(line.text.chars().count() + 1, "expected ']'"));
+        } else if let Some(i) = curr {
+            ret = Some(Packet::Int(i));
+        }
+        ret.ok_or_else(|| line.error(1, "expected a packet"))
+    }
+
+    fn divider(i: u32) -> Packet {
+        Packet::List(vec![Packet::List(vec![Packet::Int(i)])])
+    }
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<[Packet; 2]>, ParseError> {
+    parsing::sections(puzzle_input).iter().map(|lines| {
+        if lines.len() != 2 {
+            let line = lines.get(2).copied().unwrap_or(Line { number: lines[0].number + 1, text: "" });
+            return Err(line.error(1, "expected a pair of packets"));
+        }
+        Ok([Packet::parse(lines[0])?, Packet::parse(lines[1])?])
+    }).collect()
+}
+
+pub fn part1(packet_pairs: &[[Packet; 2]]) -> usize {
+    let mut sum = 0;
+    for (i, [left, right]) in packet_pairs.iter().enumerate() {
+        if left < right {
+            sum += i + 1;
+        }
+    }
+    sum
+}
+
+pub fn part2(packet_pairs: &[[Packet; 2]]) -> usize {
+    let mut packets = Vec::with_capacity(2 * packet_pairs.len() + 2);
+    for [left, right] in packet_pairs {
+        packets.push(left);
+        packets.push(right);
+    }
+    packets.sort();
+
+    let pos1 = packets.binary_search(&&Packet::divider(2)).unwrap_err() + 1;
+    let pos2 = packets.binary_search(&&Packet::divider(6)).unwrap_err() + 2;
+    pos1 * pos2
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<[Packet; 2]>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]
+";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 13);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 140);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("[1,1]\n[1,[2]").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 7));
+        let err = parse("[1,1]\n[1,,2]").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 4));
+        let err = parse("[1,1]\n[1,2]]").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 6));
+        let err = parse("[1,1]\n[1,2]\n[3]").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 1));
+        let err = parse("[1,1]\n[[1]2]").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 5));
+    }
+}
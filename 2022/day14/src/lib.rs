@@ -0,0 +1,142 @@
+use std::cmp::{max, min};
+use std::collections::HashSet;
+
+use parsing::{Field, ParseError};
+use solution::Solution;
+
+#[derive(Hash, Eq, PartialEq, Copy, Clone, Debug)]
+pub struct Pos {
+    x: usize,
+    y: usize,
+}
+
+impl Pos {
+    fn new(x: usize, y: usize) -> Pos {
+        Pos { x, y }
+    }
+
+    fn parse(field: Field) -> Result<Pos, ParseError> {
+        let (x, y) = field.split_once(",")?;
+        Ok(Pos { x: x.parse()?, y: y.parse()? })
+    }
+}
+
+// Sparse map of filled squares
+pub fn parse(puzzle_input: &str) -> Result<(HashSet<Pos>, usize), ParseError> {
+    let mut grid = HashSet::new();
+    let mut max_y = 0;
+    for line in parsing::lines(puzzle_input) {
+        let mut last_pos: Option<Pos> = None;
+        for pos_field in line.split(" -> ") {
+            let next_pos = Pos::parse(pos_field)?;
+            max_y = max(max_y, next_pos.y);
+            if let Some(last_pos) = last_pos {
+                if last_pos.x == next_pos.x {
+                    let first_y = min(last_pos.y, next_pos.y);
+                    let last_y = max(last_pos.y, next_pos.y);
+                    for y in first_y..=last_y {
+                        grid.insert(Pos::new(last_pos.x, y));
+                    }
+                } else if last_pos.y == next_pos.y {
+                    let first_x = min(last_pos.x, next_pos.x);
+                    let last_x = max(last_pos.x, next_pos.x);
+                    for x in first_x..=last_x {
+                        grid.insert(Pos::new(x, last_pos.y));
+                    }
+                } else {
+                    return Err(pos_field.error("expected a horizontal or vertical line"));
+                }
+            }
+            last_pos = Some(next_pos);
+        }
+    }
+    Ok((grid, max_y))
+}
+
+fn simulate(mut grid: HashSet<Pos>, max_y: usize, has_floor: bool) -> usize {
+    let mut cnt = 0;
+    loop {
+        let mut pos = Pos::new(500, 0);
+        loop {
+            if has_floor {
+                if pos.y == max_y + 1 {
+                    grid.insert(pos);
+                    break;
+                }
+            } else if pos.y > max_y {
+                return cnt
+            }
+
+            if !grid.contains(&Pos::new(pos.x, pos.y + 1)) {
+                pos = Pos::new(pos.x, pos.y + 1);
+            } else if !grid.contains(&Pos::new(pos.x - 1, pos.y + 1)) {
+                pos = Pos::new(pos.x - 1, pos.y + 1);
+            } else if !grid.contains(&Pos::new(pos.x + 1, pos.y + 1)) {
+                pos = Pos::new(pos.x + 1, pos.y + 1);
+            } else if has_floor && pos == Pos::new(500, 0) {
+                return cnt + 1;
+            } else {
+                grid.insert(pos);
+                break;
+            }
+        }
+        cnt += 1;
+    }
+}
+
+pub fn part1(grid: &HashSet<Pos>, max_y: usize) -> usize {
+    simulate(grid.clone(), max_y, false)
+}
+
+pub fn part2(grid: &HashSet<Pos>, max_y: usize) -> usize {
+    simulate(grid.clone(), max_y, true)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (HashSet<Pos>, usize);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(&input.0, input.1)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(&input.0, input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9
+";
+
+    #[test]
+    fn test_part1() {
+        let (grid, max_y) = parse(EX).unwrap();
+        assert_eq!(part1(&grid, max_y), 24);
+    }
+
+    #[test]
+    fn test_part2() {
+        let (grid, max_y) = parse(EX).unwrap();
+        assert_eq!(part2(&grid, max_y), 93);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("498,4 -> 498,6\n503,4 -> 502,5").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 10));
+        let err = parse("498,4 -> 498,6\n503,4 -> 502").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 10));
+    }
+}
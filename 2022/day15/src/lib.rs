@@ -0,0 +1,144 @@
+use std::collections::HashSet;
+
+use intervals::IntervalSet;
+use params::Params;
+use parsing::ParseError;
+use solution::Solution;
+
+// The real input's row for part 1 and bound for part 2, which the example shrinks
+pub const ROW: i32 = 2000000;
+pub const MAX_COORD: i32 = 4000000;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+struct Pos {
+    x: i32,
+    y: i32,
+}
+
+impl Pos {
+    fn new(x: i32, y: i32) -> Pos {
+        Pos { x, y }
+    }
+}
+
+pub struct Measurement {
+    sensor: Pos,
+    beacon: Pos,
+    sensor_range: i32,
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<Measurement>, ParseError> {
+    parsing::parse_lines(puzzle_input, |line| {
+        if !line.text.starts_with("Sensor at x=") {
+            return Err(line.error(1, "expected \"Sensor at x=...\""));
+        }
+        let [sensor_x, sensor_y, beacon_x, beacon_y] = line.numbers_n()?;
+        let sensor = Pos::new(sensor_x, sensor_y);
+        let beacon = Pos::new(beacon_x, beacon_y);
+        Ok(Measurement { sensor, beacon, sensor_range: manhattan_dist(sensor, beacon) })
+    })
+}
+
+fn manhattan_dist(pos1: Pos, pos2: Pos) -> i32 {
+    (pos1.x - pos2.x).abs() + (pos1.y - pos2.y).abs()
+}
+
+// The x ranges the sensors can see on a row
+fn coverage(measurements: &[Measurement], row: i32) -> IntervalSet<i32> {
+    measurements.iter()
+        .map(|m| {
+            let diff = m.sensor_range - (row - m.sensor.y).abs();
+            (m.sensor.x - diff)..(m.sensor.x + diff + 1)
+        })
+        .collect()
+}
+
+fn count_impossible_beacons(measurements: &[Measurement], row: i32) -> usize {
+    let covered = coverage(measurements, row);
+    let beacons: HashSet<i32> = measurements.iter()
+        .filter(|m| m.beacon.y == row && covered.contains(m.beacon.x))
+        .map(|m| m.beacon.x)
+        .collect();
+    covered.len() as usize - beacons.len()
+}
+
+// Since there is a unique location, it's the only gap in the coverage of any row in the square
+fn find_distress_beacon(measurements: &[Measurement], max: i32) -> i64 {
+    for y in 0..=max {
+        if let Some(gap) = coverage(measurements, y).gaps(0..max + 1).next() {
+            return gap.start as i64 * 4000000 + y as i64;
+        }
+    }
+    panic!()
+}
+
+pub fn part1(measurements: &[Measurement], row: i32) -> usize {
+    count_impossible_beacons(measurements, row)
+}
+
+pub fn part2(measurements: &[Measurement], max_coord: i32) -> i64 {
+    find_distress_beacon(measurements, max_coord)
+}
+
+pub struct Sensors {
+    pub measurements: Vec<Measurement>,
+    pub row: i32,
+    pub max_coord: i32,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Sensors;
+    type Answer1 = usize;
+    type Answer2 = i64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        let measurements = parse(puzzle_input).map_err(|e| e.to_string())?;
+        Ok(Sensors { measurements, row: ROW, max_coord: MAX_COORD })
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(&input.measurements, input.row)
+    }
+
+    fn part2(input: &Self::Input) -> i64 {
+        part2(&input.measurements, input.max_coord)
+    }
+
+    fn configure(input: &mut Self::Input, params: &Params) {
+        input.row = params.get("row", ROW);
+        input.max_coord = params.get("max_coord", MAX_COORD);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3
+";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap(), 10), 26);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap(), 20), 56000011);
+    }
+}
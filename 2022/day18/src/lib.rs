@@ -0,0 +1,156 @@
+use std::collections::{HashSet, VecDeque};
+use std::ops::Add;
+
+use lazy_static::lazy_static;
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub struct Pos {
+    x: i8,
+    y: i8,
+    z: i8,
+}
+
+impl Pos {
+    fn new(x: i8, y: i8, z: i8) -> Pos {
+        Pos { x, y, z }
+    }
+}
+
+impl Add<Pos> for Pos {
+    type Output = Pos;
+    fn add(self, other: Pos) -> Pos {
+        Pos::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+// Part 2's flood fill goes two steps past the outermost cubes, which has to fit in an i8
+const MAX_COORD: i8 = i8::MAX - 2;
+
+pub fn parse(puzzle_input: &str) -> Result<HashSet<Pos>, ParseError> {
+    let cubes = parsing::parse_lines(puzzle_input, |line| {
+        let fields = line.split(",");
+        if fields.len() != 3 {
+            return Err(line.error(1, format!("expected 3 coordinates but found {}", fields.len())));
+        }
+        let mut coords = [0; 3];
+        for (coord, field) in coords.iter_mut().zip(fields) {
+            *coord = field.parse()?;
+            if !(-MAX_COORD..=MAX_COORD).contains(coord) {
+                return Err(field.error(format!("expected a coordinate from {} to {}", -MAX_COORD, MAX_COORD)));
+            }
+        }
+        Ok(Pos::new(coords[0], coords[1], coords[2]))
+    })?;
+    if cubes.is_empty() {
+        return Err(Line { number: 1, text: "" }.error(1, "expected a cube"));
+    }
+    Ok(cubes.into_iter().collect())
+}
+
+lazy_static!{
+    static ref OFFSETS: Vec<Pos> = vec![
+        Pos::new(-1, 0, 0), Pos::new(1, 0, 0),
+        Pos::new(0, -1, 0), Pos::new(0, 1, 0),
+        Pos::new(0, 0, -1), Pos::new(0, 0, 1),
+    ];
+}
+
+pub fn part1(cubes: &HashSet<Pos>) -> usize {
+    let mut cnt = 0;
+    for &pos in cubes.iter() {
+        for &offset in OFFSETS.iter() {
+            if !cubes.contains(&(pos + offset)) {
+                cnt += 1;
+            }
+        }
+    }
+    cnt
+}
+
+pub fn part2(cubes: &HashSet<Pos>) -> usize {
+    // Conservative bounding box for the external flood fill
+    let min_x = cubes.iter().map(|pos| pos.x).min().unwrap() - 1;
+    let max_x = cubes.iter().map(|pos| pos.x).max().unwrap() + 1;
+    let min_y = cubes.iter().map(|pos| pos.y).min().unwrap() - 1;
+    let max_y = cubes.iter().map(|pos| pos.y).max().unwrap() + 1;
+    let min_z = cubes.iter().map(|pos| pos.z).min().unwrap() - 1;
+    let max_z = cubes.iter().map(|pos| pos.z).max().unwrap() + 1;
+    let mut seen = HashSet::new();
+    let mut cnt = 0;
+    let mut queue = VecDeque::new();
+    queue.push_back(Pos::new(min_x, min_y, min_z));
+    while let Some(pos) = queue.pop_front() {
+        if !seen.insert(pos) { continue; }
+        for &offset in OFFSETS.iter() {
+            let pos = pos + offset;
+            if cubes.contains(&pos) {
+                cnt += 1;
+            } else if pos.x >= min_x && pos.x <= max_x &&
+                      pos.y >= min_y && pos.y <= max_y &&
+                      pos.z >= min_z && pos.z <= max_z {
+                queue.push_back(pos);
+            }
+        }
+    }
+    cnt
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = HashSet<Pos>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "2,2,2
+1,2,2
+3,2,2
+2,1,2
+2,3,2
+2,2,1
+2,2,3
+2,2,4
+2,2,6
+1,2,5
+3,2,5
+2,1,5
+2,3,5
+";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 64);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 58);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("2,2,2\n1,2").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("2,2,2\n1,2,126").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 5));
+    }
+}
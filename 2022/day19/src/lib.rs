@@ -0,0 +1,218 @@
+use std::ops::{Index, IndexMut};
+
+use parsing::{Field, ParseError};
+use regex::Regex;
+use solution::Solution;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+enum Resource {
+    Ore, Clay, Obsidian, Geode
+}
+
+const NUM_RESOURCES: usize = 4;
+const RESOURCES: [Resource; NUM_RESOURCES] = [
+    Resource::Ore, Resource::Clay, Resource::Obsidian, Resource::Geode,
+];
+
+impl Resource {
+    fn parse(field: Field) -> Result<Self, ParseError> {
+        match field.text {
+            "ore" => Ok(Resource::Ore),
+            "clay" => Ok(Resource::Clay),
+            "obsidian" => Ok(Resource::Obsidian),
+            "geode" => Ok(Resource::Geode),
+            _ => Err(field.error(format!("unknown resource {:?}", field.text))),
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug, PartialEq, Eq, Hash)]
+struct ResourceMap<T>([T; NUM_RESOURCES]);
+
+impl <T> Index<Resource> for ResourceMap<T> {
+    type Output = T;
+    fn index(&self, resource: Resource) -> &T {
+        &self.0[resource as usize]
+    }
+}
+
+impl <T> IndexMut<Resource> for ResourceMap<T> {
+    fn index_mut(&mut self, resource: Resource) -> &mut T {
+        &mut self.0[resource as usize]
+    }
+}
+
+// Resource collected by robot -> cost by resource
+pub struct Blueprint(ResourceMap<ResourceMap<usize>>);
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<Blueprint>, ParseError> {
+    let robot_re = Regex::new("^Each ([a-z]+) robot costs (.+?)\\.?$").unwrap();
+    let cost_re = Regex::new("^(\\d+) ([a-z]+)$").unwrap();
+    parsing::lines(puzzle_input).into_iter().enumerate().map(|(i, line)| {
+        let robots_field = line.as_field().strip_prefix(&format!("Blueprint {}: ", i + 1))?;
+        let mut robots = ResourceMap::<ResourceMap<usize>>::default();
+        let mut described = ResourceMap::<bool>::default();
+        for robot_field in robots_field.split(". ") {
+            let robot_cap = robot_re.captures(robot_field.text)
+                .ok_or_else(|| robot_field.error("expected \"Each X robot costs N Y\""))?;
+            let collect_resource = Resource::parse(line.field(robot_cap.get(1).unwrap().as_str()))?;
+            if std::mem::replace(&mut described[collect_resource], true) {
+                return Err(robot_field.error(format!("the {:?} robot was already described", collect_resource)));
+            }
+            let robot = &mut robots[collect_resource];
+            for cost_field in line.field(robot_cap.get(2).unwrap().as_str()).split(" and ") {
+                let cost_cap = cost_re.captures(cost_field.text)
+                    .ok_or_else(|| cost_field.error("expected \"N resource\""))?;
+                let cost_num: usize = line.field(cost_cap.get(1).unwrap().as_str()).parse()?;
+                let cost_resource = Resource::parse(line.field(cost_cap.get(2).unwrap().as_str()))?;
+                robot[cost_resource] += cost_num;
+            }
+        }
+        if let Some(&missing) = RESOURCES.iter().find(|&&resource| !described[resource]) {
+            return Err(line.error(line.text.chars().count() + 1, format!("expected the {:?} robot's cost", missing)));
+        }
+        Ok(Blueprint(robots))
+    }).collect()
+}
+
+// Only one robot can be built a minute, so there's no point having more robots collecting a
+// resource than the most any robot costs of it
+fn max_useful_robots(blueprint: &Blueprint) -> ResourceMap<usize> {
+    let mut ret = ResourceMap::<usize>::default();
+    for robot in RESOURCES {
+        for resource in RESOURCES {
+            ret[resource] = ret[resource].max(blueprint.0[robot][resource]);
+        }
+    }
+    ret[Resource::Geode] = usize::MAX;
+    ret
+}
+
+// A depth first search over which robot to build next, jumping straight to the minute it can be
+// afforded, and giving up on any branch that couldn't beat the best so far even if it built a
+// geode robot every minute from now on
+struct Search<'a> {
+    blueprint: &'a Blueprint,
+    max_robots: ResourceMap<usize>,
+    best: usize,
+}
+
+impl Search<'_> {
+    fn search(&mut self, robots: &ResourceMap<usize>, collected: &ResourceMap<usize>, mins_left: usize) {
+        let geodes = collected[Resource::Geode] + robots[Resource::Geode] * mins_left;
+        self.best = self.best.max(geodes);
+        if geodes + mins_left * mins_left.saturating_sub(1) / 2 <= self.best {
+            return;
+        }
+        // Geode robots first, so good answers are found early and prune more
+        for robot in RESOURCES.iter().rev().copied() {
+            // Enough stock and robots to spend the maximum every minute until the end is enough
+            let max = self.max_robots[robot];
+            if robots[robot] >= max || collected[robot] + robots[robot] * mins_left >= max.saturating_mul(mins_left) {
+                continue;
+            }
+            let cost = &self.blueprint.0[robot];
+            let wait = RESOURCES.iter().copied().try_fold(0, |wait, resource| {
+                match (cost[resource].saturating_sub(collected[resource]), robots[resource]) {
+                    (0, _) => Some(wait),
+                    (_, 0) => None,
+                    (needed, rate) => Some(wait.max(needed.div_ceil(rate))),
+                }
+            });
+            // It has to be built with at least a minute left to collect anything
+            let mins = match wait {
+                Some(wait) if wait + 1 < mins_left => wait + 1,
+                _ => continue,
+            };
+            let mut next_collected = collected.clone();
+            for resource in RESOURCES {
+                next_collected[resource] += robots[resource] * mins;
+                next_collected[resource] -= cost[resource];
+            }
+            let mut next_robots = robots.clone();
+            next_robots[robot] += 1;
+            self.search(&next_robots, &next_collected, mins_left - mins);
+        }
+    }
+}
+
+fn max_geodes(blueprint: &Blueprint, mins_left: usize) -> usize {
+    let mut search = Search { blueprint, max_robots: max_useful_robots(blueprint), best: 0 };
+    let mut robots = ResourceMap::<usize>::default();
+    robots[Resource::Ore] += 1;
+    search.search(&robots, &ResourceMap::default(), mins_left);
+    search.best
+}
+
+// Blueprints are independent, so each gets its own thread
+pub fn part1(blueprints: &[Blueprint]) -> usize {
+    parallel::map(blueprints, |blueprint| max_geodes(blueprint, 24)).into_iter()
+        .enumerate()
+        .map(|(i, geodes)| (i + 1) * geodes)
+        .sum()
+}
+
+pub fn part2(blueprints: &[Blueprint]) -> usize {
+    parallel::map(&blueprints[..blueprints.len().min(3)], |blueprint| max_geodes(blueprint, 32)).into_iter()
+        .product()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Blueprint>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
+Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.
+";
+
+    #[test]
+    fn test_max_geodes() {
+        let blueprints = parse(EX).unwrap();
+        assert_eq!(max_geodes(&blueprints[0], 24), 9);
+        assert_eq!(max_geodes(&blueprints[1], 24), 12);
+        assert_eq!(max_geodes(&blueprints[0], 32), 56);
+        assert_eq!(max_geodes(&blueprints[1], 32), 62);
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 33);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 56 * 62);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse(&EX.replace("Blueprint 2", "Blueprint 3")).err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse(&EX.replace("14 clay", "14 sand")).err().unwrap();
+        assert_eq!((err.line, err.column), (1, 110));
+        let err = parse(&EX.replace("Each clay robot", "Each ore robot")).err().unwrap();
+        assert_eq!((err.line, err.column), (1, 42));
+        let err = parse(&EX.replace(" Each geode robot costs 2 ore and 7 obsidian.", "")).err().unwrap();
+        assert_eq!((err.line, err.column), (1, 115));
+    }
+}
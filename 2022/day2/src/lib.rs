@@ -0,0 +1,157 @@
+use parsing::ParseError;
+use solution::Solution;
+
+#[derive(Copy, Clone)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+#[derive(Copy, Clone)]
+enum Outcome {
+    Win,
+    Loss,
+    Draw
+}
+
+fn outcome_of_round(opponent: Shape, player: Shape) -> Outcome {
+    match opponent {
+        Shape::Rock => match player {
+            Shape::Rock => Outcome::Draw,
+            Shape::Paper => Outcome::Win,
+            Shape::Scissors => Outcome::Loss,
+        },
+        Shape::Paper => match player {
+            Shape::Rock => Outcome::Loss,
+            Shape::Paper => Outcome::Draw,
+            Shape::Scissors => Outcome::Win,
+        },
+        Shape::Scissors => match player {
+            Shape::Rock => Outcome::Win,
+            Shape::Paper => Outcome::Loss,
+            Shape::Scissors => Outcome::Draw,
+        },
+    }
+}
+
+fn required_play(opponent: Shape, outcome: Outcome) -> Shape {
+    match opponent {
+        Shape::Rock => match outcome {
+            Outcome::Draw => Shape::Rock,
+            Outcome::Win => Shape::Paper,
+            Outcome::Loss => Shape::Scissors,
+        },
+        Shape::Paper => match outcome {
+            Outcome::Loss => Shape::Rock,
+            Outcome::Draw => Shape::Paper,
+            Outcome::Win => Shape::Scissors,
+        },
+        Shape::Scissors => match outcome {
+            Outcome::Win => Shape::Rock,
+            Outcome::Loss => Shape::Paper,
+            Outcome::Draw => Shape::Scissors,
+        },
+    }
+}
+
+fn score(outcome: Outcome, player: Shape) -> u64 {
+    (match outcome {
+        Outcome::Loss => 0,
+        Outcome::Draw => 3,
+        Outcome::Win => 6,
+    }) + (match player {
+        Shape::Rock => 1,
+        Shape::Paper => 2,
+        Shape::Scissors => 3,
+    })
+}
+
+fn score_part1(opponent: Shape, player: Shape) -> u64 {
+    score(outcome_of_round(opponent, player), player)
+}
+
+fn score_part2(opponent: Shape, outcome: Outcome) -> u64 {
+    score(outcome, required_play(opponent, outcome))
+}
+
+// The second column means the player's shape in part 1 but the outcome in part 2, so it's kept as
+// 0, 1 or 2 for X, Y or Z
+pub fn parse(puzzle_input: &str) -> Result<Vec<(Shape, usize)>, ParseError> {
+    parsing::parse_lines(puzzle_input, |line| {
+        let fields = line.fields();
+        if fields.len() != 2 {
+            return Err(line.error(1, format!("expected 2 columns but found {}", fields.len())));
+        }
+        let opponent = match fields[0].text {
+            "A" => Shape::Rock,
+            "B" => Shape::Paper,
+            "C" => Shape::Scissors,
+            _ => return Err(fields[0].error("expected A, B or C")),
+        };
+        let second = ["X", "Y", "Z"].iter().position(|&s| s == fields[1].text)
+            .ok_or_else(|| fields[1].error("expected X, Y or Z"))?;
+        Ok((opponent, second))
+    })
+}
+
+pub fn part1(strats: &[(Shape, usize)]) -> u64 {
+    const PLAYER: [Shape; 3] = [Shape::Rock, Shape::Paper, Shape::Scissors];
+    strats.iter().map(|&(opponent, second)| score_part1(opponent, PLAYER[second])).sum()
+}
+
+pub fn part2(strats: &[(Shape, usize)]) -> u64 {
+    const OUTCOME: [Outcome; 3] = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+    strats.iter().map(|&(opponent, second)| score_part2(opponent, OUTCOME[second])).sum()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<(Shape, usize)>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "A Y
+B X
+C Z
+";
+
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 15);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 12);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("A Y\nD X").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("A Y\nB W").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 3));
+        let err = parse("A Y\nB").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
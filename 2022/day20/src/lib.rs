@@ -0,0 +1,115 @@
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<i64>, ParseError> {
+    let lines = parsing::lines(puzzle_input);
+    let nums = lines.iter().map(|line| line.parse()).collect::<Result<Vec<i64>, _>>()?;
+    // The coordinates are found from the only 0, and mixing needs something to move around
+    if let Some(second_zero) = nums.iter().enumerate().filter(|&(_, &num)| num == 0).nth(1) {
+        return Err(lines[second_zero.0].error(1, "expected only one 0"));
+    } else if nums.len() < 2 || !nums.contains(&0) {
+        let line = Line { number: lines.len() + 1, text: "" };
+        return Err(line.error(1, "expected at least 2 numbers including a 0"));
+    }
+    Ok(nums)
+}
+
+fn mix(nums: &[i64], positions: &mut Vec<usize>) {
+    let len = nums.len();
+    debug_assert_eq!(len, positions.len());
+    let removed_len = len as i64 - 1;
+
+    for num_idx in 0..len {
+        let num = nums[num_idx];
+        let pos_idx = positions.iter().cloned().enumerate()
+            .find(|(_, v)| *v == num_idx).unwrap().0;
+        positions.remove(pos_idx);
+        let pos_idx = ((((pos_idx as i64 + num) % removed_len) + removed_len) % removed_len) as usize;
+        positions.insert(pos_idx, num_idx);
+    }
+}
+
+fn coord(nums: &[i64], positions: &[usize]) -> i64 {
+    let len = nums.len();
+    debug_assert_eq!(len, positions.len());
+
+    let zero_num_idx = nums.iter().cloned().enumerate()
+        .find(|(_, num)| *num == 0).unwrap().0;
+    let zero_pos_idx = positions.iter().cloned().enumerate()
+        .find(|(_, idx2)| zero_num_idx == *idx2).unwrap().0;
+
+    [1000, 2000, 3000].into_iter()
+        .map(|offset| nums[positions[(zero_pos_idx + offset) % len]])
+        .sum()
+}
+
+pub fn part1(nums: &[i64]) -> i64 {
+    let mut positions: Vec<_> = (0..nums.len()).into_iter().collect();
+
+    mix(&nums, &mut positions);
+    coord(&nums, &positions)
+}
+
+pub fn part2(nums: &[i64]) -> i64 {
+    let nums: Vec<_> = nums.iter().map(|num| num * 811589153).collect();
+    let mut positions: Vec<_> = (0..nums.len()).into_iter().collect();
+
+    for _ in 0..10 {
+        mix(&nums, &mut positions);
+    }
+    coord(&nums, &positions)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> i64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "1
+2
+-3
+3
+-2
+0
+4
+";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 3);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 1623178306);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("1\n0\n2\n0").err().unwrap();
+        assert_eq!((err.line, err.column), (4, 1));
+        let err = parse("1\n2\n3").err().unwrap();
+        assert_eq!((err.line, err.column), (4, 1));
+        let err = parse("1\n0\n+-2").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 1));
+    }
+}
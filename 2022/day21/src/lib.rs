@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+pub enum Op {
+    Add, Sub, Mul, Div
+}
+
+pub enum Yell {
+    Num(i64),
+    Math(String, String, Op),
+}
+
+// Our solutions to both parts rely on the structure of the input being such that any monkey is
+// referenced by at most one other monkey. This means we don't have to solve generic symbolic
+// execution or bother to memoize. Part 2 also needs humn to be somewhere under root.
+pub fn parse(puzzle_input: &str) -> Result<HashMap<String, Yell>, ParseError> {
+    let lines = parsing::lines(puzzle_input);
+    let mut monkeys = HashMap::new();
+    let mut parents = HashMap::new();
+    let mut refs = Vec::new();
+    for line in lines.iter() {
+        let (name, yell) = line.as_field().split_once(": ")?;
+        let yell = if let Ok(num) = yell.text.parse() {
+            Yell::Num(num)
+        } else {
+            let fields = yell.fields();
+            if fields.len() != 3 {
+                return Err(yell.error("expected a number or \"name OP name\""));
+            }
+            let op = match fields[1].text {
+                "+" => Op::Add,
+                "-" => Op::Sub,
+                "*" => Op::Mul,
+                "/" => Op::Div,
+                _ => return Err(fields[1].error("expected +, -, * or /")),
+            };
+            for field in [fields[0], fields[2]] {
+                if parents.insert(field.text, name.text).is_some() {
+                    return Err(field.error(format!("{} is already referenced by another monkey", field.text)));
+                }
+                refs.push(field);
+            }
+            Yell::Math(fields[0].text.to_string(), fields[2].text.to_string(), op)
+        };
+        if monkeys.insert(name.text.to_string(), yell).is_some() {
+            return Err(name.error(format!("{} is listed twice", name.text)));
+        }
+    }
+    if let Some(field) = refs.iter().find(|field| !monkeys.contains_key(field.text)) {
+        return Err(field.error(format!("no monkey {} is listed", field.text)));
+    }
+    // Bounded in case the monkeys above humn wait on each other in a loop
+    let mut curr = "humn";
+    for _ in 0..monkeys.len() {
+        match parents.get(curr) {
+            Some(parent) => curr = parent,
+            None => break,
+        }
+    }
+    if curr != "root" || !matches!(monkeys.get("root"), Some(Yell::Math(..))) {
+        let line = Line { number: lines.len() + 1, text: "" };
+        return Err(line.error(1, "expected root to be waiting on humn"));
+    }
+    Ok(monkeys)
+}
+
+fn calc_yell(monkeys: &HashMap<String, Yell>, monkey: &str) -> i64 {
+    match &monkeys[monkey] {
+        Yell::Num(num) => *num,
+        Yell::Math(name1, name2, op) => {
+            let val1 = calc_yell(monkeys, name1);
+            let val2 = calc_yell(monkeys, name2);
+            match op {
+                Op::Add => val1 + val2,
+                Op::Sub => val1 - val2,
+                Op::Mul => val1 * val2,
+                Op::Div => {
+                    debug_assert_eq!(val1 % val2, 0);
+                    val1 / val2
+                }
+            }
+        }
+    }
+}
+
+pub fn part1(monkeys: &HashMap<String, Yell>) -> i64 {
+    calc_yell(monkeys, "root")
+}
+
+pub fn part2(monkeys: &HashMap<String, Yell>) -> i64 {
+    fn calc_refs_human(monkeys: &HashMap<String, Yell>, root: &str, memo: &mut HashMap<String, bool>) -> bool {
+        if memo.contains_key(root) { return memo[root] }
+        let ret = match &monkeys[root] {
+            Yell::Num(_) => false,
+            Yell::Math(name1, name2, _) => {
+                // No short circuit to memoize the full tree
+                let refs1 = calc_refs_human(monkeys, name1, memo);
+                let refs2 = calc_refs_human(monkeys, name2, memo);
+                refs1 || refs2
+            }
+        };
+        let ret = ret || root == "humn";
+        memo.insert(root.to_string(), ret);
+        ret
+    }
+
+    let mut refs_human = HashMap::new();
+    calc_refs_human(monkeys, "root", &mut refs_human);
+
+    let Yell::Math(left, right, _) = &monkeys["root"] else { panic!() };
+    let (mut val, mut curr) = if refs_human[left] {
+        (calc_yell(monkeys, right), left)
+    } else {
+        (calc_yell(monkeys, left), right)
+    };
+    while curr != "humn" {
+        debug_assert!(refs_human[curr]);
+        let Yell::Math(left, right, op) = &monkeys[curr] else { panic!() };
+        let other;
+        if refs_human[left] {
+            // val = humn OP other
+            curr = left;
+            other = calc_yell(monkeys, right);
+            match op {
+                Op::Add => val -= other,
+                Op::Sub => val += other,
+                Op::Mul => {
+                    debug_assert_eq!(val % other, 0);
+                    val /= other;
+                },
+                Op::Div => val *= other,
+            }
+        } else {
+            // val = other OP humn
+            curr = right;
+            other = calc_yell(monkeys, left);
+            match op {
+                Op::Add => val -= other,
+                Op::Sub => val = other - val,
+                Op::Mul => {
+                    debug_assert_eq!(val % other, 0);
+                    val /= other;
+                },
+                Op::Div => {
+                    debug_assert_eq!(other % val, 0);
+                    val = other / val;
+                },
+            }
+        }
+    }
+    val
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = HashMap<String, Yell>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> i64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "root: pppw + sjmn
+dbpl: 5
+cczh: sllz + lgvd
+zczc: 2
+ptdq: humn - dvpt
+dvpt: 3
+lfqf: 4
+humn: 5
+ljgn: 2
+sjmn: drzm * dbpl
+sllz: 4
+pppw: cczh / lfqf
+lgvd: ljgn * ptdq
+drzm: hmdt - zczc
+hmdt: 32
+";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 152);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 301);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("root: humn + abcd\nhumn: 5\nabcd: humn * 2").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 7));
+        let err = parse("root: humn + abcd\nhumn: 5\nabcd: efgh * 2").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 7));
+        let err = parse("root: humn % abcd\nhumn: 5\nabcd: 2").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 12));
+        let err = parse("root: abcd + efgh\nhumn: 5\nabcd: 2\nefgh: 3").err().unwrap();
+        assert_eq!((err.line, err.column), (5, 1));
+        let err = parse("root: abcd + efgh\nhumn: abcd + efgh\nabcd: humn * 2\nefgh: 3").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 7));
+        let err = parse("root: abcd + efgh\nhumn: ijkl + mnop\nijkl: humn * qrst\nmnop: 1\nqrst: 2\nabcd: 2\nefgh: 3").err().unwrap();
+        assert_eq!((err.line, err.column), (8, 1));
+    }
+}
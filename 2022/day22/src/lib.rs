@@ -0,0 +1,415 @@
+use std::collections::HashMap;
+use std::ops::{Add, AddAssign};
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+#[derive(Copy, Clone)]
+pub enum Turn {
+    Left, Right
+}
+
+pub enum Step {
+    Walk(usize),
+    Turn(Turn),
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+enum Dir {
+    Up, Down, Left, Right
+}
+
+impl Dir {
+    fn turn(self, turn: Turn) -> Dir {
+        match turn {
+            Turn::Left => match self {
+                Dir::Up => Dir::Left,
+                Dir::Right => Dir::Up,
+                Dir::Down => Dir::Right,
+                Dir::Left => Dir::Down,
+            },
+            Turn::Right => match self {
+                Dir::Up => Dir::Right,
+                Dir::Right => Dir::Down,
+                Dir::Down => Dir::Left,
+                Dir::Left => Dir::Up,
+            },
+        }
+    }
+
+    fn score(self) -> isize {
+        match self {
+            Dir::Right => 0,
+            Dir::Down  => 1,
+            Dir::Left  => 2,
+            Dir::Up    => 3,
+        }
+    }
+
+    fn as_offset(self) -> Pos {
+        match self {
+            Dir::Up    => Pos { x:  0,  y: -1 },
+            Dir::Down  => Pos { x:  0,  y:  1 },
+            Dir::Left  => Pos { x: -1,  y:  0 },
+            Dir::Right => Pos { x:  1,  y:  0 },
+        }
+    }
+
+    fn flip(self) -> Dir {
+        match self {
+            Dir::Up    => Dir::Down,
+            Dir::Down  => Dir::Up,
+            Dir::Left  => Dir::Right,
+            Dir::Right => Dir::Left,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+struct Pos {
+    x: isize,
+    y: isize,
+}
+
+impl Add<Pos> for Pos {
+    type Output = Pos;
+    fn add(self, other: Pos) -> Pos {
+        let mut pos = self;
+        pos += other;
+        pos
+    }
+}
+
+impl AddAssign<Pos> for Pos {
+    fn add_assign(&mut self, other: Pos) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum Tile {
+    Open, Wall
+}
+
+#[derive(Clone)]
+pub struct Grid {
+    initial_pos: Pos,
+    tiles: HashMap<Pos, Tile>,
+    block_size: usize,
+    jumps: HashMap<(Pos, Dir), (Pos, Dir)>,
+}
+
+impl Grid {
+    fn walk_one(&self, pos: Pos, dir: Dir) -> (Pos, Dir) {
+        if let Some((next_pos, next_dir)) = self.jumps.get(&(pos, dir)) {
+            match self.tiles[next_pos] {
+                Tile::Open => (*next_pos, *next_dir),
+                Tile::Wall => (pos, dir),
+            }
+        } else {
+            match self.tiles[&(pos + dir.as_offset())] {
+                Tile::Open => (pos + dir.as_offset(), dir),
+                Tile::Wall => (pos, dir),
+            }
+        }
+    }
+}
+
+fn parse_grid(lines: &[Line]) -> Result<Grid, ParseError> {
+    let mut initial_pos: Option<Pos> = None;
+    let mut tiles = HashMap::new();
+    for (y, line) in lines.iter().enumerate() {
+        let y = y as isize;
+        for (x, ch) in line.text.chars().enumerate() {
+            let pos = Pos { x: x as isize, y };
+            let tile = match ch {
+                ' ' => continue,
+                '#' => Tile::Wall,
+                '.' => Tile::Open,
+                _ => return Err(line.error(x + 1, "expected ' ', '#' or '.'")),
+            };
+            if tile == Tile::Open && initial_pos == None {
+                initial_pos = Some(pos);
+            }
+            tiles.insert(pos, tile);
+        }
+    }
+    let initial_pos = initial_pos.ok_or_else(|| lines[0].error(1, "no open tile to start on"))?;
+    // The map folds into a cube, so it's six square faces
+    let block_size = (1..).find(|size| 6 * size * size >= tiles.len()).unwrap();
+    if 6 * block_size * block_size != tiles.len() {
+        return Err(lines[0].error(1, "the map isn't six square faces"));
+    }
+    Ok(Grid {
+        initial_pos,
+        tiles,
+        block_size,
+        jumps: HashMap::new(),
+    })
+}
+
+fn parse_steps(line: Line) -> Result<Vec<Step>, ParseError> {
+    let mut ret = Vec::new();
+    let mut last_walk: Option<usize> = None;
+    for (i, ch) in line.text.chars().enumerate() {
+        match ch {
+            '0'..='9' => {
+                let num = ch as usize - '0' as usize;
+                let walk = last_walk.unwrap_or(0).checked_mul(10).and_then(|walk| walk.checked_add(num));
+                last_walk = Some(walk.ok_or_else(|| line.error(i + 1, "number too large"))?);
+            },
+            _ => {
+                if let Some(num) = last_walk {
+                    ret.push(Step::Walk(num))
+                }
+                last_walk = None;
+                match ch {
+                    'L' => ret.push(Step::Turn(Turn::Left)),
+                    'R' => ret.push(Step::Turn(Turn::Right)),
+                    _ => return Err(line.error(i + 1, "expected a number, 'L' or 'R'")),
+                }
+            },
+        }
+    }
+    if let Some(num) = last_walk {
+        ret.push(Step::Walk(num));
+    }
+    Ok(ret)
+}
+
+pub fn parse(puzzle_input: &str) -> Result<(Grid, Vec<Step>), ParseError> {
+    let sections = parsing::sections(puzzle_input);
+    let (grid_lines, step_lines) = match &sections[..] {
+        [grid_lines, step_lines] => (grid_lines, step_lines),
+        _ => {
+            let line = sections.get(2).map_or_else(
+                || Line { number: parsing::lines(puzzle_input).last().map_or(1, |line| line.number + 1), text: "" },
+                |section| section[0]);
+            return Err(line.error(1, "expected the map then the path"));
+        }
+    };
+    if let Some(line) = step_lines.get(1) {
+        return Err(line.error(1, "expected the path on one line"));
+    }
+    Ok((parse_grid(grid_lines)?, parse_steps(step_lines[0])?))
+}
+
+fn fill_jumps_part1(grid: &mut Grid) {
+    let height = grid.tiles.keys().map(|pos| pos.y).max().unwrap() + 1;
+    let width = grid.tiles.keys().map(|pos| pos.x).max().unwrap() + 1;
+    for pos in grid.tiles.keys().copied() {
+        for dir in [Dir::Up, Dir::Down, Dir::Left, Dir::Right] {
+            let mut pos2 = pos + dir.as_offset();
+            if grid.tiles.contains_key(&pos2) {
+                continue; // Just a normal step
+            }
+            loop {
+                debug_assert!(pos2 != pos);
+                if grid.tiles.contains_key(&pos2) {
+                    grid.jumps.insert((pos, dir), (pos2, dir));
+                    break;
+                }
+                pos2 += dir.as_offset();
+                if pos2.x >= width { pos2.x = 0 };
+                if pos2.x < 0 { pos2.x = width - 1 };
+                if pos2.y >= height { pos2.y = 0 };
+                if pos2.y < 0 { pos2.y = height - 1 };
+            }
+        }
+    }
+}
+
+fn walk_all(grid: &Grid, steps: &[Step]) -> isize {
+    let mut pos = grid.initial_pos;
+    let mut dir = Dir::Right;
+    for step in steps {
+        match step {
+            Step::Walk(num) => for _ in 0..*num {
+                (pos, dir) = grid.walk_one(pos, dir);
+            },
+            Step::Turn(turn) => dir = dir.turn(*turn),
+        }
+    }
+    1000 * (pos.y + 1) + 4 * (pos.x + 1) + dir.score()
+}
+
+pub fn part1((grid, steps): &(Grid, Vec<Step>)) -> isize {
+    let mut grid = grid.clone();
+    fill_jumps_part1(&mut grid);
+    walk_all(&grid, steps)
+}
+
+type Vec3 = [isize; 3];
+
+fn neg(v: Vec3) -> Vec3 {
+    [-v[0], -v[1], -v[2]]
+}
+
+// Where a face of the net ends up once it's folded, as the directions its outside and its local
+// right and down point in on the cube
+#[derive(Copy, Clone)]
+struct Face {
+    normal: Vec3,
+    right: Vec3,
+    down: Vec3,
+}
+
+impl Face {
+    fn axis(&self, dir: Dir) -> Vec3 {
+        match dir {
+            Dir::Up    => neg(self.down),
+            Dir::Down  => self.down,
+            Dir::Left  => neg(self.right),
+            Dir::Right => self.right,
+        }
+    }
+
+    // The face across the edge in the given direction. Folding it down over the edge points its
+    // outside the way we were going, and carrying on the same way on it means heading back into
+    // the cube.
+    fn fold(&self, dir: Dir) -> Face {
+        let normal = self.axis(dir);
+        let inward = neg(self.normal);
+        match dir {
+            Dir::Up    => Face { normal, right: self.right, down: self.normal },
+            Dir::Down  => Face { normal, right: self.right, down: inward },
+            Dir::Left  => Face { normal, right: self.normal, down: self.down },
+            Dir::Right => Face { normal, right: inward, down: self.down },
+        }
+    }
+
+    // The direction the cells along an edge are listed in by edge_cells
+    fn along(&self, dir: Dir) -> Vec3 {
+        match dir {
+            Dir::Up | Dir::Down => self.right,
+            Dir::Left | Dir::Right => self.down,
+        }
+    }
+}
+
+// The cells along one edge of a block, in increasing x or y
+fn edge_cells(block: Pos, dir: Dir, block_size: usize) -> Vec<Pos> {
+    let size = block_size as isize;
+    let (x, y) = (block.x * size, block.y * size);
+    (0..size).map(|i| match dir {
+        Dir::Up    => Pos { x: x + i, y },
+        Dir::Down  => Pos { x: x + i, y: y + size - 1 },
+        Dir::Left  => Pos { x, y: y + i },
+        Dir::Right => Pos { x: x + size - 1, y: y + i },
+    }).collect()
+}
+
+const DIRS: [Dir; 4] = [Dir::Up, Dir::Down, Dir::Left, Dir::Right];
+
+// Folds the net into a cube by walking from face to face across the edges the net already joins,
+// then glues every loose edge to the one that ends up in the same place on the cube
+fn fill_jumps_part2(grid: &mut Grid) {
+    let size = grid.block_size as isize;
+    let block_of = |pos: Pos| Pos { x: pos.x.div_euclid(size), y: pos.y.div_euclid(size) };
+    let start = block_of(grid.initial_pos);
+    let mut faces = HashMap::new();
+    faces.insert(start, Face { normal: [0, 0, -1], right: [1, 0, 0], down: [0, 1, 0] });
+    let mut stack = vec![start];
+    while let Some(block) = stack.pop() {
+        for dir in DIRS {
+            let next = block + dir.as_offset();
+            let corner = Pos { x: next.x * size, y: next.y * size };
+            if next.x >= 0 && next.y >= 0 && grid.tiles.contains_key(&corner) && !faces.contains_key(&next) {
+                faces.insert(next, faces[&block].fold(dir));
+                stack.push(next);
+            }
+        }
+    }
+    assert_eq!(faces.len(), 6, "the map isn't the net of a cube");
+
+    for (&block, face) in faces.iter() {
+        for dir in DIRS {
+            if faces.contains_key(&(block + dir.as_offset())) {
+                continue;
+            }
+            let (&block2, face2) = faces.iter().find(|(_, face2)| face2.normal == face.axis(dir)).unwrap();
+            // We come in over the edge that's folded against the face we left
+            let dir2 = DIRS.iter().copied().find(|&dir2| face2.axis(dir2) == face.normal).unwrap();
+            let from = edge_cells(block, dir, grid.block_size);
+            let mut to = edge_cells(block2, dir2, grid.block_size);
+            if face.along(dir) != face2.along(dir2) {
+                to.reverse();
+            }
+            for (pos, pos2) in from.into_iter().zip(to) {
+                grid.jumps.insert((pos, dir), (pos2, dir2.flip()));
+            }
+        }
+    }
+}
+
+pub fn part2((grid, steps): &(Grid, Vec<Step>)) -> isize {
+    let mut grid = grid.clone();
+    fill_jumps_part2(&mut grid);
+    walk_all(&grid, steps)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Grid, Vec<Step>);
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> isize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> isize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "        ...#
+        .#..
+        #...
+        ....
+...#.......#
+........#...
+..#....#....
+..........#.
+        ...#....
+        .....#..
+        .#......
+        ......#.
+
+10R5L5R10L4R5L5
+";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 6032);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 5031);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("..\n..\n\n1R2").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 1));
+        let err = parse("......\n\n1X2").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 2));
+        let err = parse("......\n\n1R2\n\nL").err().unwrap();
+        assert_eq!((err.line, err.column), (5, 1));
+        let err = parse("......\n\n1R2\nL").err().unwrap();
+        assert_eq!((err.line, err.column), (4, 1));
+        let err = parse("  .o\n\n1R2").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 4));
+    }
+}
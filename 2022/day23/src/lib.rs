@@ -0,0 +1,194 @@
+use std::collections::HashSet;
+use std::ops::Add;
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+#[derive(Copy, Clone, Hash, Eq, PartialEq)]
+pub struct Pos {
+    x: isize,
+    y: isize,
+}
+
+impl Add<Pos> for Pos {
+    type Output = Pos;
+    fn add(self, other: Pos) -> Pos {
+        Pos { x: self.x + other.x, y: self.y + other.y }
+    }
+}
+
+mod offsets {
+    use super::Pos;
+    pub const NW: Pos = Pos { x: -1, y: -1 };
+    pub const N:  Pos = Pos { x:  0, y: -1 };
+    pub const NE: Pos = Pos { x:  1, y: -1 };
+    pub const W: Pos  = Pos { x: -1, y:  0 };
+    pub const E: Pos  = Pos { x:  1, y:  0 };
+    pub const SW: Pos = Pos { x: -1, y:  1 };
+    pub const S: Pos  = Pos { x:  0, y:  1 };
+    pub const SE: Pos = Pos { x:  1, y:  1 };
+    pub const ALL: [Pos; 8] = [NW, N, NE, W, E, SW, S, SE];
+    pub const ALL_NORTH: [Pos; 3] = [NW, N, NE];
+    pub const ALL_SOUTH: [Pos; 3] = [SW, S, SE];
+    pub const ALL_WEST:  [Pos; 3] = [NW, W, SW];
+    pub const ALL_EAST:  [Pos; 3] = [NE, E, SE];
+}
+
+#[derive(Copy, Clone)]
+enum Dir {
+    North, South, West, East
+}
+
+pub fn parse(puzzle_input: &str) -> Result<HashSet<Pos>, ParseError> {
+    let lines = parsing::lines(puzzle_input);
+    let mut elves = HashSet::new();
+    for (y, line) in lines.iter().enumerate() {
+        for (x, ch) in line.text.chars().enumerate() {
+            match ch {
+                '#' => {
+                    elves.insert(Pos { x: x as isize, y: y as isize });
+                },
+                '.' => (),
+                _ => return Err(line.error(x + 1, "expected '#' or '.'")),
+            }
+        }
+    }
+    if elves.is_empty() {
+        return Err(Line { number: 1, text: "" }.error(1, "no elves on the map"));
+    }
+    Ok(elves)
+}
+
+fn propose(elf: Pos, elves: &HashSet<Pos>, dirs: &[Dir]) -> Pos {
+    if offsets::ALL.iter().all(|pos| !elves.contains(&(elf + *pos))) {
+        return elf;
+    }
+    for dir in dirs {
+        let offsets = match dir {
+            Dir::North => offsets::ALL_NORTH,
+            Dir::South => offsets::ALL_SOUTH,
+            Dir::West  => offsets::ALL_WEST,
+            Dir::East  => offsets::ALL_EAST,
+        };
+        if offsets.iter().all(|pos| !elves.contains(&(elf + *pos))) {
+            let offset = match dir {
+                Dir::North => offsets::N,
+                Dir::South => offsets::S,
+                Dir::West  => offsets::W,
+                Dir::East  => offsets::E,
+            };
+            return elf + offset;
+        }
+    }
+    return elf;
+}
+
+fn step(elves: &mut HashSet<Pos>, dirs: &[Dir]) -> bool {
+    let mut proposed = HashSet::with_capacity(elves.len());
+    let mut overlapping = HashSet::with_capacity(elves.len());
+
+    for elf in elves.iter() {
+        let pos = propose(*elf, elves, dirs);
+        if !proposed.insert(pos) {
+            overlapping.insert(pos);
+        }
+    }
+
+    let mut updated_elves = HashSet::with_capacity(elves.len());
+    for elf in elves.iter() {
+        let mut pos = propose(*elf, elves, dirs);
+        if overlapping.contains(&pos) {
+            pos = *elf;
+        }
+        assert!(updated_elves.insert(pos));
+    }
+
+    let ret = elves != &updated_elves;
+    *elves = updated_elves;
+    ret
+}
+
+fn count_empty(elves: &HashSet<Pos>) -> usize {
+    let xmin = elves.iter().map(|pos| pos.x).min().unwrap();
+    let xmax = elves.iter().map(|pos| pos.x).max().unwrap();
+    let ymin = elves.iter().map(|pos| pos.y).min().unwrap();
+    let ymax = elves.iter().map(|pos| pos.y).max().unwrap();
+    let total = (xmax - xmin + 1) * (ymax - ymin + 1);
+    total as usize - elves.len()
+}
+
+pub fn part1(elves: &HashSet<Pos>) -> usize {
+    let mut elves = elves.clone();
+    let mut dirs = vec![Dir::North, Dir::South, Dir::West, Dir::East];
+    for _ in 0..10 {
+        step(&mut elves, &dirs);
+        let dir = dirs.remove(0);
+        dirs.push(dir);
+    }
+    count_empty(&elves)
+}
+
+pub fn part2(elves: &HashSet<Pos>) -> usize {
+    let mut elves = elves.clone();
+    let mut dirs = vec![Dir::North, Dir::South, Dir::West, Dir::East];
+    for round in 1.. {
+        if !step(&mut elves, &dirs) {
+            return round;
+        }
+        let dir = dirs.remove(0);
+        dirs.push(dir);
+    }
+    unreachable!()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = HashSet<Pos>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "....#..
+..###.#
+#...#.#
+.#...##
+#.###..
+##.#.##
+.#..#..
+";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 110);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 20);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("..#\n.x.").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 2));
+        let err = parse("...\n...").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 1));
+    }
+}
@@ -0,0 +1,133 @@
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+fn snafu_to_decimal(line: Line) -> Result<i64, ParseError> {
+    let digits = line.map_chars(|ch| match ch {
+        '=' => Some(-2),
+        '-' => Some(-1),
+        '0' => Some(0),
+        '1' => Some(1),
+        '2' => Some(2),
+        _ => None,
+    })?;
+    if digits.is_empty() {
+        return Err(line.error(1, "expected a SNAFU number"));
+    }
+    let mut ret: i64 = 0;
+    for (i, n) in digits.into_iter().enumerate() {
+        ret = ret.checked_mul(5).and_then(|ret| ret.checked_add(n))
+            .ok_or_else(|| line.error(i + 1, "number too large"))?;
+    }
+    Ok(ret)
+}
+
+fn decimal_to_snafu(mut n: i64) -> String {
+    let mut reversed = Vec::new();
+    while n != 0 {
+        let ch = match n % 5 {
+            0 => '0',
+            1 => {
+                n -= 1;
+                '1'
+            },
+            2 => {
+                n -= 2;
+                '2'
+            },
+            3 => {
+                n += 2;
+                '='
+            },
+            4 => {
+                n += 1;
+                '-'
+            },
+            _ => unreachable!(),
+        };
+        reversed.push(ch);
+        n /= 5;
+    }
+    let mut ret = String::with_capacity(reversed.len());
+    for ch in reversed.iter().rev() {
+        ret.push(*ch);
+    }
+    ret
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<i64>, ParseError> {
+    parsing::parse_lines(puzzle_input, snafu_to_decimal)
+}
+
+pub fn part1(numbers: &[i64]) -> String {
+    decimal_to_snafu(numbers.iter().sum())
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<i64>;
+    type Answer1 = String;
+    type Answer2 = &'static str;
+
+    const PARTS: usize = 1;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        part1(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+const EX: &str = "1=-0-2
+12111
+2=0=
+21
+2=01
+111
+20012
+112
+1=-1=
+1-12
+12
+1=
+122
+";
+
+    fn test_round_trip(s: &str, n: i64) {
+        assert_eq!(snafu_to_decimal(Line { number: 1, text: s }).unwrap(), n);
+        assert_eq!(&decimal_to_snafu(n), s);
+    }
+
+    #[test]
+    fn test_part1() {
+        test_round_trip("1=-0-2", 1747);
+        test_round_trip("12111", 906);
+        test_round_trip("2=0=", 198);
+        test_round_trip("21", 11);
+        test_round_trip("2=01", 201);
+        test_round_trip("111", 31);
+        test_round_trip("20012", 1257);
+        test_round_trip("112", 32);
+        test_round_trip("1=-1=", 353);
+        test_round_trip("1-12", 107);
+        test_round_trip("12", 7);
+        test_round_trip("1=", 3);
+        test_round_trip("122", 37);
+
+        assert_eq!(part1(&parse(EX).unwrap()), "2=-1=0".to_string());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("1=\n12-3").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 4));
+        let err = parse("1=\n\n12").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
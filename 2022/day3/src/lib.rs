@@ -0,0 +1,112 @@
+use parsing::ParseError;
+use solution::Solution;
+
+fn priority(ch: char) -> Option<u64> {
+    match ch {
+        'a'..='z' => Some((ch as u64 - 'a' as u64) + 1),
+        'A'..='Z' => Some((ch as u64 - 'A' as u64) + 27),
+        _ => None,
+    }
+}
+
+// Each rucksack as the priorities of its items
+pub fn parse(puzzle_input: &str) -> Result<Vec<Vec<u64>>, ParseError> {
+    let rucksacks = parsing::parse_lines(puzzle_input, |line| {
+        let items = line.map_chars(priority)?;
+        if items.len() % 2 != 0 {
+            return Err(line.error(1, "expected the two compartments to be the same size"));
+        }
+        Ok(items)
+    })?;
+    if rucksacks.len() % 3 != 0 {
+        let lines = parsing::lines(puzzle_input);
+        return Err(lines[lines.len() - 1].error(1, "expected groups of three elves"));
+    }
+    Ok(rucksacks)
+}
+
+pub fn part1(rucksacks: &[Vec<u64>]) -> u32 {
+    rucksacks.iter().map(|items| {
+        let compartment_len = items.len() / 2;
+        let mut left = 0u64;
+        let mut right = 0u64;
+        for (i, &priority) in items.iter().enumerate() {
+            if i < compartment_len {
+                left |= 1 << priority;
+            } else {
+                right |= 1 << priority;
+            }
+        }
+        let overlap = left & right;
+        assert_eq!(overlap.count_ones(), 1);
+        overlap.trailing_zeros()
+    }).sum()
+}
+
+pub fn part2(rucksacks: &[Vec<u64>]) -> u32 {
+    let rucksacks = rucksacks.iter().map(|items| {
+        let mut sack = 0u64;
+        for &priority in items {
+            sack |= 1 << priority;
+        }
+        sack
+    }).collect::<Vec<_>>();
+    rucksacks.chunks(3).map(|group| {
+        let overlap = group[0] & group[1] & group[2];
+        assert_eq!(overlap.count_ones(), 1);
+        overlap.trailing_zeros()
+    }).sum()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<u64>>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 157);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 70);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("abca\nab1a\nabab").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 3));
+        let err = parse("abca\nabc\nabab").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("abca\nabab").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
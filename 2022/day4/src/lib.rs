@@ -0,0 +1,88 @@
+use std::ops::RangeInclusive;
+
+use parsing::{Field, ParseError};
+use solution::Solution;
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<[RangeInclusive<u32>; 2]>, ParseError> {
+    let parse_range = |range: Field| -> Result<RangeInclusive<u32>, ParseError> {
+        let (start, end) = range.split_once("-")?;
+        let (start, end) = (start.parse()?, end.parse()?);
+        if start > end {
+            return Err(range.error("expected the range to go from low to high"));
+        }
+        Ok(start ..= end)
+    };
+    parsing::parse_lines(puzzle_input, |line| {
+        let (first, second) = line.as_field().split_once(",")?;
+        Ok([parse_range(first)?, parse_range(second)?])
+    })
+}
+
+fn fully_contains(outer: &RangeInclusive<u32>, inner: &RangeInclusive<u32>) -> bool {
+    outer.start() <= inner.start() && outer.end() >= inner.end()
+}
+
+fn contains(a: &RangeInclusive<u32>, b: &RangeInclusive<u32>) -> bool {
+    a.end() >= b.start() && b.end() >= a.start() 
+}
+
+pub fn part1(elf_pairs: &[[RangeInclusive<u32>; 2]]) -> usize {
+    elf_pairs.iter().filter(|[a, b]| fully_contains(a, b) || fully_contains(b, a)).count()
+}
+
+pub fn part2(elf_pairs: &[[RangeInclusive<u32>; 2]]) -> usize {
+    elf_pairs.iter().filter(|[a, b]| contains(a, b)).count()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<[RangeInclusive<u32>; 2]>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8
+";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 2);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 4);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("2-4,6-8\n2-3;4-5").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("2-4,6-8\n2-3,5-4").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 5));
+        let err = parse("2-4,6-8\n2-3,4-x").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 7));
+    }
+}
@@ -0,0 +1,166 @@
+use parsing::{Line, ParseError};
+use regex::Regex;
+use solution::Solution;
+
+pub struct Move {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+
+pub fn parse(puzzle_input: &str) -> Result<(Vec<Vec<char>>, Vec<Move>), ParseError> {
+    let sections = parsing::sections(puzzle_input);
+    let (stack_lines, move_lines) = match &sections[..] {
+        [stack_lines, move_lines] => (stack_lines, move_lines),
+        _ => {
+            let line = sections.get(2).map_or_else(
+                || Line { number: parsing::lines(puzzle_input).last().map_or(1, |line| line.number + 1), text: "" },
+                |section| section[0]);
+            return Err(line.error(1, "expected the stacks then the moves"));
+        }
+    };
+
+    let (label_line, crate_lines) = stack_lines.split_last().unwrap();
+    let labels = label_line.fields();
+    for (i, label) in labels.iter().enumerate() {
+        if label.text != (i + 1).to_string() || label.column != 4 * i + 2 {
+            return Err(label.error(format!("expected stack {} to be labelled here", i + 1)));
+        }
+    }
+    let mut stacks = vec![Vec::new(); labels.len()];
+    for (height, line) in crate_lines.iter().rev().enumerate() {
+        for (i, ch) in line.text.chars().enumerate() {
+            if i % 4 != 1 { continue }
+            if ch == ' ' { continue }
+            if !ch.is_ascii_uppercase() {
+                return Err(line.error(i + 1, format!("unexpected {:?}", ch)));
+            } else if i / 4 >= stacks.len() {
+                return Err(line.error(i + 1, "expected the crate to be above a stack"));
+            } else if stacks[i / 4].len() != height {
+                return Err(line.error(i + 1, "expected the crate to be on top of another"));
+            }
+            stacks[i / 4].push(ch);
+        }
+    }
+
+    let move_re = Regex::new("^move (\\d+) from (\\d+) to (\\d+)$").unwrap();
+    let mut heights = stacks.iter().map(Vec::len).collect::<Vec<_>>();
+    let moves = move_lines.iter().map(|line| {
+        let cap = move_re.captures(line.text)
+            .ok_or_else(|| line.error(1, "expected \"move N from A to B\""))?;
+        let stack = |s: &str| -> Result<usize, ParseError> {
+            let field = line.field(s);
+            match field.parse::<usize>()? {
+                n @ 1.. if n <= stacks.len() => Ok(n - 1),
+                _ => Err(field.error(format!("expected a stack from 1 to {}", stacks.len()))),
+            }
+        };
+        let m = Move { count: line.field(&cap[1]).parse()?, from: stack(&cap[2])?, to: stack(&cap[3])? };
+        // Both parts move the same number of crates, so the stacks' heights can be checked here
+        if m.count > heights[m.from] {
+            return Err(line.error(1, format!("expected at most {} crates to move", heights[m.from])));
+        }
+        heights[m.from] -= m.count;
+        heights[m.to] += m.count;
+        Ok(m)
+    }).collect::<Result<_, _>>()?;
+
+    Ok((stacks, moves))
+}
+
+fn stack_tops(stacks: &[Vec<char>]) -> String {
+    let mut ret = String::new();
+    for stack in stacks {
+        ret.push(stack[stack.len() - 1]);
+    }
+    ret
+}
+
+pub fn part1(stacks: &[Vec<char>], moves: &[Move]) -> String {
+    let mut stacks = stacks.to_vec();
+    for m in moves {
+        for _ in 0..m.count {
+            let ch = stacks[m.from].pop().unwrap();
+            stacks[m.to].push(ch);
+        }
+    }
+    stack_tops(&stacks)
+}
+
+pub fn part2(stacks: &[Vec<char>], moves: &[Move]) -> String {
+    let mut stacks = stacks.to_vec();
+    for m in moves {
+        for i in 0..m.count {
+            let from_idx = stacks[m.from].len() - m.count + i;
+            let ch = stacks[m.from][from_idx];
+            stacks[m.to].push(ch);
+        }
+        for _ in 0..m.count {
+            stacks[m.from].pop().unwrap();
+        }
+    }
+    stack_tops(&stacks)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<Vec<char>>, Vec<Move>);
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        part1(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        part2(&input.0, &input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+";
+
+    #[test]
+    fn test_part1() {
+        let (stacks, moves) = parse(EX).unwrap();
+        assert_eq!(&part1(&stacks, &moves), "CMZ");
+    }
+
+    #[test]
+    fn test_part2() {
+        let (stacks, moves) = parse(EX).unwrap();
+        assert_eq!(&part2(&stacks, &moves), "MCD");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let stacks = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\n";
+        let err = parse(&format!("{}move 1 from 2 to 4", stacks)).err().unwrap();
+        assert_eq!((err.line, err.column), (6, 18));
+        let err = parse(&format!("{}move 1 from 2 to 1\nmove 4 from 1 to 3", stacks)).err().unwrap();
+        assert_eq!((err.line, err.column), (7, 1));
+        let err = parse(&format!("{}move 1 to 2", stacks)).err().unwrap();
+        assert_eq!((err.line, err.column), (6, 1));
+        let err = parse("    [D]    \n[N]     [P]\n 1   2   3 \n\nmove 1 from 2 to 1").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 6));
+        let err = parse("[D]\n 2 \n\nmove 1 from 1 to 1").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+}
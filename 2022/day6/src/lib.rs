@@ -0,0 +1,88 @@
+use std::collections::{VecDeque, HashSet};
+
+use parsing::ParseError;
+use solution::Solution;
+
+fn is_unique(chunk: &VecDeque<char>) -> bool {
+    let mut set = HashSet::new();
+    for ch in chunk {
+        set.insert(ch);
+    }
+    set.len() == chunk.len()
+}
+
+fn search_distinct_sequence(data: &[char], num_distinct: usize) -> usize {
+    let mut chunk = VecDeque::with_capacity(num_distinct);
+    for (i, &ch) in data.iter().enumerate() {
+        if chunk.len() == num_distinct {
+            chunk.pop_front();
+        }
+        chunk.push_back(ch);
+        if chunk.len() == num_distinct && is_unique(&chunk) {
+            return i + 1;
+        }
+    }
+    panic!()
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<char>, ParseError> {
+    parsing::single_line(puzzle_input)?.map_chars(|ch| Some(ch).filter(char::is_ascii_lowercase))
+}
+
+pub fn part1(data: &[char]) -> usize {
+    search_distinct_sequence(data, 4)
+}
+
+pub fn part2(data: &[char]) -> usize {
+    search_distinct_sequence(data, 14)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse("bvwbjplbgvbhsrlpgdmjqwftvncz").unwrap()), 5);
+        assert_eq!(part1(&parse("nppdvjthqldpwncqszvftbrmjlhg").unwrap()), 6);
+        assert_eq!(part1(&parse("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg").unwrap()), 10);
+        assert_eq!(part1(&parse("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw").unwrap()), 11);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse("mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap()), 19);
+        assert_eq!(part2(&parse("bvwbjplbgvbhsrlpgdmjqwftvncz").unwrap()), 23);
+        assert_eq!(part2(&parse("nppdvjthqldpwncqszvftbrmjlhg").unwrap()), 23);
+        assert_eq!(part2(&parse("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg").unwrap()), 29);
+        assert_eq!(part2(&parse("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw").unwrap()), 26);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("mjqjpq mgbl").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 7));
+        let err = parse("mjqjpqmgbl\nmjqj").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+
+use parsing::ParseError;
+use solution::Solution;
+
+struct DirEnt {
+    is_dir: bool,
+    bytes: u64,
+    parent_idx: usize,
+    children: HashMap<String, usize>, // path from cwd -> idx
+}
+
+impl DirEnt {
+    fn new_dir(parent_idx: usize) -> Self {
+        Self {
+            is_dir: true,
+            bytes: 0,
+            parent_idx,
+            children: HashMap::new(),
+        }
+    }
+    fn new_file(parent_idx: usize, bytes: u64) -> Self {
+        Self {
+            is_dir: false,
+            bytes,
+            parent_idx,
+            children: HashMap::new(),
+        }
+    }
+}
+
+pub struct Fs {
+    entries: Vec<DirEnt>, // Root directory is idx 0
+}
+
+impl Fs {
+    const ROOT: usize = 0;
+
+    fn new() -> Self {
+        Self {
+            entries: vec![DirEnt::new_dir(0)],
+        }
+    }
+
+    fn create_dir(&mut self, cwd: usize, path: &str) {
+        if let None = self.entries[cwd].children.get(path) {
+            let child = self.entries.len();
+            self.entries.push(DirEnt::new_dir(cwd));
+            self.entries[cwd].children.insert(path.to_string(), child);
+        }
+    }
+
+    fn create_file(&mut self, mut cwd: usize, path: &str, bytes: u64) {
+        assert!(self.entries[cwd].children.get(path).is_none());
+        let child = self.entries.len();
+        self.entries.push(DirEnt::new_file(cwd, bytes));
+        self.entries[cwd].children.insert(path.to_string(), child);
+        self.entries[cwd].bytes += bytes;
+        while cwd != Fs::ROOT {
+            cwd = self.entries[cwd].parent_idx;
+            self.entries[cwd].bytes += bytes;
+        }
+    }
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Fs, ParseError> {
+    let mut fs = Fs::new();
+    let mut cwd = Fs::ROOT;
+    const CD_CMD_PREFIX: &str = "$ cd ";
+    const LS_CMD: &str = "$ ls";
+    const LS_DIR_PREFIX: &str = "dir ";
+    let mut ls_mode = false;
+
+    for line in parsing::lines(puzzle_input) {
+        if let Some(path) = line.text.strip_prefix(CD_CMD_PREFIX) {
+            ls_mode = false;
+            match path {
+                "/" => cwd = Fs::ROOT,
+                ".." => cwd = fs.entries[cwd].parent_idx,
+                _ => {
+                    cwd = fs.entries[cwd].children.get(path).copied()
+                        .filter(|&child| fs.entries[child].is_dir)
+                        .ok_or_else(|| line.field(path).error(format!("no directory {:?} has been listed here", path)))?;
+                },
+            }
+        } else if line.text == LS_CMD {
+            ls_mode = true;
+        } else if !ls_mode {
+            return Err(line.error(1, "expected a command"));
+        } else if let Some(path) = line.text.strip_prefix(LS_DIR_PREFIX) {
+            fs.create_dir(cwd, path);
+        } else {
+            let (bytes, path) = line.as_field().split_once(" ")?;
+            if fs.entries[cwd].children.contains_key(path.text) {
+                return Err(path.error(format!("{:?} was already listed here", path.text)));
+            }
+            fs.create_file(cwd, path.text, bytes.parse()?);
+        }
+    }
+    Ok(fs)
+}
+
+pub fn part1(fs: &Fs) -> u64 {
+    fs.entries.iter()
+        .filter(|ent| ent.is_dir && ent.bytes <= 100_000)
+        .map(|ent| ent.bytes)
+        .sum()
+}
+
+pub fn part2(fs: &Fs) -> u64 {
+    let bytes_needed = 30_000_000 - (70_000_000 - fs.entries[Fs::ROOT].bytes);
+    fs.entries.iter()
+        .filter(|ent| ent.is_dir)
+        .map(|ent| ent.bytes)
+        .filter(|&bytes| bytes >= bytes_needed)
+        .min().unwrap()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Fs;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k
+";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 95437);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 24933642);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("$ cd /\n$ ls\ndir a\n$ cd b").err().unwrap();
+        assert_eq!((err.line, err.column), (4, 6));
+        let err = parse("$ cd /\n14848514 b.txt").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("$ cd /\n$ ls\n14848514 b.txt\n$ ls\n14848514 b.txt").err().unwrap();
+        assert_eq!((err.line, err.column), (5, 10));
+        let err = parse("$ cd /\n$ ls\nlots b.txt").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 1));
+    }
+}
@@ -0,0 +1,174 @@
+use std::cmp::max;
+use std::ops::Index;
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+pub struct Grid {
+    len_x: usize,
+    len_y: usize,
+    data: Vec<u8>,
+}
+
+impl Index<(usize, usize)> for Grid {
+    type Output = u8;
+    fn index(&self, (x, y): (usize, usize)) -> &u8 {
+        &self.data[y * self.len_x + x]
+    }
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Grid, ParseError> {
+    let rows = parsing::grid(&parsing::lines(puzzle_input), |ch| ch.to_digit(10).map(|height| height as u8))?;
+    if rows.is_empty() {
+        return Err(Line { number: 1, text: "" }.error(1, "expected a map of the trees"));
+    }
+    let len_y = rows.len();
+    let len_x = rows[0].len();
+    Ok(Grid { len_x, len_y, data: rows.concat() })
+}
+
+pub fn part1(grid: &Grid) -> usize {
+    let mut visible = vec![false; grid.len_x * grid.len_y];
+    let iterate_visibility = |x, y, curr: &mut u8, visible: &mut [bool]| {
+        if grid[(x, y)] > *curr {
+            *curr = grid[(x, y)];
+            visible[y * grid.len_x + x] = true;
+        }
+    };
+    for y in 0..grid.len_y {
+        visible[y * grid.len_x] = true;
+        let mut curr = grid[(0, y)];
+        for x in 1..grid.len_x-1 {
+            iterate_visibility(x, y, &mut curr, &mut visible);
+        }
+
+        visible[y * grid.len_x + grid.len_y - 1] = true;
+        let mut curr = grid[(grid.len_x - 1, y)];
+        for x in (1..grid.len_x - 1).rev() {
+            iterate_visibility(x, y, &mut curr, &mut visible);
+        }
+    }
+    for x in 0..grid.len_x {
+        visible[x] = true;
+        let mut curr = grid[(x, 0)];
+        for y in 1..grid.len_y-1 {
+            iterate_visibility(x, y, &mut curr, &mut visible);
+        }
+
+        visible[(grid.len_y - 1) * grid.len_x + x] = true;
+        let mut curr = grid[(x, grid.len_y - 1)];
+        for y in (1..grid.len_y - 1).rev() {
+            iterate_visibility(x, y, &mut curr, &mut visible);
+        }
+    }
+
+    visible.iter().filter(|&&v| v).count()
+}
+
+fn sight_dist_left(mut x: usize, y: usize, grid: &Grid) -> usize {
+    let height = grid[(x, y)];
+    let mut dist = 0;
+    while x > 0 {
+        x -= 1;
+        dist += 1;
+        if grid[(x, y)] >= height { break }
+    }
+    dist
+}
+
+fn sight_dist_right(mut x: usize, y: usize, grid: &Grid) -> usize {
+    let height = grid[(x, y)];
+    let mut dist = 0;
+    while x < grid.len_x - 1 {
+        x += 1;
+        dist += 1;
+        if grid[(x, y)] >= height { break }
+    }
+    dist
+}
+
+fn sight_dist_up(x: usize, mut y: usize, grid: &Grid) -> usize {
+    let height = grid[(x, y)];
+    let mut dist = 0;
+    while y > 0 {
+        y -= 1;
+        dist += 1;
+        if grid[(x, y)] >= height { break }
+    }
+    dist
+}
+
+fn sight_dist_down(x: usize, mut y: usize, grid: &Grid) -> usize {
+    let height = grid[(x, y)];
+    let mut dist = 0;
+    while y < grid.len_y - 1 {
+        y += 1;
+        dist += 1;
+        if grid[(x, y)] >= height { break }
+    }
+    dist
+}
+
+pub fn part2(grid: &Grid) -> usize {
+    let mut best = 0;
+    for y in 0..grid.len_y {
+        for x in 0..grid.len_x {
+            let curr = sight_dist_left(x, y, grid) *
+                sight_dist_right(x, y, grid) *
+                sight_dist_up(x, y, grid) *
+                sight_dist_down(x, y, grid);
+            best = max(best, curr);
+        }
+    }
+    best
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Grid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "30373
+25512
+65332
+33549
+35390
+";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 21);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 8);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("303\n2a5").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 2));
+        let err = parse("303\n25").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+}
@@ -0,0 +1,146 @@
+use std::cmp::max;
+use std::collections::HashSet;
+
+use parsing::ParseError;
+use solution::Solution;
+
+#[derive(Copy, Clone)]
+enum Dir {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+pub struct Motion {
+    dir: Dir,
+    steps: usize,
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<Motion>, ParseError> {
+    parsing::parse_lines(puzzle_input, |line| {
+        let (dir, steps) = line.as_field().split_once(" ")?;
+        let dir = match dir.text {
+            "U" => Dir::Up,
+            "D" => Dir::Down,
+            "R" => Dir::Right,
+            "L" => Dir::Left,
+            _ => return Err(dir.error("expected U, D, L or R")),
+        };
+        Ok(Motion { dir, steps: steps.parse()? })
+    })
+}
+
+#[derive(Copy, Clone, Hash, Eq, PartialEq)]
+struct Pos {
+    x: isize,
+    y: isize,
+}
+
+impl Pos {
+    const ORIGIN: Pos = Pos { x: 0, y: 0 };
+}
+
+fn step(rope: &mut [Pos], dir: Dir) {
+    match dir {
+        Dir::Up => rope[0].y -= 1,
+        Dir::Down => rope[0].y += 1,
+        Dir::Left => rope[0].x -= 1,
+        Dir::Right => rope[0].x += 1,
+    }
+    // Snap each subsequent knot towards the one just ahead of it
+    for i in 1..rope.len() {
+        let head = rope[i - 1];
+        let tail = &mut rope[i];
+        let dist = max((head.x - tail.x).abs(), (head.y - tail.y).abs());
+        if dist <= 1 {
+            break; // If this knot doesn't move, the remaining knots don't
+        }
+        tail.x += (head.x - tail.x).signum();
+        tail.y += (head.y - tail.y).signum();
+    }
+}
+
+
+fn simulate(motions: &[Motion], mut rope: Vec<Pos>) -> usize {
+    let mut seen = HashSet::new();
+    seen.insert(*rope.last().unwrap());
+    for motion in motions {
+        for _ in 0..motion.steps {
+            step(&mut rope, motion.dir);
+            seen.insert(*rope.last().unwrap());
+        }
+    }
+    seen.len()
+}
+
+pub fn part1(motions: &[Motion]) -> usize {
+    simulate(motions, vec![Pos::ORIGIN; 2])
+}
+
+pub fn part2(motions: &[Motion]) -> usize {
+    simulate(motions, vec![Pos::ORIGIN; 10])
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Motion>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX1: &str = "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2
+";
+    const EX2: &str = "R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20
+";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX1).unwrap()), 13);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX1).unwrap()), 1);
+        assert_eq!(part2(&parse(EX2).unwrap()), 36);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("R 4\nX 4").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("R 4\nU -4").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+}
@@ -0,0 +1,147 @@
+use std::collections::HashSet;
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+pub struct Map {
+    width: usize,
+    height: usize,
+    galaxies: HashSet<(usize, usize)>
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Map, ParseError> {
+    let rows = parsing::grid(&parsing::lines(puzzle_input), |ch| match ch {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    if rows.is_empty() {
+        return Err(Line { number: 1, text: "" }.error(1, "expected an image of the galaxies"));
+    }
+    let height = rows.len();
+    let width = rows[0].len();
+    let mut galaxies = HashSet::new();
+    for (y, row) in rows.iter().enumerate() {
+        for (x, &galaxy) in row.iter().enumerate() {
+            if galaxy {
+                galaxies.insert((x, y));
+            }
+        }
+    }
+    Ok(Map { width, height, galaxies })
+}
+
+fn expand(map: &Map, multiplier: usize) -> Map {
+    let mut galaxies = HashSet::with_capacity(map.galaxies.len());
+    let mut empty_cols = vec![false; map.width];
+    let mut empty_rows = vec![false; map.height];
+    let mut extra_cols = 0;
+    let mut extra_rows = 0;
+    for y in 0..map.height {
+        if (0..map.width).all(|x| !map.galaxies.contains(&(x, y))) {
+            extra_rows += 1;
+            empty_rows[y] = true;
+        }
+    }
+    for x in 0..map.width {
+        if (0..map.height).all(|y| !map.galaxies.contains(&(x, y))) {
+            extra_cols += 1;
+            empty_cols[x] = true;
+        }
+    }
+    let width = map.width + extra_cols * (multiplier - 1);
+    let height = map.height + extra_rows * (multiplier - 1);
+    for &(x, y) in &map.galaxies {
+        let x = x + (0..x).filter(|&x| empty_cols[x]).count() * (multiplier - 1);
+        let y = y + (0..y).filter(|&y| empty_rows[y]).count() * (multiplier - 1);
+        galaxies.insert((x, y));
+    }
+    Map { width, height, galaxies }
+}
+
+fn manhattan_dist(x1: usize, y1: usize, x2: usize, y2: usize) -> u64 {
+    let mut sum = 0;
+    sum += if x1 > x2 { x1 - x2 } else { x2 - x1 };
+    sum += if y1 > y2 { y1 - y2 } else { y2 - y1 };
+    sum as u64
+}
+
+fn sum_all_pairwise_distances(map: &Map) -> u64 {
+    let galaxies: Vec<_> = map.galaxies.iter().cloned().collect();
+    let mut sum = 0;
+    for i in 0..galaxies.len() {
+        for j in (i+1)..galaxies.len() {
+            sum += manhattan_dist(
+                galaxies[i].0, galaxies[i].1,
+                galaxies[j].0, galaxies[j].1);
+        }
+    }
+    sum
+}
+
+pub fn part1(map: &Map) -> u64 {
+    sum_all_pairwise_distances(&expand(map, 2))
+}
+
+pub fn part2(map: &Map) -> u64 {
+    sum_all_pairwise_distances(&expand(map, 1_000_000))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Map;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 374);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            sum_all_pairwise_distances(&expand(&parse(EX).unwrap(), 10)),
+            1030);
+        assert_eq!(
+            sum_all_pairwise_distances(&expand(&parse(EX).unwrap(), 100)),
+            8410);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("...#\n.#.x").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 4));
+        let err = parse("").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 1));
+    }
+}
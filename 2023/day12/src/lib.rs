@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+
+use parsing::ParseError;
+use solution::Solution;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+enum Condition {
+    Damaged,
+    Operational,
+    Unknown,
+}
+
+#[derive(Debug)]
+pub struct ConditionRecord {
+    conditions: Vec<Condition>,
+    contiguous_damaged: Vec<usize>,
+
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<ConditionRecord>, ParseError> {
+    parsing::parse_lines(puzzle_input, |line| {
+        let (conditions, contiguous_damaged) = line.as_field().split_once(" ")?;
+        let conditions = conditions.map_chars(|ch| match ch {
+            '#' => Some(Condition::Damaged),
+            '.' => Some(Condition::Operational),
+            '?' => Some(Condition::Unknown),
+            _ => None,
+        })?;
+        let contiguous_damaged = contiguous_damaged.split(",").iter()
+            .map(|token| token.parse()).collect::<Result<_, _>>()?;
+        Ok(ConditionRecord { conditions, contiguous_damaged })
+    })
+}
+
+fn count_arrangements(record: &ConditionRecord) -> u64 {
+    fn recur(conditions: &mut [Condition], mut expected_contiguous: &[usize], prior_contiguous: usize,
+        memo: &mut HashMap<(Vec<Condition>, Vec<usize>, usize), u64>) -> u64 {
+        if conditions.is_empty() {
+            if prior_contiguous > 0 {
+                if expected_contiguous.is_empty() {
+                    return 0;
+                } else if prior_contiguous != expected_contiguous[0] {
+                    return 0;
+                }
+                expected_contiguous = &expected_contiguous[1..];
+            }
+            return if expected_contiguous.is_empty() { 1 } else { 0 };
+        } else if let Some(ret) = memo.get(&(conditions.to_vec(), expected_contiguous.to_vec(), prior_contiguous)) {
+            return *ret;
+        }
+
+        let ret = match conditions[0] {
+            Condition::Damaged => recur(&mut conditions[1..], expected_contiguous, prior_contiguous + 1, memo),
+            Condition::Operational => {
+                if prior_contiguous > 0 {
+                    if expected_contiguous.is_empty() {
+                        return 0;
+                    } else if prior_contiguous != expected_contiguous[0] {
+                        return 0;
+                    }
+                    expected_contiguous = &expected_contiguous[1..];
+                }
+                recur(&mut conditions[1..], expected_contiguous, 0, memo)
+            },
+            Condition::Unknown => {
+                let mut sum = 0;
+                conditions[0] = Condition::Damaged;
+                sum += recur(conditions, expected_contiguous, prior_contiguous, memo);
+                conditions[0] = Condition::Operational;
+                sum += recur(conditions, expected_contiguous, prior_contiguous, memo);
+                conditions[0] = Condition::Unknown;
+                sum
+            },
+        };
+        memo.insert((conditions.to_vec(), expected_contiguous.to_vec(), prior_contiguous), ret);
+        ret
+    }
+    let mut conditions = record.conditions.clone();
+    let mut memo = HashMap::new();
+    recur(&mut conditions, &record.contiguous_damaged, 0, &mut memo)
+}
+
+// Records are independent, so they're counted in parallel
+pub fn part1(records: &[ConditionRecord]) -> u64 {
+    parallel::map(records, count_arrangements).into_iter().sum()
+}
+
+pub fn part2(records: &[ConditionRecord]) -> u64 {
+    let records: Vec<_> = records.iter().map(|record| {
+        let mut conditions = Vec::with_capacity(5 * record.conditions.len() + 4);
+        let mut contiguous_damaged = Vec::with_capacity(5 * record.contiguous_damaged.len());
+        for i in 0..5 {
+            if i > 0 { conditions.push(Condition::Unknown); }
+            for &c in &record.conditions {
+                conditions.push(c);
+            }
+            for &d in &record.contiguous_damaged {
+                contiguous_damaged.push(d);
+            }
+        }
+        ConditionRecord { conditions, contiguous_damaged }
+    }).collect();
+    parallel::map(&records, count_arrangements).into_iter().sum()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<ConditionRecord>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 21);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 525152);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("???.### 1,1,3\n.??..??...?##.").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("???.#x# 1,1,3").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 6));
+        let err = parse("???.### 1,,3").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 11));
+    }
+}
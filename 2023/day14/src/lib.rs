@@ -0,0 +1,236 @@
+use std::collections::HashMap;
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+enum Tile {
+    RoundRock,
+    CubeRock,
+    Empty,
+}
+
+#[derive(Clone)]
+pub struct Map {
+    width: usize,
+    height: usize,
+    tiles: Vec<Tile>,
+}
+
+impl Map {
+    fn idx(&self, x: usize, y: usize) -> usize {
+        self.width * y + x
+    }
+
+    fn at(&self, x: usize, y: usize) -> Tile {
+        self.tiles[self.idx(x, y)]
+    }
+
+    fn set(&mut self, x: usize, y: usize, tile: Tile) {
+        let i = self.idx(x, y);
+        self.tiles[i] = tile;
+    }
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Map, ParseError> {
+    let rows = parsing::grid(&parsing::lines(puzzle_input), |ch| match ch {
+        'O' => Some(Tile::RoundRock),
+        '#' => Some(Tile::CubeRock),
+        '.' => Some(Tile::Empty),
+        _ => None,
+    })?;
+    if rows.is_empty() {
+        return Err(Line { number: 1, text: "" }.error(1, "expected a map of the platform"));
+    }
+    let height = rows.len();
+    let width = rows[0].len();
+    Ok(Map { width, height, tiles: rows.concat() })
+}
+
+fn shift_rocks_north(map: &mut Map) {
+    for y in 0..map.height {
+        for x in 0..map.width {
+            if let Tile::RoundRock = map.at(x, y) {
+                let mut y2 = y;
+                while y2 > 0 {
+                    if let Tile::Empty = map.at(x, y2-1) {
+                        map.set(x, y2, Tile::Empty);
+                        map.set(x, y2-1, Tile::RoundRock);
+                        y2 -= 1;
+                    } else {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn shift_rocks_south(map: &mut Map) {
+    for y in (0..map.height).rev() {
+        for x in 0..map.width {
+            if let Tile::RoundRock = map.at(x, y) {
+                let mut y2 = y;
+                while y2 < map.height - 1 {
+                    if let Tile::Empty = map.at(x, y2+1) {
+                        map.set(x, y2, Tile::Empty);
+                        map.set(x, y2+1, Tile::RoundRock);
+                        y2 += 1;
+                    } else {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn shift_rocks_west(map: &mut Map) {
+    for y in 0..map.height {
+        for x in 0..map.width {
+            if let Tile::RoundRock = map.at(x, y) {
+                let mut x2 = x;
+                while x2 > 0 {
+                    if let Tile::Empty = map.at(x2-1, y) {
+                        map.set(x2, y, Tile::Empty);
+                        map.set(x2-1, y, Tile::RoundRock);
+                        x2 -= 1;
+                    } else {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn shift_rocks_east(map: &mut Map) {
+    for y in 0..map.height {
+        for x in (0..map.width).rev() {
+            if let Tile::RoundRock = map.at(x, y) {
+                let mut x2 = x;
+                while x2 < map.width - 1 {
+                    if let Tile::Empty = map.at(x2+1, y) {
+                        map.set(x2, y, Tile::Empty);
+                        map.set(x2+1, y, Tile::RoundRock);
+                        x2 += 1;
+                    } else {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn shift_rocks_cycle(map: &mut Map) {
+    shift_rocks_north(map);
+    shift_rocks_west(map);
+    shift_rocks_south(map);
+    shift_rocks_east(map);
+}
+
+#[allow(dead_code)]
+fn print_map(map: &Map) -> String {
+    let mut s = String::new();
+    for y in 0..map.height {
+        if y != 0 { s.push('\n'); }
+        for x in 0..map.width {
+            s.push(match map.at(x, y) {
+                Tile::RoundRock => 'O',
+                Tile::CubeRock => '#',
+                Tile::Empty => '.',
+            });
+        }
+    }
+    s
+}
+
+fn calc_load(map: &Map) -> usize {
+    let mut sum = 0;
+    for y in 0..map.height {
+        for x in 0..map.width {
+            if let Tile::RoundRock = map.at(x, y) {
+                sum += map.height - y;
+            }
+        }
+    }
+    sum
+}
+
+pub fn part1(map: &Map) -> usize {
+    let mut map = map.clone();
+    shift_rocks_north(&mut map);
+    calc_load(&map)
+}
+
+pub fn part2(map: &Map) -> usize {
+    let mut map = map.clone();
+    let mut saved = HashMap::new();
+    const N_ITERS: u32 = 1_000_000_000;
+    for i in 0..N_ITERS {
+        if let Some(prior_iter) = saved.insert(map.tiles.clone(), i) {
+            let j = i + ((N_ITERS - i) / (i - prior_iter) * (i - prior_iter));
+            for _ in j..N_ITERS {
+                shift_rocks_cycle(&mut map);
+            }
+            return calc_load(&map);
+        }
+        shift_rocks_cycle(&mut map);
+    }
+    panic!()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 136);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 64);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("O....#\nO.OO#.\n.....##").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 7));
+        let err = parse("O....#\nO.OX#.").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 4));
+    }
+}
@@ -0,0 +1,126 @@
+use parsing::ParseError;
+use solution::Solution;
+
+enum Op {
+    Remove,
+    Insert(u32),
+}
+
+pub struct Step {
+    text: String,
+    label: String,
+    op: Op,
+}
+
+fn hash(s: &str) -> u32 {
+    let mut curr = 0;
+    for ch in s.chars() {
+        curr += ch as u32;
+        curr *= 17;
+        curr %= 256;
+    }
+    curr
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<Step>, ParseError> {
+    parsing::single_line(puzzle_input)?.split(",").iter().map(|step| {
+        let op_idx = step.text.find(|ch| ch == '-' || ch == '=')
+            .ok_or_else(|| step.error("expected a '-' or '=' operation"))?;
+        let (label, op) = step.text.split_at(op_idx);
+        if label.is_empty() || !label.chars().all(|ch| ch.is_ascii_lowercase()) {
+            return Err(step.error("expected a label of lowercase letters"));
+        }
+        let op = match op {
+            "-" => Op::Remove,
+            _ if op.starts_with('=') => {
+                let focal_len = step.line.field(&op[1..]);
+                match focal_len.parse()? {
+                    focal_len @ 1..=9 => Op::Insert(focal_len),
+                    _ => return Err(focal_len.error("expected a focal length from 1 to 9")),
+                }
+            },
+            _ => return Err(step.line.field(op).error("expected nothing after '-'")),
+        };
+        Ok(Step { text: step.text.to_string(), label: label.to_string(), op })
+    }).collect()
+}
+
+pub fn part1(steps: &[Step]) -> u32 {
+    steps.iter().map(|step| hash(&step.text)).sum()
+}
+
+pub fn part2(steps: &[Step]) -> u32 {
+    let mut boxes = vec![Vec::new(); 256];
+    for step in steps {
+        let label = step.label.as_str();
+        let box_num = hash(label) as usize;
+        match step.op {
+            Op::Remove => {
+                boxes[box_num].retain(|(l, _)| l != &label);
+            },
+            Op::Insert(focal_len) => {
+                if let Some(idx) = boxes[box_num].iter().position(|(l, _)| l == &label) {
+                    boxes[box_num][idx].1 = focal_len;
+                } else {
+                    boxes[box_num].push((label, focal_len));
+                }
+            },
+        }
+    }
+    let mut sum = 0;
+    for (i, b) in boxes.into_iter().enumerate() {
+        for (j, (_label, focal_len)) in b.into_iter().enumerate() {
+            sum += (i as u32 + 1) * (j as u32 + 1) * focal_len;
+        }
+    }
+    sum
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Step>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 1320);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 145);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("rn=1,cm").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 6));
+        let err = parse("rn=1,cm=0").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 9));
+        let err = parse("rn=1,cm-2").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 8));
+        let err = parse("rn=1,=2").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 6));
+    }
+}
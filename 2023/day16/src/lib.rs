@@ -0,0 +1,192 @@
+use std::cmp::max;
+use std::collections::{HashSet, VecDeque};
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+#[derive(Copy, Clone)]
+enum Tile {
+    Empty, // .
+    ForwardSlashMirror, // /
+    BackslashMirror, // \
+    VerticalSplitter, // |
+    HorizontalSplitter, // -
+}
+
+#[derive(Copy, Clone, Hash, Eq, PartialEq)]
+enum Dir {
+    Left, Right, Up, Down
+}
+
+pub struct Map {
+    width: usize,
+    height: usize,
+    tiles: Vec<Tile>,
+}
+
+impl Map {
+    fn idx(&self, x: usize, y: usize) -> usize {
+        y * self.width + x
+    }
+
+    fn at(&self, x: usize, y: usize) -> Tile {
+        self.tiles[self.idx(x, y)]
+    }
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Map, ParseError> {
+    let rows = parsing::grid(&parsing::lines(puzzle_input), |ch| match ch {
+        '.' => Some(Tile::Empty),
+        '/' => Some(Tile::ForwardSlashMirror),
+        '\\' => Some(Tile::BackslashMirror),
+        '|' => Some(Tile::VerticalSplitter),
+        '-' => Some(Tile::HorizontalSplitter),
+        _ => None,
+    })?;
+    if rows.is_empty() {
+        return Err(Line { number: 1, text: "" }.error(1, "expected a map of the contraption"));
+    }
+    let height = rows.len();
+    let width = rows[0].len();
+    Ok(Map { width, height, tiles: rows.concat() })
+}
+
+fn next_beams(map: &Map, x: usize, y: usize, dir: Dir) -> Vec<(usize, usize, Dir)> {
+    let mut candidate_dirs = Vec::new();
+    match map.at(x, y) {
+        Tile::Empty => {
+            candidate_dirs.push(dir);
+        },
+        Tile::ForwardSlashMirror => { // /
+            candidate_dirs.push(match dir {
+                Dir::Left => Dir::Down,
+                Dir::Right => Dir::Up,
+                Dir::Up => Dir::Right,
+                Dir::Down => Dir::Left,
+            });
+        },
+        Tile::BackslashMirror => { // \
+            candidate_dirs.push(match dir {
+                Dir::Left => Dir::Up,
+                Dir::Right => Dir::Down,
+                Dir::Up => Dir::Left,
+                Dir::Down => Dir::Right,
+            });
+        },
+        Tile::VerticalSplitter => { // |
+            match dir {
+                Dir::Up | Dir::Down => candidate_dirs.push(dir),
+                Dir::Left | Dir::Right => {
+                    candidate_dirs.push(Dir::Up);
+                    candidate_dirs.push(Dir::Down);
+                }
+            };
+        },
+        Tile::HorizontalSplitter => { // -
+            match dir {
+                Dir::Left | Dir::Right => candidate_dirs.push(dir),
+                Dir::Up | Dir::Down => {
+                    candidate_dirs.push(Dir::Left);
+                    candidate_dirs.push(Dir::Right);
+                }
+            };
+        },
+    };
+    let mut ret = Vec::new();
+    for dir in candidate_dirs {
+        match dir {
+            Dir::Up if y > 0 => ret.push((x, y-1, dir)),
+            Dir::Down if y < map.height - 1 => ret.push((x, y+1, dir)),
+            Dir::Left if x > 0 => ret.push((x-1, y, dir)),
+            Dir::Right if x < map.width - 1 => ret.push((x+1, y, dir)),
+            _ => (),
+        };
+    }
+    ret
+}
+
+fn count_energized(map: &Map, x: usize, y: usize, dir: Dir) -> usize {
+    let mut energized = HashSet::new();
+    let mut seen = HashSet::new();
+    let mut deque = VecDeque::new();
+    deque.push_back((x, y, dir));
+    while let Some((x, y, dir)) = deque.pop_front() {
+        if !seen.insert((x, y, dir)) { continue }
+        energized.insert((x, y));
+        for beam in next_beams(map, x, y, dir) {
+            deque.push_back(beam);
+        }
+    }
+    energized.len()
+}
+
+pub fn part1(map: &Map) -> usize {
+    count_energized(map, 0, 0, Dir::Right)
+}
+
+pub fn part2(map: &Map) -> usize {
+    let mut best = 0;
+    for y in 0..map.height {
+        best = max(best, count_energized(map, 0, y, Dir::Right));
+        best = max(best, count_energized(map, map.width - 1, y, Dir::Left));
+    }
+    for x in 0..map.width {
+        best = max(best, count_energized(map, x, 0, Dir::Down));
+        best = max(best, count_energized(map, x, map.height - 1, Dir::Up));
+    }
+    best
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 46);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 51);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse(".|...\\\n|.-.").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 5));
+        let err = parse(".|..x\\\n|.-.\\.").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 5));
+    }
+}
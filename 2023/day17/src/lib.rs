@@ -0,0 +1,174 @@
+use std::cmp::Reverse;
+use std::collections::{HashSet, BinaryHeap};
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+enum Dir {
+    Up, Down, Left, Right
+}
+
+impl Dir {
+    fn rotate_left(self) -> Dir {
+        match self {
+            Dir::Up => Dir::Left,
+            Dir::Down => Dir::Right,
+            Dir::Left => Dir::Down,
+            Dir::Right => Dir::Up,
+        }
+    }
+    fn rotate_right(self) -> Dir {
+        match self {
+            Dir::Up => Dir::Right,
+            Dir::Down => Dir::Left,
+            Dir::Left => Dir::Up,
+            Dir::Right => Dir::Down,
+        }
+    }
+}
+
+
+pub struct Map {
+    width: usize,
+    height: usize,
+    tiles: Vec<u32>,
+}
+
+impl Map {
+    fn idx(&self, x: usize, y: usize) -> usize {
+        self.width * y + x
+    }
+
+    fn at(&self, x: usize, y: usize) -> u32 {
+        self.tiles[self.idx(x, y)]
+    }
+
+    fn can_step(&self, x: usize, y: usize, dir: Dir) -> bool {
+        match dir {
+            Dir::Up => y > 0,
+            Dir::Down => y < self.height - 1,
+            Dir::Left => x > 0,
+            Dir::Right => x < self.width - 1,
+        }
+    }
+
+    fn step(&self, x: usize, y: usize, dir: Dir) -> (usize, usize) {
+        debug_assert!(self.can_step(x, y, dir));
+        match dir {
+            Dir::Up => (x, y-1),
+            Dir::Down => (x, y+1),
+            Dir::Left => (x-1, y),
+            Dir::Right => (x+1, y),
+        }
+    }
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Map, ParseError> {
+    let rows = parsing::grid(&parsing::lines(puzzle_input), |ch| ch.to_digit(10))?;
+    if rows.is_empty() {
+        return Err(Line { number: 1, text: "" }.error(1, "expected a map of the heat loss"));
+    }
+    let height = rows.len();
+    let width = rows[0].len();
+    Ok(Map { width, height, tiles: rows.concat() })
+}
+
+fn find_best_path(map: &Map, min_consec: usize, max_consec: usize) -> u32 {
+    let mut queue = BinaryHeap::new();
+    queue.push(Reverse((0, 0, 0, Dir::Down, 0)));
+    queue.push(Reverse((0, 0, 0, Dir::Right, 0)));
+    let mut seen = HashSet::new();
+    while let Some(Reverse((heat_lost, x, y, dir, consec))) = queue.pop() {
+        if !seen.insert((x, y, dir, consec)) {
+            continue;
+        }
+        if x == map.width - 1 && y == map.height - 1 {
+            return heat_lost;
+        }
+        let mut dirs = Vec::new();
+        if consec < max_consec { dirs.push(dir); }
+        if consec >= min_consec {
+            dirs.push(dir.rotate_right());
+            dirs.push(dir.rotate_left());
+        }
+        for next_dir in dirs {
+            if !map.can_step(x, y, next_dir) { continue }
+            let next_consec = if dir == next_dir { consec + 1 } else { 1 };
+            let (next_x, next_y) = map.step(x, y, next_dir);
+            queue.push(Reverse((
+                        heat_lost + map.at(next_x, next_y),
+                        next_x,
+                        next_y,
+                        next_dir,
+                        next_consec)));
+        }
+    }
+    panic!()
+}
+
+pub fn part1(map: &Map) -> u32 {
+    find_best_path(map, 0, 3)
+}
+
+pub fn part2(map: &Map) -> u32 {
+    find_best_path(map, 4, 10)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Map;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 102);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 94);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("2413\n32x5").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 3));
+        let err = parse("2413\n325").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 4));
+    }
+}
@@ -0,0 +1,313 @@
+use std::collections::HashMap;
+use std::cmp::{min, max};
+use std::ops::Range;
+
+use parsing::{Field, Line, ParseError};
+use regex::Regex;
+use solution::Solution;
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Category {
+    X, M, A, S,
+}
+
+#[derive(Copy, Clone)]
+enum Op {
+    LT, GT,
+}
+
+enum Outcome {
+    Accept, Reject, To(String),
+}
+
+struct Condition {
+    category: Category,
+    op: Op,
+    val: u32,
+
+}
+
+pub struct Workflow {
+    rules: Vec<(Condition, Outcome)>,
+    default: Outcome,
+}
+
+type Part = HashMap<Category, u32>;
+
+// Part 2 counts the combinations of ratings from 1 to this
+const MAX_RATING: u32 = 4000;
+
+fn parse_condition(field: Field) -> Result<Condition, ParseError> {
+    let mut chars = field.text.chars();
+    let category = match chars.next() {
+        Some('x') => Category::X,
+        Some('m') => Category::M,
+        Some('a') => Category::A,
+        Some('s') => Category::S,
+        _ => return Err(field.error("expected x, m, a or s")),
+    };
+    let op = match chars.next() {
+        Some('<') => Op::LT,
+        Some('>') => Op::GT,
+        _ => return Err(field.line.error(field.column + 1, "expected '<' or '>'")),
+    };
+    let val = field.line.field(chars.as_str());
+    match val.parse()? {
+        val @ 1..=MAX_RATING => Ok(Condition { category, op, val }),
+        _ => Err(val.error(format!("expected a rating from 1 to {}", MAX_RATING))),
+    }
+}
+
+fn parse_outcome<'a>(field: Field<'a>, targets: &mut Vec<Field<'a>>) -> Outcome {
+    match field.text {
+        "A" => Outcome::Accept,
+        "R" => Outcome::Reject,
+        _ => {
+            targets.push(field);
+            Outcome::To(field.text.to_string())
+        }
+    }
+}
+
+fn parse_workflow<'a>(line: Line<'a>, targets: &mut Vec<Field<'a>>) -> Result<(String, Workflow), ParseError> {
+    let (name, rules) = line.as_field().split_once("{")?;
+    let rules = rules.text.strip_suffix('}').ok_or_else(|| rules.error("expected the rules to end with '}'"))?;
+    let mut rules = line.field(rules).split(",");
+    let default = parse_outcome(rules.pop().unwrap(), targets);
+    let rules = rules.into_iter().map(|rule| {
+        let (condition, outcome) = rule.split_once(":")?;
+        Ok((parse_condition(condition)?, parse_outcome(outcome, targets)))
+    }).collect::<Result<_, ParseError>>()?;
+
+    Ok((name.text.to_string(), Workflow { rules, default }))
+}
+
+fn parse_workflows(lines: &[Line]) -> Result<HashMap<String, Workflow>, ParseError> {
+    let mut workflows = HashMap::new();
+    let mut targets = Vec::new();
+    for line in lines {
+        let (name, workflow) = parse_workflow(*line, &mut targets)?;
+        if workflows.insert(name, workflow).is_some() {
+            return Err(line.error(1, "there's already a workflow with this name"));
+        }
+    }
+    if let Some(target) = targets.iter().find(|target| !workflows.contains_key(target.text)) {
+        return Err(target.error(format!("there's no workflow {}", target.text)));
+    }
+    if !workflows.contains_key("in") {
+        return Err(lines[0].error(1, "there's no workflow \"in\""));
+    }
+    Ok(workflows)
+}
+
+fn parse_parts(lines: &[Line]) -> Result<Vec<Part>, ParseError> {
+    let re = Regex::new(r"^\{x=(\d+),m=(\d+),a=(\d+),s=(\d+)\}$").unwrap();
+    lines.iter().map(|line| {
+        let cap = re.captures(line.text).ok_or_else(|| line.error(1, "expected \"{x=...,m=...,a=...,s=...}\""))?;
+        let rating = |i| line.field(cap.get(i).unwrap().as_str()).parse();
+        let mut part = HashMap::new();
+        part.insert(Category::X, rating(1)?);
+        part.insert(Category::M, rating(2)?);
+        part.insert(Category::A, rating(3)?);
+        part.insert(Category::S, rating(4)?);
+        Ok(part)
+    }).collect()
+}
+
+pub fn parse(puzzle_input: &str) -> Result<(HashMap<String, Workflow>, Vec<Part>), ParseError> {
+    let sections = parsing::sections(puzzle_input);
+    let (workflow_lines, part_lines) = match &sections[..] {
+        [workflow_lines, part_lines] => (workflow_lines, part_lines),
+        _ => {
+            let line = sections.get(2).map_or_else(
+                || Line { number: parsing::lines(puzzle_input).last().map_or(1, |line| line.number + 1), text: "" },
+                |section| section[0]);
+            return Err(line.error(1, "expected the workflows then the parts"));
+        }
+    };
+    Ok((parse_workflows(workflow_lines)?, parse_parts(part_lines)?))
+}
+
+fn workflow_accepts(workflows: &HashMap<String, Workflow>, part: &Part) -> bool {
+    let mut curr_name = "in".to_string();
+    'outer: loop {
+        let workflow = &workflows[&curr_name];
+        for (condition, outcome) in &workflow.rules {
+            let part_val = part[&condition.category];
+            let matches_rule = match condition.op {
+                Op::LT => part_val < condition.val,
+                Op::GT => part_val > condition.val,
+            };
+            if matches_rule {
+                match outcome {
+                    Outcome::Accept => return true,
+                    Outcome::Reject => return false,
+                    Outcome::To(name) => {
+                        curr_name = name.to_string();
+                        continue 'outer;
+                    }
+                }
+            }
+        }
+        match &workflow.default {
+            Outcome::Accept => return true,
+            Outcome::Reject => return false,
+            Outcome::To(name) => curr_name = name.to_string(),
+        }
+    }
+}
+
+pub fn part1(workflows: &HashMap<String, Workflow>, parts: &[Part]) -> u32 {
+    let mut sum = 0;
+    for part in parts {
+        if workflow_accepts(workflows, part) {
+            sum += part[&Category::X];
+            sum += part[&Category::M];
+            sum += part[&Category::A];
+            sum += part[&Category::S];
+        }
+    }
+    sum
+}
+
+// Returns (matching, not_matching)
+fn split_range(range: Range<u32>, op: Op, val: u32) -> (Range<u32>, Range<u32>) {
+    match op {
+        Op::LT => (
+            range.start..min(range.end, val),
+            max(range.start, val)..range.end
+        ),
+        Op::GT => (
+            max(range.start, val+1)..range.end,
+            range.start..min(range.end, val+1)
+        ),
+    }
+}
+
+pub fn part2(workflows: &HashMap<String, Workflow>) -> u64 {
+    let mut pending = Vec::new();
+    pending.push({
+        let mut combo = HashMap::new();
+        combo.insert(Category::X, 1..MAX_RATING + 1);
+        combo.insert(Category::M, 1..MAX_RATING + 1);
+        combo.insert(Category::A, 1..MAX_RATING + 1);
+        combo.insert(Category::S, 1..MAX_RATING + 1);
+        (combo, "in".to_string())
+    });
+    let mut cnt = 0u64;
+    'outer: while let Some((mut combo, name)) = pending.pop() {
+        let workflow = &workflows[&name];
+        for (condition, outcome) in &workflow.rules {
+            let part_range = combo[&condition.category].clone();
+            let (matching_range, non_matching_range) =
+                split_range(part_range, condition.op, condition.val);
+            if !matching_range.is_empty() {
+                let mut matching_combo = combo.clone();
+                matching_combo.insert(condition.category, matching_range);
+                match outcome {
+                    Outcome::Accept => {
+                        let mut product = 1;
+                        for r in matching_combo.values() {
+                            product *= (r.end - r.start) as u64;
+                        }
+                        cnt += product;
+                    }
+                    Outcome::Reject => (),
+                    Outcome::To(name) => {
+                        pending.push((matching_combo, name.to_string()));
+                    }
+                }
+            }
+            if non_matching_range.is_empty() {
+                continue 'outer;
+            } else {
+                combo.insert(condition.category, non_matching_range);
+            }
+        }
+        match &workflow.default {
+            Outcome::Accept => {
+                let mut product = 1;
+                for r in combo.values() {
+                    product *= (r.end - r.start) as u64;
+                }
+                cnt += product;
+            }
+            Outcome::Reject => (),
+            Outcome::To(name) => {
+                pending.push((combo, name.to_string()));
+            }
+        }
+    }
+    cnt
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (HashMap<String, Workflow>, Vec<Part>);
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        part1(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(&input.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}";
+
+    #[test]
+    fn test_part1() {
+        let (workflows, parts) = parse(EX).unwrap();
+        assert_eq!(part1(&workflows, &parts), 19114);
+    }
+
+    #[test]
+    fn test_part2() {
+        let (workflows, _parts) = parse(EX).unwrap();
+        assert_eq!(part2(&workflows), 167409079868000);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("in{a<2006:qkq,R}\nqkq{x<1416:A,crn}\n\n{x=787,m=2655,a=1222,s=2876}").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 14));
+        let err = parse("in{a<2006:A,R}\n\n{x=787,m=2655,a=1222}").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 1));
+        let err = parse("in{a=2006:A,R}\n\n{x=787,m=2655,a=1222,s=2876}").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 5));
+        let err = parse("in{a<5000:A,R}\n\n{x=787,m=2655,a=1222,s=2876}").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 6));
+        let err = parse("px{a<2006:A,R}\n\n{x=787,m=2655,a=1222,s=2876}").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 1));
+        let err = parse("in{a<2006:A,R\n\n{x=787,m=2655,a=1222,s=2876}").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 4));
+    }
+}
@@ -0,0 +1,137 @@
+use std::cmp::max;
+use std::collections::HashMap;
+
+use parsing::{Field, ParseError};
+use solution::Solution;
+
+#[derive(Hash, PartialEq, Eq, Copy, Clone)]
+pub enum Color {
+    RED,
+    GREEN,
+    BLUE
+}
+
+impl Color {
+    fn parse(field: Field) -> Result<Color, ParseError> {
+        match field.text {
+            "red" => Ok(Color::RED),
+            "green" => Ok(Color::GREEN),
+            "blue" => Ok(Color::BLUE),
+            _ => Err(field.error("expected red, green or blue")),
+        }
+    }
+}
+
+pub type Game = Vec<HashMap<Color, usize>>;
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<Game>, ParseError> {
+    parsing::parse_lines(puzzle_input, |line| {
+        let (id, sets) = line.as_field().strip_prefix("Game ")?.split_once(": ")?;
+        // Part 1 adds up the games' positions, so they'd better be their IDs
+        if id.parse::<usize>()? != line.number {
+            return Err(id.error(format!("expected game {}", line.number)));
+        }
+        sets.split(";").iter().map(|set| {
+            set.split(",").iter().map(|cube| {
+                let (num, color) = cube.trim().split_once(" ")?;
+                Ok((Color::parse(color)?, num.parse()?))
+            }).collect()
+        }).collect()
+    })
+}
+
+pub fn part1(games: &[Game]) -> usize {
+    let mut sum = 0;
+    let mut max_cubes = HashMap::new();
+    max_cubes.insert(Color::RED, 12);
+    max_cubes.insert(Color::GREEN, 13);
+    max_cubes.insert(Color::BLUE, 14);
+    let max_cubes = max_cubes;
+
+    for (i, game) in games.iter().enumerate() {
+        let mut possible = true;
+        'set: for set in game {
+            for (color, num) in set {
+                if num > &max_cubes[color] {
+                    possible = false;
+                    break 'set;
+                }
+            }
+        }
+        if possible {
+            sum += i + 1;
+        }
+    }
+    sum
+}
+
+pub fn part2(games: &[Game]) -> usize {
+    let mut sum = 0;
+    for game in games {
+        let mut max_cubes = HashMap::new();
+        for set in game {
+            for (color, num) in set {
+                max_cubes.entry(color)
+                    .and_modify(|max_num| *max_num = max(*max_num, *num))
+                    .or_insert(*num);
+            }
+        }
+        let mut pow = 1;
+        for (_, num) in max_cubes {
+            pow *= num;
+        }
+        sum += pow;
+    }
+    sum
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Game>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 8);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 2286);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("Game 1: 3 blue\nGame 3: 4 red").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 6));
+        let err = parse("Game 1: 3 blue; 2 purple").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 19));
+        let err = parse("Game 1: 3 blue, x red").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 17));
+    }
+}
@@ -0,0 +1,195 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use num::integer::lcm;
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+#[derive(Clone, Debug)]
+enum ModuleType {
+    Broadcaster,
+    FlipFlop { state: bool },
+    Conjunction { inputs_memory: HashMap<String, bool> },
+}
+
+#[derive(Clone, Debug)]
+pub struct Module {
+    module_type: ModuleType,
+    outputs: Vec<String>,
+}
+
+pub fn parse(puzzle_input: &str) -> Result<HashMap<String, Module>, ParseError> {
+    let mut modules = HashMap::new();
+    for line in parsing::lines(puzzle_input) {
+        let (name, outputs) = line.as_field().split_once(" -> ")?;
+        let (name, module_type) = if let Some(name) = name.text.strip_prefix('%') {
+            (name, ModuleType::FlipFlop { state: false })
+        } else if let Some(name) = name.text.strip_prefix('&') {
+            (name, ModuleType::Conjunction { inputs_memory: HashMap::new() })
+        } else if name.text == "broadcaster" {
+            (name.text, ModuleType::Broadcaster)
+        } else {
+            return Err(name.error("expected a flip-flop, a conjunction or the broadcaster"));
+        };
+        let outputs = outputs.split(", ").iter().map(|out| out.text.to_string()).collect();
+        if modules.insert(name.to_string(), Module { module_type, outputs }).is_some() {
+            return Err(line.error(1, format!("there's already a module {}", name)));
+        }
+    }
+    if !modules.contains_key("broadcaster") {
+        return Err(Line { number: 1, text: "" }.error(1, "there's no broadcaster"));
+    }
+    for (from, from_module) in modules.clone() {
+        for to in from_module.outputs {
+            if let Some(ref mut to_module) = modules.get_mut(&to) {
+                if let ModuleType::Conjunction { inputs_memory } = &mut to_module.module_type {
+                    inputs_memory.insert(from.to_string(), false);
+                }
+            }
+        }
+    }
+    Ok(modules)
+}
+
+pub fn part1(modules: &HashMap<String, Module>) -> u64 {
+    let mut modules = modules.clone();
+    fn broadcast_outputs(from: &str, outputs: &[String], is_high: bool, pulses: &mut VecDeque<(String, String, bool)>) {
+        for output in outputs {
+            pulses.push_back((from.to_string(), output.to_string(), is_high));
+        }
+    }
+    let mut cnt_high = 0;
+    let mut cnt_low = 0;
+    for _ in 0..1000 {
+        let mut pulses = VecDeque::new();
+        pulses.push_back(("button".to_string(), "broadcaster".to_string(), false));
+        while let Some((from, to, is_high)) = pulses.pop_front() {
+            if is_high { cnt_high += 1 } else { cnt_low += 1 };
+            if let Some(ref mut module) = modules.get_mut(&to) {
+                match &mut module.module_type {
+                    ModuleType::Broadcaster =>
+                        broadcast_outputs(&to, &module.outputs, is_high, &mut pulses),
+                        ModuleType::FlipFlop { state } => {
+                            if !is_high {
+                                *state = !*state;
+                                broadcast_outputs(&to, &module.outputs, *state, &mut pulses);
+                            }
+                        },
+                        ModuleType::Conjunction { ref mut inputs_memory } => {
+                            inputs_memory.insert(from.clone(), is_high);
+                            broadcast_outputs(&to, &module.outputs, !inputs_memory.values().all(|v| *v), &mut pulses);
+                        }
+                };
+            }
+        }
+    }
+    cnt_high * cnt_low
+}
+
+fn get_inputs_to(modules: &HashMap<String, Module>, to: &str) -> HashSet<String> {
+    let mut ret = HashSet::new();
+    for (name, module) in modules {
+        if module.outputs.iter().any(|e| e == to) {
+            ret.insert(name.to_string());
+        }
+    }
+    ret
+}
+
+pub fn part2(modules: &HashMap<String, Module>) -> u64 {
+    let mut modules = modules.clone();
+    fn broadcast_outputs(from: &str, outputs: &[String], is_high: bool, pulses: &mut VecDeque<(String, String, bool)>) {
+        for output in outputs {
+            pulses.push_back((from.to_string(), output.to_string(), is_high));
+        }
+    }
+
+    // There's a weird structure to the input where several cycles of different length feed into a
+    // single conjunction which feeds into the output...
+    let conjunction_name = get_inputs_to(&modules, "rx");
+    assert_eq!(conjunction_name.len(), 1);
+    let conjunction_name = conjunction_name.into_iter().next().unwrap();
+    assert!(matches!(modules[&conjunction_name].module_type, ModuleType::Conjunction { .. }));
+    let conjunction_inputs = get_inputs_to(&modules, &conjunction_name);
+    let mut cycles = HashMap::new();
+
+    for i in 1.. {
+        if cycles.len() == conjunction_inputs.len() { break }
+        let mut pulses = VecDeque::new();
+        pulses.push_back(("button".to_string(), "broadcaster".to_string(), false));
+        while let Some((from, to, is_high)) = pulses.pop_front() {
+            if conjunction_name == to && is_high { cycles.entry(from.to_string()).or_insert(i); }
+            if let Some(ref mut module) = modules.get_mut(&to) {
+                match &mut module.module_type {
+                    ModuleType::Broadcaster =>
+                        broadcast_outputs(&to, &module.outputs, is_high, &mut pulses),
+                        ModuleType::FlipFlop { state } => {
+                            if !is_high {
+                                *state = !*state;
+                                broadcast_outputs(&to, &module.outputs, *state, &mut pulses);
+                            }
+                        },
+                        ModuleType::Conjunction { ref mut inputs_memory } => {
+                            inputs_memory.insert(from.clone(), is_high);
+                            broadcast_outputs(&to, &module.outputs, !inputs_memory.values().all(|v| *v), &mut pulses);
+                        }
+                };
+            }
+        }
+    }
+    cycles.values().cloned().reduce(lcm).unwrap()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = HashMap<String, Module>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX1: &str = "broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a";
+
+    const EX2: &str = "broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX1).unwrap()), 32000000);
+        assert_eq!(part1(&parse(EX2).unwrap()), 11687500);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("broadcaster -> a\n%a -> b\n#b -> a").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 1));
+        let err = parse("broadcaster -> a\n%a -> b\n&a -> b").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 1));
+        let err = parse("%a -> b\n&b -> a").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 1));
+        let err = parse("broadcaster -> a\n%a b").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
@@ -0,0 +1,260 @@
+use std::collections::{HashMap, VecDeque};
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+struct Pos {
+    x: usize,
+    y: usize,
+}
+
+impl Pos {
+    fn new(x: usize, y: usize) -> Pos {
+        Pos { x, y }
+    }
+}
+
+pub struct Map {
+    width: usize,
+    height: usize,
+    start: Pos,
+    rocks: Vec<bool>,
+}
+
+impl Map {
+    fn idx(&self, pos: Pos) -> usize {
+        self.width * pos.y + pos.x
+    }
+
+    fn is_rock(&self, pos: Pos) -> bool {
+        self.rocks[self.idx(pos)]
+    }
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Map, ParseError> {
+    let lines = parsing::lines(puzzle_input);
+    let rows = parsing::grid(&lines, |ch| match ch {
+        '#' | '.' | 'S' => Some(ch),
+        _ => None,
+    })?;
+    let mut start = None;
+    let mut rocks = Vec::new();
+    for (y, (row, line)) in rows.iter().zip(&lines).enumerate() {
+        for (x, &ch) in row.iter().enumerate() {
+            if ch == 'S' && start.replace(Pos::new(x, y)).is_some() {
+                return Err(line.error(x + 1, "there's already a starting position"));
+            }
+            rocks.push(ch == '#');
+        }
+    }
+    let start = start.ok_or_else(|| Line { number: 1, text: "" }.error(1, "no starting position"))?;
+    let height = rows.len();
+    let width = rows[0].len();
+    Ok(Map { width, height, start, rocks })
+}
+
+fn find_shortest_paths(map: &Map, start: Pos) -> HashMap<Pos, usize> {
+    let mut shortest_paths = HashMap::new();
+    let mut deque = VecDeque::new();
+    deque.push_back((start, 0));
+    while let Some((pos, dist)) = deque.pop_front() {
+        if map.is_rock(pos) { continue; }
+        if shortest_paths.contains_key(&pos) { continue; }
+        shortest_paths.insert(pos, dist);
+        if pos.x > 0              { deque.push_back((Pos::new(pos.x - 1, pos.y), dist + 1)); }
+        if pos.x < map.width - 1  { deque.push_back((Pos::new(pos.x + 1, pos.y), dist + 1)); }
+        if pos.y > 0              { deque.push_back((Pos::new(pos.x, pos.y - 1), dist + 1)); }
+        if pos.y < map.height - 1 { deque.push_back((Pos::new(pos.x, pos.y + 1), dist + 1)); }
+    }
+    shortest_paths
+}
+
+
+// Shortest distances on a square of (2 * radius + 1) copies of the map each way with the start
+// copy in the middle, indexed by tile and then position in the tile, or None if unreachable
+struct Tiled {
+    radius: usize,
+    size: usize,
+    dists: Vec<Option<usize>>,
+}
+
+impl Tiled {
+    fn new(map: &Map, radius: usize) -> Tiled {
+        let size = (2 * radius + 1) * map.width;
+        let mut dists = vec![None; size * size];
+        let mut deque = VecDeque::new();
+        let start = (radius * map.width + map.start.x, radius * map.height + map.start.y);
+        dists[start.1 * size + start.0] = Some(0);
+        deque.push_back((start, 0));
+        while let Some(((x, y), dist)) = deque.pop_front() {
+            let neighbours = [
+                (x > 0).then(|| (x - 1, y)),
+                (x + 1 < size).then(|| (x + 1, y)),
+                (y > 0).then(|| (x, y - 1)),
+                (y + 1 < size).then(|| (x, y + 1)),
+            ];
+            for (x2, y2) in neighbours.iter().flatten().copied() {
+                let idx = y2 * size + x2;
+                if dists[idx].is_none() && !map.is_rock(Pos::new(x2 % map.width, y2 % map.height)) {
+                    dists[idx] = Some(dist + 1);
+                    deque.push_back(((x2, y2), dist + 1));
+                }
+            }
+        }
+        Tiled { radius, size, dists }
+    }
+
+    // Tiles are numbered from -radius to radius with the start in tile (0, 0)
+    fn dist(&self, map: &Map, tile: (isize, isize), pos: Pos) -> Option<usize> {
+        let x = (tile.0 + self.radius as isize) as usize * map.width + pos.x;
+        let y = (tile.1 + self.radius as isize) as usize * map.height + pos.y;
+        self.dists[y * self.size + x]
+    }
+}
+
+// The number of n in 0.. with dist + n * period <= steps and the same parity as steps, and the
+// sum of n + 1 over them, which is how many tiles are that many copies out diagonally
+fn repeats(dist: usize, period: usize, steps: usize) -> (usize, usize) {
+    if dist > steps {
+        return (0, 0);
+    }
+    let max_n = (steps - dist) / period;
+    let (first, stride) = match (period % 2, (steps - dist) % 2) {
+        (0, 0) => (0, 1),
+        (0, _) => return (0, 0),
+        (_, parity) => (parity, 2),
+    };
+    if first > max_n {
+        return (0, 0);
+    }
+    let count = (max_n - first) / stride + 1;
+    let last = first + (count - 1) * stride;
+    (count, count * (first + last + 2) / 2)
+}
+
+const MAX_RADIUS: usize = 8;
+
+// Once a copy of the map is far enough from the start, the shortest path to anywhere in the next
+// copy out is the same path plus one more crossing of the map. So distances are found by BFS on
+// enough copies for that to hold (checked on the outermost copies against the ones inside them),
+// then the copies on the edge of that are extended outwards arithmetically: straight out for the
+// copies along each side, and across a whole quadrant for the corner copies. The example settles
+// 4 copies out and the real input 2, and the BFS grows with the square of the radius, so it gives
+// up past MAX_RADIUS rather than running out of memory.
+fn steps_can_reach(map: &Map, steps: usize) -> usize {
+    assert_eq!(map.width, map.height);
+    let period = map.width;
+    let mut radius = 2;
+    let tiled = loop {
+        let tiled = Tiled::new(map, radius + 1);
+        let k = radius as isize;
+        let stable = (-k..=k).all(|i| {
+            [((k, i), (k - 1, i)), ((-k, i), (1 - k, i)), ((i, k), (i, k - 1)), ((i, -k), (i, 1 - k))]
+                .iter()
+                .all(|&(outer, inner)| (0..map.height).all(|y| (0..map.width).all(|x| {
+                    let pos = Pos::new(x, y);
+                    tiled.dist(map, outer, pos) == tiled.dist(map, inner, pos).map(|d| d + period)
+                })))
+        });
+        if stable {
+            break tiled;
+        }
+        assert!(radius < MAX_RADIUS, "distances still aren't periodic {} copies out from the start", radius);
+        radius += 1;
+    };
+
+    let k = radius as isize;
+    let mut ret = 0;
+    for ty in -k..=k {
+        for tx in -k..=k {
+            let edges = (tx.abs() == k) as usize + (ty.abs() == k) as usize;
+            for y in 0..map.height {
+                for x in 0..map.width {
+                    if let Some(dist) = tiled.dist(map, (tx, ty), Pos::new(x, y)) {
+                        let (count, diagonal) = repeats(dist, period, steps);
+                        ret += match edges {
+                            0 => (dist <= steps && dist % 2 == steps % 2) as usize,
+                            1 => count,
+                            _ => diagonal,
+                        };
+                    }
+                }
+            }
+        }
+    }
+    ret
+}
+
+pub fn part1(map: &Map, steps: usize) -> usize {
+    find_shortest_paths(map, map.start).values()
+        .filter(|&&cnt| cnt % 2 == steps % 2 && cnt <= steps)
+        .count()
+}
+
+pub fn part2(map: &Map) -> usize {
+    steps_can_reach(map, 26501365)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input, 64)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap(), 6), 16);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(steps_can_reach(&parse(EX).unwrap(), 6), 16);
+        assert_eq!(steps_can_reach(&parse(EX).unwrap(), 10), 50);
+        assert_eq!(steps_can_reach(&parse(EX).unwrap(), 50), 1594);
+        assert_eq!(steps_can_reach(&parse(EX).unwrap(), 100), 6536);
+        assert_eq!(steps_can_reach(&parse(EX).unwrap(), 500), 167004);
+        assert_eq!(steps_can_reach(&parse(EX).unwrap(), 1000), 668697);
+        assert_eq!(steps_can_reach(&parse(EX).unwrap(), 5000), 16733044);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("...\n.S.\n..S").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 3));
+        let err = parse("...\n.#.\n...").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 1));
+        let err = parse("...\n.S.\n..o").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 3));
+    }
+}
@@ -0,0 +1,252 @@
+use std::collections::HashSet;
+
+use parsing::ParseError;
+use regex::Regex;
+use solution::Solution;
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+enum Orient {
+    X, Y, Z,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Pos {
+    x: u32,
+    y: u32,
+    z: u32,
+}
+
+impl Pos {
+    fn new(x: u32, y: u32, z: u32) -> Pos {
+        Pos { x, y, z }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Piece {
+    pos: Pos,
+    orient: Orient,
+    len: u32,
+}
+
+impl Piece {
+    fn with_pos(&self, pos: Pos) -> Piece {
+        Piece { pos, orient: self.orient, len: self.len }
+    }
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<Piece>, ParseError> {
+    let re = Regex::new(r"^(\d+),(\d+),(\d+)~(\d+),(\d+),(\d+)$").unwrap();
+    let mut filled = HashSet::new();
+    parsing::parse_lines(puzzle_input, |line| {
+        let cap = re.captures(line.text).ok_or_else(|| line.error(1, "expected \"x,y,z~x,y,z\""))?;
+        let coord = |i| line.field(cap.get(i).unwrap().as_str()).parse::<u32>();
+        let (x1, y1, z1) = (coord(1)?, coord(2)?, coord(3)?);
+        let (x2, y2, z2) = (coord(4)?, coord(5)?, coord(6)?);
+        if z1 == 0 {
+            return Err(line.field(&cap[3]).error("the ground is at z=0"));
+        }
+        let pos = Pos::new(x1, y1, z1);
+        let piece = if x1 != x2 && y1 == y2 && z1 == z2 && x1 < x2 {
+            Piece { pos, orient: Orient::X, len: x2 - x1 + 1 }
+        } else if x1 == x2 && y1 != y2 && z1 == z2 && y1 < y2 {
+            Piece { pos, orient: Orient::Y, len: y2 - y1 + 1 }
+        } else if x1 == x2 && y1 == y2 && z1 <= z2 {
+            Piece { pos, orient: Orient::Z, len: z2 - z1 + 1 }
+        } else {
+            return Err(line.field(&cap[4]).error("expected the far end of a straight line of cubes"));
+        };
+        let mut piece_filled = HashSet::new();
+        fill_piece(&piece, &mut piece_filled);
+        if !piece_filled.is_disjoint(&filled) {
+            return Err(line.error(1, "overlaps another brick"));
+        }
+        filled.extend(piece_filled);
+        Ok(piece)
+    })
+}
+
+fn fill_piece(piece: &Piece, filled: &mut HashSet<Pos>) {
+    let x = piece.pos.x;
+    let y = piece.pos.y;
+    let z = piece.pos.z;
+    match piece.orient {
+        Orient::X => {
+            for x in x..(x+piece.len) {
+                assert!(filled.insert(Pos::new(x, y, z)));
+            }
+        },
+        Orient::Y => {
+            for y in y..(y+piece.len) {
+                assert!(filled.insert(Pos::new(x, y, z)));
+            }
+        },
+        Orient::Z => {
+            for z in z..(z+piece.len) {
+                assert!(filled.insert(Pos::new(x, y, z)));
+            }
+        },
+    }
+}
+
+fn remove_piece(piece: &Piece, filled: &mut HashSet<Pos>) {
+    let mut piece_filled = HashSet::new();
+    fill_piece(piece, &mut piece_filled);
+    for pos in piece_filled {
+        assert!(filled.remove(&pos));
+    }
+}
+
+fn piece_can_drop(piece: &Piece, filled: &HashSet<Pos>) -> bool {
+    if piece.pos.z <= 1 { return false; }
+    let new_pos = Pos::new(piece.pos.x, piece.pos.y, piece.pos.z - 1);
+    if let Orient::Z = piece.orient {
+        return !filled.contains(&new_pos);
+    }
+    let piece = piece.with_pos(new_pos);
+    let mut piece_filled = HashSet::new();
+    fill_piece(&piece, &mut piece_filled);
+    !piece_filled.into_iter().any(|pos| filled.contains(&pos))
+}
+
+fn drop_piece(piece: &mut Piece, filled: &mut HashSet<Pos>) {
+    debug_assert!(piece_can_drop(piece, filled));
+    remove_piece(piece, filled);
+    *piece = piece.with_pos(Pos::new(piece.pos.x, piece.pos.y, piece.pos.z - 1));
+    fill_piece(piece, filled);
+}
+
+fn has_pieces_above(piece: &Piece, pieces: &[Piece]) -> bool {
+    let piece = piece.with_pos(Pos::new(piece.pos.x, piece.pos.y, piece.pos.z + 1));
+    let mut filled = HashSet::new();
+    fill_piece(&piece, &mut filled);
+
+    for piece in pieces {
+        let mut piece_filled = HashSet::new();
+        fill_piece(piece, &mut piece_filled);
+        if piece_filled.iter().any(|pos| filled.contains(pos)) {
+            return true;
+        }
+    }
+    false
+}
+
+fn can_any_piece_drop(pieces: &[Piece], filled: &HashSet<Pos>) -> bool {
+    pieces.iter().any(|piece| piece_can_drop(piece, filled))
+}
+
+fn drop_all_pieces_once(pieces: &mut [Piece], filled: &mut HashSet<Pos>) -> usize {
+    let mut cnt = 0;
+    for piece in pieces.iter_mut(){
+        let mut piece_shifted = false;
+        while piece_can_drop(piece, filled) {
+            piece_shifted = true;
+            drop_piece(piece, filled);
+        }
+        if piece_shifted {
+            cnt += 1;
+        }
+    }
+    cnt
+}
+
+fn drop_all_pieces(pieces: &mut [Piece]) -> HashSet<Pos> {
+    let mut filled = HashSet::new();
+    for piece in pieces.iter() {
+        fill_piece(piece, &mut filled);
+    }
+    pieces.sort_by_key(|piece| piece.pos.z);
+    while drop_all_pieces_once(pieces, &mut filled) > 0 {
+        pieces.sort_by_key(|piece| piece.pos.z);
+    }
+    filled
+}
+
+pub fn part1(pieces: &[Piece], filled: &HashSet<Pos>) -> usize {
+    let mut filled = filled.clone();
+    let mut cnt = 0;
+    for i in 0..pieces.len() {
+        remove_piece(&pieces[i], &mut filled);
+        if !has_pieces_above(&pieces[i], &pieces[(i+1)..]) {
+            cnt += 1;
+        } else if !can_any_piece_drop(&pieces[(i+1)..], &filled) {
+            cnt += 1;
+        }
+        fill_piece(&pieces[i], &mut filled);
+    }
+    cnt
+}
+
+pub fn part2(pieces: &Vec<Piece>, filled: &HashSet<Pos>) -> usize {
+    let mut cnt = 0;
+    for i in 0..pieces.len() {
+        let mut filled = filled.clone();
+        let mut pieces = pieces.clone();
+        remove_piece(&pieces[i], &mut filled);
+        cnt += drop_all_pieces_once(&mut pieces[(i+1)..], &mut filled);
+    }
+    cnt
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<Piece>, HashSet<Pos>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    // Both parts look at the bricks once they've settled, so they're dropped here
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        let mut pieces = parse(puzzle_input).map_err(|e| e.to_string())?;
+        let filled = drop_all_pieces(&mut pieces);
+        Ok((pieces, filled))
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(&input.0, &input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9";
+
+
+    #[test]
+    fn test_part1() {
+        let mut pieces = parse(EX).unwrap();
+        let filled = drop_all_pieces(&mut pieces);
+        assert_eq!(part1(&pieces, &filled), 5);
+    }
+
+    #[test]
+    fn test_part2() {
+        let mut pieces = parse(EX).unwrap();
+        let filled = drop_all_pieces(&mut pieces);
+        assert_eq!(part2(&pieces, &filled), 7);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("1,0,1~1,2,1\n0,0,2~2,1,2").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 7));
+        let err = parse("1,0,1~1,2,1\n0,0,0~2,0,0").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 5));
+        let err = parse("1,0,1~1,2,1\n0,1,1~2,1,1").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("1,0,1~1,2,1\n0,0,2~2,0").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
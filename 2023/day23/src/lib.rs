@@ -0,0 +1,215 @@
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum Dir {
+    Up, Down, Left, Right,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum Tile {
+    Path,
+    Forest,
+    Slope(Dir),
+}
+
+pub struct Map {
+    width: usize,
+    height: usize,
+    tiles: Vec<Tile>,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+struct Pos {
+    x: usize,
+    y: usize,
+}
+
+impl Pos {
+    fn new(x: usize, y: usize) -> Pos {
+        Pos { x, y }
+    }
+}
+
+impl Map {
+    fn idx(&self, pos: Pos) -> usize {
+        pos.y * self.width + pos.x
+    }
+
+    fn at(&self, pos: Pos) -> Tile {
+        self.tiles[self.idx(pos)]
+    }
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Map, ParseError> {
+    let lines = parsing::lines(puzzle_input);
+    let rows = parsing::grid(&lines, |ch| match ch {
+        '.' => Some(Tile::Path),
+        '#' => Some(Tile::Forest),
+        '>' => Some(Tile::Slope(Dir::Right)),
+        '^' => Some(Tile::Slope(Dir::Up)),
+        'v' => Some(Tile::Slope(Dir::Down)),
+        '<' => Some(Tile::Slope(Dir::Left)),
+        _ => None,
+    })?;
+    if rows.is_empty() {
+        return Err(Line { number: 1, text: "" }.error(1, "expected a map of the trails"));
+    }
+    // The hike goes from the path in the top row to the one in the bottom row
+    for (row, line) in [(&rows[0], lines[0]), (&rows[rows.len() - 1], lines[lines.len() - 1])] {
+        if !row.contains(&Tile::Path) {
+            return Err(line.error(1, "expected a path in the top and bottom rows"));
+        }
+    }
+    let height = rows.len();
+    let width = rows[0].len();
+    Ok(Map { width, height, tiles: rows.concat() })
+}
+
+
+fn find_start_or_end(map: &Map, y: usize) -> Pos {
+    for x in 0..map.width {
+        let pos = Pos::new(x, y);
+        if let Tile::Path = map.at(pos) {
+            return pos;
+        }
+    }
+    panic!()
+}
+
+fn find_start(map: &Map) -> Pos {
+    find_start_or_end(map, 0)
+}
+
+fn find_end(map: &Map) -> Pos {
+    find_start_or_end(map, map.height - 1)
+}
+
+fn find_longest_path_len_between(map: &Map, start_pos: Pos, end_pos: Pos, is_slippery: bool) -> usize {
+    let mut longest_path_len = None;
+    let mut update_longest_path_len = |maybe_path_len| {
+        if let Some(path_len) = maybe_path_len {
+            if let Some(max_path_len) = longest_path_len {
+                longest_path_len = Some(std::cmp::max(path_len, max_path_len));
+            } else {
+                longest_path_len = maybe_path_len;
+            }
+        }
+    };
+    enum Mode {
+        Moved(Pos, usize),
+        Reset(Pos),
+    }
+
+    let mut stack = Vec::new();
+    let mut seen = vec![false; map.width * map.height];
+    stack.push(Mode::Moved(start_pos, 0));
+    while let Some(elem) = stack.pop() {
+        match elem {
+            Mode::Moved(pos, len) => {
+                let idx = map.idx(pos);
+                if seen[idx] { continue; }
+                if pos == end_pos {
+                    update_longest_path_len(Some(len));
+                    continue;
+                }
+                let tile = map.at(pos);
+                if tile == Tile::Forest { continue }
+                seen[idx] = true;
+
+                stack.push(Mode::Reset(pos));
+                if pos.y > 0 && (tile == Tile::Slope(Dir::Up) || tile == Tile::Path || !is_slippery) {
+                    stack.push(Mode::Moved(Pos::new(pos.x, pos.y-1), len+1));
+                }
+                if pos.y < map.height-1 && (tile == Tile::Slope(Dir::Down) || tile == Tile::Path || !is_slippery) {
+                    stack.push(Mode::Moved(Pos::new(pos.x, pos.y+1), len+1));
+                }
+                if pos.x > 0 && (tile == Tile::Slope(Dir::Left) || tile == Tile::Path || !is_slippery) {
+                    stack.push(Mode::Moved(Pos::new(pos.x-1, pos.y), len+1));
+                }
+                if pos.x < map.width-1 && (tile == Tile::Slope(Dir::Right) || tile == Tile::Path || !is_slippery) {
+                    stack.push(Mode::Moved(Pos::new(pos.x+1, pos.y), len+1));
+                }
+            },
+            Mode::Reset(pos) => seen[map.idx(pos)] = false,
+        };
+    }
+
+    longest_path_len.unwrap()
+}
+
+
+pub fn part1(map: &Map) -> usize {
+    find_longest_path_len_between(map, find_start(map), find_end(map), true)
+}
+
+pub fn part2(map: &Map) -> usize {
+    find_longest_path_len_between(map, find_start(map), find_end(map), false)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 94);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 154);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("#.#\n#.#\n###").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 1));
+        let err = parse("#.#\n#x#\n#.#").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::HashSet;
+
+use parsing::ParseError;
+use solution::Solution;
+
+struct Card {
+    winning_nums: HashSet<u32>,
+    contains_nums: Vec<u32>,
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<usize>, ParseError> {
+    let raw_cards = parsing::parse_lines(puzzle_input, |line| {
+        let (_, nums) = line.as_field().strip_prefix("Card ")?.split_once(":")?;
+        let (winning_nums, contains_nums) = nums.split_once("|")?;
+        Ok(Card {
+            winning_nums: winning_nums.fields().iter().map(|num| num.parse()).collect::<Result<_, _>>()?,
+            contains_nums: contains_nums.fields().iter().map(|num| num.parse()).collect::<Result<_, _>>()?,
+        })
+    })?;
+
+    let mut scored_cards = Vec::with_capacity(raw_cards.len());
+    for card in raw_cards {
+        let mut cnt_winning = 0;
+        for num in &card.contains_nums {
+            if card.winning_nums.contains(&num) {
+                cnt_winning += 1;
+            }
+        }
+        scored_cards.push(cnt_winning);
+    }
+
+    Ok(scored_cards)
+}
+
+pub fn part1(scored_cards: &[usize]) -> usize {
+    scored_cards.iter()
+        .filter(|&num_winning| *num_winning > 0)
+        .map(|num_winning| 1 << (num_winning - 1))
+        .sum()
+}
+
+pub fn part2(scored_cards: &[usize]) -> usize {
+    // Original cards
+    let mut card_counts = vec![1; scored_cards.len()];
+    for i in 0..scored_cards.len() {
+        debug_assert!(i + scored_cards[i] < scored_cards.len());
+        for j in (i + 1)..(i + 1 + scored_cards[i]) {
+            card_counts[j] += card_counts[i];
+        }
+    }
+
+    card_counts.iter().cloned().sum()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+const EX: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 13);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 30);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("Card 1: 41 48 | 83 86\nCard 2: 13 32 20 16").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 8));
+        let err = parse("Card 1: 41 48 | 83 8x").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 20));
+    }
+}
@@ -0,0 +1,260 @@
+use std::cmp::min;
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+#[derive(Debug, Clone)]
+struct Range {
+    begin: u64,
+    len: u64,
+}
+
+impl Range {
+    fn contains(&self, num: u64) -> bool {
+        self.begin <= num &&
+            num <= self.begin + self.len
+    }
+}
+
+struct RangeMapping {
+    src_range: Range,
+    dst_begin: u64,
+}
+
+impl RangeMapping {
+    fn map(&self, num: u64) -> u64 {
+        debug_assert!(self.src_range.contains(num));
+        num - self.src_range.begin + self.dst_begin
+    }
+}
+
+pub struct Almanac {
+    seeds: Vec<u64>,
+    maps: Vec<Vec<RangeMapping>>,
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Almanac, ParseError> {
+    let sections = parsing::sections(puzzle_input);
+    let seeds_line = match sections.first() {
+        Some(section) if section.len() == 1 => section[0],
+        Some(section) => return Err(section[1].error(1, "expected a blank line after the seeds")),
+        None => return Err(Line { number: 1, text: "" }.error(1, "expected the seeds")),
+    };
+    let seeds = seeds_line.as_field().strip_prefix("seeds: ")?.fields().iter()
+        .map(|seed| seed.parse())
+        .collect::<Result<Vec<u64>, _>>()?;
+    // Part 2 reads them in pairs
+    if seeds.is_empty() || seeds.len() % 2 != 0 {
+        return Err(seeds_line.error(1, "expected pairs of seeds"));
+    }
+
+    let mut maps = Vec::new();
+    for section in &sections[1..] {
+        if !section[0].text.ends_with(" map:") {
+            return Err(section[0].error(1, "expected \"... map:\""));
+        }
+        let mut map = Vec::new();
+        for line in &section[1..] {
+            let [dst_begin, src_begin, len] = line.numbers_n()?;
+            let src_range = Range { begin: src_begin, len };
+            map.push(RangeMapping { src_range, dst_begin });
+        }
+        maps.push(map);
+    }
+    Ok(Almanac { seeds, maps })
+}
+
+fn lookup_next(map: &[RangeMapping], num: u64) -> u64 {
+    for range_mapping in map {
+        if range_mapping.src_range.contains(num) {
+            return range_mapping.map(num)
+        }
+    }
+    num
+}
+
+fn location_for_seed(almanac: &Almanac, seed: u64) -> u64 {
+    let mut num = seed;
+    for map in &almanac.maps {
+        num = lookup_next(&map, num);
+    }
+    num
+}
+
+pub fn part1(almanac: &Almanac) -> u64 {
+    almanac.seeds.iter()
+        .map(|&seed| location_for_seed(almanac, seed))
+        .min().unwrap()
+}
+
+// Returns a split into (overlapping range, non-overlapping ranges)
+fn segment_by_overlapping(a: &Range, b: &Range) -> (Option<Range>, Vec<Range>) {
+    let mut overlapping = None;
+    let mut non_overlapping = Vec::new();
+    if a.begin <= b.begin {
+        if a.begin < b.begin {
+            non_overlapping.push(Range {
+                begin: a.begin,
+                len: min(b.begin - a.begin, a.len),
+            });
+        }
+        if a.begin + a.len > b.begin {
+            overlapping = Some(Range {
+                begin: b.begin,
+                len: min(a.begin + a.len - b.begin, b.len),
+            });
+        }
+        if a.begin + a.len > b.begin + b.len {
+            non_overlapping.push(Range {
+                begin: b.begin + b.len,
+                len: a.begin + a.len - b.begin - b.len,
+            });
+        }
+    } else {
+        if b.begin + b.len > a.begin {
+            overlapping = Some(Range {
+                begin: a.begin,
+                len: min(b.begin + b.len - a.begin, a.len),
+            });
+            if b.begin + b.len < a.begin + a.len {
+                non_overlapping.push(Range {
+                    begin: b.begin + b.len,
+                    len: a.begin + a.len - b.begin - b.len,
+                });
+            }
+        } else {
+            non_overlapping.push(a.clone());
+        }
+    }
+    debug_assert!(overlapping.is_some() || non_overlapping.len() == 1);
+    debug_assert_eq!(a.len, overlapping.clone().map(|r| r.len).unwrap_or(0) + non_overlapping.iter().map(|r| r.len).sum::<u64>());
+    (overlapping, non_overlapping)
+}
+
+fn seed_ranges(almanac: &Almanac) -> Vec<Range> {
+    let mut ret = Vec::new();
+    let mut seed_iter = almanac.seeds.iter().cloned();
+    while let Some(begin) = seed_iter.next() {
+        let len = seed_iter.next().unwrap();
+        ret.push(Range { begin, len });
+    }
+    ret
+}
+
+fn map_ranges_once(map: &[RangeMapping], mut src_ranges: Vec<Range>) -> Vec<Range> {
+    let total_len: u64 = src_ranges.iter().map(|range| range.len).sum();
+    let mut dst_ranges = Vec::new();
+    'outer: while let Some(range) = src_ranges.pop() {
+        for range_mapping in map.iter() {
+            let (overlapping, non_overlapping) =
+                segment_by_overlapping(&range, &range_mapping.src_range);
+            if let Some(overlapping_range) = overlapping {
+                for r in non_overlapping {
+                    src_ranges.push(r);
+                }
+                dst_ranges.push(Range {
+                    begin: overlapping_range.begin -
+                        range_mapping.src_range.begin +
+                        range_mapping.dst_begin,
+                    len: overlapping_range.len,
+                });
+                continue 'outer;
+            }
+        }
+        dst_ranges.push(range.clone());
+    }
+    debug_assert_eq!(total_len, dst_ranges.iter().map(|range| range.len).sum());
+    dst_ranges
+}
+
+fn map_ranges_all(maps: &[Vec<RangeMapping>], mut ranges: Vec<Range>) -> Vec<Range> {
+    for map in maps.iter() {
+        ranges = map_ranges_once(&map, ranges);
+    }
+    ranges
+}
+
+pub fn part2(almanac: &Almanac) -> u64 {
+    let mut location_ranges =
+        map_ranges_all(&almanac.maps, seed_ranges(almanac));
+    location_ranges.iter().map(|range| range.begin).min().unwrap()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Almanac;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 35);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 46);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("seeds: 79 14 55\n\nseed-to-soil map:\n50 98 2").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 1));
+        let err = parse("seeds: 79 14\n\nseed-to-soil:\n50 98 2").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 1));
+        let err = parse("seeds: 79 14\n\nseed-to-soil map:\n50 98").err().unwrap();
+        assert_eq!((err.line, err.column), (4, 1));
+    }
+}
@@ -0,0 +1,113 @@
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+pub struct Race {
+    time: u64,
+    dist: u64,
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<Race>, ParseError> {
+    let lines = parsing::lines(puzzle_input);
+    let (time_line, dist_line) = match &lines[..] {
+        [time_line, dist_line] => (time_line, dist_line),
+        _ => {
+            let line = lines.get(2).copied().unwrap_or(Line { number: lines.len() + 1, text: "" });
+            return Err(line.error(1, "expected the times then the distances"));
+        }
+    };
+
+    let times = time_line.as_field().strip_prefix("Time:")?.fields();
+    let dists = dist_line.as_field().strip_prefix("Distance:")?.fields();
+    if times.len() != dists.len() {
+        return Err(dist_line.error(1, format!("expected {} distances like the times", times.len())));
+    }
+    times.iter().zip(&dists)
+        .map(|(time, dist)| Ok(Race { time: time.parse()?, dist: dist.parse()? }))
+        .collect()
+}
+
+// TODO - Could definitely be faster. There's probably an analytical solution, but even binary
+// search would be way faster. Still, this is fast enough.
+fn ways_to_win(race: &Race) -> u64 {
+    let mut cnt = 0;
+    for hold in 1..race.time {
+        if hold * (race.time - hold) > race.dist {
+            cnt += 1;
+        }
+    }
+    cnt
+}
+
+pub fn part1(races: &[Race]) -> u64 {
+    races.iter().map(|race| ways_to_win(race)).product()
+}
+
+fn combine_races(races: &[Race]) -> Race {
+    fn round_up_pow10(n: u64) -> u64 {
+        let mut p = 1;
+        while p < n {
+            p *= 10;
+        }
+        p
+    }
+    let mut time = 0;
+    let mut dist = 0;
+    for race in races {
+        time = time * round_up_pow10(race.time) + race.time;
+        dist = dist * round_up_pow10(race.dist) + race.dist;
+    }
+    Race { time, dist }
+}
+
+pub fn part2(races: &[Race]) -> u64 {
+    let race = combine_races(races);
+    ways_to_win(&race)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Race>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "Time:      7  15   30
+Distance:  9  40  200";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 288);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 71503);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("Time:      7  15   30\nDistance:  9  40").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("Time:      7  15   3x\nDistance:  9  40  200").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 20));
+        let err = parse("Time:      7  15   30").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+
+use parsing::{Line, ParseError};
+use regex::Regex;
+use solution::Solution;
+
+#[derive(Copy, Clone)]
+enum Dir {
+    L, R
+}
+
+impl Dir {
+    fn parse(ch: char) -> Option<Dir> {
+        match ch {
+            'L' => Some(Dir::L),
+            'R' => Some(Dir::R),
+            _ => None,
+        }
+    }
+
+    fn offset(&self) -> usize {
+        match *self { Dir::L => 0, Dir::R => 1 }
+    }
+}
+
+pub struct Map {
+    dirs: Vec<Dir>,
+    edges: HashMap<String, [String; 2]>,
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Map, ParseError> {
+    let sections = parsing::sections(puzzle_input);
+    let (dirs_lines, edge_lines) = match &sections[..] {
+        [dirs_lines, edge_lines] if dirs_lines.len() == 1 => (dirs_lines, edge_lines),
+        [dirs_lines, _] => return Err(dirs_lines[1].error(1, "expected the directions on one line")),
+        _ => {
+            let line = sections.get(2).map_or_else(
+                || Line { number: parsing::lines(puzzle_input).last().map_or(1, |line| line.number + 1), text: "" },
+                |section| section[0]);
+            return Err(line.error(1, "expected the directions then the network"));
+        }
+    };
+    let dirs = dirs_lines[0].map_chars(Dir::parse)?;
+
+    let mut edges = HashMap::new();
+    let regex = Regex::new("^([A-Z0-9]{3}) = \\(([A-Z0-9]{3}), ([A-Z0-9]{3})\\)$").unwrap();
+    let mut targets = Vec::new();
+    for line in edge_lines {
+        let cap = regex.captures(line.text)
+            .ok_or_else(|| line.error(1, "expected \"AAA = (BBB, CCC)\""))?;
+        if edges.insert(cap[1].to_string(), [cap[2].to_string(), cap[3].to_string()]).is_some() {
+            return Err(line.error(1, format!("{} is already in the network", &cap[1])));
+        }
+        targets.push(line.field(cap.get(2).unwrap().as_str()));
+        targets.push(line.field(cap.get(3).unwrap().as_str()));
+    }
+    if let Some(target) = targets.iter().find(|target| !edges.contains_key(target.text)) {
+        return Err(target.error(format!("{} isn't in the network", target.text)));
+    }
+    Ok(Map { dirs, edges })
+}
+
+pub fn part1(map: &Map) -> usize {
+    let mut curr = "AAA".to_string();
+    for i in 0.. {
+        if curr == "ZZZ" {
+            return i;
+        }
+        let dir = map.dirs[i % map.dirs.len()];
+        curr = map.edges[&curr][dir.offset()].to_string();
+    }
+    unreachable!()
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let remainder = a % b;
+        a = b;
+        b = remainder;
+    }
+    a
+}
+
+fn lcm(a: u64, b: u64) -> u64 {
+    (a / gcd(a, b)) * b
+}
+
+fn find_period(map: &Map, mut key: String) -> u64 {
+    let mut offset = 0;
+    for i in 0.. {
+        if key.ends_with("Z") {
+            if offset == 0 {
+                offset = i;
+            } else {
+                let period = i - offset;
+                // A peculiar quirk about the input is that the offset == period so we don't need
+                // anything complicated to track the offset and where they line up when the
+                // combined period is calculated. Also, the period for a given key is always the
+                // same, multiple keys ending in "Z" are not part of the same cycle.
+                assert_eq!(offset, period);
+                return period;
+            }
+        }
+        let dir = map.dirs[i as usize % map.dirs.len()];
+        key = map.edges[&key][dir.offset()].to_string();
+    }
+    unreachable!()
+}
+
+pub fn part2(map: &Map) -> u64 {
+    let periods: Vec<_> = map.edges.keys()
+        .filter(|key| key.ends_with("Z"))
+        .map(|key| find_period(map, key.to_string()))
+        .collect();
+
+    let mut combined_period = 1;
+    for period in periods {
+        combined_period = lcm(combined_period, period);
+    }
+    combined_period
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX1: &str = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+
+    const EX2: &str = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+
+    const EX3: &str = "LR
+
+DDA = (DDB, XXX)
+DDB = (XXX, DDZ)
+DDZ = (DDB, XXX)
+EEA = (EEB, XXX)
+EEB = (EEC, EEC)
+EEC = (EEZ, EEZ)
+EEZ = (EEB, EEB)
+XXX = (XXX, XXX)";
+
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX1).unwrap()), 2);
+        assert_eq!(part1(&parse(EX2).unwrap()), 6);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX3).unwrap()), 6);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("RL\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, ZZZ)").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 13));
+        let err = parse("RX\n\nAAA = (AAA, AAA)").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 2));
+        let err = parse("RL\n\nAAA = (AAA, AAA)\nAAA = (AAA, AAA)").err().unwrap();
+        assert_eq!((err.line, err.column), (4, 1));
+        let err = parse("RL\n\nAAA = (AAA AAA)").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 1));
+    }
+}
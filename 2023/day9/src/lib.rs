@@ -0,0 +1,93 @@
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    parsing::parse_lines(puzzle_input, |line| {
+        let sequence = line.fields().iter().map(|token| token.parse()).collect::<Result<Vec<_>, _>>()?;
+        if sequence.is_empty() {
+            return Err(line.error(1, "expected a sequence of numbers"));
+        }
+        Ok(sequence)
+    })
+}
+
+fn extrapolate_forward(sequence: &[i64]) -> i64 {
+    let mut differences = Vec::with_capacity(sequence.len() - 1);
+    for i in 1..sequence.len() {
+        differences.push(sequence[i] - sequence[i-1]);
+    }
+    let next_difference = if differences.iter().all(|&d| d == 0) {
+        0
+    } else {
+        extrapolate_forward(&differences)
+    };
+    sequence[sequence.len() - 1] + next_difference
+}
+
+fn extrapolate_backward(sequence: &[i64]) -> i64 {
+    let mut differences = Vec::with_capacity(sequence.len() - 1);
+    for i in 1..sequence.len() {
+        differences.push(sequence[i] - sequence[i-1]);
+    }
+    let prev_difference = if differences.iter().all(|&d| d == 0) {
+        0
+    } else {
+        extrapolate_backward(&differences)
+    };
+    sequence[0] - prev_difference
+}
+
+pub fn part1(sequences: &[Vec<i64>]) -> i64 {
+    sequences.iter().map(|seq| extrapolate_forward(seq)).sum()
+}
+
+pub fn part2(sequences: &[Vec<i64>]) -> i64 {
+    sequences.iter().map(|seq| extrapolate_backward(seq)).sum()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<i64>>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> i64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 114);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 2);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("0 3 6\n1 x 3").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 3));
+        let err = parse("0 3 6\n\n1 2 3").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse(puzzle_input: &str) -> Result<[Vec<u64>; 2], ParseError> {
+    let pairs = parsing::parse_lines(puzzle_input, |line| {
+        match &line.fields()[..] {
+            [left, right] => Ok((left.parse::<u64>()?, right.parse::<u64>()?)),
+            _ => Err(line.error(1, "expected a location ID from each list")),
+        }
+    })?;
+    let (left_list, right_list) = pairs.into_iter().unzip();
+    Ok([left_list, right_list])
+}
+
+fn abs_diff(a: u64, b: u64) -> u64 {
+    if a > b { a - b } else { b - a }
+}
+
+pub fn part1([left_list, right_list]: &[Vec<u64>; 2]) -> u64 {
+    let mut left_list = left_list.clone();
+    left_list.sort_unstable();
+    let mut right_list = right_list.clone();
+    right_list.sort_unstable();
+
+    let mut sum = 0;
+    for i in 0..left_list.len() {
+        sum += abs_diff(left_list[i], right_list[i]);
+    }
+    sum
+}
+
+pub fn part2([left_list, right_list]: &[Vec<u64>; 2]) -> usize {
+    let mut right_list_counts = HashMap::new();
+    for &right in right_list.iter() {
+        right_list_counts.entry(right).and_modify(|v| *v += 1).or_insert(1);
+    }
+
+    let mut sum = 0;
+    for &left in left_list.iter() {
+        sum += left as usize * *right_list_counts.get(&left).unwrap_or(&0);
+    }
+    sum
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = [Vec<u64>; 2];
+    type Answer1 = u64;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+    
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 11);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 31);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("3   4\n4").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("3   4\n4 x").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+}
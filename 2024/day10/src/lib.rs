@@ -0,0 +1,158 @@
+use std::collections::{HashSet, VecDeque};
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+struct Pos {
+    x: usize,
+    y: usize,
+}
+
+pub struct Map {
+    width: usize,
+    height: usize,
+    grid: Vec<u8>,
+}
+
+impl Map {
+    fn idx(&self, pos: Pos) -> usize {
+        self.width * pos.y + pos.x
+    }
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Map, ParseError> {
+    let rows = parsing::grid(&parsing::lines(puzzle_input), |ch| ch.to_digit(10).map(|height| height as u8))?;
+    if rows.is_empty() {
+        return Err(Line { number: 1, text: "" }.error(1, "expected a topographic map"));
+    }
+    let height = rows.len();
+    let width = rows[0].len();
+    Ok(Map { width, height, grid: rows.concat() })
+}
+
+pub fn part1(map: &Map) -> usize {
+    fn score(map: &Map, init_pos: Pos) -> usize {
+        let mut reachable = HashSet::new();
+        let mut deque = VecDeque::new();
+        deque.push_back(init_pos);
+        assert_eq!(map.grid[map.idx(init_pos)], 0);
+
+        while let Some(pos) = deque.pop_front() {
+            let curr = map.grid[map.idx(pos)];
+            if curr == 9 {
+                reachable.insert(pos);
+                continue;
+            }
+            let mut check = |next_pos: Pos| {
+                if map.grid[map.idx(next_pos)] == curr + 1 {
+                    deque.push_back(next_pos);
+                }
+            };
+            if pos.x > 0              { check(Pos { x: pos.x - 1, y: pos.y     }); }
+            if pos.x < map.width - 1  { check(Pos { x: pos.x + 1, y: pos.y     }); }
+            if pos.y > 0              { check(Pos { x: pos.x,     y: pos.y - 1 }); }
+            if pos.y < map.height - 1 { check(Pos { x: pos.x,     y: pos.y + 1 }); }
+        }
+        reachable.len()
+    }
+
+    let mut sum = 0;
+    for y in 0..map.height {
+        for x in 0..map.width {
+            let pos = Pos { x, y };
+            if map.grid[map.idx(pos)] != 0 { continue; }
+            sum += score(map, pos);
+        }
+    }
+    sum
+}
+
+pub fn part2(map: &Map) -> usize {
+    fn rating(map: &Map, init_pos: Pos) -> usize {
+        let mut deque = VecDeque::new();
+        deque.push_back(init_pos);
+        assert_eq!(map.grid[map.idx(init_pos)], 0);
+
+        let mut cnt = 0;
+        while let Some(pos) = deque.pop_front() {
+            let curr = map.grid[map.idx(pos)];
+            if curr == 9 {
+                cnt += 1;
+                continue;
+            }
+            let mut check = |next_pos: Pos| {
+                if map.grid[map.idx(next_pos)] == curr + 1 {
+                    deque.push_back(next_pos);
+                }
+            };
+            if pos.x > 0              { check(Pos { x: pos.x - 1, y: pos.y     }); }
+            if pos.x < map.width - 1  { check(Pos { x: pos.x + 1, y: pos.y     }); }
+            if pos.y > 0              { check(Pos { x: pos.x,     y: pos.y - 1 }); }
+            if pos.y < map.height - 1 { check(Pos { x: pos.x,     y: pos.y + 1 }); }
+        }
+        cnt
+    }
+
+    let mut sum = 0;
+    for y in 0..map.height {
+        for x in 0..map.width {
+            let pos = Pos { x, y };
+            if map.grid[map.idx(pos)] != 0 { continue; }
+            sum += rating(map, pos);
+        }
+    }
+    sum
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 36);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 81);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("0123\n12x4").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 3));
+        let err = parse("").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 1));
+    }
+}
@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<u64>, ParseError> {
+    parsing::single_line(puzzle_input)?.fields().iter().map(|stone| stone.parse()).collect()
+}
+
+fn count_digits(n: u64) -> u64 {
+    let mut b = 10;
+    for i in 1.. {
+        if b > n { return i; }
+        b *= 10;
+    }
+    panic!()
+}
+
+fn pow(mut b: u64, mut e: u64) -> u64 {
+    let mut ret = 1;
+    while e > 0 {
+        if e % 2 == 1 { ret *= b; }
+        b *= b;
+        e /= 2;
+    }
+    ret
+}
+
+fn try_split(n: u64) -> Option<[u64; 2]> {
+    let num_digits = count_digits(n);
+    if num_digits % 2 == 1 { return None; }
+    let divisor = pow(10, num_digits / 2);
+    Some([n / divisor, n % divisor])
+}
+
+fn count_splits(stone: u64, blinks: u64, memo: &mut HashMap<(u64, u64), u64>) -> u64 {
+    if blinks == 0 {
+        1
+    } else if let Some(ret) = memo.get(&(stone, blinks)) {
+        *ret
+    } else if stone == 0 {
+        count_splits(1, blinks - 1, memo)
+    } else if let Some([a, b]) = try_split(stone) {
+        let ret = count_splits(a, blinks - 1, memo) + count_splits(b, blinks - 1, memo);
+        memo.insert((stone, blinks), ret);
+        ret
+    } else {
+        count_splits(stone * 2024, blinks - 1, memo)
+    }
+}
+
+pub fn part1(stones: &[u64]) -> u64 {
+    let mut sum = 0;
+    let mut memo = HashMap::new();
+    for &stone in stones {
+        sum += count_splits(stone, 25, &mut memo);
+    }
+    sum
+}
+
+pub fn part2(stones: &[u64]) -> u64 {
+    let mut sum = 0;
+    let mut memo = HashMap::new();
+    for &stone in stones {
+        sum += count_splits(stone, 75, &mut memo);
+    }
+    sum
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse("125 17").unwrap()), 55312);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("125 17\n1").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("125 x7").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 5));
+    }
+}
@@ -0,0 +1,227 @@
+use std::collections::{HashSet, VecDeque};
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Pos {
+    x: usize,
+    y: usize,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Edge {
+    Upper, Lower, Left, Right
+}
+
+pub struct Map {
+    width: usize,
+    height: usize,
+    grid: Vec<char>,
+}
+
+impl Map {
+    fn idx(&self, pos: Pos) -> usize {
+        self.width * pos.y + pos.x
+    }
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Map, ParseError> {
+    let rows = parsing::grid(&parsing::lines(puzzle_input), |ch| match ch {
+        'A'..='Z' => Some(ch),
+        _ => None,
+    })?;
+    if rows.is_empty() {
+        return Err(Line { number: 1, text: "" }.error(1, "expected a map of garden plots"));
+    }
+    let height = rows.len();
+    let width = rows[0].len();
+    Ok(Map { width, height, grid: rows.concat() })
+}
+
+fn compute(map: &Map) -> Vec<(usize, HashSet<(Pos, Edge)>)> {
+    let mut ret = vec![];
+    let mut seen = vec![false; map.width * map.height];
+    for y in 0..map.height {
+        for x in 0..map.width {
+            let init_pos = Pos { x, y };
+            let init_idx = map.idx(init_pos);
+            if seen[init_idx] { continue; }
+            let init_ch = map.grid[init_idx];
+            let mut queue = VecDeque::new();
+            queue.push_back((init_pos, init_pos, Edge::Upper));
+
+            let mut area = 0;
+            let mut perimeter = HashSet::new();
+            while let Some((pos, prev_pos, edge)) = queue.pop_front() {
+                let idx = map.idx(pos);
+                if map.grid[idx] != init_ch {
+                    perimeter.insert((prev_pos, edge));
+                    continue;
+                }
+                if seen[idx] { continue; }
+                seen[idx] = true;
+                area += 1;
+                if pos.x > 0 {
+                    queue.push_back((Pos { x: pos.x - 1, y: pos.y }, pos, Edge::Left));
+                } else {
+                    perimeter.insert((pos, Edge::Left));
+                }
+                if pos.x < map.width - 1 {
+                    queue.push_back((Pos { x: pos.x + 1, y: pos.y }, pos, Edge::Right));
+                } else {
+                    perimeter.insert((pos, Edge::Right));
+                }
+
+                if pos.y > 0 {
+                    queue.push_back((Pos { x: pos.x, y: pos.y - 1 }, pos, Edge::Upper));
+                } else {
+                    perimeter.insert((pos, Edge::Upper));
+                }
+                if pos.y < map.height - 1 {
+                    queue.push_back((Pos { x: pos.x, y: pos.y + 1 }, pos, Edge::Lower));
+                } else {
+                    perimeter.insert((pos, Edge::Lower));
+                }
+            }
+            ret.push((area, perimeter));
+        }
+    }
+    ret
+}
+
+pub fn part1(computed_areas: &[(usize, HashSet<(Pos, Edge)>)]) -> usize {
+    let mut sum = 0;
+    for (area, perimeter) in computed_areas {
+        sum += *area * perimeter.len();
+    }
+    sum
+}
+
+pub fn part2(computed_areas: &[(usize, HashSet<(Pos, Edge)>)]) -> usize {
+    let mut sum = 0;
+    for (area, perimeter) in computed_areas {
+        let mut seen = HashSet::new();
+        let mut straight_edges = 0;
+        for (pos, edge) in perimeter {
+            let edge = *edge;
+            if !seen.insert((*pos, edge)) { continue; }
+            straight_edges += 1;
+            match edge {
+                Edge::Upper | Edge::Lower => {
+                    for x in (0..pos.x).rev() {
+                        let pos2 = Pos { x, y: pos.y };
+                        if !perimeter.contains(&(pos2, edge)) { break; }
+                        if !seen.insert((pos2, edge)) { break; }
+                    }
+                    for x in (pos.x + 1).. {
+                        let pos2 = Pos { x, y: pos.y };
+                        if !perimeter.contains(&(pos2, edge)) { break; }
+                        if !seen.insert((pos2, edge)) { break; }
+                    }
+                },
+                Edge::Left | Edge::Right => {
+                    for y in (0..pos.y).rev() {
+                        let pos2 = Pos { x: pos.x, y };
+                        if !perimeter.contains(&(pos2, edge)) { break; }
+                        if !seen.insert((pos2, edge)) { break; }
+                    }
+                    for y in (pos.y + 1).. {
+                        let pos2 = Pos { x: pos.x, y };
+                        if !perimeter.contains(&(pos2, edge)) { break; }
+                        if !seen.insert((pos2, edge)) { break; }
+                    }
+                },
+            }
+        }
+
+        sum += *area * straight_edges;
+    }
+    sum
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<(usize, HashSet<(Pos, Edge)>)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        Ok(compute(&parse(puzzle_input).map_err(|e| e.to_string())?))
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX1: &str = "AAAA
+BBCD
+BBCC
+EEEC";
+
+    const EX2: &str = "OOOOO
+OXOXO
+OOOOO
+OXOXO
+OOOOO";
+
+
+    const EX3: &str = "RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE";
+
+    const EX4: &str = "EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE";
+
+    const EX5: &str = "AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA";
+
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&compute(&parse(EX1).unwrap())), 140);
+        assert_eq!(part1(&compute(&parse(EX2).unwrap())), 772);
+        assert_eq!(part1(&compute(&parse(EX3).unwrap())), 1930);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&compute(&parse(EX1).unwrap())), 80);
+        assert_eq!(part2(&compute(&parse(EX2).unwrap())), 436);
+        assert_eq!(part2(&compute(&parse(EX3).unwrap())), 1206);
+        assert_eq!(part2(&compute(&parse(EX4).unwrap())), 236);
+        assert_eq!(part2(&compute(&parse(EX5).unwrap())), 368);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("AAAA\nBBcD").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 3));
+        let err = parse("AAAA\nBBC").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 4));
+    }
+}
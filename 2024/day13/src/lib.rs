@@ -0,0 +1,185 @@
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd, Debug)]
+struct Pos {
+    x: usize,
+    y: usize,
+}
+
+impl std::ops::Add for Pos {
+    type Output = Pos;
+    fn add(self, other: Pos) -> Pos {
+        Pos { x: self.x + other.x, y: self.y + other.y }
+    }
+}
+
+impl std::ops::Sub for Pos {
+    type Output = Pos;
+    fn sub(self, other: Pos) -> Pos {
+        assert!(self.x >= other.x && self.y >= other.y);
+        Pos { x: self.x - other.x, y: self.y - other.y }
+    }
+}
+
+fn abs_diff(a: usize, b: usize) -> usize {
+    if a > b { a - b } else { b - a }
+}
+
+impl Pos {
+    fn scale_by(self, factor: usize) -> Pos {
+        Pos { x: self.x * factor, y: self.y * factor }
+    }
+}
+
+pub struct Machine {
+    button_a: Pos,
+    button_b: Pos,
+    prize: Pos,
+}
+
+
+// Like "Button A: X+94, Y+34" or "Prize: X=8400, Y=5400"
+fn parse_pos(line: &Line, prefix: &str, sign: &str) -> Result<Pos, ParseError> {
+    let (x, y) = line.as_field().strip_prefix(prefix)?.split_once(", ")?;
+    Ok(Pos {
+        x: x.strip_prefix(&format!("X{}", sign))?.parse()?,
+        y: y.strip_prefix(&format!("Y{}", sign))?.parse()?,
+    })
+}
+
+fn parse_machine(lines: &[Line]) -> Result<Machine, ParseError> {
+    let (a, b, prize) = match lines {
+        [a, b, prize] => (a, b, prize),
+        _ => return Err(lines[0].error(1, "expected buttons A and B then the prize")),
+    };
+    let machine = Machine {
+        button_a: parse_pos(a, "Button A: ", "+")?,
+        button_b: parse_pos(b, "Button B: ", "+")?,
+        prize: parse_pos(prize, "Prize: ", "=")?,
+    };
+    // It's technically possible to solve for these cases too but the input doesn't contain
+    // them so it simplifies the implementation
+    if machine.button_a.x == machine.button_a.y {
+        return Err(a.error(1, "expected button A to move different amounts along X and Y"));
+    }
+    if machine.button_b.x == machine.button_b.y {
+        return Err(b.error(1, "expected button B to move different amounts along X and Y"));
+    }
+    Ok(machine)
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<Machine>, ParseError> {
+    parsing::sections(puzzle_input).iter().map(|section| parse_machine(section)).collect()
+}
+
+
+// Ternary search... Probably a way to formulate this as binary search but it's good enough
+fn cheapest_win(button_a: Pos, button_b: Pos, prize: Pos) -> Option<usize> {
+    let mut left = 0;
+    let mut right = 1 + std::cmp::min(prize.x / button_a.x, prize.y / button_a.y);
+    let f = |a_presses: usize| -> usize {
+        let pos = button_a.scale_by(a_presses);
+        abs_diff((prize.x - pos.x) / button_b.x, (prize.y - pos.y) / button_b.y)
+    };
+
+    while right - left > 2 {
+        let m1 = left + (right - left) / 3;
+        let m2 = right - (right - left) / 3;
+
+        if f(m1) < f(m2) {
+            right = m2;
+        } else {
+            left = m1;
+
+        }
+    }
+
+    for a_presses in left..right {
+        let pos = button_a.scale_by(a_presses);
+        if prize.x < pos.x { continue; }
+        let b_presses = (prize.x - pos.x) / button_b.x;
+        if pos + button_b.scale_by(b_presses) == prize {
+            return Some(a_presses * 3 + b_presses);
+        }
+    }
+    None
+}
+
+pub fn part1(machines: &[Machine]) -> usize {
+    let mut sum = 0;
+    for machine in machines {
+        sum += cheapest_win(machine.button_a, machine.button_b, machine.prize).unwrap_or(0);
+    }
+    sum
+}
+
+pub fn part2(machines: &[Machine]) -> usize {
+    let mut sum = 0;
+    for machine in machines {
+        let prize = Pos {
+            x: machine.prize.x + 10000000000000, y: machine.prize.y + 10000000000000,
+        };
+        sum += cheapest_win(machine.button_a, machine.button_b, prize).unwrap_or(0);
+    }
+    sum
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Machine>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279";
+
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 480);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400\n\nButton A: X+26, Y+66\nButton B: X+67, Y+21").err().unwrap();
+        assert_eq!((err.line, err.column), (5, 1));
+        let err = parse("Button A: X+94, Y+34\nButton B: X+22, Y-67\nPrize: X=8400, Y=5400").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 17));
+        let err = parse("Button A: X+34, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 1));
+        let err = parse("Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize X=8400, Y=5400").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 1));
+    }
+}
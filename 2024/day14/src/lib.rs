@@ -0,0 +1,149 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use frames::{Animation, Frame};
+use params::Params;
+use parsing::ParseError;
+use solution::Solution;
+
+#[derive(Copy, Clone, Hash, PartialEq, Eq, Debug)]
+pub struct Pos {
+    x: usize,
+    y: usize,
+}
+
+#[derive(Copy, Clone)]
+pub struct Dir {
+    dx: isize,
+    dy: isize,
+}
+
+// The real input's space, which the example shrinks
+pub const WIDTH: usize = 101;
+pub const HEIGHT: usize = 103;
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<(Pos, Dir)>, ParseError> {
+    parsing::parse_lines(puzzle_input, |line| {
+        let [x, y, dx, dy] = line.numbers_n::<isize, 4>()?;
+        if x < 0 || y < 0 {
+            return Err(line.error(1, "robots must start at a non-negative position"));
+        }
+        Ok((Pos { x: x as usize, y: y as usize }, Dir { dx, dy }))
+    })
+}
+
+pub fn part1(robots: &[(Pos, Dir)], width: usize, height: usize) -> usize {
+    let mut positions = Vec::new();
+    for robot in robots {
+        positions.push(Pos {
+            x: (robot.0.x + 100 * (width as isize + robot.1.dx) as usize) % width,
+            y: (robot.0.y + 100 * (height as isize + robot.1.dy) as usize) % height,
+        });
+    }
+    let mut quadrants = vec![0usize; 4];
+    for pos in &positions {
+        if pos.y < height / 2 {
+            if pos.x < width / 2 {
+                quadrants[0] += 1;
+            } else if pos.x > width / 2 {
+                quadrants[1] += 1;
+            }
+        } else if pos.y > height / 2 {
+            if pos.x < width / 2 {
+                quadrants[2] += 1;
+            } else if pos.x > width / 2 {
+                quadrants[3] += 1;
+            }
+        }
+    }
+    quadrants.iter().cloned().product()
+}
+
+fn to_frame(positions: &HashMap<Pos, usize>, width: usize, height: usize) -> Frame {
+    let mut frame = Frame::new(width, height, '.');
+    for (pos, &cnt) in positions {
+        frame.set(pos.x, pos.y, if cnt > 9 { '*' } else { char::from_digit(cnt as u32, 10).unwrap() });
+    }
+    frame
+}
+
+pub fn part2(robots: &[(Pos, Dir)], width: usize, height: usize, animation: &mut Animation) -> usize {
+    for iters in 1..(width * height) {
+        let mut positions = HashMap::new();
+        for (pos, dir) in robots {
+            positions.entry(Pos {
+                x: (pos.x + iters * (width as isize + dir.dx) as usize) % width,
+                y: (pos.y + iters * (height as isize + dir.dy) as usize) % height,
+            }).and_modify(|cnt| *cnt += 1).or_insert(1);
+        }
+        animation.frame(|| to_frame(&positions, width, height));
+
+        // If enough robots are next to each other, that's the easter egg
+        let mut contiguous = 0usize;
+        for (pos, _) in &positions {
+            if pos.x > 0 && positions.contains_key(&Pos { x: pos.x - 1, y: pos.y }) { contiguous += 1; }
+            if pos.y > 0 && positions.contains_key(&Pos { x: pos.x, y: pos.y - 1 }) { contiguous += 1; }
+        }
+        if contiguous >= robots.len() {
+            return iters;
+        }
+    }
+    panic!()
+}
+
+// The robots along with the size of the space they're in and where part 2's frames go
+pub struct Lobby {
+    pub robots: Vec<(Pos, Dir)>,
+    pub width: usize,
+    pub height: usize,
+    pub animation: RefCell<Animation>,
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Lobby;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        let robots = parse(puzzle_input).map_err(|e| e.to_string())?;
+        Ok(Lobby { robots, width: WIDTH, height: HEIGHT, animation: RefCell::new(Animation::off()) })
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(&input.robots, input.width, input.height)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(&input.robots, input.width, input.height, &mut input.animation.borrow_mut())
+    }
+
+    fn configure(input: &mut Self::Input, params: &Params) {
+        input.width = params.get("width", WIDTH);
+        input.height = params.get("height", HEIGHT);
+        input.animation = RefCell::new(Animation::from_params(params));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap(), 11, 7), 12);
+    }
+}
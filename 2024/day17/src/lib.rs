@@ -0,0 +1,375 @@
+use parsing::{Line, ParseError};
+use register_machine::{Flow, Machine, Status};
+use solution::Solution;
+
+#[derive(Copy, Clone)]
+pub struct Registers {
+    a: u64,
+    b: u64,
+    c: u64,
+}
+
+pub type Program = Vec<u8>;
+
+pub fn parse(puzzle_input: &str) -> Result<(Registers, Program), ParseError> {
+    let sections = parsing::sections(puzzle_input);
+    let (registers, program) = match &sections[..] {
+        [registers, program] => (registers, program),
+        _ => {
+            let line = sections.get(2).map_or_else(|| Line {
+                number: parsing::lines(puzzle_input).last().map_or(1, |line| line.number + 1),
+                text: "",
+            }, |section| section[0]);
+            return Err(line.error(1, "expected the registers then the program"));
+        }
+    };
+    let [a, b, c] = match &registers[..] {
+        [a, b, c] => [
+            a.as_field().strip_prefix("Register A: ")?.parse()?,
+            b.as_field().strip_prefix("Register B: ")?.parse()?,
+            c.as_field().strip_prefix("Register C: ")?.parse()?,
+        ],
+        _ => return Err(registers[0].error(1, "expected registers A, B and C")),
+    };
+    let registers = Registers { a, b, c };
+
+    let program = match &program[..] {
+        [line] => line.as_field().strip_prefix("Program: ")?,
+        _ => return Err(program[1].error(1, "expected the program on one line")),
+    };
+    let values = program.split(",");
+    let mut parsed = Vec::with_capacity(values.len());
+    for value in &values {
+        let v = value.parse::<u8>()?;
+        if v > 7 {
+            return Err(value.error("expected a 3-bit value"));
+        }
+        parsed.push(v);
+    }
+    if parsed.len() % 2 != 0 {
+        return Err(program.error("expected opcode and operand pairs"));
+    }
+    Ok((registers, parsed))
+}
+
+fn combo(registers: &Registers, op: u8) -> u64 {
+    match op {
+        0 | 1 | 2 | 3 => op as u64,
+        4 => registers.a,
+        5 => registers.b,
+        6 => registers.c,
+        _ => panic!(),
+    }
+}
+
+#[derive(Copy, Clone)]
+struct Instruction {
+    opcode: u8,
+    operand: u8,
+}
+
+// Every position in the program decodes as an instruction with the following value as its
+// operand, so that jumps to any address (even or odd) land on the right instruction.
+fn decode(program: &Program) -> Vec<Instruction> {
+    program.windows(2).map(|w| Instruction { opcode: w[0], operand: w[1] }).collect()
+}
+
+impl register_machine::Instruction<Registers, Program> for Instruction {
+    fn execute(&self, _pc: usize, registers: &mut Registers, output: &mut Program) -> Flow {
+        let literal_op = self.operand as u64;
+        match self.opcode {
+            0 /* adv (division) */ => {
+                let combo_op = combo(registers, self.operand);
+                registers.a = registers.a / (1 << combo_op);
+            },
+            1 /* bxl */ => {
+                registers.b = registers.b ^ literal_op;
+            },
+            2 /* bst */ => {
+                let combo_op = combo(registers, self.operand);
+                registers.b = combo_op % 8;
+            },
+            3 /* jnz */ => {
+                if registers.a != 0 {
+                    return Flow::JumpAbsolute(literal_op as i64);
+                }
+            },
+            4 /* bxc */ => {
+                registers.b = registers.b ^ registers.c;
+            },
+            5 /* out */ => {
+                let combo_op = combo(registers, self.operand);
+                output.push((combo_op % 8) as u8);
+            },
+            6 /* bdv */ => {
+                let combo_op = combo(registers, self.operand);
+                registers.b = registers.a / (1 << combo_op);
+            },
+            7 /* cdv */ => {
+                let combo_op = combo(registers, self.operand);
+                registers.c = registers.a / (1 << combo_op);
+            },
+            _ => panic!(),
+        }
+        Flow::JumpRelative(2)
+    }
+}
+
+fn execute(registers: Registers, program: &Program) -> Program {
+    let mut output = Vec::with_capacity(program.len());
+    Machine::new(registers).run(&decode(program), &mut output);
+    output
+}
+
+pub fn part1(registers: Registers, program: &Program) -> String {
+    let output = execute(registers, program);
+    let mut output_str = String::with_capacity(output.len() * 2 + 1);
+    let mut first = true;
+    for v in output {
+        if first {
+            first = false;
+        } else {
+            output_str.push(',');
+        }
+        output_str.push_str(&v.to_string());
+    }
+    output_str
+}
+
+const MNEMONICS: [&str; 8] = ["adv", "bxl", "bst", "jnz", "bxc", "out", "bdv", "cdv"];
+
+fn combo_name(op: u8) -> String {
+    match op {
+        0 | 1 | 2 | 3 => op.to_string(),
+        4 => "A".to_string(),
+        5 => "B".to_string(),
+        6 => "C".to_string(),
+        _ => "<reserved>".to_string(),
+    }
+}
+
+fn disassemble(program: &Program) -> String {
+    program.chunks(2).enumerate().map(|(i, chunk)| {
+        let (opcode, operand) = (chunk[0], chunk[1]);
+        let mnemonic = MNEMONICS[opcode as usize];
+        match opcode {
+            1 | 3 => format!("{:2}: {} {}", i * 2, mnemonic, operand), // Literal operand
+            4 => format!("{:2}: {}", i * 2, mnemonic), // Operand ignored
+            _ => format!("{:2}: {} {}", i * 2, mnemonic, combo_name(operand)),
+        }
+    }).collect::<Vec<_>>().join("\n")
+}
+
+// Both part 2 solvers need the program to be a single loop which shifts A right by a constant
+// number of bits once per iteration and jumps back to the start until A is zero. Returns the number
+// of bits shifted and the number of outputs per iteration.
+fn loop_shape(program: &Program) -> Result<(u64, usize), String> {
+    let mut shift = None;
+    let mut outputs = 0;
+    for (i, chunk) in program.chunks(2).enumerate() {
+        let (opcode, operand) = (chunk[0], chunk[1]);
+        let is_last = (i + 1) * 2 == program.len();
+        if matches!(opcode, 0 | 2 | 5 | 6 | 7) && operand == 7 {
+            return Err(format!("instruction {} uses the reserved combo operand 7", i * 2));
+        }
+        match opcode {
+            0 => {
+                if shift.is_some() {
+                    return Err("A is shifted more than once per iteration".to_string());
+                } else if !(1..=3).contains(&operand) {
+                    return Err(format!("A is shifted by {} instead of a constant", combo_name(operand)));
+                }
+                shift = Some(operand as u64);
+            },
+            3 if !is_last || operand != 0 => return Err(format!("instruction {} isn't a jump back to the start of the loop", i * 2)),
+            5 => outputs += 1,
+            _ => (),
+        }
+    }
+    if program.len() < 2 || program[program.len() - 2] != 3 {
+        return Err("the program doesn't end by jumping back to the start".to_string());
+    }
+    let shift = shift.ok_or_else(|| "A is never shifted".to_string())?;
+    Ok((shift, outputs))
+}
+
+// The fast search for part 2 also needs each iteration to output one value which only depends on
+// A, because B and C are always written before they are read. Returns the number of bits shifted
+// per iteration.
+fn check_loop_shift(program: &Program) -> Result<u64, String> {
+    let (shift, outputs) = loop_shape(program)?;
+    let mut b_written = false;
+    let mut c_written = false;
+    for (i, chunk) in program.chunks(2).enumerate() {
+        let (opcode, operand) = (chunk[0], chunk[1]);
+        let uses_combo = matches!(opcode, 0 | 2 | 5 | 6 | 7);
+        let reads_b = matches!(opcode, 1 | 4) || (uses_combo && operand == 5);
+        let reads_c = opcode == 4 || (uses_combo && operand == 6);
+        if reads_b && !b_written {
+            return Err(format!("instruction {} reads B before it is written, so it carries over between iterations", i * 2));
+        }
+        if reads_c && !c_written {
+            return Err(format!("instruction {} reads C before it is written, so it carries over between iterations", i * 2));
+        }
+        match opcode {
+            2 | 4 | 6 => b_written = true,
+            7 => c_written = true,
+            _ => (),
+        }
+    }
+    if outputs != 1 {
+        return Err(format!("there are {} outputs per iteration instead of one", outputs));
+    }
+    Ok(shift)
+}
+
+// Each iteration's output only depends on the value of A, which is shifted down each iteration.
+// So the last output only depends on the top bits of A, the second last on the top bits and the
+// next chunk, etc. and we can search for each chunk of A working backwards through the program.
+fn search(registers: Registers, program: &Program, shift: u64, prefix: u64) -> Option<u64> {
+    let start = if prefix == 0 { 1 } else { 0 };
+    for suffix in start..(1 << shift) {
+        let curr = (prefix << shift) + suffix;
+        let output = execute(Registers { a: curr, ..registers }, program);
+        if output.len() <= program.len() && output == program[(program.len() - output.len())..] {
+            if output.len() == program.len() {
+                return Some(curr);
+            } else if let Some(ret) = search(registers, program, shift, curr) {
+                return Some(ret);
+            }
+        }
+    }
+    None
+}
+
+// Anything bigger would take hours to search
+const MAX_BRUTE_FORCE_BITS: u64 = 32;
+
+// Tries every value of A in order, stopping each run as soon as the output diverges from the
+// program. Much slower, but works when B or C carry over between iterations or there isn't one
+// output per iteration. The loop runs once per shift bits of A, so only values of A below
+// 2^(shift * iterations) can print as many values as there are in the program.
+fn brute_force(registers: Registers, program: &Program) -> Result<u64, String> {
+    let (shift, outputs) = loop_shape(program)?;
+    if outputs == 0 || !program.len().is_multiple_of(outputs) {
+        return Err(format!("{} outputs per iteration can't print all {} values of the program", outputs, program.len()));
+    }
+    let bits = shift * (program.len() / outputs) as u64;
+    if bits > MAX_BRUTE_FORCE_BITS {
+        return Err(format!("trying every value of A below 2^{} would take too long", bits));
+    }
+    let instructions = decode(program);
+    (1..1 << bits).find(|&a| {
+        let mut machine = Machine::new(Registers { a, ..registers });
+        let mut output = Vec::with_capacity(program.len());
+        while machine.step(&instructions, &mut output) == Status::Running {
+            if output.len() > program.len() || output.last().is_some_and(|&v| v != program[output.len() - 1]) {
+                return false;
+            }
+        }
+        output == *program
+    }).ok_or_else(|| format!("no value of A below 2^{} prints the program", bits))
+}
+
+pub fn part2(registers: Registers, program: &Program) -> u64 {
+    match check_loop_shift(program) {
+        Ok(shift) => search(registers, program, shift, 0).unwrap(),
+        Err(_) => brute_force(registers, program).unwrap_or_else(|reason| {
+            panic!("Can't solve part 2 because {}:\n{}", reason, disassemble(program))
+        })
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Registers, Program);
+    type Answer1 = String;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        part1(input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input.0, &input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX1: &str = "Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0";
+
+    const EX2: &str = "Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0";
+
+    #[test]
+    fn test_part1() {
+        let (registers, program) = parse(EX1).unwrap();
+        assert_eq!(part1(registers, &program), "4,6,3,5,6,3,5,2,1,0");
+    }
+
+    #[test]
+    fn test_part2() {
+        let (registers, program) = parse(EX2).unwrap();
+        assert_eq!(part2(registers, &program), 117440);
+    }
+
+    #[test]
+    fn test_disassemble() {
+        let (_, program) = parse(EX2).unwrap();
+        assert_eq!(disassemble(&program), " 0: adv 3\n 2: out A\n 4: jnz 0");
+        assert_eq!(disassemble(&vec![2, 4, 1, 7, 4, 0, 7, 5]), " 0: bst A\n 2: bxl 7\n 4: bxc\n 6: cdv B");
+    }
+
+    #[test]
+    fn test_check_loop_shift() {
+        assert_eq!(check_loop_shift(&parse(EX1).unwrap().1), Ok(1));
+        assert_eq!(check_loop_shift(&parse(EX2).unwrap().1), Ok(3));
+        // bxl 1, out B, adv 3, jnz 0
+        assert!(check_loop_shift(&vec![1, 1, 5, 5, 0, 3, 3, 0]).unwrap_err().contains("reads B"));
+        // bst A, adv B, out A, jnz 0
+        assert!(check_loop_shift(&vec![2, 4, 0, 5, 5, 4, 3, 0]).unwrap_err().contains("instead of a constant"));
+    }
+
+    #[test]
+    fn test_brute_force() {
+        let (registers, program) = parse(EX2).unwrap();
+        assert_eq!(brute_force(registers, &program), Ok(117440));
+        // out A, jnz 0
+        assert!(brute_force(registers, &vec![5, 4, 3, 0]).unwrap_err().contains("never shifted"));
+        // bst A, adv B, out A, jnz 0
+        assert!(brute_force(registers, &vec![2, 4, 0, 5, 5, 4, 3, 0]).unwrap_err().contains("instead of a constant"));
+        // adv 1, out A, out A, jnz 0 prints pairs of equal values, which the program isn't
+        assert!(brute_force(registers, &vec![0, 1, 5, 4, 5, 4, 3, 0]).unwrap_err().contains("below 2^4"));
+        // bxl 1 four times, adv 3, out A, jnz 0 would need A up to 2^42
+        assert!(brute_force(registers, &vec![1, 1, 1, 1, 1, 1, 1, 1, 0, 3, 5, 4, 3, 0]).unwrap_err().contains("too long"));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,4,3").err().unwrap();
+        assert_eq!((err.line, err.column), (5, 10));
+        let err = parse("Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,8,5,4").err().unwrap();
+        assert_eq!((err.line, err.column), (5, 12));
+        let err = parse("Register A: 729\nRegister X: 0\nRegister C: 0\n\nProgram: 0,1").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("Register A: 729\nRegister B: 0\n\nProgram: 0,1").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 1));
+        let err = parse("Register A: 729\nRegister B: 0\nRegister C: 0").err().unwrap();
+        assert_eq!((err.line, err.column), (4, 1));
+    }
+}
@@ -0,0 +1,133 @@
+use std::collections::{HashSet, VecDeque};
+
+use parsing::ParseError;
+use solution::Solution;
+
+#[derive(Copy, Clone, Hash, Eq, PartialEq)]
+pub struct Pos {
+    x: usize,
+    y: usize,
+}
+
+// The real input's grid size and how many bytes have fallen for part 1
+const SIZE: usize = 71;
+const FALLEN: usize = 1024;
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<Pos>, ParseError> {
+    parsing::parse_lines(puzzle_input, |line| {
+        let (x, y) = line.as_field().split_once(",")?;
+        Ok(Pos { x: x.parse()?, y: y.parse()? })
+    })
+}
+
+fn walls_from_positions(width: usize, height: usize, positions: &[Pos]) -> Vec<bool> {
+    let mut walls = vec![false; width * height];
+    for &pos in positions {
+        walls[pos.y * width + pos.x] = true;
+    }
+    walls
+}
+
+fn find_shortest_path(width: usize, height: usize, walls: &[bool]) -> Option<usize> {
+    let mut deque = VecDeque::new();
+    let finish = Pos { x: width - 1, y: height - 1 };
+    deque.push_back((Pos { x: 0, y: 0 }, 0));
+    let mut seen = HashSet::new();
+    while let Some((pos, steps)) = deque.pop_front() {
+        if pos == finish { return Some(steps); }
+        if walls[pos.y * width + pos.x] { continue; }
+        if !seen.insert(pos) { continue; }
+
+        let steps = steps + 1;
+        if pos.x > 0          { deque.push_back((Pos { x: pos.x - 1, y: pos.y     }, steps)); }
+        if pos.x < width - 1  { deque.push_back((Pos { x: pos.x + 1, y: pos.y     }, steps)); }
+        if pos.y > 0          { deque.push_back((Pos { x: pos.x    , y: pos.y - 1 }, steps)); }
+        if pos.y < height - 1 { deque.push_back((Pos { x: pos.x    , y: pos.y + 1 }, steps)); }
+    }
+    None
+}
+
+pub fn part1(width: usize, height: usize, positions: &[Pos]) -> usize {
+    let walls = walls_from_positions(width, height, positions);
+    find_shortest_path(width, height, &walls).unwrap()
+}
+
+pub fn part2(width: usize, height: usize, positions: &[Pos]) -> String {
+    let mut walls = walls_from_positions(width, height, &[]);
+    for pos in positions {
+        walls[pos.y * width + pos.x] = true;
+        if find_shortest_path(width, height, &walls).is_none() {
+            return format!("{},{}", pos.x, pos.y);
+        }
+    }
+    unreachable!();
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Pos>;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(SIZE, SIZE, &input[..FALLEN])
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        part2(SIZE, SIZE, input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(7, 7, &parse(EX).unwrap()[0..12]), 22);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(&part2(7, 7, &parse(EX).unwrap()), "6,1");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("5,4\n4;2").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("5,4\n4,x").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+}
@@ -0,0 +1,118 @@
+use parsing::{Field, Line, ParseError};
+use solution::Solution;
+
+fn parse_stripes(field: &Field) -> Result<String, ParseError> {
+    let stripes = field.map_chars(|ch| if ch.is_ascii_lowercase() { Some(ch) } else { None })?;
+    if stripes.is_empty() {
+        return Err(field.error("expected some stripes"));
+    }
+    Ok(stripes.into_iter().collect())
+}
+
+fn parse_available(lines: &[Line]) -> Result<Vec<String>, ParseError> {
+    match lines {
+        [line] => line.split(", ").iter().map(parse_stripes).collect(),
+        _ => Err(lines[1].error(1, "expected the towel patterns on one line")),
+    }
+}
+
+fn parse_designs(lines: &[Line]) -> Result<Vec<String>, ParseError> {
+    lines.iter().map(|line| parse_stripes(&line.as_field())).collect()
+}
+
+pub fn parse(puzzle_input: &str) -> Result<(Vec<String>, Vec<String>), ParseError> {
+    let sections = parsing::sections(puzzle_input);
+    let (available, designs) = match &sections[..] {
+        [available, designs] => (available, designs),
+        _ => {
+            let line = sections.get(2).map_or_else(|| Line {
+                number: parsing::lines(puzzle_input).last().map_or(1, |line| line.number + 1),
+                text: "",
+            }, |section| section[0]);
+            return Err(line.error(1, "expected the towel patterns then the designs"));
+        }
+    };
+    Ok((parse_available(available)?, parse_designs(designs)?))
+}
+
+fn num_possible_designs(available: &[String], design: &str) -> usize {
+    let mut counts = vec![0; design.len() + 1];
+    counts[0] = 1;
+    for idx in 0..design.len() {
+        let cnt = counts[idx];
+        for towel in available {
+            if design[idx..].starts_with(towel) {
+                counts[idx + towel.len()] += cnt;
+            }
+        }
+    }
+    counts[design.len()]
+}
+
+pub fn part1(available: &[String], designs: &[String]) -> usize {
+    designs.iter().filter(|design| num_possible_designs(available, design) != 0).count()
+}
+
+pub fn part2(available: &[String], designs: &[String]) -> usize {
+    designs.iter().map(|design| num_possible_designs(available, design)).sum()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<String>, Vec<String>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(&input.0, &input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb";
+
+    #[test]
+    fn test_part1() {
+        let (available, designs) = parse(EX).unwrap();
+        assert_eq!(part1(&available, &designs), 6);
+    }
+
+    #[test]
+    fn test_part2() {
+        let (available, designs) = parse(EX).unwrap();
+        assert_eq!(part2(&available, &designs), 16);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("r, wr, b\n\nbrwrr\nb1wr").err().unwrap();
+        assert_eq!((err.line, err.column), (4, 2));
+        let err = parse("r, , b\n\nbrwrr").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 4));
+        let err = parse("r, wr\nb\n\nbrwrr").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("r, wr").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
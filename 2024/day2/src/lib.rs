@@ -0,0 +1,92 @@
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<Vec<u64>>, ParseError> {
+    parsing::parse_lines(puzzle_input, |line| {
+        let report = line.fields().iter().map(|level| level.parse()).collect::<Result<Vec<_>, _>>()?;
+        if report.is_empty() {
+            return Err(line.error(1, "expected a report of levels"));
+        }
+        Ok(report)
+    })
+}
+
+fn check_decreasing(report: &[u64]) -> bool {
+    report.windows(2).all(|w| w[0] <= w[1] + 3 && w[0] >= w[1] + 1)
+}
+
+fn check_increasing(report: &[u64]) -> bool {
+    report.windows(2).all(|w| w[1] <= w[0] + 3 && w[1] >= w[0] + 1)
+}
+
+fn is_safe(report: &[u64]) -> bool {
+    check_decreasing(report) || check_increasing(report)
+}
+
+
+pub fn part1(reports: &[Vec<u64>]) -> usize {
+    reports.iter().filter(|report| is_safe(report)).count()
+}
+
+pub fn part2(reports: &[Vec<u64>]) -> usize {
+    reports.iter().filter(|orig_report| {
+        if is_safe(orig_report) { return true; }
+        let mut report = orig_report[1..].to_vec();
+        if is_safe(&report) { return true; }
+        for i in 0..report.len() {
+            report[i] = orig_report[i];
+            if is_safe(&report) { return true; }
+        }
+        false
+    }).count()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<u64>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 2);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 4);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("7 6 4\n1 x 3").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 3));
+        let err = parse("7 6 4\n\n1 2").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
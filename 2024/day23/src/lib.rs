@@ -0,0 +1,155 @@
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse(puzzle_input: &str) -> Result<HashMap<String, HashSet<String>>, ParseError> {
+    let connections_raw = parsing::parse_lines(puzzle_input, |line| {
+        let (a, b) = line.as_field().split_once("-")?;
+        if a.text == b.text {
+            return Err(b.error("expected a connection between two different computers"));
+        }
+        Ok((a.text.to_string(), b.text.to_string()))
+    })?;
+
+    let mut connections: HashMap<String, HashSet<String>> = HashMap::new();
+    for (a, b) in connections_raw {
+        connections.entry(a.clone())
+            .and_modify(|set| { set.insert(b.clone()); })
+            .or_insert_with(|| {
+                let mut set = HashSet::new();
+                set.insert(b.clone());
+                set
+            });
+        connections.entry(b.clone())
+            .and_modify(|set| { set.insert(a.clone()); })
+            .or_insert_with(|| {
+                let mut set = HashSet::new();
+                set.insert(a.clone());
+                set
+            });
+    }
+    Ok(connections)
+}
+
+pub fn part1(connections: &HashMap<String, HashSet<String>>) -> usize {
+    let mut cnt = 0;
+    let mut seen = HashSet::new();
+    for (a, others_a) in connections {
+        for b in others_a {
+            if a == b { continue; }
+            for c in &connections[b] {
+                if a == c || b == c { continue; }
+                if !connections[c].contains(a) { continue; }
+                if !seen.insert(BTreeSet::from([a.clone(), b.clone(), c.clone()])) { continue; }
+
+                if a.starts_with("t") || b.starts_with("t") || c.starts_with("t") {
+                    cnt += 1;
+                }
+            }
+        }
+    }
+    cnt
+}
+
+pub fn part2(connections: &HashMap<String, HashSet<String>>) -> String {
+    let mut seen = HashSet::new();
+    let mut best = BTreeSet::new();
+    let mut deque = VecDeque::new();
+    for (x, _) in connections {
+        deque.push_back(BTreeSet::from([x.clone()]));
+    }
+
+    while let Some(set) = deque.pop_front() {
+        if !seen.insert(set.clone()) { continue; }
+        if set.len() > best.len() { best = set.clone(); }
+
+        for x in &connections[set.iter().next().unwrap()] {
+            if set.contains(x) { continue; }
+            let others = &connections[x];
+            if set.iter().all(|y| others.contains(y)) {
+                let mut set2 = set.clone();
+                set2.insert(x.clone());
+                deque.push_back(set2);
+            }
+        }
+    }
+
+    best.iter().cloned().collect::<Vec<_>>().join(",")
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = HashMap<String, HashSet<String>>;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 7);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(&part2(&parse(EX).unwrap()), "co,de,ka,ta");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("kh-tc\nqp kh").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("kh-kh").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 4));
+    }
+}
@@ -0,0 +1,347 @@
+use std::collections::HashMap;
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Op {
+    OR, AND, XOR
+}
+pub struct Gate {
+    in1: String,
+    in2: String,
+    op: Op,
+    out: String,
+}
+
+fn parse_inputs(lines: &[Line]) -> Result<HashMap<String, bool>, ParseError> {
+    lines.iter().map(|line| {
+        let (wire, val) = line.as_field().split_once(": ")?;
+        let val = match val.text {
+            "0" => false,
+            "1" => true,
+            _ => return Err(val.error("expected 0 or 1")),
+        };
+        Ok((wire.text.to_string(), val))
+    }).collect()
+}
+
+fn parse_gates(lines: &[Line]) -> Result<Vec<Gate>, ParseError> {
+    lines.iter().map(|line| {
+        let fields = line.fields();
+        let (in1, op, in2, out) = match &fields[..] {
+            [in1, op, in2, arrow, out] if arrow.text == "->" => (in1, op, in2, out),
+            _ => return Err(line.error(1, "expected a gate like \"x00 AND y00 -> z00\"")),
+        };
+        let op = match op.text {
+            "OR"  => Op::OR,
+            "AND" => Op::AND,
+            "XOR" => Op::XOR,
+            _ => return Err(op.error("expected AND, OR or XOR")),
+        };
+        let (in1, in2, out) = (in1.text.to_string(), in2.text.to_string(), out.text.to_string());
+        Ok(Gate { in1, in2, op, out })
+    }).collect()
+}
+
+
+pub fn parse(puzzle_input: &str) -> Result<(HashMap<String, bool>, Vec<Gate>), ParseError> {
+    let sections = parsing::sections(puzzle_input);
+    let (inputs, gates) = match &sections[..] {
+        [inputs, gates] => (inputs, gates),
+        _ => {
+            let line = sections.get(2).map_or_else(|| Line {
+                number: parsing::lines(puzzle_input).last().map_or(1, |line| line.number + 1),
+                text: "",
+            }, |section| section[0]);
+            return Err(line.error(1, "expected the input wires then the gates"));
+        }
+    };
+    Ok((parse_inputs(inputs)?, parse_gates(gates)?))
+}
+
+// Simulates the circuit with the outputs of some gates swapped, giving the number on the z wires, or
+// None if a swap made a loop that leaves some of them unset
+fn simulate(inputs: &HashMap<String, bool>, gates: &[Gate], swaps: &HashMap<&str, &str>) -> Option<u64> {
+    let out_of = |gate: &Gate| -> String {
+        swaps.get(gate.out.as_str()).map_or(gate.out.clone(), |s| s.to_string())
+    };
+    let mut out_to_gate = HashMap::new();
+    let mut in_to_out = HashMap::new();
+    for gate in gates {
+        out_to_gate.insert(out_of(gate), gate);
+        for input in [&gate.in1, &gate.in2] {
+            in_to_out.entry(input.clone())
+                .and_modify(|outputs: &mut Vec<String>| outputs.push(out_of(gate)))
+                .or_insert(vec![out_of(gate)]);
+        }
+    }
+    let mut wires = HashMap::new();
+    for (input, val) in inputs {
+        wires.insert(input.to_string(), *val);
+    }
+    let mut stack = gates.iter().map(out_of).collect::<Vec<_>>();
+    while let Some(out) = stack.pop() {
+        let gate = out_to_gate[&out];
+        if let Some(outval1) = wires.get(&out) {
+            continue;
+        }
+        if let Some(&inval1) = wires.get(&gate.in1) {
+            if let Some(&inval2) = wires.get(&gate.in2) {
+                let outval = match gate.op {
+                    Op::OR  => inval1 || inval2,
+                    Op::AND => inval1 && inval2,
+                    Op::XOR => inval1 != inval2,
+                };
+                wires.insert(out.clone(), outval);
+                // Possibly recalculate since we have the wire set now
+                if let Some(outputs) = in_to_out.get(&out) {
+                    for out2 in outputs {
+                        stack.push(out2.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    let mut ret = 0;
+    for out in out_to_gate.keys().filter(|out| out.starts_with('z')) {
+        match wires.get(out) {
+            Some(&true) => ret |= 1 << out[1..].parse::<u32>().unwrap(),
+            Some(&false) => (),
+            None => return None,
+        }
+    }
+    Some(ret)
+}
+
+pub fn part1(inputs: &HashMap<String, bool>, gates: &[Gate]) -> u64 {
+    simulate(inputs, gates, &HashMap::new()).unwrap()
+}
+
+fn is_input(wire: &str) -> bool {
+    wire.starts_with('x') || wire.starts_with('y')
+}
+
+// Each section of the adder looks like this:
+//
+// x02 AND y02 -> a02
+// x02 XOR y02 -> b02
+// b01 AND d01 -> c02
+// a01 OR c02 -> d02
+// b02 XOR d02 -> z02
+//
+// with bit 00 being a half adder (x00 XOR y00 -> z00, x00 AND y00 -> carry) and the last carry
+// going straight to the top z wire. Any gate whose output doesn't fit that pattern has been swapped.
+fn suspicious_wires(gates: &[Gate], bits: usize) -> Vec<&str> {
+    let feeds = |out: &str, op: Op| gates.iter().any(|g| g.op == op && (g.in1 == out || g.in2 == out));
+    let top = format!("z{bits:02}");
+    let mut ret: Vec<&str> = gates.iter()
+        .filter(|gate| {
+            let out = gate.out.as_str();
+            let first_bit = gate.in1.ends_with("00") && is_input(&gate.in1);
+            match gate.op {
+                _ if out == top => gate.op != Op::OR,
+                Op::XOR if is_input(&gate.in1) => !first_bit && !feeds(out, Op::XOR),
+                Op::XOR => !out.starts_with('z'),
+                Op::AND => !first_bit && !feeds(out, Op::OR),
+                Op::OR => out.starts_with('z'),
+            }
+        })
+        .map(|gate| gate.out.as_str())
+        .collect();
+    ret.sort();
+    ret
+}
+
+// A few sums that exercise every bit and every carry, plus some arbitrary ones
+fn adds_correctly(gates: &[Gate], bits: usize, swaps: &HashMap<&str, &str>) -> bool {
+    let mask = (1 << bits) - 1;
+    let mut cases = Vec::new();
+    for i in 0..bits {
+        cases.extend([(1 << i, 0), (0, 1 << i), (1 << i, 1 << i), (mask, 1 << i)]);
+    }
+    let mut seed = 0x2024_u64;
+    for _ in 0..16 {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        cases.push((seed & mask, (seed >> 32) & mask));
+    }
+    cases.into_iter().all(|(x, y)| {
+        let mut inputs = HashMap::new();
+        for i in 0..bits {
+            inputs.insert(format!("x{i:02}"), x >> i & 1 == 1);
+            inputs.insert(format!("y{i:02}"), y >> i & 1 == 1);
+        }
+        simulate(&inputs, gates, swaps) == Some(x + y)
+    })
+}
+
+// Tries every way of pairing up the remaining wires, leaving out any the budget allows
+fn find_pairs<'a>(gates: &[Gate], bits: usize, wires: &[&'a str], pairs: usize,
+    swaps: &mut HashMap<&'a str, &'a str>) -> bool {
+    if pairs == 0 {
+        return adds_correctly(gates, bits, swaps);
+    }
+    if wires.len() < pairs * 2 {
+        return false;
+    }
+    let (first, rest) = (wires[0], &wires[1..]);
+    for (i, &other) in rest.iter().enumerate() {
+        swaps.insert(first, other);
+        swaps.insert(other, first);
+        let remaining: Vec<&str> = rest.iter().enumerate().filter(|&(j, _)| j != i).map(|(_, &w)| w).collect();
+        if find_pairs(gates, bits, &remaining, pairs - 1, swaps) {
+            return true;
+        }
+        swaps.remove(first);
+        swaps.remove(other);
+    }
+    find_pairs(gates, bits, rest, pairs, swaps)
+}
+
+fn swapped_wires(gates: &[Gate], pairs: usize) -> Option<String> {
+    let bits = gates.iter().filter(|gate| gate.in1.starts_with('x') || gate.in2.starts_with('x'))
+        .map(|gate| if gate.in1.starts_with('x') { &gate.in1 } else { &gate.in2 })
+        .map(|x| x[1..].parse::<usize>().unwrap() + 1)
+        .max()?;
+    let suspicious = suspicious_wires(gates, bits);
+    let mut swaps = HashMap::new();
+    if !find_pairs(gates, bits, &suspicious, pairs, &mut swaps) {
+        return None;
+    }
+    let mut wires: Vec<&str> = swaps.into_keys().collect();
+    wires.sort();
+    Some(wires.join(","))
+}
+
+pub fn part2(gates: &[Gate]) -> String {
+    swapped_wires(gates, 4).expect("no four swaps make the circuit an adder")
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (HashMap<String, bool>, Vec<Gate>);
+    type Answer1 = u64;
+    type Answer2 = String;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        part2(&input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX1: &str = "x00: 1
+x01: 1
+x02: 1
+y00: 0
+y01: 1
+y02: 0
+
+x00 AND y00 -> z00
+x01 XOR y01 -> z01
+x02 OR y02 -> z02";
+
+    const EX2: &str = "x00: 1
+x01: 0
+x02: 1
+x03: 1
+x04: 0
+y00: 1
+y01: 1
+y02: 1
+y03: 1
+y04: 1
+
+ntg XOR fgs -> mjb
+y02 OR x01 -> tnw
+kwq OR kpj -> z05
+x00 OR x03 -> fst
+tgd XOR rvg -> z01
+vdt OR tnw -> bfw
+bfw AND frj -> z10
+ffh OR nrd -> bqk
+y00 AND y03 -> djm
+y03 OR y00 -> psh
+bqk OR frj -> z08
+tnw OR fst -> frj
+gnj AND tgd -> z11
+bfw XOR mjb -> z00
+x03 OR x00 -> vdt
+gnj AND wpb -> z02
+x04 AND y00 -> kjc
+djm OR pbm -> qhw
+nrd AND vdt -> hwm
+kjc AND fst -> rvg
+y04 OR y02 -> fgs
+y01 AND x02 -> pbm
+ntg OR kjc -> kwq
+psh XOR fgs -> tgd
+qhw XOR tgd -> z09
+pbm OR djm -> kpj
+x03 XOR y03 -> ffh
+x00 XOR y04 -> ntg
+bfw OR bqk -> z06
+nrd XOR fgs -> wpb
+frj XOR qhw -> z04
+bqk OR frj -> z07
+y03 OR x01 -> nrd
+hwm AND bqk -> z03
+tgd XOR rvg -> z12
+tnw OR pbm -> gnj";
+
+    #[test]
+    fn test_part1() {
+        let (inputs, gates) = parse(EX1).unwrap();
+        assert_eq!(part1(&inputs, &gates), 4);
+        let (inputs, gates) = parse(EX2).unwrap();
+        assert_eq!(part1(&inputs, &gates), 2024);
+    }
+
+    // A 6 bit ripple carry adder in the puzzle's shape, with the outputs of two pairs of gates swapped
+    fn broken_adder() -> Vec<Gate> {
+        let mut lines = vec!["x00 XOR y00 -> z00".to_string(), "x00 AND y00 -> d00".to_string()];
+        for i in 1..6 {
+            let carry = if i == 5 { "z06".to_string() } else { format!("d{i:02}") };
+            lines.push(format!("x{i:02} AND y{i:02} -> a{i:02}"));
+            lines.push(format!("y{i:02} XOR x{i:02} -> b{i:02}"));
+            lines.push(format!("b{i:02} AND d{:02} -> c{i:02}", i - 1));
+            lines.push(format!("a{i:02} OR c{i:02} -> {carry}"));
+            lines.push(format!("d{:02} XOR b{i:02} -> z{i:02}", i - 1));
+        }
+        let swapped = lines.join("\n")
+            .replace("-> z02", "-> TMP").replace("-> a02", "-> z02").replace("-> TMP", "-> a02")
+            .replace("-> b04", "-> TMP").replace("-> c04", "-> b04").replace("-> TMP", "-> c04");
+        parse_gates(&parsing::lines(&swapped)).unwrap()
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(swapped_wires(&broken_adder(), 2), Some("a02,b04,c04,z02".to_string()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("x00: 1\nx01: 2\n\nx00 AND x01 -> z00").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 6));
+        let err = parse("x00: 1\n\nx00 NAND x01 -> z00").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 5));
+        let err = parse("x00: 1\n\nx00 AND x01 => z00").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 1));
+        let err = parse("x00: 1").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
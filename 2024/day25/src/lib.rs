@@ -0,0 +1,141 @@
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+pub type PinHeights = [u8; 5];
+
+// Locks have their top row filled and keys their bottom row, with the pins in between
+fn parse_schematic(lines: &[Line]) -> Result<(bool, PinHeights), ParseError> {
+    let rows = parsing::grid(lines, |ch| match ch {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    if rows.len() != 7 || rows[0].len() != 5 {
+        return Err(lines[0].error(1, "expected a schematic 5 wide and 7 tall"));
+    }
+    let is_lock = rows[0][0];
+    if rows[0].contains(&!is_lock) || rows[6].contains(&is_lock) {
+        return Err(lines[0].error(1, "expected a lock or a key"));
+    }
+    let mut pin_heights = [0, 0, 0, 0, 0];
+    for row in &rows[1..6] {
+        for (x, &filled) in row.iter().enumerate() {
+            if filled {
+                pin_heights[x] += 1;
+            }
+        }
+    }
+    Ok((is_lock, pin_heights))
+}
+
+pub fn parse(puzzle_input: &str) -> Result<(Vec<PinHeights>, Vec<PinHeights>), ParseError> {
+    let mut locks = vec![];
+    let mut keys = vec![];
+    for schematic in parsing::sections(puzzle_input) {
+        let (is_lock, pin_heights) = parse_schematic(&schematic)?;
+        if is_lock {
+            locks.push(pin_heights);
+        } else {
+            keys.push(pin_heights);
+        }
+    }
+    Ok((locks, keys))
+}
+
+fn is_overlapping(lock: &PinHeights, key: &PinHeights) -> bool {
+    for i in 0..5 {
+        if lock[i] + key[i] > 5 { return true; }
+    }
+    false
+}
+
+pub fn part1(locks: &[PinHeights], keys: &[PinHeights]) -> usize {
+    let mut cnt = 0;
+    for lock in locks {
+        for key in keys {
+            if !is_overlapping(lock, key) {
+                cnt += 1;
+            }
+        }
+    }
+    cnt
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<PinHeights>, Vec<PinHeights>);
+    type Answer1 = usize;
+    type Answer2 = &'static str;
+
+    const PARTS: usize = 1;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(&input.0, &input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+#####
+##.##
+.#.##
+...##
+...#.
+...#.
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####
+
+.....
+.....
+#.#..
+###..
+###.#
+###.#
+#####
+
+.....
+.....
+.....
+#....
+#.#..
+#.#.#
+#####";
+
+    #[test]
+    fn test_part1() {
+        let (locks, keys) = parse(EX).unwrap();
+        assert_eq!(part1(&locks, &keys), 3);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("#####\n.####\n.####\n.####\n.#.#.\n.#...\n.....\n\n.....\n#....\n#....\n#...#\n#.#.#\n#.###\n####.").err().unwrap();
+        assert_eq!((err.line, err.column), (9, 1));
+        let err = parse("#####\n.####\n.####\n.####\n.#.#.\n.#...").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 1));
+        let err = parse("#####\n.####\n.#x##\n.####\n.#.#.\n.#...\n.....").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 3));
+    }
+}
@@ -0,0 +1,71 @@
+use regex::Regex;
+use solution::Solution;
+
+pub fn part1(puzzle_input: &str) -> u64 {
+    let re = Regex::new("mul\\((\\d+),(\\d+)\\)").unwrap();
+    let mut sum = 0;
+    for cap in re.captures_iter(puzzle_input) {
+        let x = cap[1].parse::<u64>().unwrap();
+        let y = cap[2].parse::<u64>().unwrap();
+        sum += x * y;
+    }
+    sum
+}
+
+pub fn part2(puzzle_input: &str) -> u64 {
+    const DO: &str = "do()";
+    const DONT: &str = "don't()";
+    let re = Regex::new("do\\(\\)|don't\\(\\)|mul\\((\\d+),(\\d+)\\)").unwrap();
+    let mut sum = 0;
+    let mut enabled = true;
+    for cap in re.captures_iter(puzzle_input) {
+        if& cap[0] == DO {
+            enabled = true;
+        } else if &cap[0] == DONT {
+            enabled = false;
+        } else if enabled {
+            let x = cap[1].parse::<u64>().unwrap();
+            let y = cap[2].parse::<u64>().unwrap();
+            sum += x * y;
+        }
+    }
+    sum
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = String;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        Ok(puzzle_input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX1: &str = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+    const EX2: &str = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(EX1), 161);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(EX2), 48);
+    }
+}
@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+pub struct ParsedInput {
+    rules: Vec<[u64; 2]>,
+    pages: Vec<Vec<u64>>,
+}
+
+fn parse_rules(lines: &[Line]) -> Result<Vec<[u64; 2]>, ParseError> {
+    lines.iter().map(|line| {
+        let (x, y) = line.as_field().split_once("|")?;
+        Ok([x.parse()?, y.parse()?])
+    }).collect()
+}
+
+fn parse_pages(lines: &[Line]) -> Result<Vec<Vec<u64>>, ParseError> {
+    lines.iter().map(|line| {
+        let pages = line.split(",").iter().map(|page| page.parse()).collect::<Result<Vec<_>, _>>()?;
+        if pages.len() % 2 == 0 {
+            return Err(line.error(1, "expected an odd number of pages, to have a middle one"));
+        }
+        Ok(pages)
+    }).collect()
+}
+
+pub fn parse(puzzle_input: &str) -> Result<ParsedInput, ParseError> {
+    let sections = parsing::sections(puzzle_input);
+    let (rules, pages) = match &sections[..] {
+        [rules, pages] => (rules, pages),
+        _ => {
+            let line = sections.get(2).map_or_else(|| Line {
+                number: parsing::lines(puzzle_input).last().map_or(1, |line| line.number + 1),
+                text: "",
+            }, |section| section[0]);
+            return Err(line.error(1, "expected the ordering rules then the updates"));
+        }
+    };
+    Ok(ParsedInput { rules: parse_rules(rules)?, pages: parse_pages(pages)? })
+}
+
+fn is_correct_order(rules: &[[u64; 2]], pages: &[u64]) -> bool {
+    let mut page_order = HashMap::new();
+    for (i, v) in pages.iter().enumerate() {
+        let prior = page_order.insert(v, i);
+        assert!(prior.is_none());
+    }
+    for &[a, b] in rules {
+        if let Some(a_i) = page_order.get(&a) {
+            if let Some(b_i) = page_order.get(&b) {
+                if a_i > b_i { return false; }
+            }
+        }
+    }
+    true
+}
+
+pub fn part1(parsed_input: &ParsedInput) -> u64 {
+    let mut sum = 0;
+    for pages in &parsed_input.pages {
+        if is_correct_order(&parsed_input.rules, pages) {
+            sum += pages[pages.len() / 2];
+        }
+    }
+    sum
+}
+
+fn find_correct_order(rules: &[[u64; 2]], pages_in: &[u64]) -> Vec<u64> {
+    fn find_correct_order_recur(rules: &[[u64; 2]], pages_in: &[u64], pages_curr: &mut Vec<u64>) ->
+        Option<Vec<u64>> {
+        if !is_correct_order(rules, &pages_curr) { return None; }
+        if pages_in.is_empty() { return Some(pages_curr.clone()); }
+        pages_curr.push(pages_in[0]);
+        let pages_in = &pages_in[1..];
+        if let Some(ret) = find_correct_order_recur(rules, pages_in, pages_curr) {
+            return Some(ret);
+        }
+        let mut i = pages_curr.len() - 1;
+        while i > 0 {
+            pages_curr.swap(i - 1, i);
+            i -= 1;
+            if let Some(ret) = find_correct_order_recur(rules, pages_in, pages_curr) {
+                return Some(ret);
+            }
+        }
+        None
+    }
+
+
+    let mut pages_out = Vec::with_capacity(pages_in.len());
+    find_correct_order_recur(rules, pages_in, &mut pages_out).unwrap()
+}
+
+pub fn part2(parsed_input: &ParsedInput) -> u64 {
+    let mut sum = 0;
+    for pages in &parsed_input.pages {
+        if !is_correct_order(&parsed_input.rules, pages) {
+            let pages = find_correct_order(&parsed_input.rules, pages);
+            sum += pages[pages.len() / 2];
+        }
+    }
+    sum
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = ParsedInput;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 143);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 123);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("47|53\n97-13\n\n75,47,61").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("47|53\n\n75,x,61").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 4));
+        let err = parse("47|53\n\n75,47").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 1));
+        let err = parse("47|53").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
@@ -0,0 +1,204 @@
+use std::collections::HashSet;
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+struct Pos {
+    x: usize,
+    y: usize,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+struct Dir {
+    dx: isize,
+    dy: isize,
+}
+
+impl Pos {
+    fn step(self, dir: Dir) -> Pos {
+        assert!(dir.dx >= 0 || self.x > 0);
+        assert!(dir.dy >= 0 || self.y > 0);
+        let add = |a: usize, da: isize| -> usize { ((a as isize) + da) as usize };
+        Pos { x: add(self.x, dir.dx), y: add(self.y, dir.dy) }
+    }
+}
+
+impl Dir {
+    fn turn_right(self) -> Dir {
+        Dir { dx: self.dy * -1, dy: self.dx }
+    }
+}
+
+#[derive(Clone)]
+pub struct Map {
+    width: usize,
+    height: usize,
+    guard_pos: Pos,
+    obstructions: Vec<bool>,
+}
+
+impl Map {
+    fn idx(&self, pos: Pos) -> usize {
+        assert!(pos.x < self.width);
+        assert!(pos.y < self.height);
+        self.width * pos.y + pos.x
+    }
+
+    fn would_step_out_of_bounds(&self, pos: Pos, dir: Dir) -> bool {
+        if dir.dx < 0 && pos.x == 0               { return true; }
+        if dir.dx > 0 && pos.x == self.width - 1  { return true; }
+        if dir.dy < 0 && pos.y == 0               { return true; }
+        if dir.dy > 0 && pos.y == self.height - 1 { return true; }
+        false
+    }
+
+    fn blocked_by_obstruction(&self, pos: Pos, dir: Dir) -> bool {
+        assert!(!self.would_step_out_of_bounds(pos, dir));
+        self.obstructions[self.idx(pos.step(dir))]
+    }
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Map, ParseError> {
+    let lines = parsing::lines(puzzle_input);
+    let rows = parsing::grid(&lines, |ch| match ch {
+        '.' | '#' | '^' => Some(ch),
+        _ => None,
+    })?;
+    let mut obstructions = Vec::new();
+    let mut guard_pos: Option<Pos> = None;
+    for (y, row) in rows.iter().enumerate() {
+        for (x, &ch) in row.iter().enumerate() {
+            if ch == '^' {
+                if guard_pos.is_some() {
+                    return Err(lines[y].error(x + 1, "expected a single guard"));
+                }
+                guard_pos = Some(Pos { x, y });
+            }
+            obstructions.push(ch == '#');
+        }
+    }
+    let guard_pos = guard_pos
+        .ok_or_else(|| Line { number: 1, text: "" }.error(1, "expected a guard on the map"))?;
+    Ok(Map { width: rows[0].len(), height: rows.len(), guard_pos, obstructions })
+}
+
+
+pub fn part1(map: &Map) -> usize {
+    let mut seen = HashSet::new();
+    let mut dir = Dir { dx: 0, dy: -1 }; // Facing up
+    let mut pos = map.guard_pos;
+
+    seen.insert(pos);
+    while !map.would_step_out_of_bounds(pos, dir) {
+        if map.blocked_by_obstruction(pos, dir) {
+            dir = dir.turn_right();
+        } else {
+            pos = pos.step(dir);
+            seen.insert(pos);
+        }
+    }
+
+    seen.len()
+}
+
+fn would_loop(map: &Map) -> bool {
+    let mut seen = HashSet::new();
+    let mut dir = Dir { dx: 0, dy: -1 }; // Facing up
+    let mut pos = map.guard_pos;
+
+    seen.insert((pos, dir));
+    while !map.would_step_out_of_bounds(pos, dir) {
+        if map.blocked_by_obstruction(pos, dir) {
+            dir = dir.turn_right();
+        } else {
+            pos = pos.step(dir);
+        }
+        if !seen.insert((pos, dir)) { return true; }
+    }
+    false
+}
+
+pub fn part2(mut map: Map) -> usize {
+    let mut cnt = 0;
+
+    // Same as part1 - pre-calculate everywhere the guard goes since placing an obstruction
+    // anywhere not visited would not impact the path
+    let mut seen = HashSet::new();
+    let mut dir = Dir { dx: 0, dy: -1 }; // Facing up
+    let mut pos = map.guard_pos;
+
+    seen.insert(pos);
+    while !map.would_step_out_of_bounds(pos, dir) {
+        if map.blocked_by_obstruction(pos, dir) {
+            dir = dir.turn_right();
+        } else {
+            pos = pos.step(dir);
+            seen.insert(pos);
+        }
+    }
+
+    for pos in seen {
+        let idx = map.idx(pos);
+        map.obstructions[idx] = true;
+        if would_loop(&map) { cnt += 1; }
+        map.obstructions[idx] = false;
+    }
+    cnt
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+        
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 41);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(parse(EX).unwrap()), 6);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("..^\n.^.").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 2));
+        let err = parse("...\n.#.").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 1));
+        let err = parse("..^\n.x.").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+}
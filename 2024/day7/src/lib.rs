@@ -0,0 +1,112 @@
+use parsing::ParseError;
+use solution::Solution;
+
+pub struct Equation(u64, Vec<u64>);
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<Equation>, ParseError> {
+    parsing::parse_lines(puzzle_input, |line| {
+        let (result, values) = line.as_field().split_once(": ")?;
+        let values = values.fields().iter().map(|value| value.parse()).collect::<Result<Vec<_>, _>>()?;
+        if values.is_empty() {
+            return Err(line.error(1, "expected values after the test value"));
+        }
+        Ok(Equation(result.parse()?, values))
+    })
+}
+
+fn can_satisfy(equation: &Equation, is_part2: bool) -> bool {
+    fn concat(a: u64, b: u64) -> u64 {
+        let mut n = 10;
+        while n <= b {
+            n *= 10;
+        }
+        a * n + b
+    }
+    fn can_satisfy_recur(expected: u64, curr: u64, remaining: &[u64], is_part2: bool) -> bool {
+        if remaining.is_empty() { return expected == curr; }
+        let next = remaining[0];
+        let remaining = &remaining[1..];
+        if can_satisfy_recur(expected, curr * next, remaining, is_part2) { return true; }
+        if can_satisfy_recur(expected, curr + next, remaining, is_part2) { return true; }
+        is_part2 && can_satisfy_recur(expected, concat(curr, next), remaining, is_part2)
+    }
+
+    let curr = equation.1[0];
+    let remaining = &equation.1[1..];
+    can_satisfy_recur(equation.0, curr, remaining, is_part2)
+}
+
+pub fn part1(equations: &[Equation]) -> u64 {
+    let mut sum = 0;
+    for equation in equations {
+        if can_satisfy(equation, false) {
+            sum += equation.0;
+        }
+    }
+    sum
+}
+
+pub fn part2(equations: &[Equation]) -> u64 {
+    let mut sum = 0;
+    for equation in equations {
+        if can_satisfy(equation, true) {
+            sum += equation.0;
+        }
+    }
+    sum
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Equation>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 3749);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 11387);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("190: 10 19\n3267 81 40").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("190: 10 x").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 9));
+        let err = parse("190: ").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 1));
+    }
+}
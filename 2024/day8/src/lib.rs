@@ -0,0 +1,175 @@
+use std::ops::Sub;
+use std::collections::{HashSet, HashMap};
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+struct Pos {
+    x: usize,
+    y: usize,
+}
+
+#[derive(Copy, Clone)]
+struct Delta {
+    dx: isize,
+    dy: isize,
+}
+
+impl Sub for Pos {
+    type Output = Delta;
+    fn sub(self, other: Pos) -> Delta {
+        Delta {
+            dx: self.x as isize - other.x as isize,
+            dy: self.y as isize - other.y as isize,
+        }
+    }
+}
+
+impl Delta {
+    fn scale_by(self, n: isize) -> Delta {
+        Delta { dx: self.dx * n, dy: self.dy * n }
+    }
+}
+
+pub struct Map {
+    width: usize,
+    height: usize,
+    antennae: HashMap<char, Vec<Pos>>,
+}
+
+impl Map {
+    fn checked_add(&self, pos: Pos, delta: Delta) -> Option<Pos> {
+        let x = pos.x as isize + delta.dx;
+        if x < 0 || x as usize >= self.width { return None; }
+
+        let y = pos.y as isize + delta.dy;
+        if y < 0 || y as usize >= self.height { return None; }
+
+        Some(Pos { x: x as usize, y: y as usize })
+    }
+}
+
+
+pub fn parse(puzzle_input: &str) -> Result<Map, ParseError> {
+    let rows = parsing::grid(&parsing::lines(puzzle_input), |ch| {
+        if ch == '.' || ch.is_ascii_alphanumeric() { Some(ch) } else { None }
+    })?;
+    if rows.is_empty() {
+        return Err(Line { number: 1, text: "" }.error(1, "expected a map of the antennas"));
+    }
+    let mut antennae = HashMap::new();
+    for (y, row) in rows.iter().enumerate() {
+        for (x, &ch) in row.iter().enumerate() {
+            if ch == '.' { continue; }
+            antennae.entry(ch).or_insert(vec![]).push(Pos { x, y })
+        }
+    }
+    Ok(Map { width: rows[0].len(), height: rows.len(), antennae })
+}
+
+pub fn part1(map: &Map) -> usize {
+    let mut antinodes = HashSet::new();
+    for (_, positions) in &map.antennae {
+        for i in 0..(positions.len() - 1) {
+            for j in (i + 1)..positions.len() {
+                let a = positions[i];
+                let b = positions[j];
+                if let Some(x) = map.checked_add(a, a - b) {
+                    antinodes.insert(x);
+                }
+                if let Some(y) = map.checked_add(b, b - a) {
+                    antinodes.insert(y);
+                }
+            }
+        }
+    }
+
+    antinodes.len()
+}
+
+pub fn part2(map: &Map) -> usize {
+    let mut antinodes = HashSet::new();
+    for (_, positions) in &map.antennae {
+        for &pos in positions {
+            antinodes.insert(pos);
+        }
+        for i in 0..(positions.len() - 1) {
+            for j in (i + 1)..positions.len() {
+                let a = positions[i];
+                let b = positions[j];
+                for n in 1.. {
+                    if let Some(x) = map.checked_add(a, (a - b).scale_by(n)) {
+                        antinodes.insert(x);
+                    } else {
+                        break;
+                    }
+                }
+                for n in 1.. {
+                    if let Some(y) = map.checked_add(b, (b - a).scale_by(n)) {
+                        antinodes.insert(y);
+                    } else {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+    antinodes.len()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 14);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 34);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("..a.\n..#.").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 3));
+        let err = parse("..a.\n...").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 4));
+    }
+}
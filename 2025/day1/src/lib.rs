@@ -0,0 +1,113 @@
+use parsing::ParseError;
+use solution::Solution;
+
+pub struct Rotation {
+    inc: bool,
+    dist: u64,
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<Rotation>, ParseError> {
+    parsing::parse_lines(puzzle_input, |line| {
+        let field = line.as_field();
+        let (inc, dist) = if let Ok(dist) = field.strip_prefix("L") {
+            (false, dist)
+        } else if let Ok(dist) = field.strip_prefix("R") {
+            (true, dist)
+        } else {
+            return Err(line.error(1, "expected a rotation starting with L or R"));
+        };
+        Ok(Rotation { inc, dist: dist.parse()? })
+    })
+}
+
+pub fn part1(rotations: &[Rotation]) -> u64 {
+    let mut dial = 50;
+    let mut cnt = 0;
+    for rot in rotations {
+        dial = if rot.inc {
+            (dial + (rot.dist % 100)) % 100
+        } else {
+            (dial + 100 - (rot.dist % 100)) % 100
+        };
+
+        if dial == 0 {
+            cnt += 1;
+        }
+    }
+    cnt
+}
+
+pub fn part2(rotations: &[Rotation]) -> u64 {
+    let mut dial = 50;
+    let mut cnt = 0;
+    for rot in rotations {
+        let old_dial = dial;
+        dial = if rot.inc {
+            (dial + (rot.dist % 100)) % 100
+        } else {
+            (dial + 100 - (rot.dist % 100)) % 100
+        };
+
+        cnt += rot.dist / 100;
+        if rot.inc {
+            if dial < old_dial { cnt += 1 };
+        } else {
+            if old_dial != 0 && (dial == 0 || dial > old_dial) { cnt += 1 };
+        }
+    }
+    cnt
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Rotation>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 3);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 6);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("L68\nX30").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("L68\nR3x").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+}
@@ -0,0 +1,214 @@
+use std::cmp::Reverse;
+use std::collections::{HashSet, BinaryHeap};
+
+use parsing::{Field, ParseError};
+use solution::Solution;
+
+type Mask = u16;
+
+pub struct Machine {
+    target: Mask,
+    buttons: Vec<Mask>,
+    jolts: Vec<usize>,
+}
+
+// The text between the delimiters, like the lights in "[.##.]"
+fn delimited<'a>(field: Field<'a>, open: &str, close: &str) -> Result<Field<'a>, ParseError> {
+    let (inner, rest) = field.strip_prefix(open)?.split_once(close)?;
+    if !rest.text.is_empty() {
+        return Err(rest.error(format!("unexpected text after {:?}", close)));
+    }
+    Ok(inner)
+}
+
+fn parse_light_target(field: Field) -> Result<(Mask, usize), ParseError> {
+    let lights = delimited(field, "[", "]")?.map_chars(|ch| match ch {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    if lights.is_empty() || lights.len() > Mask::BITS as usize {
+        return Err(field.error(format!("expected between 1 and {} lights", Mask::BITS)));
+    }
+    let mut ret = 0;
+    for (i, &on) in lights.iter().enumerate() {
+        if on {
+            ret |= 1 << i;
+        }
+    }
+    Ok((ret, lights.len()))
+}
+
+fn parse_button(field: Field, num_lights: usize) -> Result<Mask, ParseError> {
+    let mut ret = 0;
+    for light in delimited(field, "(", ")")?.split(",") {
+        let i = light.parse::<usize>()?;
+        if i >= num_lights {
+            return Err(light.error(format!("expected a light below {}", num_lights)));
+        }
+        ret |= 1 << i;
+    }
+    Ok(ret)
+}
+
+fn parse_joltages(field: Field, num_lights: usize) -> Result<Vec<usize>, ParseError> {
+    let jolts = delimited(field, "{", "}")?.split(",").iter()
+        .map(|jolt| jolt.parse()).collect::<Result<Vec<_>, _>>()?;
+    if jolts.len() != num_lights {
+        return Err(field.error(format!("expected {} joltages, one per light", num_lights)));
+    }
+    Ok(jolts)
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<Machine>, ParseError> {
+    parsing::parse_lines(puzzle_input, |line| {
+        let fields = line.fields();
+        let (target, buttons, jolts) = match &fields[..] {
+            [target, buttons @ .., jolts] if !buttons.is_empty() => (target, buttons, jolts),
+            _ => return Err(line.error(1, "expected lights, buttons and joltages")),
+        };
+        // Presses are tracked in a Mask too, one bit per button
+        if let Some(extra) = buttons.get(Mask::BITS as usize) {
+            return Err(extra.error(format!("expected at most {} buttons", Mask::BITS)));
+        }
+        let (target, num_lights) = parse_light_target(*target)?;
+        let buttons = buttons.iter()
+            .map(|&button| parse_button(button, num_lights)).collect::<Result<_, _>>()?;
+        let jolts = parse_joltages(*jolts, num_lights)?;
+        Ok(Machine { target, buttons, jolts })
+    })
+}
+
+// Returns a Vec of Mask (representing the buttons pressed), sorted by total
+// buttons pressed
+fn compute_for_target(buttons: &[Mask], target: Mask) -> Vec<Mask> {
+    fn recur(buttons: &[Mask], target: Mask, i: usize, path: Mask, results: &mut Vec<Mask>) {
+        if i == buttons.len() {
+            if target == 0 { results.push(path); }
+            return;
+        }
+
+        recur(buttons, target, i + 1, path, results);
+        recur(buttons, target ^ buttons[i], i + 1, path | (1 << i), results);
+    }
+
+    let mut results = Vec::new();
+    recur(buttons, target, 0, 0, &mut results);
+    results.sort_unstable_by_key(|pressed| pressed.count_ones());
+    results
+}
+
+pub fn part1(machines: &[Machine]) -> usize {
+    let mut ret = 0;
+    for machine in machines {
+        ret += compute_for_target(&machine.buttons, machine.target)
+            .first().unwrap().count_ones() as usize;
+    }
+    ret
+}
+
+fn get_parity(jolts: &[usize]) -> Mask {
+    let mut mask = 0;
+    for (i, v) in jolts.iter().enumerate() {
+        if v % 2 == 1 {
+            mask |= 1 << i;
+        }
+    }
+    mask
+}
+
+fn do_presses(buttons: &[Mask], presses: Mask, mut jolts: Vec<usize>)
+    -> Option<Vec<usize>> {
+    for i in 0..buttons.len() {
+        if presses & (1 << i) != 0 {
+            for j in 0..jolts.len() {
+                if buttons[i] & (1 << j) == 0 { continue; }
+                if jolts[j] == 0 { return None; }
+                jolts[j] -= 1;
+            }
+        }
+    }
+    Some(jolts)
+}
+
+fn count_presses(buttons: &[Mask], jolts: Vec<usize>) -> Option<usize> {
+    if jolts.iter().all(|&v| v == 0) { return Some(0); }
+
+    let mut min_presses: Option<usize> = None;
+    for init_presses in compute_for_target(buttons, get_parity(&jolts)) {
+        if let Some(mut jolts) = do_presses(buttons, init_presses, jolts.clone()) {
+            for v in &mut jolts { *v /= 2; }
+            if let Some(subs_num_presses) = count_presses(buttons, jolts) {
+                let num_presses = init_presses.count_ones() as usize
+                    + 2 * subs_num_presses;
+                if min_presses.is_none()
+                    || num_presses < min_presses.unwrap() {
+                        min_presses = Some(num_presses);
+                }
+            }
+        }
+    }
+    min_presses
+}
+
+// Using https://www.reddit.com/r/adventofcode/comments/1pk87hl/2025_day_10_part_2_bifurcate_your_way_to_victory/
+pub fn part2(machines: &[Machine]) -> usize {
+    let mut ret = 0;
+    for machine in machines {
+        ret += count_presses(&machine.buttons, machine.jolts.clone()).unwrap();
+    }
+    ret
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Machine>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 7);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 33);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("[.##.] (3) (1,3) {3,5,4,7}\n[.##.] {3,5,4,7}").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("[.#x.] (3) (1,3) {3,5,4,7}").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 4));
+        let err = parse("[.##.] (3) (1,4) {3,5,4,7}").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 15));
+        let err = parse("[.##.] (3) (1,3) {3,5,4}").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 18));
+        let err = parse("[.##.] (3) (1,3 {3,5,4,7}").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 13));
+    }
+}
@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse(puzzle_input: &str) -> Result<HashMap<String, Vec<String>>, ParseError> {
+    let lines = parsing::lines(puzzle_input);
+    let mut ret = HashMap::new();
+    for line in &lines {
+        let (device, outputs) = line.as_field().split_once(": ")?;
+        let outputs = outputs.fields().iter().map(|output| output.text.to_string()).collect();
+        if ret.insert(device.text.to_string(), outputs).is_some() {
+            return Err(device.error(format!("duplicate device {:?}", device.text)));
+        }
+    }
+    for line in &lines {
+        for output in line.as_field().split_once(": ")?.1.fields() {
+            if output.text != "out" && !ret.contains_key(output.text) {
+                return Err(output.error(format!("unknown device {:?}", output.text)));
+            }
+        }
+    }
+    Ok(ret)
+}
+
+pub fn part1(devices: &HashMap<String, Vec<String>>) -> usize {
+    fn count_paths(devices: &HashMap<String, Vec<String>>, curr: &str)
+        -> usize {
+        if curr == "out" { return 1; }
+        let mut cnt = 0;
+        for next in &devices[curr] {
+            cnt += count_paths(devices, next);
+        }
+        cnt
+    }
+
+    count_paths(devices, "you")
+}
+
+pub fn part2(devices: &HashMap<String, Vec<String>>) -> usize {
+    fn count_paths(devices: &HashMap<String, Vec<String>>, curr: &str,
+        dac: bool, fft: bool,
+        memo: &mut HashMap<(String, bool, bool), usize>) -> usize {
+        if curr == "out" {
+            return if dac && fft { 1 } else { 0 };
+        } else if let Some(cached) = memo.get(&(curr.to_string(), dac, fft)) {
+            return *cached;
+        }
+
+        let mut cnt = 0;
+        for next in &devices[curr] {
+            let dac = dac || next == "dac";
+            let fft = fft || next == "fft";
+            cnt += count_paths(devices, next, dac, fft, memo);
+        }
+        memo.insert((curr.to_string(), dac, fft), cnt);
+        cnt
+    }
+
+    let mut memo = HashMap::new();
+    count_paths(devices, "svr", false, false, &mut memo)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = HashMap<String, Vec<String>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX1: &str = "aaa: you hhh
+you: bbb ccc
+bbb: ddd eee
+ccc: ddd eee fff
+ddd: ggg
+eee: out
+fff: out
+ggg: out
+hhh: ccc fff iii
+iii: out";
+    
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX1).unwrap()), 5);
+    }
+
+    const EX2: &str = "svr: aaa bbb
+aaa: fft
+fft: ccc
+bbb: tty
+tty: ccc
+ccc: ddd eee
+ddd: hub
+hub: fff
+eee: dac
+dac: fff
+fff: ggg hhh
+ggg: out
+hhh: out";
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX2).unwrap()), 2);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("you: bbb\nbbb: out\nyou: out").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 1));
+        let err = parse("you: bbb ccc\nbbb: out").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 10));
+        let err = parse("you: bbb\nbbb out").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
@@ -0,0 +1,293 @@
+use std::collections::HashSet;
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+// Bitset where bits correspond to positions:
+//
+// 012
+// 345
+// 678
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+struct Shape(u16);
+
+impl Shape {
+    // Rotated clockwise
+    fn rotated(self) -> Self {
+        let mut out = Shape(self.0 & (1<<4));
+        if self.0 & (1<<0) != 0 { out.0 |= 1<<2; }
+        if self.0 & (1<<1) != 0 { out.0 |= 1<<5; }
+        if self.0 & (1<<2) != 0 { out.0 |= 1<<8; }
+        if self.0 & (1<<5) != 0 { out.0 |= 1<<7; }
+        if self.0 & (1<<8) != 0 { out.0 |= 1<<6; }
+        if self.0 & (1<<7) != 0 { out.0 |= 1<<3; }
+        if self.0 & (1<<6) != 0 { out.0 |= 1<<0; }
+        if self.0 & (1<<3) != 0 { out.0 |= 1<<1; }
+        out
+    }
+
+    // Flipped along vertical axis
+    fn flipped(self) -> Self {
+        Shape(
+            (self.0 & ((1<<1) | (1<<4) | (1<<7))) |
+            ((self.0 & ((1<<0) | (1<<3) | (1<<6))) << 2) |
+            ((self.0 & ((1<<2) | (1<<5) | (1<<8))) >> 2))
+    }
+
+    fn orientations(self) -> HashSet<Shape> {
+        let mut ret = HashSet::new();
+        ret.insert(self);
+        ret.insert(self.rotated());
+        ret.insert(self.rotated().rotated());
+        ret.insert(self.rotated().rotated().rotated());
+        ret.insert(self.flipped());
+        ret.insert(self.flipped().rotated());
+        ret.insert(self.flipped().rotated().rotated());
+        ret.insert(self.flipped().rotated().rotated().rotated());
+        ret
+    }
+
+    fn xoffset(&self) -> usize {
+        for i in 0..3 {
+            if self.0 & (1<<i) != 0 {
+                return i;
+            }
+        }
+        panic!("Offset for y is not implemented, none of the rotations should produce this");
+    }
+}
+
+#[derive(Clone)]
+struct Region {
+    width: usize,
+    height: usize,
+    shapes: Vec<usize>,
+    grid: Vec<bool>,
+}
+
+impl Region {
+    fn idx(&self, x: usize, y: usize) -> usize {
+        y * self.width + x
+    }
+
+    // NOTE - placing at x,y means that the cell at x,y is guaranteed to be filled by the
+    // operation. Shifting where the shape is placed in the x coord is handled automatically
+    fn can_place(&self, x: usize, y: usize, shape: Shape) -> bool {
+        if shape.xoffset() > x { return false; }
+        let x = x - shape.xoffset();
+        if x + 2 >= self.width { return false; }
+        if y + 2 >= self.height { return false; }
+        if self.grid[self.idx(x  , y  )] && shape.0 & (1<<0) != 0 { return false; }
+        if self.grid[self.idx(x+1, y  )] && shape.0 & (1<<1) != 0 { return false; }
+        if self.grid[self.idx(x+2, y  )] && shape.0 & (1<<2) != 0 { return false; }
+        if self.grid[self.idx(x  , y+1)] && shape.0 & (1<<3) != 0 { return false; }
+        if self.grid[self.idx(x+1, y+1)] && shape.0 & (1<<4) != 0 { return false; }
+        if self.grid[self.idx(x+2, y+1)] && shape.0 & (1<<5) != 0 { return false; }
+        if self.grid[self.idx(x  , y+2)] && shape.0 & (1<<6) != 0 { return false; }
+        if self.grid[self.idx(x+1, y+2)] && shape.0 & (1<<7) != 0 { return false; }
+        if self.grid[self.idx(x+2, y+2)] && shape.0 & (1<<8) != 0 { return false; }
+        true
+    }
+
+    fn place_unplace_inner(&mut self, x: usize, y: usize, shape: Shape, val: bool) {
+        let x = x - shape.xoffset();
+        if shape.0 & (1<<0) != 0 { let i = self.idx(x  , y  ); self.grid[i] = val; }
+        if shape.0 & (1<<1) != 0 { let i = self.idx(x+1, y  ); self.grid[i] = val; }
+        if shape.0 & (1<<2) != 0 { let i = self.idx(x+2, y  ); self.grid[i] = val; }
+        if shape.0 & (1<<3) != 0 { let i = self.idx(x  , y+1); self.grid[i] = val; }
+        if shape.0 & (1<<4) != 0 { let i = self.idx(x+1, y+1); self.grid[i] = val; }
+        if shape.0 & (1<<5) != 0 { let i = self.idx(x+2, y+1); self.grid[i] = val; }
+        if shape.0 & (1<<6) != 0 { let i = self.idx(x  , y+2); self.grid[i] = val; }
+        if shape.0 & (1<<7) != 0 { let i = self.idx(x+1, y+2); self.grid[i] = val; }
+        if shape.0 & (1<<8) != 0 { let i = self.idx(x+2, y+2); self.grid[i] = val; }
+    }
+
+
+    fn place(&mut self, x: usize, y: usize, shape: Shape) {
+        self.place_unplace_inner(x, y, shape, true);
+    }
+
+    fn unplace(&mut self, x: usize, y: usize, shape: Shape) {
+        self.place_unplace_inner(x, y, shape, false);
+    }
+}
+
+pub struct Summary {
+    shapes: Vec<Shape>,
+    regions: Vec<Region>,
+}
+
+
+fn parse_regions(lines: &[Line], num_shapes: usize) -> Result<Vec<Region>, ParseError> {
+    lines.iter().map(|line| {
+        let (size, counts) = line.as_field().split_once(": ")?;
+        let (width, height) = size.split_once("x")?;
+        let (width, height) = (width.parse()?, height.parse()?);
+        if width == 0 || height == 0 {
+            return Err(size.error("expected a non-empty region"));
+        }
+        let shapes = counts.fields().iter().map(|count| count.parse()).collect::<Result<Vec<_>, _>>()?;
+        if shapes.len() != num_shapes {
+            return Err(counts.error(format!("expected a count for each of the {} shapes", num_shapes)));
+        }
+        let grid = vec![false; width * height];
+        Ok(Region { width, height, shapes, grid })
+    }).collect()
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Summary, ParseError> {
+    let sections = parsing::sections(puzzle_input);
+    let (regions, shape_sections) = match sections.split_last() {
+        Some(split) => split,
+        None => return Err(Line { number: 1, text: "" }.error(1, "expected shapes then regions")),
+    };
+
+    let mut shapes = Vec::new();
+    for (i, section) in shape_sections.iter().enumerate() {
+        let header = section[0];
+        if header.text != format!("{}:", i) {
+            return Err(header.error(1, format!("expected shape {}", i)));
+        }
+        let rows = parsing::grid(&section[1..], |ch| match ch {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+        if rows.len() != 3 || rows[0].len() != 3 {
+            return Err(header.error(1, "expected a 3x3 shape"));
+        }
+        let mut shape_val = 0;
+        for (y, row) in rows.iter().enumerate() {
+            for (x, &filled) in row.iter().enumerate() {
+                if filled {
+                    shape_val |= 1 << (y * 3 + x);
+                }
+            }
+        }
+        shapes.push(Shape(shape_val));
+    }
+    let regions = parse_regions(regions, shapes.len())?;
+
+    Ok(Summary { shapes, regions })
+}
+
+fn can_fit_conservatively(region: &Region, shapes: &[Shape], x: usize, y: usize) -> bool {
+    let available = region.grid[region.idx(x, y)..].iter().filter(|&&b| !b).count();
+    let required = region.shapes.iter().enumerate()
+        .map(|(i,&cnt)| cnt * shapes[i].0.count_ones() as usize).sum::<usize>();
+    available >= required
+}
+
+fn try_place_all(region: &mut Region, shapes: &[Shape], x: usize, y: usize) -> bool {
+    if region.shapes.iter().all(|&s| s == 0) { return true; }
+    if y >= region.height { return false; }
+    if !can_fit_conservatively(region, shapes, x, y) { return false; }
+    let (x2, y2) = if x + 1 == region.width { (0, y + 1) } else { (x + 1, y) };
+
+    assert_eq!(shapes.len(), region.shapes.len());
+    if !region.grid[region.idx(x, y)] {
+        for i in 0..shapes.len() {
+            if region.shapes[i] == 0 { continue; }
+            region.shapes[i] -= 1;
+            for shape in shapes[i].orientations() {
+                if region.can_place(x, y, shape) {
+                    region.place(x, y, shape);
+                    if try_place_all(region, shapes, x2, y2) {
+                        return true;
+                    }
+                    region.unplace(x, y, shape);
+                }
+            }
+            region.shapes[i] += 1;
+        }
+    }
+
+    // NOTE - x,y may be filled already, but we can also try with leaving it blank
+    try_place_all(region, shapes, x2, y2)
+}
+
+pub fn part1(summary: &Summary) -> usize {
+    let mut cnt = 0;
+    for region in &summary.regions {
+        if try_place_all(&mut region.clone(), &summary.shapes, 0, 0) {
+            cnt += 1;
+        }
+    }
+    cnt
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Summary;
+    type Answer1 = usize;
+    type Answer2 = &'static str;
+
+    const PARTS: usize = 1;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "0:
+###
+##.
+##.
+
+1:
+###
+##.
+.##
+
+2:
+.##
+###
+##.
+
+3:
+##.
+###
+##.
+
+4:
+###
+#..
+###
+
+5:
+###
+.#.
+###
+
+4x4: 0 0 0 0 2 0
+12x5: 1 0 1 0 2 2
+12x5: 1 0 1 0 3 2";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 2);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("0:\n###\n##.\n##.\n\n2:\n###\n##.\n##.\n\n4x4: 1 2").err().unwrap();
+        assert_eq!((err.line, err.column), (6, 1));
+        let err = parse("0:\n###\n##.\n\n4x4: 1").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 1));
+        let err = parse("0:\n###\n#x.\n##.\n\n4x4: 1").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 2));
+        let err = parse("0:\n###\n##.\n##.\n\n4x4: 1 2").err().unwrap();
+        assert_eq!((err.line, err.column), (6, 6));
+        let err = parse("0:\n###\n##.\n##.\n\n4y4: 1").err().unwrap();
+        assert_eq!((err.line, err.column), (6, 1));
+    }
+}
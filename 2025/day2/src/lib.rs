@@ -0,0 +1,102 @@
+use std::ops::RangeInclusive;
+
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<RangeInclusive<u64>>, ParseError> {
+    let line = parsing::single_line(puzzle_input)?;
+    line.split(",").iter().map(|range| {
+        let (lower_id, upper_id) = range.split_once("-")?;
+        Ok(lower_id.parse()?..=upper_id.parse()?)
+    }).collect()
+}
+
+fn is_invalid_by_reps(id: &str, rep_len: usize) -> bool {
+    if (id.len() % rep_len) != 0 { return false };
+    let base = &id[0..rep_len];
+    for i in 1..(id.len() / rep_len) {
+        if base != &id[(rep_len*i)..(rep_len*(i+1))] {
+            return false;
+        }
+    }
+    true
+}
+
+pub fn part1(ranges: &[RangeInclusive<u64>]) -> u64 {
+    let mut sum = 0;
+    for range in ranges {
+        for id_num in range.clone() {
+            let id = id_num.to_string();
+            if id.len() % 2 == 0 && is_invalid_by_reps(&id, id.len() / 2) {
+                sum += id_num;
+            }
+        }
+    }
+    sum
+}
+
+pub fn part2(ranges: &[RangeInclusive<u64>]) -> u64 {
+    fn is_invalid(id: &str) -> bool {
+        for rep_len in 1..=(id.len() / 2) {
+            if is_invalid_by_reps(id, rep_len) { return true };
+        }
+        false
+    }
+    let mut sum = 0;
+    for range in ranges {
+        for id_num in range.clone() {
+            let id = id_num.to_string();
+            if is_invalid(&id) {
+                sum += id_num;
+            }
+        }
+    }
+    sum
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<RangeInclusive<u64>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 1227775554);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 4174379265);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("11-22,95-115\n998-1012").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("11-22,95").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 7));
+        let err = parse("11-22,95-1x5").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 10));
+    }
+}
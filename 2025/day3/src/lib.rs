@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use parsing::ParseError;
+use solution::Solution;
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<Vec<u8>>, ParseError> {
+    parsing::parse_lines(puzzle_input, |line| {
+        let bank = line.map_chars(|ch| ch.to_digit(10).map(|d| d as u8))?;
+        if bank.len() < 12 {
+            return Err(line.error(1, "expected a bank of at least twelve batteries"));
+        }
+        Ok(bank)
+    })
+}
+
+pub fn part1(banks: &[Vec<u8>]) -> u64 {
+    fn largest_joltage(bank: &[u8]) -> u64 {
+        let n = bank.len();
+        let mut max = 0;
+        for i in 0..(n-1) {
+            for j in (i+1)..n {
+                let jolts = (bank[i] * 10 + bank[j]) as u64;
+                if jolts > max {
+                    max = jolts;
+                }
+            }
+        }
+        max
+    }
+
+    let mut sum = 0;
+    for bank in banks {
+        sum += largest_joltage(&bank);
+    }
+    sum
+}
+
+pub fn part2(banks: &[Vec<u8>]) -> u64 {
+    fn e(pow: usize) -> u64 {
+        let mut ret = 1;
+        for _ in 0..pow {
+            ret *= 10;
+        }
+        ret
+    }
+    fn largest_joltage(bank: &[u8], i: usize, left: usize, memo: &mut HashMap<(usize, usize), u64>)
+        -> Option<u64> {
+        if left == 0 {
+            return Some(0);
+        } else if i + left > bank.len() {
+            return None;
+        } else if let Some(cached) = memo.get(&(i, left)) {
+            return Some(*cached);
+        }
+        let mut max = largest_joltage(bank, i + 1, left - 1, memo).unwrap()
+            + (bank[i] as u64 * e(left - 1));
+        if let Some(rem) = largest_joltage(bank, i + 1, left, memo) {
+            if rem > max {
+                max = rem;
+            }
+        }
+        memo.insert((i, left), max);
+        Some(max)
+    }
+
+    let mut sum = 0;
+    for bank in banks {
+        sum += largest_joltage(&bank, 0, 12, &mut HashMap::new()).unwrap();
+    }
+    sum
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<u8>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "987654321111111
+811111111111119
+234234234234278
+818181911112111";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 357);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 3121910778619);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("987654321111111\n81111111x111119").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 9));
+        let err = parse("987654321111111\n8111").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
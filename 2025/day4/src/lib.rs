@@ -0,0 +1,138 @@
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+#[derive(Clone)]
+pub struct Map {
+    width: usize,
+    height: usize,
+    grid: Vec<bool>,
+}
+
+impl Map {
+    fn at(&self, x: usize, y: usize) -> bool {
+        debug_assert!(x < self.width && y < self.height);
+        self.grid[y * self.width + x]
+    }
+
+    fn count_adjacent(&self, x: usize, y: usize) -> usize {
+        let mut cnt = 0;
+        if x > 0              && y > 0               && self.at(x - 1, y - 1) { cnt += 1; }
+        if                       y > 0               && self.at(x    , y - 1) { cnt += 1; }
+        if x < self.width - 1 && y > 0               && self.at(x + 1, y - 1) { cnt += 1; }
+        if x > 0                                     && self.at(x - 1, y    ) { cnt += 1; }
+        if x < self.width - 1                        && self.at(x + 1, y    ) { cnt += 1; }
+        if x > 0              && y < self.height - 1 && self.at(x - 1, y + 1) { cnt += 1; }
+        if                       y < self.height - 1 && self.at(x    , y + 1) { cnt += 1; }
+        if x < self.width - 1 && y < self.height - 1 && self.at(x + 1, y + 1) { cnt += 1; }
+        cnt
+    }
+
+    fn is_accessible(&self, x: usize, y: usize) -> bool {
+        self.count_adjacent(x, y) < 4
+    }
+
+    fn remove(&mut self, x: usize, y: usize) {
+        debug_assert!(x < self.width && y < self.height);
+        self.grid[y * self.width + x] = false;
+    }
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Map, ParseError> {
+    let rows = parsing::grid(&parsing::lines(puzzle_input), |ch| match ch {
+        '@' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    if rows.is_empty() {
+        return Err(Line { number: 1, text: "" }.error(1, "expected a map of the paper rolls"));
+    }
+    let height = rows.len();
+    let width = rows[0].len();
+    Ok(Map { width, height, grid: rows.concat() })
+}
+
+pub fn part1(map: &Map) -> usize {
+    let mut cnt = 0;
+    for y in 0..map.height {
+        for x in 0..map.width {
+            if map.at(x, y) && map.is_accessible(x, y) {
+                cnt += 1;
+            }
+        }
+    }
+    cnt
+}
+
+pub fn part2(map: &Map) -> usize {
+    let mut map = map.clone();
+    let mut cnt = 0;
+    // Could do BFS if needed but it's good enough
+    loop {
+        let mut any_changed = false;
+        for y in 0..map.height {
+            for x in 0..map.width {
+                if map.at(x, y) && map.is_accessible(x, y) {
+                    map.remove(x, y);
+                    cnt += 1;
+                    any_changed = true;
+                }
+            }
+        }
+        if !any_changed { break; }
+    }
+    cnt
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 13);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 43);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("..@@.\n@@x..").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 3));
+        let err = parse("..@@.\n@@.").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 4));
+    }
+}
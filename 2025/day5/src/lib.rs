@@ -0,0 +1,135 @@
+use std::cmp::{min, max};
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+pub struct Inventory {
+    fresh_ranges: Vec<RangeInclusive<usize>>,
+    available: Vec<usize>
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Inventory, ParseError> {
+    let sections = parsing::sections(puzzle_input);
+    let (ranges, ids) = match &sections[..] {
+        [ranges, ids] => (ranges, ids),
+        _ => {
+            let line = sections.get(2).map_or_else(|| Line {
+                number: parsing::lines(puzzle_input).last().map_or(1, |line| line.number + 1),
+                text: "",
+            }, |section| section[0]);
+            return Err(line.error(1, "expected the fresh ID ranges then the available IDs"));
+        }
+    };
+
+    let fresh_ranges = ranges.iter().map(|line| {
+        let (lower, upper) = line.as_field().split_once("-")?;
+        Ok(lower.parse()?..=upper.parse()?)
+    }).collect::<Result<_, _>>()?;
+    let available = ids.iter().map(|line| line.parse()).collect::<Result<_, _>>()?;
+    Ok(Inventory { fresh_ranges, available })
+}
+
+pub fn part1(inventory: &Inventory) -> usize {
+    let is_fresh = |id: usize| -> bool {
+        inventory.fresh_ranges.iter().any(|range| range.contains(&id))
+    };
+
+    inventory.available.iter().filter(|&&id| is_fresh(id)).count()
+}
+
+struct IntervalSet {
+    intervals: BTreeMap<usize, usize>
+}
+
+impl IntervalSet {
+    fn new() -> Self { IntervalSet { intervals: BTreeMap::new() } }
+    fn insert(&mut self, mut lower: usize, mut upper: usize) {
+        // This is kinda goofy to re-look up the matching range but the cursor API isn't stable,
+        // and it's plenty fast anyways
+        loop {
+            let mut matching = self.intervals.range(..=upper);
+            if let Some((lower2, upper2)) = matching.next_back() {
+                if lower <= *upper2 && *lower2 <= upper {
+                    lower = min(lower, *lower2);
+                    upper = max(upper, *upper2);
+                    let lower2 = *lower2; // Hack to stop borrowing the Range iter
+                    self.intervals.remove(&lower2);
+                    continue;
+                }
+            }
+            break;
+        }
+        self.intervals.insert(lower, upper);
+    }
+}
+
+pub fn part2(inventory: &Inventory) -> usize {
+    let mut merged = IntervalSet::new();
+    for range in inventory.fresh_ranges.iter() {
+        merged.insert(*range.start(), *range.end());
+    }
+    let mut sum = 0;
+    for (lower, upper) in merged.intervals {
+        sum += upper - lower + 1;
+    }
+    sum
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Inventory;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 3);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 14);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("3-5\n10-14").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 1));
+        let err = parse("3-5\n10:14\n\n1").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("3-5\n\n1\nx").err().unwrap();
+        assert_eq!((err.line, err.column), (4, 1));
+    }
+}
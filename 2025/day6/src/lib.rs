@@ -0,0 +1,149 @@
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+#[derive(Copy, Clone)]
+enum Op {
+    Add,
+    Mul,
+}
+
+pub struct Problem {
+    numbers: Vec<String>,
+    op: Op,
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Vec<Problem>, ParseError> {
+    let lines = parsing::lines(puzzle_input);
+    let (op_line, number_lines) = match lines.split_last() {
+        Some((op_line, number_lines)) if !number_lines.is_empty() => (op_line, number_lines),
+        _ => return Err(Line { number: lines.len() + 1, text: "" }
+            .error(1, "expected rows of numbers then a row of operators")),
+    };
+
+    // Each problem starts at its operator's column, and is followed by a column of spaces
+    let mut problems = vec![];
+    let mut starts = vec![];
+    for (i, ch) in op_line.text.chars().enumerate() {
+        let op = match ch {
+            ' ' if i != 0 => continue,
+            '+' => Op::Add,
+            '*' => Op::Mul,
+            _ => return Err(op_line.error(i + 1, "expected + or *")),
+        };
+        problems.push(Problem { numbers: vec![], op });
+        starts.push(i);
+    }
+
+    let width = number_lines[0].text.len();
+    for line in number_lines {
+        line.map_chars(|ch| if ch == ' ' || ch.is_ascii_digit() { Some(()) } else { None })?;
+        if line.text.len() != width || width <= *starts.last().unwrap() {
+            return Err(line.error(line.text.len().min(width) + 1,
+                                  format!("expected {} columns like line 1", width)));
+        }
+        for (j, problem) in problems.iter_mut().enumerate() {
+            let end = match starts.get(j + 1) {
+                Some(&next) => {
+                    if &line.text[(next - 1)..next] != " " {
+                        return Err(line.error(next, "expected a space between problems"));
+                    }
+                    next - 1
+                },
+                None => width,
+            };
+            let number = line.field(&line.text[starts[j]..end]);
+            number.trim().parse::<u64>()?;
+            problem.numbers.push(number.text.to_string());
+        }
+    }
+    Ok(problems)
+}
+
+fn calculate<I: Iterator<Item=u64>>(op: Op, numbers: I) -> u64 {
+    match op {
+        Op::Add => numbers.sum::<u64>(),
+        Op::Mul => numbers.product::<u64>(),
+    }
+}
+
+pub fn part1(problems: &[Problem]) -> u64 {
+    let mut sum = 0;
+    for problem in problems {
+        sum += calculate(problem.op, problem.numbers.iter()
+            .map(|s| s.trim().parse::<u64>().unwrap()));
+    }
+    sum
+}
+
+pub fn part2(problems: &[Problem]) -> u64 {
+    let mut sum = 0;
+    for problem in problems.iter() {
+        let mut parsed_nums = vec![];
+        for i in 0..problem.numbers.first().unwrap().len() {
+            let mut num = 0;
+            for s in problem.numbers.iter() {
+                if &s[i..=i] != " " {
+                    num *= 10;
+                    num += s[i..=i].parse::<u64>().unwrap();
+                }
+            }
+            parsed_nums.push(num);
+        }
+        sum += calculate(problem.op, parsed_nums.into_iter());
+    }
+    sum
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Problem>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = "123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +  ";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 4277556);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 3263827);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("123 328\n 45 64 ").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = parse("123 328\n 45 64 \n*   -  ").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 5));
+        let err = parse("123 328\n 45x64 \n*   +  ").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 4));
+        let err = parse("123 328\n 4 564 \n*   +  ").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 4));
+        let err = parse("123 328\n 45 64\n*   +  ").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 7));
+    }
+}
@@ -0,0 +1,161 @@
+use parsing::{Line, ParseError};
+use solution::Solution;
+
+pub struct Map {
+    width: usize,
+    height: usize,
+    startx: usize,
+    // Where the splitters are
+    grid: Vec<bool>,
+}
+
+impl Map {
+    fn idx(&self, x: usize, y: usize) -> usize {
+        debug_assert!(x < self.width);
+        debug_assert!(y < self.height);
+        y * self.width + x
+    }
+
+    fn at(&self, x: usize, y: usize) -> bool {
+        self.grid[self.idx(x, y)]
+    }
+}
+
+pub fn parse(puzzle_input: &str) -> Result<Map, ParseError> {
+    let lines = parsing::lines(puzzle_input);
+    let rows = parsing::grid(&lines, |ch| match ch {
+        '.' | '^' | 'S' => Some(ch),
+        _ => None,
+    })?;
+    let mut start = None;
+    for (y, row) in rows.iter().enumerate() {
+        for (x, &ch) in row.iter().enumerate() {
+            if ch != 'S' {
+                continue;
+            }
+            // Assume starty == 0
+            if y != 0 || start.is_some() {
+                return Err(lines[y].error(x + 1, "expected a single start in the top row"));
+            }
+            start = Some(x);
+        }
+    }
+    let startx = start.ok_or_else(|| Line { number: 1, text: "" }.error(1, "expected a start in the top row"))?;
+    let height = rows.len();
+    let width = rows[0].len();
+    let grid = rows.concat().into_iter().map(|ch| ch == '^').collect();
+    Ok(Map { width, height, startx, grid })
+}
+
+pub fn part1(map: &Map) -> usize {
+    let mut split_cnt = 0;
+    let mut step_beams = |y: usize, old_beams: Vec<bool>| -> Vec<bool> {
+        let mut beams = vec![false; map.width];
+        for x in 0..map.width {
+            if !old_beams[x] { continue }
+            if map.at(x, y) {
+                // Splitter
+                if x > 0             { beams[x - 1] = true; }
+                if x < map.width - 1 { beams[x + 1] = true; }
+                split_cnt += 1;
+            } else {
+                // Straight down
+                beams[x] = true;
+            }
+        }
+        beams
+    };
+    let mut beams = vec![false; map.width];
+    beams[map.startx] = true;
+    for y in 1..map.height {
+        beams = step_beams(y, beams);
+    }
+    split_cnt
+}
+
+pub fn part2(map: &Map) -> usize {
+    let mut split_cnt = 0;
+    let mut step_beams = |y: usize, old_beams: Vec<usize>| -> Vec<usize> {
+        let mut beams = vec![0; map.width];
+        for x in 0..map.width {
+            if map.at(x, y) {
+                // Splitter
+                if x > 0             { beams[x - 1] += old_beams[x]; }
+                if x < map.width - 1 { beams[x + 1] += old_beams[x]; }
+                split_cnt += 1;
+            } else {
+                // Straight down
+                beams[x] += old_beams[x];
+            }
+        }
+        beams
+    };
+    let mut beams = vec![0; map.width];
+    beams[map.startx] = 1;
+    for y in 1..map.height {
+        beams = step_beams(y, beams);
+    }
+    beams.iter().sum()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        parse(puzzle_input).map_err(|e| e.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = ".......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse(EX).unwrap()), 21);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse(EX).unwrap()), 40);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse("..S..\n..^..\n..S..").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 3));
+        let err = parse("..S..\n..v..").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 3));
+        let err = parse(".....\n..^..").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 1));
+    }
+}
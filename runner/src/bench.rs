@@ -0,0 +1,191 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs::{self, OpenOptions};
+use std::io::Write as _;
+use std::path::PathBuf;
+use std::process::Command;
+
+use crate::{Day, Run, build, default_input, is_slow, repo_root, run, solutions, TIMEOUT};
+
+// Phases shown as columns in the report, days run as a binary only have a total and are marked
+pub const PHASES: [&str; 4] = ["total", "parse", "part1", "part2"];
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sample {
+    pub commit: String,
+    pub day: Day,
+    pub phase: String,
+    pub seconds: f64,
+}
+
+// Local only, since timings depend on the machine
+pub fn history_path() -> PathBuf {
+    repo_root().join("runner").join("bench_history.tsv")
+}
+
+pub fn current_commit() -> String {
+    let git = |args: &[&str]| Command::new("git").args(args).current_dir(repo_root()).output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string());
+    let commit = git(&["rev-parse", "--short", "HEAD"]).unwrap_or_else(|| "unknown".to_string());
+    match git(&["status", "--porcelain"]) {
+        Some(status) if !status.is_empty() => format!("{}-dirty", commit),
+        _ => commit
+    }
+}
+
+pub fn parse_history(contents: &str) -> Result<Vec<Sample>, String> {
+    contents.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.starts_with('#') && !line.is_empty())
+        .map(|(i, line)| {
+            let fields: Vec<&str> = line.split('\t').collect();
+            if let [commit, day, phase, seconds] = fields[..] {
+                Ok(Sample {
+                    commit: commit.to_string(),
+                    day: day.parse().map_err(|e| format!("Line {}: {}", i + 1, e))?,
+                    phase: phase.to_string(),
+                    seconds: seconds.parse().map_err(|e| format!("Line {}: {}", i + 1, e))?,
+                })
+            } else {
+                Err(format!("Line {}: expected 4 fields", i + 1))
+            }
+        })
+        .collect()
+}
+
+pub fn load_history() -> Result<Vec<Sample>, String> {
+    match fs::read_to_string(history_path()) {
+        Ok(contents) => parse_history(&contents),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e.to_string())
+    }
+}
+
+pub fn append_history(samples: &[Sample]) -> Result<(), String> {
+    let path = history_path();
+    let new = !path.exists();
+    let mut file = OpenOptions::new().create(true).append(true).open(&path).map_err(|e| e.to_string())?;
+    let mut contents = String::new();
+    if new {
+        contents.push_str("# Benchmark timings recorded with `runner bench`: commit, day, phase, seconds\n");
+    }
+    for sample in samples {
+        writeln!(contents, "{}\t{}\t{}\t{:.6}", sample.commit, sample.day, sample.phase, sample.seconds).unwrap();
+    }
+    file.write_all(contents.as_bytes()).map_err(|e| e.to_string())
+}
+
+// Runs a day several times and keeps the fastest time for each phase, which is the least noisy
+pub fn bench_day(day: Day, runs: usize) -> Result<BTreeMap<String, f64>, String> {
+    let input = &default_input(day);
+    // Binaries are only built once, rather than on every run like crate::solve would
+    let run_once: Box<dyn Fn() -> Result<Run, String>> = match solutions::solver(day) {
+        Some(solver) => Box::new(move || solutions::solve(day, solver, input)),
+        None => {
+            let binary = build(day)?;
+            Box::new(move || run(day, &binary, input, TIMEOUT))
+        }
+    };
+    let mut best: BTreeMap<String, f64> = BTreeMap::new();
+    for _ in 0..runs {
+        let run = run_once()?;
+        let phases = std::iter::once(("total".to_string(), run.elapsed.as_secs_f64())).chain(run.phases);
+        for (phase, seconds) in phases {
+            let entry = best.entry(phase).or_insert(f64::INFINITY);
+            *entry = entry.min(seconds);
+        }
+    }
+    Ok(best)
+}
+
+// The most recent total for each day from a commit other than this one
+pub fn previous_totals(history: &[Sample], commit: &str) -> BTreeMap<Day, f64> {
+    history.iter()
+        .filter(|sample| sample.commit != commit && sample.phase == "total")
+        .map(|sample| (sample.day, sample.seconds))
+        .collect()
+}
+
+pub fn report(results: &BTreeMap<Day, BTreeMap<String, f64>>, previous: &BTreeMap<Day, f64>, slowest: usize) -> String {
+    let mut ret = String::new();
+    let mut year = None;
+    for (day, phases) in results {
+        if year != Some(day.year) {
+            year = Some(day.year);
+            write!(ret, "\n{:<8}", day.year).unwrap();
+            for phase in PHASES.iter().chain(&["previous"]) {
+                write!(ret, "{:>10}", phase).unwrap();
+            }
+            ret.push('\n');
+        }
+        write!(ret, "{:<8}", format!("day{}", day.day)).unwrap();
+        for phase in PHASES {
+            match phases.get(phase) {
+                Some(seconds) => write!(ret, "{:>10.3}", seconds).unwrap(),
+                None => write!(ret, "{:>10}", "-").unwrap()
+            }
+        }
+        match previous.get(day) {
+            Some(&prev) => {
+                write!(ret, "{:>10.3}", prev).unwrap();
+                if is_slow(prev, phases["total"]) {
+                    ret.push_str("  REGRESSION");
+                }
+            },
+            None => write!(ret, "{:>10}", "-").unwrap()
+        }
+        if phases.len() == 1 {
+            ret.push_str("  total only");
+        }
+        ret.push('\n');
+    }
+
+    let mut by_total: Vec<(Day, f64)> = results.iter().map(|(&day, phases)| (day, phases["total"])).collect();
+    by_total.sort_by(|a, b| b.1.total_cmp(&a.1));
+    writeln!(ret, "\nSlowest days:").unwrap();
+    for (day, seconds) in by_total.into_iter().take(slowest) {
+        writeln!(ret, "{:<12}{:>10.3}", day.to_string(), seconds).unwrap();
+    }
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(commit: &str, day: u32, phase: &str, seconds: f64) -> Sample {
+        Sample { commit: commit.to_string(), day: Day { year: 2016, day }, phase: phase.to_string(), seconds }
+    }
+
+    #[test]
+    fn test_history() {
+        let history = parse_history("\
+# comment
+abc123\t2016/day5\ttotal\t12.500000
+abc123\t2016/day5\tpart1\t6.000000
+def456-dirty\t2016/day5\ttotal\t10.000000
+def456-dirty\t2016/day14\ttotal\t3.000000").unwrap();
+        assert_eq!(history[1], sample("abc123", 5, "part1", 6.0));
+        assert_eq!(previous_totals(&history, "def456-dirty"), vec![(Day { year: 2016, day: 5 }, 12.5)].into_iter().collect());
+        assert_eq!(previous_totals(&history, "new").len(), 2);
+        assert!(parse_history("abc123\t2016/day5\ttotal").is_err());
+    }
+
+    #[test]
+    fn test_report() {
+        let mut results = BTreeMap::new();
+        results.insert(Day { year: 2016, day: 5 }, vec![("total".to_string(), 30.0), ("part1".to_string(), 12.0)].into_iter().collect());
+        results.insert(Day { year: 2016, day: 14 }, vec![("total".to_string(), 3.0)].into_iter().collect());
+        let previous = vec![(Day { year: 2016, day: 5 }, 12.5), (Day { year: 2016, day: 14 }, 3.1)].into_iter().collect();
+        assert_eq!(report(&results, &previous, 1), "
+2016         total     parse     part1     part2  previous
+day5        30.000         -    12.000         -    12.500  REGRESSION
+day14        3.000         -         -         -     3.100  total only
+
+Slowest days:
+2016/day5       30.000
+");
+    }
+}
@@ -0,0 +1,24 @@
+use std::env;
+use std::fs;
+use std::process;
+
+use runner::examples::{self, DEFAULT_TEST_CALL};
+
+// Prints a tests module for the examples in a saved puzzle page
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (page, test_call) = match &args[..] {
+        [page] => (page, DEFAULT_TEST_CALL),
+        [page, test_call] => (page, test_call.as_str()),
+        _ => {
+            eprintln!("Usage: extract_examples PAGE.html [TEST_CALL]");
+            eprintln!("TEST_CALL defaults to {:?}", DEFAULT_TEST_CALL);
+            process::exit(2);
+        }
+    };
+    let html = fs::read_to_string(page).unwrap_or_else(|e| {
+        eprintln!("error: can't read {}: {}", page, e);
+        process::exit(1);
+    });
+    print!("{}", examples::tests_module(&examples::parse_page(&html), test_call));
+}
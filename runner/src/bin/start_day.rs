@@ -0,0 +1,117 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+
+use runner::fetch::{self, Curl, Fetcher};
+use runner::{Day, examples, repo_root, scaffold};
+
+fn usage() -> ! {
+    eprintln!("Usage: start_day [--template NAME] [--examples PAGE.html] [--offline] [[YEAR] DAY]");
+    eprintln!("Templates: {}", scaffold::template_names(&scaffold::templates_dir()).join(", "));
+    eprintln!("The year defaults to the one containing the current directory, and the day to the next one");
+    process::exit(2);
+}
+
+// The year directory we're inside, resolving symlinks like a `current -> 2025` link
+fn current_year(root: &Path) -> Option<u32> {
+    let cwd = env::current_dir().ok()?;
+    let relative = cwd.strip_prefix(root).ok()?;
+    let first = relative.components().next()?;
+    let dir = root.join(first);
+    let dir = fs::read_link(&dir).map(|target| root.join(target)).unwrap_or(dir);
+    dir.file_name()?.to_str()?.parse().ok()
+}
+
+fn next_day(root: &Path, year: u32) -> u32 {
+    fs::read_dir(root.join(year.to_string())).into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| entry.file_name().to_str()?.strip_prefix("day")?.parse::<u32>().ok())
+        .max()
+        .unwrap_or(0) + 1
+}
+
+fn author() -> String {
+    let git = |key: &str| Command::new("git").args(["config", key]).output().ok()
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .unwrap_or_default();
+    format!("{} <{}>", git("user.name"), git("user.email"))
+}
+
+fn parse_num(s: &str) -> u32 {
+    s.parse().unwrap_or_else(|_| usage())
+}
+
+fn main() {
+    let root = repo_root();
+    let mut template = "plain".to_string();
+    let mut examples_page = None;
+    let mut offline = false;
+    let mut positional = Vec::new();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--template" => template = args.next().unwrap_or_else(|| usage()),
+            "--examples" => examples_page = Some(args.next().unwrap_or_else(|| usage())),
+            "--offline" => offline = true,
+            "-h" | "--help" => usage(),
+            _ => positional.push(arg)
+        }
+    }
+    let (year, day) = match &positional[..] {
+        [year, day] => (parse_num(year), parse_num(day)),
+        [day] => (current_year(&root).unwrap_or_else(|| usage()), parse_num(day)),
+        [] => {
+            let year = current_year(&root).unwrap_or_else(|| usage());
+            (year, next_day(&root, year))
+        },
+        _ => usage()
+    };
+    if !(2015..2100).contains(&year) || !(1..=25).contains(&day) {
+        eprintln!("error: invalid day {}/day{}", year, day);
+        usage();
+    }
+    let day = Day { year, day };
+    println!("Day {} {}", day.day, day.year);
+
+    let mut template = scaffold::load_template(&scaffold::templates_dir(), &template).unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        usage()
+    });
+    if let Some(page) = examples_page {
+        let html = fs::read_to_string(&page).unwrap_or_else(|e| {
+            eprintln!("error: can't read {}: {}", page, e);
+            process::exit(1);
+        });
+        let tests = examples::tests_module(&examples::parse_page(&html), &template.test_call);
+        template.lib = examples::replace_tests(&template.lib, &tests);
+    }
+    let dir: PathBuf = match scaffold::create_day(&root, day, &author(), &template) {
+        Ok(dir) => {
+            if let Some(workspace) = scaffold::register_in_workspace(&root, &dir).unwrap() {
+                println!("Added to {}", workspace.display());
+            }
+            dir
+        },
+        // Keep going so that the input can still be fetched for a day that was started offline
+        Err(e) => {
+            eprintln!("warning: not creating the crate: {}", e);
+            day.dir()
+        }
+    };
+
+    let input_path = dir.join("input");
+    if input_path.exists() {
+        return;
+    }
+    let mut fetcher = Fetcher::new(Curl, &root);
+    fetcher.offline = offline;
+    match fetcher.input(day, || fetch::session(&root)) {
+        Ok(input) => fs::write(&input_path, input).unwrap(),
+        Err(e) => {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        }
+    }
+}
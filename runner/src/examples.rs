@@ -0,0 +1,194 @@
+// Pulls the worked examples out of a saved puzzle page. Each part's description is an <article>,
+// its examples are <pre><code> blocks, and the answer to the example is almost always the last
+// emphasised code in the article. That's a heuristic, so the output is a starting point to check.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Part {
+    pub example: Option<String>,
+    pub answer: Option<String>,
+}
+
+fn unescape(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+fn strip_tags(s: &str) -> String {
+    let mut ret = String::new();
+    let mut in_tag = false;
+    for ch in s.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => ret.push(ch),
+            _ => ()
+        }
+    }
+    unescape(&ret)
+}
+
+// The contents of every non-overlapping start..end span, with their offsets
+fn between<'a>(s: &'a str, start: &str, end: &str) -> Vec<(usize, &'a str)> {
+    let mut ret = Vec::new();
+    let mut pos = 0;
+    while let Some(i) = s[pos..].find(start) {
+        pos += i + start.len();
+        match s[pos..].find(end) {
+            Some(j) => {
+                ret.push((pos, &s[pos..pos + j]));
+                pos += j + end.len();
+            },
+            None => break
+        }
+    }
+    ret
+}
+
+pub fn parse_page(html: &str) -> Vec<Part> {
+    let mut articles: Vec<&str> = between(html, "<article", "</article>").into_iter()
+        .map(|(_, article)| article)
+        .collect();
+    if articles.is_empty() {
+        articles.push(html);
+    }
+    articles.into_iter()
+        .map(|article| {
+            let example = between(article, "<pre><code>", "</code></pre>").first()
+                .map(|(_, block)| strip_tags(block).trim_end_matches('\n').to_string());
+            let answer = between(article, "<code><em>", "</em></code>").into_iter()
+                .chain(between(article, "<em><code>", "</code></em>"))
+                .max_by_key(|&(pos, _)| pos)
+                .map(|(_, answer)| strip_tags(answer));
+            Part { example, answer }
+        })
+        .collect()
+}
+
+fn string_literal(s: &str) -> String {
+    let escaped = s.replace('\\', "\\\\").replace('"', "\\\"");
+    // A line continuation would swallow the first line's indentation
+    if escaped.starts_with([' ', '\t']) {
+        format!("\"{}\"", escaped)
+    } else {
+        format!("\"\\\n{}\"", escaped)
+    }
+}
+
+// How a test calls each part, for days whose parts don't take the input directly
+pub const DEFAULT_TEST_CALL: &str = "part{part}({example})";
+
+pub fn tests_module(parts: &[Part], test_call: &str) -> String {
+    let examples: Vec<&str> = parts.iter().take(2).filter_map(|part| part.example.as_deref()).collect();
+    let mut distinct = examples.clone();
+    distinct.dedup();
+    let name = |example: &str| -> String {
+        if distinct.len() == 1 {
+            "EX".to_string()
+        } else {
+            format!("EX{}", distinct.iter().position(|&ex| ex == example).unwrap() + 1)
+        }
+    };
+
+    let mut ret = String::from("#[cfg(test)]\nmod tests {\n    use super::*;\n");
+    for example in distinct.iter() {
+        ret.push_str(&format!("\n    const {}: &str = {};\n", name(example), string_literal(example)));
+    }
+    for i in 0..2 {
+        ret.push_str(&format!("\n    #[test]\n    fn test_part{}() {{\n", i + 1));
+        // Part 2 usually reuses part 1's example
+        let example = parts.get(i).and_then(|part| part.example.as_deref()).or_else(|| examples.first().copied());
+        let answer = parts.get(i).and_then(|part| part.answer.as_deref());
+        match (example, answer) {
+            (Some(example), Some(answer)) => {
+                // Compared as strings, since the templates' parts return placeholder strings and a
+                // real answer could be any Display type
+                let call = test_call.replace("{part}", &(i + 1).to_string()).replace("{example}", &name(example));
+                ret.push_str(&format!("        assert_eq!({}.to_string(), {:?});\n", call, answer));
+            },
+            _ => ret.push_str("        // FIXME\n")
+        }
+        ret.push_str("    }\n");
+    }
+    ret.push_str("}\n");
+    ret
+}
+
+// Swaps the tests module at the end of a day's lib.rs for the generated one
+pub fn replace_tests(main: &str, tests: &str) -> String {
+    match main.find("#[cfg(test)]") {
+        Some(i) => format!("{}{}", &main[..i], tests),
+        None => format!("{}\n{}", main, tests)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = "<html><body><main>
+<article class=\"day-desc\"><h2>--- Day 1: Test ---</h2><p>For example:</p>
+<pre><code>1abc2
+  &lt;<em>x</em>&gt;
+</code></pre>
+<p>Adding these together produces <code><em>142</em></code>.</p>
+<p>What is the <em>sum</em>?</p>
+</article>
+<p>Your puzzle answer was <code>55</code>.</p>
+<article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2>
+<p>In this example the <code>\"key\"</code> is <em><code>a-b</code></em>.</p>
+</article>
+</main></body></html>";
+
+    #[test]
+    fn test_parse_page() {
+        assert_eq!(parse_page(PAGE), vec![
+            Part { example: Some("1abc2\n  <x>".to_string()), answer: Some("142".to_string()) },
+            Part { example: None, answer: Some("a-b".to_string()) },
+        ]);
+    }
+
+    #[test]
+    fn test_tests_module() {
+        assert_eq!(tests_module(&parse_page(PAGE), DEFAULT_TEST_CALL), "\
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EX: &str = \"\\
+1abc2
+  <x>\";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(EX).to_string(), \"142\");
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(EX).to_string(), \"a-b\");
+    }
+}
+");
+    }
+
+    #[test]
+    fn test_two_examples() {
+        let parts = vec![
+            Part { example: Some("  [D]".to_string()), answer: None },
+            Part { example: Some("a\\b\"c".to_string()), answer: Some("3".to_string()) },
+        ];
+        let module = tests_module(&parts, "part{part}(&parse({example}))");
+        assert!(module.contains("const EX1: &str = \"  [D]\";\n"));
+        assert!(module.contains("const EX2: &str = \"\\\na\\\\b\\\"c\";\n"));
+        assert!(module.contains("fn test_part1() {\n        // FIXME\n"));
+        assert!(module.contains("assert_eq!(part2(&parse(EX2)).to_string(), \"3\");"));
+    }
+
+    #[test]
+    fn test_replace_tests() {
+        assert_eq!(replace_tests("fn main() {}\n\n#[cfg(test)]\nmod tests {}\n", "NEW\n"), "fn main() {}\n\nNEW\n");
+    }
+}
@@ -0,0 +1,151 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::Day;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+pub trait HttpClient {
+    fn get(&self, url: &str, session: &str) -> Result<String, String>;
+}
+
+// std has no TLS, so shell out to curl like the old script did with wget
+pub struct Curl;
+
+impl HttpClient for Curl {
+    fn get(&self, url: &str, session: &str) -> Result<String, String> {
+        let output = Command::new("curl")
+            .args(["--silent", "--show-error", "--fail", "--max-time", "30"])
+            .args(["--header", &format!("Cookie: session={}", session)])
+            .arg(url)
+            .output()
+            .map_err(|e| format!("Failed to run curl: {}", e))?;
+        if !output.status.success() {
+            return Err(format!("Fetching {} failed: {}", url, String::from_utf8_lossy(&output.stderr).trim()));
+        }
+        String::from_utf8(output.stdout).map_err(|e| e.to_string())
+    }
+}
+
+// Reads the session cookie from AOC_SESSION, or a cookies.txt in the repo root like
+//   adventofcode.com	TRUE	/	FALSE	9999999999	session	mysessionkeyhere
+pub fn session(root: &Path) -> Result<String, String> {
+    if let Ok(session) = std::env::var("AOC_SESSION") {
+        return Ok(session);
+    }
+    let cookies = fs::read_to_string(root.join("cookies.txt"))
+        .map_err(|_| "Set AOC_SESSION or save a cookies.txt to fetch inputs".to_string())?;
+    cookies.lines()
+        .filter(|line| !line.starts_with('#'))
+        .find_map(|line| {
+            let fields: Vec<&str> = line.split('\t').collect();
+            match fields[..] {
+                [.., "session", value] => Some(value.trim().to_string()),
+                _ => None
+            }
+        })
+        .ok_or_else(|| "No session cookie in cookies.txt".to_string())
+}
+
+pub struct Fetcher<C: HttpClient> {
+    pub client: C,
+    pub base_url: String,
+    pub cache_dir: PathBuf,
+    // Only use the cache, for working without a connection
+    pub offline: bool,
+}
+
+impl<C: HttpClient> Fetcher<C> {
+    pub fn new(client: C, root: &Path) -> Fetcher<C> {
+        Fetcher {
+            client,
+            base_url: BASE_URL.to_string(),
+            cache_dir: root.join(".cache").join("inputs"),
+            offline: false,
+        }
+    }
+
+    fn cache_path(&self, day: Day) -> PathBuf {
+        self.cache_dir.join(day.year.to_string()).join(format!("day{}", day.day))
+    }
+
+    // Inputs never change, so each is only downloaded once
+    pub fn input(&self, day: Day, session: impl FnOnce() -> Result<String, String>) -> Result<String, String> {
+        let path = self.cache_path(day);
+        if let Ok(input) = fs::read_to_string(&path) {
+            return Ok(input);
+        }
+        if self.offline {
+            return Err(format!("{} isn't cached and running offline", day));
+        }
+        let url = format!("{}/{}/day/{}/input", self.base_url, day.year, day.day);
+        let input = self.client.get(&url, &session()?)?;
+        fs::create_dir_all(path.parent().unwrap()).map_err(|e| e.to_string())?;
+        fs::write(&path, &input).map_err(|e| e.to_string())?;
+        Ok(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    // Serves a single request, returning the request line and cookie header it received
+    fn stub_server(body: &'static str) -> (String, thread::JoinHandle<(String, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request = String::new();
+            reader.read_line(&mut request).unwrap();
+            let mut cookie = String::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() { break; }
+                if let Some(value) = line.strip_prefix("Cookie: ") {
+                    cookie = value.trim().to_string();
+                }
+            }
+            write!(stream, "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", body.len(), body).unwrap();
+            (request.trim().to_string(), cookie)
+        });
+        (url, handle)
+    }
+
+    fn fetcher(name: &str, base_url: String) -> Fetcher<Curl> {
+        let cache_dir = std::env::temp_dir().join(format!("runner-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&cache_dir);
+        Fetcher { client: Curl, base_url, cache_dir, offline: false }
+    }
+
+    #[test]
+    fn test_fetch_and_cache() {
+        let (url, server) = stub_server("1\n2\n3\n");
+        let mut fetcher = fetcher("cache", url);
+        let day = Day { year: 2015, day: 1 };
+        assert_eq!(fetcher.input(day, || Ok("abc".to_string())), Ok("1\n2\n3\n".to_string()));
+        assert_eq!(server.join().unwrap(), ("GET /2015/day/1/input HTTP/1.1".to_string(), "session=abc".to_string()));
+        // The server has gone, so this must come from the cache
+        fetcher.offline = true;
+        assert_eq!(fetcher.input(day, || panic!("session not needed")), Ok("1\n2\n3\n".to_string()));
+        assert!(fetcher.input(Day { year: 2015, day: 2 }, || panic!("session not needed")).is_err());
+        fs::remove_dir_all(&fetcher.cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_session() {
+        let root = std::env::temp_dir().join(format!("runner-session-{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join("cookies.txt"), "# comment\nadventofcode.com\tTRUE\t/\tFALSE\t9999999999\tsession\tmysessionkeyhere\n").unwrap();
+        if std::env::var("AOC_SESSION").is_err() {
+            assert_eq!(session(&root), Ok("mysessionkeyhere".to_string()));
+        }
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,368 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub mod bench;
+pub mod examples;
+pub mod fetch;
+pub mod scaffold;
+pub mod solutions;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+}
+
+impl Day {
+    pub fn dir(&self) -> PathBuf {
+        repo_root().join(self.year.to_string()).join(format!("day{}", self.day))
+    }
+
+    pub fn package(&self) -> String {
+        format!("day{}-{}", self.day, self.year)
+    }
+
+    // Filters are a year like "2019" or a single day like "2019/day5"
+    pub fn matches(&self, filter: &str) -> bool {
+        filter == self.year.to_string() || filter == self.to_string()
+    }
+}
+
+impl fmt::Display for Day {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/day{}", self.year, self.day)
+    }
+}
+
+impl std::str::FromStr for Day {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Day, String> {
+        let err = || format!("Invalid day: {:?}", s);
+        let (year, day) = s.split_once("/day").ok_or_else(err)?;
+        Ok(Day { year: year.parse().map_err(|_| err())?, day: day.parse().map_err(|_| err())? })
+    }
+}
+
+pub fn repo_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().to_path_buf()
+}
+
+pub fn all_days() -> Vec<Day> {
+    let mut days = Vec::new();
+    for year_entry in fs::read_dir(repo_root()).unwrap() {
+        let year_entry = year_entry.unwrap();
+        let year = match year_entry.file_name().to_str().and_then(|s| s.parse().ok()) {
+            Some(year) => year,
+            None => continue
+        };
+        for day_entry in fs::read_dir(year_entry.path()).unwrap() {
+            let day_entry = day_entry.unwrap();
+            let name = day_entry.file_name();
+            let day = match name.to_str().and_then(|s| s.strip_prefix("day")).and_then(|s| s.parse().ok()) {
+                Some(day) => day,
+                None => continue
+            };
+            if day_entry.path().join("Cargo.toml").exists() {
+                days.push(Day { year, day });
+            }
+        }
+    }
+    days.sort();
+    days
+}
+
+pub fn filter_days(filters: &[String]) -> Vec<Day> {
+    all_days().into_iter()
+        .filter(|day| filters.is_empty() || filters.iter().any(|filter| day.matches(filter)))
+        .collect()
+}
+
+// Some days need a hand optimized input to finish in reasonable time, see their READMEs
+pub fn default_input(day: Day) -> String {
+    if day.dir().join("input_optimized").exists() {
+        "input_optimized".to_string()
+    } else {
+        "input".to_string()
+    }
+}
+
+// Every day builds into the shared target dir so that dependencies are only compiled once
+pub fn build(day: Day) -> Result<PathBuf, String> {
+    let target_dir = repo_root().join("target");
+    let status = Command::new("cargo")
+        .args(["build", "--release", "--quiet", "--bin", &day.package()])
+        .env("CARGO_TARGET_DIR", &target_dir)
+        .current_dir(day.dir())
+        .status()
+        .map_err(|e| format!("Failed to run cargo: {}", e))?;
+    if !status.success() {
+        return Err(format!("Build failed with {}", status));
+    }
+    Ok(target_dir.join("release").join(day.package()))
+}
+
+#[derive(Clone, Debug)]
+pub struct Run {
+    pub output: Vec<String>,
+    pub elapsed: Duration,
+    // Phases the day reported through the timing crate, in the order they ran
+    pub phases: Vec<(String, f64)>,
+}
+
+fn read_lines<R: Read + Send + 'static>(mut reader: R) -> thread::JoinHandle<std::io::Result<String>> {
+    thread::spawn(move || {
+        let mut output = String::new();
+        reader.read_to_string(&mut output).map(|_| output)
+    })
+}
+
+pub fn run(day: Day, binary: &Path, input: &str, timeout: Duration) -> Result<Run, String> {
+    let input_file = File::open(day.dir().join(input))
+        .map_err(|e| format!("Failed to open {}: {}", input, e))?;
+    let start = Instant::now();
+    let mut child = Command::new(binary)
+        .current_dir(day.dir())
+        .stdin(input_file)
+        .env(timing::ENV_VAR, "1")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to run {}: {}", binary.display(), e))?;
+    // Read on other threads so a chatty day can't block on a full pipe
+    let stdout = read_lines(child.stdout.take().unwrap());
+    let stderr = read_lines(child.stderr.take().unwrap());
+    let status = loop {
+        if let Some(status) = child.try_wait().map_err(|e| e.to_string())? {
+            break status;
+        }
+        if start.elapsed() > timeout {
+            child.kill().ok();
+            child.wait().ok();
+            return Err(format!("Timed out after {}s", timeout.as_secs()));
+        }
+        thread::sleep(Duration::from_millis(5));
+    };
+    let elapsed = start.elapsed();
+    let output = stdout.join().unwrap().map_err(|e| e.to_string())?;
+    let diagnostics = stderr.join().unwrap().map_err(|e| e.to_string())?;
+    if !status.success() {
+        return Err(format!("Exited with {}", status));
+    }
+    Ok(Run {
+        output: output.lines().map(|line| line.to_string()).collect(),
+        elapsed,
+        phases: diagnostics.lines().filter_map(timing::parse_phase).collect(),
+    })
+}
+
+// Days listed in solutions.rs are solved in process, anything else is built and run as a binary
+pub fn solve(day: Day, input: &str, timeout: Duration) -> Result<Run, String> {
+    match solutions::solver(day) {
+        Some(solver) => solutions::solve(day, solver, input),
+        None => build(day).and_then(|binary| run(day, &binary, input, timeout))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Answer {
+    pub input: String,
+    pub seconds: f64,
+    pub output: Vec<String>,
+}
+
+pub fn answers_path() -> PathBuf {
+    repo_root().join("runner").join("answers.tsv")
+}
+
+const ANSWERS_HEADER: &str = "\
+# Verified answers for each day's committed input, recorded with `runner record`. Columns are tab
+# separated: day, input file, seconds taken, then one column per line of output.";
+
+pub fn parse_answers(contents: &str) -> Result<BTreeMap<Day, Answer>, String> {
+    let mut answers = BTreeMap::new();
+    for (i, line) in contents.lines().enumerate() {
+        if line.starts_with('#') || line.is_empty() { continue }
+        let mut fields = line.split('\t');
+        let mut field = |name| fields.next().ok_or_else(|| format!("Line {}: missing {}", i + 1, name));
+        let day = field("day")?.parse().map_err(|e| format!("Line {}: {}", i + 1, e))?;
+        let input = field("input")?.to_string();
+        let seconds = field("seconds")?.parse().map_err(|e| format!("Line {}: {}", i + 1, e))?;
+        let output = fields.map(|s| s.to_string()).collect();
+        answers.insert(day, Answer { input, seconds, output });
+    }
+    Ok(answers)
+}
+
+pub fn format_answers(answers: &BTreeMap<Day, Answer>) -> String {
+    let mut ret = String::from(ANSWERS_HEADER);
+    ret.push('\n');
+    for (day, answer) in answers {
+        ret.push_str(&format!("{}\t{}\t{:.3}", day, answer.input, answer.seconds));
+        for line in answer.output.iter() {
+            ret.push('\t');
+            ret.push_str(line);
+        }
+        ret.push('\n');
+    }
+    ret
+}
+
+pub fn load_answers() -> Result<BTreeMap<Day, Answer>, String> {
+    match fs::read_to_string(answers_path()) {
+        Ok(contents) => parse_answers(&contents),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+        Err(e) => Err(e.to_string())
+    }
+}
+
+pub fn save_answers(answers: &BTreeMap<Day, Answer>) -> Result<(), String> {
+    fs::write(answers_path(), format_answers(answers)).map_err(|e| e.to_string())
+}
+
+// Days with no recorded answer that check lets through. Anything else without one is a failure.
+pub const SKIPPED: &[(&str, &str)] = &[
+    ("2016/day3", "needs itertools 0.6, which isn't available to build against"),
+    ("2016/day19", "needs linked-list 0.0.3, which isn't available to build against"),
+    ("2018/day9", "needs linked-list 0.0.3, which isn't available to build against"),
+];
+
+pub fn skip_reason(day: Day) -> Option<&'static str> {
+    SKIPPED.iter().find(|&&(skipped, _)| skipped == day.to_string()).map(|&(_, reason)| reason)
+}
+
+// Why an output shouldn't be recorded as the answer, e.g. a day that prints a pointer to its
+// README or only solves part 1. Every day has two parts except the last one of each year.
+pub fn placeholder_reason(day: Day, output: &[String]) -> Option<String> {
+    const PLACEHOLDERS: [&str; 4] = ["README", "TODO", "FIXME", "unimplemented"];
+    if let Some(line) = output.iter().find(|line| PLACEHOLDERS.iter().any(|p| line.contains(p))) {
+        return Some(format!("placeholder output {:?}", line));
+    }
+    let last_day = if day.year >= 2025 { 12 } else { 25 };
+    if output.len() < 2 && day.day != last_day {
+        return Some(format!("only {} line(s) of output, part 2 is missing", output.len()));
+    }
+    None
+}
+
+#[derive(Clone, Debug)]
+pub enum Problem {
+    Unrecorded,
+    // On the SKIPPED list, so not a failure
+    Skipped(&'static str),
+    Failed(String),
+    Mismatch { expected: Vec<String>, actual: Vec<String> },
+    // Not an error since timings are noisy, but worth looking into
+    Slow { recorded: f64, actual: f64 },
+}
+
+impl Problem {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Problem::Unrecorded | Problem::Failed(_) | Problem::Mismatch { .. })
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Problem::Unrecorded => write!(f, "no recorded answer"),
+            Problem::Skipped(reason) => write!(f, "skipped, {}", reason),
+            Problem::Failed(e) => write!(f, "failed: {}", e),
+            Problem::Mismatch { expected, actual } =>
+                write!(f, "expected {:?} but got {:?}", expected, actual),
+            Problem::Slow { recorded, actual } =>
+                write!(f, "took {:.3}s but used to take {:.3}s", actual, recorded),
+        }
+    }
+}
+
+// Slower than this is a regression, with some slack for fast days where noise dominates
+pub fn is_slow(recorded: f64, actual: f64) -> bool {
+    actual > (recorded * 2.0).max(recorded + 1.0)
+}
+
+pub const TIMEOUT: Duration = Duration::from_secs(600);
+
+// Checks one day against its recorded answer
+pub fn check(day: Day, answer: Option<&Answer>) -> Option<Problem> {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => return Some(skip_reason(day).map_or(Problem::Unrecorded, Problem::Skipped))
+    };
+    let result = solve(day, &answer.input, TIMEOUT);
+    match result {
+        Err(e) => Some(Problem::Failed(e)),
+        Ok(run) if run.output != answer.output =>
+            Some(Problem::Mismatch { expected: answer.output.clone(), actual: run.output }),
+        Ok(run) if is_slow(answer.seconds, run.elapsed.as_secs_f64()) =>
+            Some(Problem::Slow { recorded: answer.seconds, actual: run.elapsed.as_secs_f64() }),
+        Ok(_) => None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day() {
+        let day: Day = "2019/day5".parse().unwrap();
+        assert_eq!(day, Day { year: 2019, day: 5 });
+        assert_eq!(day.package(), "day5-2019");
+        assert!(day.matches("2019"));
+        assert!(day.matches("2019/day5"));
+        assert!(!day.matches("2019/day15"));
+        assert!("2019/5".parse::<Day>().is_err());
+    }
+
+    #[test]
+    fn test_answers_round_trip() {
+        let mut answers = BTreeMap::new();
+        answers.insert(Day { year: 2016, day: 8 }, Answer {
+            input: "input".to_string(),
+            seconds: 0.25,
+            output: vec!["110".to_string(), "#..# ###".to_string()],
+        });
+        answers.insert(Day { year: 2015, day: 25 }, Answer {
+            input: "input_optimized".to_string(),
+            seconds: 12.0,
+            output: vec!["2650453".to_string()],
+        });
+        let formatted = format_answers(&answers);
+        assert!(formatted.contains("\n2015/day25\tinput_optimized\t12.000\t2650453\n2016/day8\t"));
+        assert_eq!(parse_answers(&formatted), Ok(answers));
+    }
+
+    #[test]
+    fn test_is_slow() {
+        assert!(!is_slow(0.01, 0.5));
+        assert!(is_slow(0.01, 1.5));
+        assert!(!is_slow(10.0, 19.0));
+        assert!(is_slow(10.0, 21.0));
+    }
+
+    #[test]
+    fn test_unrecorded() {
+        let skipped: Day = "2016/day19".parse().unwrap();
+        assert!(matches!(check(skipped, None), Some(Problem::Skipped(_))));
+        let unlisted = Day { year: 2015, day: 1 };
+        assert!(check(unlisted, None).unwrap().is_failure());
+    }
+
+    #[test]
+    fn test_placeholder_reason() {
+        let lines = |lines: &[&str]| lines.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let day = Day { year: 2015, day: 19 };
+        assert_eq!(placeholder_reason(day, &lines(&["535", "212"])), None);
+        assert!(placeholder_reason(day, &lines(&["535", "See README"])).is_some());
+        assert!(placeholder_reason(day, &lines(&["535"])).is_some());
+        assert_eq!(placeholder_reason(Day { year: 2015, day: 25 }, &lines(&["2650453"])), None);
+        assert_eq!(placeholder_reason(Day { year: 2025, day: 12 }, &lines(&["526"])), None);
+    }
+}
@@ -0,0 +1,98 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::process;
+
+use runner::bench::{self, Sample};
+use runner::{Answer, default_input, filter_days, load_answers, placeholder_reason, save_answers};
+
+fn usage() -> ! {
+    eprintln!("Usage: runner record|check [YEAR|YEAR/dayN]...");
+    eprintln!("       runner bench [--runs N] [YEAR|YEAR/dayN]...");
+    process::exit(2);
+}
+
+// Runs each day against its current input and records the output as the expected answer
+fn record(filters: &[String]) -> bool {
+    let mut answers = load_answers().unwrap();
+    let mut ok = true;
+    for day in filter_days(filters) {
+        let input = default_input(day);
+        let result = runner::solve(day, &input, runner::TIMEOUT)
+            .and_then(|run| match placeholder_reason(day, &run.output) {
+                Some(reason) => Err(format!("not recording {}", reason)),
+                None => Ok(run)
+            });
+        match result {
+            Ok(run) => {
+                println!("{}\t{:.3}s\t{}", day, run.elapsed.as_secs_f64(), run.output.join(" | "));
+                answers.insert(day, Answer { input, seconds: run.elapsed.as_secs_f64(), output: run.output });
+                save_answers(&answers).unwrap();
+            },
+            Err(e) => {
+                println!("{}\tfailed: {}", day, e);
+                ok = false;
+            }
+        }
+    }
+    ok
+}
+
+fn check(filters: &[String]) -> bool {
+    let answers = load_answers().unwrap();
+    let mut ok = true;
+    for day in filter_days(filters) {
+        match runner::check(day, answers.get(&day)) {
+            Some(problem) => {
+                println!("{}\t{}", day, problem);
+                ok &= !problem.is_failure();
+            },
+            None => println!("{}\tok", day)
+        }
+    }
+    ok
+}
+
+// Times each day, appends the results to the local history and reports them against the last run
+// from a different commit
+fn bench(mut args: &[String]) -> bool {
+    let mut runs = 3;
+    if args.first().map(|s| s.as_str()) == Some("--runs") {
+        runs = args.get(1).and_then(|s| s.parse().ok()).unwrap_or_else(|| usage());
+        args = &args[2..];
+    }
+    let commit = bench::current_commit();
+    let previous = bench::previous_totals(&bench::load_history().unwrap(), &commit);
+    let mut results = BTreeMap::new();
+    let mut ok = true;
+    for day in filter_days(args) {
+        match bench::bench_day(day, runs) {
+            Ok(phases) => {
+                eprintln!("{}\t{:.3}s", day, phases["total"]);
+                let samples: Vec<Sample> = phases.iter()
+                    .map(|(phase, &seconds)| Sample { commit: commit.clone(), day, phase: phase.clone(), seconds })
+                    .collect();
+                bench::append_history(&samples).unwrap();
+                results.insert(day, phases);
+            },
+            Err(e) => {
+                eprintln!("{}\tfailed: {}", day, e);
+                ok = false;
+            }
+        }
+    }
+    print!("{}", bench::report(&results, &previous, 10));
+    ok
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let ok = match args.first().map(|s| s.as_str()) {
+        Some("record") => record(&args[1..]),
+        Some("check") => check(&args[1..]),
+        Some("bench") => bench(&args[1..]),
+        _ => usage()
+    };
+    if !ok {
+        process::exit(1);
+    }
+}
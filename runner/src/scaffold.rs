@@ -0,0 +1,192 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::{Day, examples};
+
+// Each template is a directory holding a lib.rs and optionally a `dependencies` file, whose lines
+// are added to the [dependencies] section of the new Cargo.toml, and a `test_call` file saying how
+// generated example tests call each part (see examples::DEFAULT_TEST_CALL). The lib.rs implements
+// solution::Solution for a `Day` struct, and the generated main.rs just runs it.
+pub fn templates_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("templates")
+}
+
+pub fn template_names(dir: &Path) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(dir).into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.path().join("lib.rs").exists())
+        .filter_map(|entry| entry.file_name().to_str().map(|s| s.to_string()))
+        .collect();
+    names.sort();
+    names
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Template {
+    pub lib: String,
+    pub dependencies: Vec<String>,
+    pub test_call: String,
+}
+
+pub fn load_template(dir: &Path, name: &str) -> Result<Template, String> {
+    let lib = fs::read_to_string(dir.join(name).join("lib.rs")).map_err(|_| {
+        format!("Unknown template {:?}, expected one of {:?}", name, template_names(dir))
+    })?;
+    let dependencies = fs::read_to_string(dir.join(name).join("dependencies"))
+        .map(|deps| deps.lines().filter(|line| !line.trim().is_empty()).map(|line| line.to_string()).collect())
+        .unwrap_or_default();
+    let test_call = fs::read_to_string(dir.join(name).join("test_call"))
+        .map(|call| call.trim().to_string())
+        .unwrap_or_else(|_| examples::DEFAULT_TEST_CALL.to_string());
+    Ok(Template { lib, dependencies, test_call })
+}
+
+pub fn cargo_toml(day: Day, author: &str, template: &Template) -> String {
+    let mut ret = format!("\
+[package]
+name = \"{}\"
+version = \"0.1.0\"
+authors = [\"{}\"]
+edition = \"2024\"
+
+[dependencies]
+solution = {{ path = \"../../shared/solution\" }}
+", day.package(), author);
+    for dep in template.dependencies.iter() {
+        ret.push_str(dep);
+        ret.push('\n');
+    }
+    ret
+}
+
+pub fn main_rs(day: Day) -> String {
+    format!("fn main() {{\n    solution::main::<{}::Day>();\n}}\n", day.package().replace('-', "_"))
+}
+
+// Adds the crate to the members of an enclosing [workspace], if there is one. Days are normally
+// standalone crates sharing a target dir, which the runner finds without any registration.
+pub fn register_in_workspace(root: &Path, crate_dir: &Path) -> Result<Option<PathBuf>, String> {
+    let mut dir = crate_dir.parent();
+    while let Some(d) = dir {
+        let manifest = d.join("Cargo.toml");
+        if let Ok(contents) = fs::read_to_string(&manifest)
+            && contents.lines().any(|line| line.trim() == "[workspace]") {
+            let member = crate_dir.strip_prefix(d).unwrap().to_string_lossy().replace('\\', "/");
+            let updated = add_member(&contents, &member)
+                .ok_or_else(|| format!("Couldn't find the members list in {}", manifest.display()))?;
+            fs::write(&manifest, updated).map_err(|e| e.to_string())?;
+            return Ok(Some(manifest));
+        }
+        if d == root { break; }
+        dir = d.parent();
+    }
+    Ok(None)
+}
+
+fn add_member(manifest: &str, member: &str) -> Option<String> {
+    let start = manifest.find("members")?;
+    let open = start + manifest[start..].find('[')?;
+    let close = open + manifest[open..].find(']')?;
+    let quoted = format!("\"{}\"", member);
+    if manifest[open..close].contains(&quoted) {
+        return Some(manifest.to_string());
+    }
+    let existing = manifest[open + 1..close].trim_end().trim_end_matches(',');
+    let members = if existing.trim().is_empty() {
+        quoted
+    } else if existing.contains('\n') {
+        format!("{},\n    {},\n", existing, quoted)
+    } else {
+        format!("{}, {}", existing, quoted)
+    };
+    Some(format!("{}[{}{}", &manifest[..open], members, &manifest[close..]))
+}
+
+// Creates the day's crate from a template. Never touches an existing Cargo.toml, lib.rs or main.rs,
+// so rerunning this on a solved day is an error rather than a way to lose work.
+pub fn create_day(root: &Path, day: Day, author: &str, template: &Template) -> Result<PathBuf, String> {
+    let dir = root.join(day.year.to_string()).join(format!("day{}", day.day));
+    let manifest = dir.join("Cargo.toml");
+    let lib = dir.join("src").join("lib.rs");
+    let main = dir.join("src").join("main.rs");
+    for path in [&manifest, &lib, &main] {
+        if path.exists() {
+            return Err(format!("{} already exists", path.display()));
+        }
+    }
+    fs::create_dir_all(dir.join("src")).map_err(|e| e.to_string())?;
+    fs::write(&manifest, cargo_toml(day, author, template)).map_err(|e| e.to_string())?;
+    fs::write(&lib, &template.lib).map_err(|e| e.to_string())?;
+    fs::write(&main, main_rs(day)).map_err(|e| e.to_string())?;
+    // Share target dirs to avoid recompiling common dependencies like regex
+    #[cfg(unix)]
+    if dir.join("target").symlink_metadata().is_err() {
+        std::os::unix::fs::symlink("../../target/", dir.join("target")).map_err(|e| e.to_string())?;
+    }
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("runner-scaffold-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_templates() {
+        let names = template_names(&templates_dir());
+        assert_eq!(names, vec!["grid", "intcode", "plain"]);
+        let template = load_template(&templates_dir(), "intcode").unwrap();
+        assert_eq!(template.dependencies, vec!["intcode = { path = \"../../2019/intcode\" }"]);
+        let toml = cargo_toml(Day { year: 2019, day: 26 }, "A <a@b>", &template);
+        assert!(toml.starts_with("[package]\nname = \"day26-2019\"\n"));
+        assert!(toml.ends_with("[dependencies]\nsolution = { path = \"../../shared/solution\" }\nintcode = { path = \"../../2019/intcode\" }\n"));
+        assert_eq!(template.test_call, examples::DEFAULT_TEST_CALL);
+        assert_eq!(load_template(&templates_dir(), "grid").unwrap().test_call, "part{part}(&parse({example}))");
+        assert!(load_template(&templates_dir(), "nope").is_err());
+    }
+
+    #[test]
+    fn test_create_day() {
+        let root = temp_dir("create");
+        let day = Day { year: 2030, day: 1 };
+        let template = load_template(&templates_dir(), "plain").unwrap();
+        let dir = create_day(&root, day, "A <a@b>", &template).unwrap();
+        assert_eq!(fs::read_to_string(dir.join("src/lib.rs")).unwrap(), template.lib);
+        assert_eq!(fs::read_to_string(dir.join("src/main.rs")).unwrap(), "fn main() {\n    solution::main::<day1_2030::Day>();\n}\n");
+        fs::write(dir.join("src/lib.rs"), "solved").unwrap();
+        assert!(create_day(&root, day, "A <a@b>", &template).is_err());
+        assert_eq!(fs::read_to_string(dir.join("src/lib.rs")).unwrap(), "solved");
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_add_member() {
+        assert_eq!(add_member("[workspace]\nmembers = []\n", "day1").unwrap(),
+            "[workspace]\nmembers = [\"day1\"]\n");
+        assert_eq!(add_member("[workspace]\nmembers = [\"day1\"]\n", "day2").unwrap(),
+            "[workspace]\nmembers = [\"day1\", \"day2\"]\n");
+        assert_eq!(add_member("[workspace]\nmembers = [\n    \"day1\",\n]\n", "day2").unwrap(),
+            "[workspace]\nmembers = [\n    \"day1\",\n    \"day2\",\n]\n");
+        assert_eq!(add_member("[workspace]\nmembers = [\"day1\"]\n", "day1").unwrap(),
+            "[workspace]\nmembers = [\"day1\"]\n");
+        assert_eq!(add_member("[workspace]\n", "day1"), None);
+    }
+
+    #[test]
+    fn test_register_in_workspace() {
+        let root = temp_dir("workspace");
+        fs::create_dir_all(root.join("2030/day1")).unwrap();
+        assert_eq!(register_in_workspace(&root, &root.join("2030/day1")), Ok(None));
+        fs::write(root.join("2030/Cargo.toml"), "[workspace]\nmembers = []\n").unwrap();
+        assert_eq!(register_in_workspace(&root, &root.join("2030/day1")), Ok(Some(root.join("2030/Cargo.toml"))));
+        assert_eq!(fs::read_to_string(root.join("2030/Cargo.toml")).unwrap(), "[workspace]\nmembers = [\"day1\"]\n");
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
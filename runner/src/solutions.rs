@@ -0,0 +1,308 @@
+use std::fs;
+use std::panic;
+use std::time::Instant;
+
+use solution::Answers;
+
+use crate::{Day, Run};
+
+type Solver = fn(&str) -> Result<(Answers, Vec<(String, f64)>), String>;
+
+// Days solved in process by check, record and bench, instead of building and running the day's
+// binary. Every day's library implements solution::Solution, but new days need adding here and to
+// the runner's dependencies.
+pub fn solver(day: Day) -> Option<Solver> {
+    Some(match (day.year, day.day) {
+        (2015, 1) => solution::solve_timed::<day1_2015::Day>,
+        (2015, 2) => solution::solve_timed::<day2_2015::Day>,
+        (2015, 3) => solution::solve_timed::<day3_2015::Day>,
+        (2015, 4) => solution::solve_timed::<day4_2015::Day>,
+        (2015, 5) => solution::solve_timed::<day5_2015::Day>,
+        (2015, 6) => solution::solve_timed::<day6_2015::Day>,
+        (2015, 7) => solution::solve_timed::<day7_2015::Day>,
+        (2015, 8) => solution::solve_timed::<day8_2015::Day>,
+        (2015, 9) => solution::solve_timed::<day9_2015::Day>,
+        (2015, 10) => solution::solve_timed::<day10_2015::Day>,
+        (2015, 11) => solution::solve_timed::<day11_2015::Day>,
+        (2015, 12) => solution::solve_timed::<day12_2015::Day>,
+        (2015, 13) => solution::solve_timed::<day13_2015::Day>,
+        (2015, 14) => solution::solve_timed::<day14_2015::Day>,
+        (2015, 15) => solution::solve_timed::<day15_2015::Day>,
+        (2015, 16) => solution::solve_timed::<day16_2015::Day>,
+        (2015, 17) => solution::solve_timed::<day17_2015::Day>,
+        (2015, 18) => solution::solve_timed::<day18_2015::Day>,
+        (2015, 19) => solution::solve_timed::<day19_2015::Day>,
+        (2015, 20) => solution::solve_timed::<day20_2015::Day>,
+        (2015, 21) => solution::solve_timed::<day21_2015::Day>,
+        (2015, 22) => solution::solve_timed::<day22_2015::Day>,
+        (2015, 23) => solution::solve_timed::<day23_2015::Day>,
+        (2015, 24) => solution::solve_timed::<day24_2015::Day>,
+        (2015, 25) => solution::solve_timed::<day25_2015::Day>,
+        (2016, 1) => solution::solve_timed::<day1_2016::Day>,
+        (2016, 2) => solution::solve_timed::<day2_2016::Day>,
+        (2016, 4) => solution::solve_timed::<day4_2016::Day>,
+        (2016, 5) => solution::solve_timed::<day5_2016::Day>,
+        (2016, 6) => solution::solve_timed::<day6_2016::Day>,
+        (2016, 7) => solution::solve_timed::<day7_2016::Day>,
+        (2016, 8) => solution::solve_timed::<day8_2016::Day>,
+        (2016, 9) => solution::solve_timed::<day9_2016::Day>,
+        (2016, 10) => solution::solve_timed::<day10_2016::Day>,
+        (2016, 11) => solution::solve_timed::<day11_2016::Day>,
+        (2016, 12) => solution::solve_timed::<day12_2016::Day>,
+        (2016, 13) => solution::solve_timed::<day13_2016::Day>,
+        (2016, 14) => solution::solve_timed::<day14_2016::Day>,
+        (2016, 15) => solution::solve_timed::<day15_2016::Day>,
+        (2016, 16) => solution::solve_timed::<day16_2016::Day>,
+        (2016, 17) => solution::solve_timed::<day17_2016::Day>,
+        (2016, 18) => solution::solve_timed::<day18_2016::Day>,
+        (2016, 20) => solution::solve_timed::<day20_2016::Day>,
+        (2016, 21) => solution::solve_timed::<day21_2016::Day>,
+        (2016, 22) => solution::solve_timed::<day22_2016::Day>,
+        (2016, 23) => solution::solve_timed::<day23_2016::Day>,
+        (2016, 24) => solution::solve_timed::<day24_2016::Day>,
+        (2016, 25) => solution::solve_timed::<day25_2016::Day>,
+        (2017, 1) => solution::solve_timed::<day1_2017::Day>,
+        (2017, 2) => solution::solve_timed::<day2_2017::Day>,
+        (2017, 3) => solution::solve_timed::<day3_2017::Day>,
+        (2017, 4) => solution::solve_timed::<day4_2017::Day>,
+        (2017, 5) => solution::solve_timed::<day5_2017::Day>,
+        (2017, 6) => solution::solve_timed::<day6_2017::Day>,
+        (2017, 7) => solution::solve_timed::<day7_2017::Day>,
+        (2017, 8) => solution::solve_timed::<day8_2017::Day>,
+        (2017, 9) => solution::solve_timed::<day9_2017::Day>,
+        (2017, 10) => solution::solve_timed::<day10_2017::Day>,
+        (2017, 11) => solution::solve_timed::<day11_2017::Day>,
+        (2017, 12) => solution::solve_timed::<day12_2017::Day>,
+        (2017, 13) => solution::solve_timed::<day13_2017::Day>,
+        (2017, 14) => solution::solve_timed::<day14_2017::Day>,
+        (2017, 15) => solution::solve_timed::<day15_2017::Day>,
+        (2017, 16) => solution::solve_timed::<day16_2017::Day>,
+        (2017, 17) => solution::solve_timed::<day17_2017::Day>,
+        (2017, 18) => solution::solve_timed::<day18_2017::Day>,
+        (2017, 19) => solution::solve_timed::<day19_2017::Day>,
+        (2017, 20) => solution::solve_timed::<day20_2017::Day>,
+        (2017, 21) => solution::solve_timed::<day21_2017::Day>,
+        (2017, 22) => solution::solve_timed::<day22_2017::Day>,
+        (2017, 23) => solution::solve_timed::<day23_2017::Day>,
+        (2017, 24) => solution::solve_timed::<day24_2017::Day>,
+        (2017, 25) => solution::solve_timed::<day25_2017::Day>,
+        (2018, 1) => solution::solve_timed::<day1_2018::Day>,
+        (2018, 2) => solution::solve_timed::<day2_2018::Day>,
+        (2018, 3) => solution::solve_timed::<day3_2018::Day>,
+        (2018, 4) => solution::solve_timed::<day4_2018::Day>,
+        (2018, 5) => solution::solve_timed::<day5_2018::Day>,
+        (2018, 6) => solution::solve_timed::<day6_2018::Day>,
+        (2018, 7) => solution::solve_timed::<day7_2018::Day>,
+        (2018, 8) => solution::solve_timed::<day8_2018::Day>,
+        (2018, 10) => solution::solve_timed::<day10_2018::Day>,
+        (2018, 11) => solution::solve_timed::<day11_2018::Day>,
+        (2018, 12) => solution::solve_timed::<day12_2018::Day>,
+        (2018, 13) => solution::solve_timed::<day13_2018::Day>,
+        (2018, 14) => solution::solve_timed::<day14_2018::Day>,
+        (2018, 15) => solution::solve_timed::<day15_2018::Day>,
+        (2018, 16) => solution::solve_timed::<day16_2018::Day>,
+        (2018, 17) => solution::solve_timed::<day17_2018::Day>,
+        (2018, 18) => solution::solve_timed::<day18_2018::Day>,
+        (2018, 19) => solution::solve_timed::<day19_2018::Day>,
+        (2018, 20) => solution::solve_timed::<day20_2018::Day>,
+        (2018, 21) => solution::solve_timed::<day21_2018::Day>,
+        (2018, 22) => solution::solve_timed::<day22_2018::Day>,
+        (2018, 23) => solution::solve_timed::<day23_2018::Day>,
+        (2018, 24) => solution::solve_timed::<day24_2018::Day>,
+        (2018, 25) => solution::solve_timed::<day25_2018::Day>,
+        (2019, 1) => solution::solve_timed::<day1_2019::Day>,
+        (2019, 2) => solution::solve_timed::<day2_2019::Day>,
+        (2019, 3) => solution::solve_timed::<day3_2019::Day>,
+        (2019, 4) => solution::solve_timed::<day4_2019::Day>,
+        (2019, 5) => solution::solve_timed::<day5_2019::Day>,
+        (2019, 6) => solution::solve_timed::<day6_2019::Day>,
+        (2019, 7) => solution::solve_timed::<day7_2019::Day>,
+        (2019, 8) => solution::solve_timed::<day8_2019::Day>,
+        (2019, 9) => solution::solve_timed::<day9_2019::Day>,
+        (2019, 10) => solution::solve_timed::<day10_2019::Day>,
+        (2019, 11) => solution::solve_timed::<day11_2019::Day>,
+        (2019, 12) => solution::solve_timed::<day12_2019::Day>,
+        (2019, 13) => solution::solve_timed::<day13_2019::Day>,
+        (2019, 14) => solution::solve_timed::<day14_2019::Day>,
+        (2019, 15) => solution::solve_timed::<day15_2019::Day>,
+        (2019, 16) => solution::solve_timed::<day16_2019::Day>,
+        (2019, 17) => solution::solve_timed::<day17_2019::Day>,
+        (2019, 18) => solution::solve_timed::<day18_2019::Day>,
+        (2019, 19) => solution::solve_timed::<day19_2019::Day>,
+        (2019, 20) => solution::solve_timed::<day20_2019::Day>,
+        (2019, 21) => solution::solve_timed::<day21_2019::Day>,
+        (2019, 22) => solution::solve_timed::<day22_2019::Day>,
+        (2019, 23) => solution::solve_timed::<day23_2019::Day>,
+        (2019, 24) => solution::solve_timed::<day24_2019::Day>,
+        (2019, 25) => solution::solve_timed::<day25_2019::Day>,
+        (2020, 1) => solution::solve_timed::<day1_2020::Day>,
+        (2020, 2) => solution::solve_timed::<day2_2020::Day>,
+        (2020, 3) => solution::solve_timed::<day3_2020::Day>,
+        (2020, 4) => solution::solve_timed::<day4_2020::Day>,
+        (2020, 5) => solution::solve_timed::<day5_2020::Day>,
+        (2020, 6) => solution::solve_timed::<day6_2020::Day>,
+        (2020, 7) => solution::solve_timed::<day7_2020::Day>,
+        (2020, 8) => solution::solve_timed::<day8_2020::Day>,
+        (2020, 9) => solution::solve_timed::<day9_2020::Day>,
+        (2020, 10) => solution::solve_timed::<day10_2020::Day>,
+        (2020, 11) => solution::solve_timed::<day11_2020::Day>,
+        (2020, 12) => solution::solve_timed::<day12_2020::Day>,
+        (2020, 13) => solution::solve_timed::<day13_2020::Day>,
+        (2020, 14) => solution::solve_timed::<day14_2020::Day>,
+        (2020, 15) => solution::solve_timed::<day15_2020::Day>,
+        (2020, 16) => solution::solve_timed::<day16_2020::Day>,
+        (2020, 17) => solution::solve_timed::<day17_2020::Day>,
+        (2020, 18) => solution::solve_timed::<day18_2020::Day>,
+        (2020, 19) => solution::solve_timed::<day19_2020::Day>,
+        (2020, 20) => solution::solve_timed::<day20_2020::Day>,
+        (2020, 21) => solution::solve_timed::<day21_2020::Day>,
+        (2020, 22) => solution::solve_timed::<day22_2020::Day>,
+        (2020, 23) => solution::solve_timed::<day23_2020::Day>,
+        (2020, 24) => solution::solve_timed::<day24_2020::Day>,
+        (2020, 25) => solution::solve_timed::<day25_2020::Day>,
+        (2021, 1) => solution::solve_timed::<day1_2021::Day>,
+        (2021, 2) => solution::solve_timed::<day2_2021::Day>,
+        (2021, 3) => solution::solve_timed::<day3_2021::Day>,
+        (2021, 4) => solution::solve_timed::<day4_2021::Day>,
+        (2021, 5) => solution::solve_timed::<day5_2021::Day>,
+        (2021, 6) => solution::solve_timed::<day6_2021::Day>,
+        (2021, 7) => solution::solve_timed::<day7_2021::Day>,
+        (2021, 8) => solution::solve_timed::<day8_2021::Day>,
+        (2021, 9) => solution::solve_timed::<day9_2021::Day>,
+        (2021, 10) => solution::solve_timed::<day10_2021::Day>,
+        (2021, 11) => solution::solve_timed::<day11_2021::Day>,
+        (2021, 12) => solution::solve_timed::<day12_2021::Day>,
+        (2021, 13) => solution::solve_timed::<day13_2021::Day>,
+        (2021, 14) => solution::solve_timed::<day14_2021::Day>,
+        (2021, 15) => solution::solve_timed::<day15_2021::Day>,
+        (2021, 16) => solution::solve_timed::<day16_2021::Day>,
+        (2021, 17) => solution::solve_timed::<day17_2021::Day>,
+        (2021, 18) => solution::solve_timed::<day18_2021::Day>,
+        (2021, 19) => solution::solve_timed::<day19_2021::Day>,
+        (2021, 20) => solution::solve_timed::<day20_2021::Day>,
+        (2021, 21) => solution::solve_timed::<day21_2021::Day>,
+        (2021, 22) => solution::solve_timed::<day22_2021::Day>,
+        (2021, 23) => solution::solve_timed::<day23_2021::Day>,
+        (2021, 24) => solution::solve_timed::<day24_2021::Day>,
+        (2021, 25) => solution::solve_timed::<day25_2021::Day>,
+        (2022, 1) => solution::solve_timed::<day1_2022::Day>,
+        (2022, 2) => solution::solve_timed::<day2_2022::Day>,
+        (2022, 3) => solution::solve_timed::<day3_2022::Day>,
+        (2022, 4) => solution::solve_timed::<day4_2022::Day>,
+        (2022, 5) => solution::solve_timed::<day5_2022::Day>,
+        (2022, 6) => solution::solve_timed::<day6_2022::Day>,
+        (2022, 7) => solution::solve_timed::<day7_2022::Day>,
+        (2022, 8) => solution::solve_timed::<day8_2022::Day>,
+        (2022, 9) => solution::solve_timed::<day9_2022::Day>,
+        (2022, 10) => solution::solve_timed::<day10_2022::Day>,
+        (2022, 11) => solution::solve_timed::<day11_2022::Day>,
+        (2022, 12) => solution::solve_timed::<day12_2022::Day>,
+        (2022, 13) => solution::solve_timed::<day13_2022::Day>,
+        (2022, 14) => solution::solve_timed::<day14_2022::Day>,
+        (2022, 15) => solution::solve_timed::<day15_2022::Day>,
+        (2022, 16) => solution::solve_timed::<day16_2022::Day>,
+        (2022, 17) => solution::solve_timed::<day17_2022::Day>,
+        (2022, 18) => solution::solve_timed::<day18_2022::Day>,
+        (2022, 19) => solution::solve_timed::<day19_2022::Day>,
+        (2022, 20) => solution::solve_timed::<day20_2022::Day>,
+        (2022, 21) => solution::solve_timed::<day21_2022::Day>,
+        (2022, 22) => solution::solve_timed::<day22_2022::Day>,
+        (2022, 23) => solution::solve_timed::<day23_2022::Day>,
+        (2022, 24) => solution::solve_timed::<day24_2022::Day>,
+        (2022, 25) => solution::solve_timed::<day25_2022::Day>,
+        (2023, 1) => solution::solve_timed::<day1_2023::Day>,
+        (2023, 2) => solution::solve_timed::<day2_2023::Day>,
+        (2023, 3) => solution::solve_timed::<day3_2023::Day>,
+        (2023, 4) => solution::solve_timed::<day4_2023::Day>,
+        (2023, 5) => solution::solve_timed::<day5_2023::Day>,
+        (2023, 6) => solution::solve_timed::<day6_2023::Day>,
+        (2023, 7) => solution::solve_timed::<day7_2023::Day>,
+        (2023, 8) => solution::solve_timed::<day8_2023::Day>,
+        (2023, 9) => solution::solve_timed::<day9_2023::Day>,
+        (2023, 10) => solution::solve_timed::<day10_2023::Day>,
+        (2023, 11) => solution::solve_timed::<day11_2023::Day>,
+        (2023, 12) => solution::solve_timed::<day12_2023::Day>,
+        (2023, 13) => solution::solve_timed::<day13_2023::Day>,
+        (2023, 14) => solution::solve_timed::<day14_2023::Day>,
+        (2023, 15) => solution::solve_timed::<day15_2023::Day>,
+        (2023, 16) => solution::solve_timed::<day16_2023::Day>,
+        (2023, 17) => solution::solve_timed::<day17_2023::Day>,
+        (2023, 18) => solution::solve_timed::<day18_2023::Day>,
+        (2023, 19) => solution::solve_timed::<day19_2023::Day>,
+        (2023, 20) => solution::solve_timed::<day20_2023::Day>,
+        (2023, 21) => solution::solve_timed::<day21_2023::Day>,
+        (2023, 22) => solution::solve_timed::<day22_2023::Day>,
+        (2023, 23) => solution::solve_timed::<day23_2023::Day>,
+        (2023, 24) => solution::solve_timed::<day24_2023::Day>,
+        (2023, 25) => solution::solve_timed::<day25_2023::Day>,
+        (2024, 1) => solution::solve_timed::<day1_2024::Day>,
+        (2024, 2) => solution::solve_timed::<day2_2024::Day>,
+        (2024, 3) => solution::solve_timed::<day3_2024::Day>,
+        (2024, 4) => solution::solve_timed::<day4_2024::Day>,
+        (2024, 5) => solution::solve_timed::<day5_2024::Day>,
+        (2024, 6) => solution::solve_timed::<day6_2024::Day>,
+        (2024, 7) => solution::solve_timed::<day7_2024::Day>,
+        (2024, 8) => solution::solve_timed::<day8_2024::Day>,
+        (2024, 9) => solution::solve_timed::<day9_2024::Day>,
+        (2024, 10) => solution::solve_timed::<day10_2024::Day>,
+        (2024, 11) => solution::solve_timed::<day11_2024::Day>,
+        (2024, 12) => solution::solve_timed::<day12_2024::Day>,
+        (2024, 13) => solution::solve_timed::<day13_2024::Day>,
+        (2024, 14) => solution::solve_timed::<day14_2024::Day>,
+        (2024, 15) => solution::solve_timed::<day15_2024::Day>,
+        (2024, 16) => solution::solve_timed::<day16_2024::Day>,
+        (2024, 17) => solution::solve_timed::<day17_2024::Day>,
+        (2024, 18) => solution::solve_timed::<day18_2024::Day>,
+        (2024, 19) => solution::solve_timed::<day19_2024::Day>,
+        (2024, 20) => solution::solve_timed::<day20_2024::Day>,
+        (2024, 21) => solution::solve_timed::<day21_2024::Day>,
+        (2024, 22) => solution::solve_timed::<day22_2024::Day>,
+        (2024, 23) => solution::solve_timed::<day23_2024::Day>,
+        (2024, 24) => solution::solve_timed::<day24_2024::Day>,
+        (2024, 25) => solution::solve_timed::<day25_2024::Day>,
+        (2025, 1) => solution::solve_timed::<day1_2025::Day>,
+        (2025, 2) => solution::solve_timed::<day2_2025::Day>,
+        (2025, 3) => solution::solve_timed::<day3_2025::Day>,
+        (2025, 4) => solution::solve_timed::<day4_2025::Day>,
+        (2025, 5) => solution::solve_timed::<day5_2025::Day>,
+        (2025, 6) => solution::solve_timed::<day6_2025::Day>,
+        (2025, 7) => solution::solve_timed::<day7_2025::Day>,
+        (2025, 8) => solution::solve_timed::<day8_2025::Day>,
+        (2025, 9) => solution::solve_timed::<day9_2025::Day>,
+        (2025, 10) => solution::solve_timed::<day10_2025::Day>,
+        (2025, 11) => solution::solve_timed::<day11_2025::Day>,
+        (2025, 12) => solution::solve_timed::<day12_2025::Day>,
+        _ => return None
+    })
+}
+
+// There's no timeout in process, a day that never finishes hangs the runner
+pub fn solve(day: Day, solver: Solver, input: &str) -> Result<Run, String> {
+    let puzzle_input = fs::read_to_string(day.dir().join(input))
+        .map_err(|e| format!("Failed to open {}: {}", input, e))?;
+    let start = Instant::now();
+    let (answers, phases) = panic::catch_unwind(|| solver(&puzzle_input))
+        .map_err(|_| "Panicked".to_string())??;
+    let elapsed = start.elapsed();
+    // Split the answers the way the binary prints them, so an answer ending in a newline keeps the
+    // blank line it prints
+    let output = std::iter::once(answers.part1).chain(answers.part2)
+        .flat_map(|answer| format!("{}\n", answer).lines().map(|line| line.to_string()).collect::<Vec<_>>())
+        .collect();
+    Ok(Run { output, elapsed, phases })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solver() {
+        assert!(solver(Day { year: 2024, day: 1 }).is_some());
+        assert!(solver(Day { year: 2018, day: 9 }).is_none());
+        let (answers, phases) = solver(Day { year: 2024, day: 1 }).unwrap()("3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n").unwrap();
+        assert_eq!(answers.part1, "11");
+        assert_eq!(answers.part2, Some("31".to_string()));
+        assert_eq!(phases.len(), 3);
+    }
+}
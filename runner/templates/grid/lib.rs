@@ -0,0 +1,84 @@
+use solution::Solution;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Cell {
+    Empty,
+    Wall,
+}
+
+pub struct Grid {
+    width: usize,
+    height: usize,
+    cells: Vec<Cell>,
+}
+
+impl Grid {
+    fn idx(&self, x: usize, y: usize) -> usize {
+        debug_assert!(x < self.width && y < self.height);
+        y * self.width + x
+    }
+
+    fn at(&self, x: usize, y: usize) -> Cell {
+        self.cells[self.idx(x, y)]
+    }
+}
+
+pub fn parse(puzzle_input: &str) -> Grid {
+    let height = puzzle_input.lines().count();
+    let width = puzzle_input.lines().next().unwrap().chars().count();
+    let mut cells = Vec::with_capacity(width * height);
+    for line in puzzle_input.lines() {
+        assert_eq!(width, line.chars().count(), "input wasn't rectangular");
+        for ch in line.chars() {
+            cells.push(match ch {
+                '.' => Cell::Empty,
+                '#' => Cell::Wall,
+                _ => panic!("unexpected cell {:?}", ch)
+            });
+        }
+    }
+    Grid { width, height, cells }
+}
+
+pub fn part1(grid: &Grid) -> &'static str {
+    "FIXME"
+}
+
+pub fn part2(grid: &Grid) -> &'static str {
+    "FIXME"
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Grid;
+    type Answer1 = &'static str;
+    type Answer2 = &'static str;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        Ok(parse(puzzle_input))
+    }
+
+    fn part1(input: &Self::Input) -> &'static str {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> &'static str {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        // FIXME
+    }
+
+    #[test]
+    fn test_part2() {
+        // FIXME
+    }
+}
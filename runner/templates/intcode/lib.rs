@@ -0,0 +1,55 @@
+use std::sync::mpsc::channel;
+
+use intcode::*;
+use solution::Solution;
+
+fn run_with_input(mem_str: &str, input: i64) -> Vec<i64> {
+    let (tx_in, rx_in) = channel();
+    let (tx_out, rx_out) = channel();
+    tx_in.send(input).unwrap();
+    run(&mut parse(mem_str), &rx_in, tx_out);
+    rx_out.iter().collect()
+}
+
+pub fn part1(puzzle_input: &str) -> i64 {
+    *run_with_input(puzzle_input, 1).last().unwrap()
+}
+
+pub fn part2(puzzle_input: &str) -> &'static str {
+    "FIXME"
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = String;
+    type Answer1 = i64;
+    type Answer2 = &'static str;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        Ok(puzzle_input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> &'static str {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        // FIXME
+    }
+
+    #[test]
+    fn test_part2() {
+        // FIXME
+    }
+}
@@ -0,0 +1,44 @@
+use solution::Solution;
+
+pub fn part1(puzzle_input: &str) -> &'static str {
+    "FIXME"
+}
+
+pub fn part2(puzzle_input: &str) -> &'static str {
+    "FIXME"
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = String;
+    type Answer1 = &'static str;
+    type Answer2 = &'static str;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String> {
+        Ok(puzzle_input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> &'static str {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> &'static str {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        // FIXME
+    }
+
+    #[test]
+    fn test_part2() {
+        // FIXME
+    }
+}
@@ -0,0 +1,23 @@
+use runner::{check, filter_days, load_answers};
+
+// Rebuilds and runs every day, which takes a while, so run it explicitly with
+//   cargo test --release -- --ignored
+// Set AOC_FILTER to a space separated list like "2016 2019/day5" to only check some days.
+#[test]
+#[ignore]
+fn recorded_answers() {
+    let filters: Vec<String> = std::env::var("AOC_FILTER")
+        .map(|s| s.split_whitespace().map(|s| s.to_string()).collect())
+        .unwrap_or_default();
+    let answers = load_answers().unwrap();
+    let mut failures = Vec::new();
+    for day in filter_days(&filters) {
+        if let Some(problem) = check(day, answers.get(&day)) {
+            eprintln!("{}: {}", day, problem);
+            if problem.is_failure() {
+                failures.push(day);
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{} days failed: {:?}", failures.len(), failures);
+}
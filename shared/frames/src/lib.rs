@@ -0,0 +1,244 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use params::Params;
+
+// Snapshots of a simulation as character grids, either redrawn in the terminal or saved as numbered
+// files. Days get an Animation from their params, so it's off unless asked for on the command line:
+//   cargo run --release animate=20 < input                   redraw on stderr at 20 frames a second
+//   cargo run --release frames=out < input                   out/000000.pgm, out/000001.pgm, ...
+//   cargo run --release frames=out frame_format=txt < input  the same as text
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    cells: Vec<char>,
+}
+
+impl Frame {
+    pub fn new(width: usize, height: usize, fill: char) -> Frame {
+        Frame { width, height, cells: vec![fill; width * height] }
+    }
+
+    // Shorter lines are padded with spaces
+    pub fn from_text(text: &str) -> Frame {
+        let lines: Vec<&str> = text.lines().collect();
+        let width = lines.iter().map(|line| line.chars().count()).max().unwrap_or(0);
+        let mut frame = Frame::new(width, lines.len(), ' ');
+        for (y, line) in lines.iter().enumerate() {
+            for (x, ch) in line.chars().enumerate() {
+                frame.set(x, y, ch);
+            }
+        }
+        frame
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> char {
+        self.cells[y * self.width + x]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, ch: char) {
+        self.cells[y * self.width + x] = ch;
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut ret = String::with_capacity((self.width + 1) * self.height);
+        for row in self.cells.chunks(self.width.max(1)) {
+            ret.extend(row);
+            ret.push('\n');
+        }
+        ret
+    }
+
+    // A binary greyscale image with each cell as a square of pixels
+    pub fn to_pgm(&self) -> Vec<u8> {
+        let mut ret = format!("P5\n{} {}\n255\n", self.width * PGM_SCALE, self.height * PGM_SCALE).into_bytes();
+        for row in self.cells.chunks(self.width.max(1)) {
+            let pixels: Vec<u8> = row.iter().flat_map(|&ch| [shade(ch); PGM_SCALE]).collect();
+            for _ in 0..PGM_SCALE {
+                ret.extend_from_slice(&pixels);
+            }
+        }
+        ret
+    }
+}
+
+const PGM_SCALE: usize = 4;
+
+// Blanks are the background, walls are brightest, and anything else (units, counts) in between
+fn shade(ch: char) -> u8 {
+    match ch {
+        ' ' => 0,
+        '.' => 40,
+        '#' => 255,
+        _ => 160,
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Pgm,
+}
+
+impl Format {
+    fn extension(self) -> &'static str {
+        match self {
+            Format::Text => "txt",
+            Format::Pgm => "pgm",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Output {
+    Off,
+    Terminal { fps: f64 },
+    Files { dir: PathBuf, format: Format },
+}
+
+pub struct Animation {
+    output: Output,
+    frames: usize,
+    next_due: Option<Instant>,
+}
+
+impl Animation {
+    pub fn new(output: Output) -> Animation {
+        Animation { output, frames: 0, next_due: None }
+    }
+
+    pub fn off() -> Animation {
+        Animation::new(Output::Off)
+    }
+
+    pub fn try_from_params(params: &Params) -> Result<Animation, String> {
+        let fps: f64 = params.try_get("animate", 0.0)?;
+        let dir: String = params.try_get("frames", String::new())?;
+        let format = match params.try_get("frame_format", "pgm".to_string())?.as_str() {
+            "pgm" => Format::Pgm,
+            "txt" => Format::Text,
+            other => return Err(format!("unknown frame_format {:?}, expected pgm or txt", other)),
+        };
+        let output = match (fps > 0.0, dir.is_empty()) {
+            (true, false) => return Err("animate and frames can't be used together".to_string()),
+            (true, true) => Output::Terminal { fps },
+            (false, false) => Output::Files { dir: PathBuf::from(dir), format },
+            (false, true) => Output::Off,
+        };
+        Ok(Animation::new(output))
+    }
+
+    // Exits with the error if the animation params are invalid
+    pub fn from_params(params: &Params) -> Animation {
+        Animation::try_from_params(params).unwrap_or_else(|e| {
+            eprintln!("error: {}", e);
+            std::process::exit(2);
+        })
+    }
+
+    pub fn is_on(&self) -> bool {
+        self.output != Output::Off
+    }
+
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    // The frame is only built when it's going to be shown, so days can call this unconditionally
+    pub fn frame(&mut self, build: impl FnOnce() -> Frame) {
+        match &self.output {
+            Output::Off => return,
+            Output::Terminal { fps } => {
+                let interval = Duration::from_secs_f64(1.0 / fps);
+                let now = Instant::now();
+                let due = self.next_due.unwrap_or(now);
+                if due > now {
+                    thread::sleep(due - now);
+                }
+                self.next_due = Some(due.max(now) + interval);
+                let mut text = String::new();
+                if self.frames == 0 {
+                    text.push_str("\x1b[2J");
+                }
+                // Home the cursor, then clear the rest of each line and anything below the frame
+                text.push_str("\x1b[H");
+                text.push_str(&build().to_text().replace('\n', "\x1b[K\n"));
+                text.push_str("\x1b[J");
+                let _ = io::stderr().write_all(text.as_bytes());
+            },
+            Output::Files { dir, format } => {
+                let frame = build();
+                let contents = match format {
+                    Format::Text => frame.to_text().into_bytes(),
+                    Format::Pgm => frame.to_pgm(),
+                };
+                let path = dir.join(format!("{:06}.{}", self.frames, format.extension()));
+                fs::create_dir_all(dir)
+                    .and_then(|_| fs::write(&path, contents))
+                    .unwrap_or_else(|e| panic!("Couldn't write {}: {}", path.display(), e));
+            },
+        }
+        self.frames += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(s: &str) -> Params {
+        Params::parse(s.split_whitespace().map(|s| s.to_string())).unwrap()
+    }
+
+    #[test]
+    fn test_frame() {
+        let mut frame = Frame::from_text("#.#\n#\n");
+        assert_eq!((frame.width(), frame.height()), (3, 2));
+        assert_eq!(frame.get(2, 1), ' ');
+        frame.set(1, 1, 'E');
+        assert_eq!(frame.to_text(), "#.#\n#E \n");
+        let pgm = frame.to_pgm();
+        let header = b"P5\n12 8\n255\n";
+        assert_eq!(&pgm[..header.len()], header);
+        assert_eq!(pgm.len(), header.len() + 12 * 8);
+        assert_eq!(pgm[header.len() + 4], shade('.'));
+        assert_eq!(pgm[header.len() + 4 * 12 + 4], shade('E'));
+    }
+
+    #[test]
+    fn test_from_params() {
+        assert!(!Animation::from_params(&params("")).is_on());
+        assert_eq!(Animation::from_params(&params("animate=10")).output, Output::Terminal { fps: 10.0 });
+        assert_eq!(Animation::from_params(&params("frames=out frame_format=txt")).output,
+            Output::Files { dir: PathBuf::from("out"), format: Format::Text });
+        assert!(Animation::try_from_params(&params("frames=out animate=5")).is_err());
+        assert!(Animation::try_from_params(&params("frames=out frame_format=gif")).is_err());
+    }
+
+    #[test]
+    fn test_files() {
+        let dir = std::env::temp_dir().join(format!("frames-test-{}", std::process::id()));
+        let mut animation = Animation::new(Output::Files { dir: dir.clone(), format: Format::Text });
+        animation.frame(|| Frame::from_text("ab"));
+        animation.frame(|| Frame::from_text("cd"));
+        assert_eq!(animation.frames(), 2);
+        assert_eq!(fs::read_to_string(dir.join("000001.txt")).unwrap(), "cd\n");
+        fs::remove_dir_all(&dir).unwrap();
+
+        let mut off = Animation::off();
+        off.frame(|| panic!("frames shouldn't be built when off"));
+        assert_eq!(off.frames(), 0);
+    }
+}
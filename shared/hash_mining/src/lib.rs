@@ -0,0 +1,262 @@
+use std::collections::VecDeque;
+
+// MD5 and the brute force searches the puzzles build on it. Digests stay as bytes, so checking for
+// leading zeros or reading a hex digit doesn't need any formatting or allocation.
+pub type Digest = [u8; 16];
+
+const SHIFTS: [u32; 64] = [
+    7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22,
+    5, 9, 14, 20, 5, 9, 14, 20, 5, 9, 14, 20, 5, 9, 14, 20,
+    4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23,
+    6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21,
+];
+
+const K: [u32; 64] = [
+    0xd76aa478, 0xe8c7b756, 0x242070db, 0xc1bdceee, 0xf57c0faf, 0x4787c62a, 0xa8304613, 0xfd469501,
+    0x698098d8, 0x8b44f7af, 0xffff5bb1, 0x895cd7be, 0x6b901122, 0xfd987193, 0xa679438e, 0x49b40821,
+    0xf61e2562, 0xc040b340, 0x265e5a51, 0xe9b6c7aa, 0xd62f105d, 0x02441453, 0xd8a1e681, 0xe7d3fbc8,
+    0x21e1cde6, 0xc33707d6, 0xf4d50d87, 0x455a14ed, 0xa9e3e905, 0xfcefa3f8, 0x676f02d9, 0x8d2a4c8a,
+    0xfffa3942, 0x8771f681, 0x6d9d6122, 0xfde5380c, 0xa4beea44, 0x4bdecfa9, 0xf6bb4b60, 0xbebfbc70,
+    0x289b7ec6, 0xeaa127fa, 0xd4ef3085, 0x04881d05, 0xd9d4d039, 0xe6db99e5, 0x1fa27cf8, 0xc4ac5665,
+    0xf4292244, 0x432aff97, 0xab9423a7, 0xfc93a039, 0x655b59c3, 0x8f0ccc92, 0xffeff47d, 0x85845dd1,
+    0x6fa87e4f, 0xfe2ce6e0, 0xa3014314, 0x4e0811a1, 0xf7537e82, 0xbd3af235, 0x2ad7d2bb, 0xeb86d391,
+];
+
+// Each round is its own loop over constant ranges so the compiler can unroll them
+fn compress(state: &mut [u32; 4], block: &[u8]) {
+    let mut m = [0u32; 16];
+    for (word, bytes) in m.iter_mut().zip(block.chunks_exact(4)) {
+        *word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    }
+    let [mut a, mut b, mut c, mut d] = *state;
+    macro_rules! round {
+        ($range:expr, |$i:ident| $f:expr, $g:expr) => {
+            for $i in $range {
+                let f = $f.wrapping_add(a).wrapping_add(K[$i]).wrapping_add(m[$g % 16]);
+                a = d;
+                d = c;
+                c = b;
+                b = b.wrapping_add(f.rotate_left(SHIFTS[$i]));
+            }
+        };
+    }
+    round!(0..16, |i| (b & c) | (!b & d), i);
+    round!(16..32, |i| (d & b) | (!d & c), 5 * i + 1);
+    round!(32..48, |i| b ^ c ^ d, 3 * i + 5);
+    round!(48..64, |i| c ^ (b | !d), 7 * i);
+    for (s, x) in state.iter_mut().zip([a, b, c, d]) {
+        *s = s.wrapping_add(x);
+    }
+}
+
+const INITIAL_STATE: [u32; 4] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476];
+
+fn to_digest(state: [u32; 4]) -> Digest {
+    let mut digest = [0; 16];
+    for (bytes, word) in digest.chunks_exact_mut(4).zip(state) {
+        bytes.copy_from_slice(&word.to_le_bytes());
+    }
+    digest
+}
+
+pub fn md5(data: &[u8]) -> Digest {
+    let mut state = INITIAL_STATE;
+    let mut chunks = data.chunks_exact(64);
+    for block in chunks.by_ref() {
+        compress(&mut state, block);
+    }
+    // Pad with a 1 bit, zeros, then the length in bits, which may spill into a second block
+    let rest = chunks.remainder();
+    let mut tail = [0u8; 128];
+    tail[..rest.len()].copy_from_slice(rest);
+    tail[rest.len()] = 0x80;
+    let tail_len = if rest.len() < 56 { 64 } else { 128 };
+    tail[tail_len - 8..tail_len].copy_from_slice(&((data.len() as u64) * 8).to_le_bytes());
+    for block in tail[..tail_len].chunks_exact(64) {
+        compress(&mut state, block);
+    }
+    to_digest(state)
+}
+
+// The i'th hex digit
+pub fn nibble(digest: &Digest, i: usize) -> u8 {
+    if i.is_multiple_of(2) { digest[i / 2] >> 4 } else { digest[i / 2] & 0xf }
+}
+
+pub fn leading_zeros(digest: &Digest, nibbles: usize) -> bool {
+    (0..nibbles).all(|i| nibble(digest, i) == 0)
+}
+
+pub fn to_hex(digest: &Digest) -> [u8; 32] {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+    let mut hex = [0; 32];
+    for (pair, byte) in hex.chunks_exact_mut(2).zip(digest) {
+        pair[0] = HEX[(byte >> 4) as usize];
+        pair[1] = HEX[(byte & 0xf) as usize];
+    }
+    hex
+}
+
+pub fn to_hex_string(digest: &Digest) -> String {
+    String::from_utf8(to_hex(digest).to_vec()).unwrap()
+}
+
+// Rehashes the lowercase hex of the digest the given number of extra times
+pub fn stretched_md5(data: &[u8], rounds: usize) -> Digest {
+    let mut digest = md5(data);
+    // The hex is always 32 bytes, so every rehash is one block with the same padding
+    let mut block = [0u8; 64];
+    block[32] = 0x80;
+    block[56..].copy_from_slice(&(32u64 * 8).to_le_bytes());
+    for _ in 0..rounds {
+        block[..32].copy_from_slice(&to_hex(&digest));
+        let mut state = INITIAL_STATE;
+        compress(&mut state, &block);
+        digest = to_digest(state);
+    }
+    digest
+}
+
+// Replaces everything after the salt with the decimal index, reusing the buffer between calls
+fn salted(buf: &mut Vec<u8>, salt_len: usize, index: u64) {
+    buf.truncate(salt_len);
+    let mut digits = [0u8; 20];
+    let mut n = index;
+    let mut i = digits.len();
+    loop {
+        i -= 1;
+        digits[i] = b'0' + (n % 10) as u8;
+        n /= 10;
+        if n == 0 { break; }
+    }
+    buf.extend_from_slice(&digits[i..]);
+}
+
+const CHUNK: u64 = 1 << 14;
+
+fn threads() -> u64 {
+    parallel::threads() as u64
+}
+
+// Calls f(index, digest) for every index in start..start+count, split across threads in contiguous
+// chunks, and returns the results in index order
+fn parallel_hashes<T, F>(salt: &[u8], rounds: usize, start: u64, count: u64, f: F) -> Vec<T>
+where T: Send, F: Fn(u64, Digest) -> Option<T> + Sync {
+    let chunk = count.div_ceil(threads()).max(1);
+    let chunk_starts: Vec<u64> = (start..start + count).step_by(chunk as usize).collect();
+    parallel::map(&chunk_starts, |&chunk_start| {
+        let mut buf = salt.to_vec();
+        (chunk_start..(chunk_start + chunk).min(start + count))
+            .filter_map(|i| {
+                salted(&mut buf, salt.len(), i);
+                f(i, stretched_md5(&buf, rounds))
+            })
+            .collect::<Vec<T>>()
+    })
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+// Every index from start whose salted hash matches, in increasing order, found a batch at a time
+// across all cores
+pub struct Search<F> {
+    salt: Vec<u8>,
+    next: u64,
+    pred: F,
+    found: VecDeque<(u64, Digest)>,
+}
+
+pub fn search<F: Fn(&Digest) -> bool + Sync>(salt: &[u8], start: u64, pred: F) -> Search<F> {
+    Search { salt: salt.to_vec(), next: start, pred, found: VecDeque::new() }
+}
+
+impl<F: Fn(&Digest) -> bool + Sync> Iterator for Search<F> {
+    type Item = (u64, Digest);
+
+    fn next(&mut self) -> Option<(u64, Digest)> {
+        while self.found.is_empty() {
+            let count = CHUNK * threads();
+            let pred = &self.pred;
+            self.found.extend(parallel_hashes(&self.salt, 0, self.next, count, |i, digest| {
+                if pred(&digest) { Some((i, digest)) } else { None }
+            }));
+            self.next += count;
+        }
+        self.found.pop_front()
+    }
+}
+
+// Stretched hashes of salt+index for every index, computed in parallel batches as they're needed
+pub struct HashCache {
+    salt: Vec<u8>,
+    rounds: usize,
+    hashes: Vec<Digest>,
+}
+
+impl HashCache {
+    pub fn new(salt: &[u8], rounds: usize) -> HashCache {
+        HashCache { salt: salt.to_vec(), rounds, hashes: Vec::new() }
+    }
+
+    pub fn get(&mut self, index: usize) -> &Digest {
+        while index >= self.hashes.len() {
+            let count = if self.rounds == 0 { CHUNK } else { CHUNK / 16 } * threads();
+            let start = self.hashes.len() as u64;
+            let batch = parallel_hashes(&self.salt, self.rounds, start, count, |_, digest| Some(digest));
+            self.hashes.extend(batch);
+        }
+        &self.hashes[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_md5() {
+        assert_eq!(to_hex_string(&md5(b"")), "d41d8cd98f00b204e9800998ecf8427e");
+        assert_eq!(to_hex_string(&md5(b"abc")), "900150983cd24fb0d6963f7d28e17f72");
+        assert_eq!(to_hex_string(&md5(b"The quick brown fox jumps over the lazy dog")),
+            "9e107d9d372bb6826bd81d3542a419d6");
+        // Lengths either side of where the padding needs a second block
+        assert_eq!(to_hex_string(&md5(&[b'a'; 55])), "ef1772b6dff9a122358552954ad0df65");
+        assert_eq!(to_hex_string(&md5(&[b'a'; 56])), "3b0c8ac703f828b04c6c197006d17218");
+        assert_eq!(to_hex_string(&md5(&[b'a'; 64])), "014842d480b571495a4a0363793f7367");
+    }
+
+    #[test]
+    fn test_nibbles() {
+        let digest = md5(b"abcdef609043");
+        assert_eq!(&to_hex(&digest)[..6], b"000001");
+        assert!(leading_zeros(&digest, 5));
+        assert!(!leading_zeros(&digest, 6));
+        assert_eq!(nibble(&digest, 5), 1);
+    }
+
+    #[test]
+    fn test_stretched_md5() {
+        assert_eq!(to_hex_string(&stretched_md5(b"abc0", 2016)), "a107ff634856bb300138cac6568c0f24");
+    }
+
+    #[test]
+    fn test_search() {
+        let mut buf = b"abc".to_vec();
+        salted(&mut buf, 3, 1234567);
+        assert_eq!(buf, b"abc1234567");
+        let found: Vec<u64> = search(b"abc", 0, |digest| leading_zeros(digest, 3)).take(4).map(|(i, _)| i).collect();
+        let expected: Vec<u64> = (0..)
+            .filter(|i| leading_zeros(&md5(format!("abc{}", i).as_bytes()), 3))
+            .take(4)
+            .collect();
+        assert_eq!(found, expected);
+    }
+
+    #[test]
+    fn test_hash_cache() {
+        let mut cache = HashCache::new(b"abc", 0);
+        assert_eq!(*cache.get(18), md5(b"abc18"));
+        assert_eq!(*cache.get(100000), md5(b"abc100000"));
+    }
+}
@@ -0,0 +1,181 @@
+use std::iter::Sum;
+use std::ops::{Range, Sub};
+
+// A set of values stored as sorted, disjoint half-open ranges, for puzzles that cover or cut up
+// huge ranges of numbers. Touching ranges are merged, so the ranges are always as few as possible.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: Copy + Ord> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet { ranges: Vec::new() }
+    }
+
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|range| range.end <= value);
+        self.ranges.get(i).is_some_and(|range| range.start <= value)
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        // Everything from the first range ending at or after the start to the last one starting at
+        // or before the end overlaps or touches, so they all become one
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+        let mut merged = range;
+        if first < last {
+            merged.start = merged.start.min(self.ranges[first].start);
+            merged.end = merged.end.max(self.ranges[last - 1].end);
+        }
+        self.ranges.splice(first..last, std::iter::once(merged));
+    }
+
+    pub fn remove(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self.ranges.partition_point(|r| r.end <= range.start);
+        let last = self.ranges.partition_point(|r| r.start < range.end);
+        let mut kept = Vec::new();
+        if first < last {
+            if self.ranges[first].start < range.start {
+                kept.push(self.ranges[first].start..range.start);
+            }
+            if range.end < self.ranges[last - 1].end {
+                kept.push(range.end..self.ranges[last - 1].end);
+            }
+        }
+        self.ranges.splice(first..last, kept);
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut ret = self.clone();
+        for range in other.ranges.iter() {
+            ret.insert(range.clone());
+        }
+        ret
+    }
+
+    pub fn subtract(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut ret = self.clone();
+        for range in other.ranges.iter() {
+            ret.remove(range.clone());
+        }
+        ret
+    }
+
+    pub fn intersect(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+            if start < end {
+                ranges.push(start..end);
+            }
+            if a.end < b.end { i += 1; } else { j += 1; }
+        }
+        IntervalSet { ranges }
+    }
+
+    // The parts of bounds that aren't in the set, in order
+    pub fn gaps(&self, bounds: Range<T>) -> impl Iterator<Item = Range<T>> + '_ {
+        let mut start = bounds.start;
+        self.ranges.iter()
+            .map(Some)
+            .chain(std::iter::once(None))
+            .filter_map(move |range| {
+                let end = range.map_or(bounds.end, |r| r.start.min(bounds.end));
+                let gap = start..end;
+                if let Some(r) = range {
+                    start = start.max(r.end);
+                }
+                (gap.start < gap.end).then_some(gap)
+            })
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T> + Sum> IntervalSet<T> {
+    // The number of values in the set
+    pub fn len(&self) -> T {
+        self.ranges.iter().map(|range| range.end - range.start).sum()
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> IntervalSet<T> {
+        let mut ret = IntervalSet::new();
+        for range in iter {
+            ret.insert(range);
+        }
+        ret
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::single_range_in_vec_init)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[Range<i64>]) -> IntervalSet<i64> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut s = set(&[10..20, 0..5, 30..40]);
+        assert_eq!(s.ranges(), &[0..5, 10..20, 30..40]);
+        s.insert(5..10);
+        assert_eq!(s.ranges(), &[0..20, 30..40]);
+        s.insert(25..26);
+        s.insert(15..35);
+        assert_eq!(s.ranges(), &[0..40]);
+        s.insert(7..7);
+        assert_eq!(s.len(), 40);
+        assert!(s.contains(0) && s.contains(39) && !s.contains(40) && !s.contains(-1));
+        assert!(IntervalSet::<i64>::new().is_empty());
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut s = set(&[0..10, 20..30]);
+        s.remove(5..25);
+        assert_eq!(s.ranges(), &[0..5, 25..30]);
+        s.remove(1..2);
+        assert_eq!(s.ranges(), &[0..1, 2..5, 25..30]);
+        s.remove(-10..100);
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[0..10, 20..30]);
+        let b = set(&[5..25, 28..40]);
+        assert_eq!(a.union(&b), set(&[0..40]));
+        assert_eq!(a.subtract(&b), set(&[0..5, 25..28]));
+        assert_eq!(a.intersect(&b), set(&[5..10, 20..25, 28..30]));
+        assert_eq!(a.intersect(&IntervalSet::new()), IntervalSet::new());
+    }
+
+    #[test]
+    fn test_gaps() {
+        let s = set(&[0..10, 20..30, 40..50]);
+        assert_eq!(s.gaps(-5..45).collect::<Vec<_>>(), vec![-5..0, 10..20, 30..40]);
+        assert_eq!(s.gaps(22..28).count(), 0);
+        assert_eq!(s.gaps(45..60).collect::<Vec<_>>(), vec![50..60]);
+        assert_eq!(IntervalSet::new().gaps(1..3).collect::<Vec<_>>(), vec![1..3]);
+    }
+}
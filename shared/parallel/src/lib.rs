@@ -0,0 +1,77 @@
+use std::panic;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+// Spreads independent pieces of work across threads. Results always come back in the order of the
+// inputs, so answers never depend on how the work happened to be scheduled.
+
+// Overrides the number of threads, e.g. AOC_THREADS=1 to time a day running serially
+pub const ENV_VAR: &str = "AOC_THREADS";
+
+pub fn threads() -> usize {
+    static THREADS: OnceLock<usize> = OnceLock::new();
+    *THREADS.get_or_init(|| {
+        std::env::var(ENV_VAR).ok()
+            .and_then(|n| n.parse().ok())
+            .filter(|&n| n > 0)
+            .unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()))
+    })
+}
+
+// Like items.iter().map(f).collect(). Each thread takes the next unclaimed item when it finishes
+// one, so a few slow items don't leave the other threads idle.
+pub fn map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    map_with_threads(threads(), items, f)
+}
+
+fn map_with_threads<T: Sync, R: Send>(threads: usize, items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    let workers = threads.min(items.len());
+    if workers <= 1 {
+        return items.iter().map(f).collect();
+    }
+    let next = AtomicUsize::new(0);
+    let mut results: Vec<(usize, R)> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| scope.spawn(|| {
+                let mut done = Vec::new();
+                loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    if i >= items.len() { break; }
+                    done.push((i, f(&items[i])));
+                }
+                done
+            }))
+            .collect();
+        handles.into_iter()
+            .flat_map(|handle| handle.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+            .collect()
+    });
+    results.sort_unstable_by_key(|&(i, _)| i);
+    results.into_iter().map(|(_, r)| r).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map() {
+        let items: Vec<u64> = (0..1000).collect();
+        // Uneven amounts of work so threads finish out of order
+        for threads in [1, 4] {
+            let squares = map_with_threads(threads, &items, |&x| {
+                if x % 7 == 0 { thread::sleep(std::time::Duration::from_micros(100)); }
+                x * x
+            });
+            assert_eq!(squares, items.iter().map(|x| x * x).collect::<Vec<_>>());
+        }
+        assert_eq!(map(&[] as &[u64], |&x| x), Vec::<u64>::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "bad item")]
+    fn test_map_panics() {
+        map_with_threads(4, &[1, 2, 3, 4], |&x| if x == 3 { panic!("bad item") } else { x });
+    }
+}
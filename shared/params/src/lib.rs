@@ -0,0 +1,126 @@
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Display;
+use std::fs;
+use std::process;
+use std::str::FromStr;
+
+// Named puzzle parameters, like a grid size that differs between the examples and the real input.
+// They're given on the command line as name=value, or as name=value lines in a file passed with
+// --params FILE, so that an example can be run with
+//   cargo run --release width=11 height=7 < example
+// Days fetch each parameter with its default for the real input, then call finish() to catch typos.
+#[derive(Debug, Default)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+    used: RefCell<BTreeSet<String>>,
+}
+
+fn parse_assignment(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((name, value)) if !name.trim().is_empty() =>
+            Ok((name.trim().to_string(), value.trim().to_string())),
+        _ => Err(format!("expected name=value but got {:?}", s))
+    }
+}
+
+pub fn parse_file(contents: &str) -> Result<Vec<(String, String)>, String> {
+    contents.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+        .map(|(i, line)| parse_assignment(line).map_err(|e| format!("line {}: {}", i + 1, e)))
+        .collect()
+}
+
+impl Params {
+    // Values on the command line override any from a file, wherever they appear
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Params, String> {
+        let mut from_file = Vec::new();
+        let mut from_args = Vec::new();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            if arg == "--params" {
+                let path = args.next().ok_or("--params needs a file")?;
+                let contents = fs::read_to_string(&path).map_err(|e| format!("can't read {}: {}", path, e))?;
+                from_file.extend(parse_file(&contents).map_err(|e| format!("{}: {}", path, e))?);
+            } else {
+                from_args.push(parse_assignment(&arg)?);
+            }
+        }
+        Ok(Params { values: from_file.into_iter().chain(from_args).collect(), used: RefCell::default() })
+    }
+
+    // Exits with a usage message if the arguments are invalid
+    pub fn from_args() -> Params {
+        Params::parse(std::env::args().skip(1)).unwrap_or_else(|e| exit(&e))
+    }
+
+    pub fn try_get<T: FromStr>(&self, name: &str, default: T) -> Result<T, String> where T::Err: Display {
+        self.used.borrow_mut().insert(name.to_string());
+        match self.values.get(name) {
+            Some(value) => value.parse().map_err(|e| format!("invalid {} {:?}: {}", name, value, e)),
+            None => Ok(default)
+        }
+    }
+
+    pub fn get<T: FromStr>(&self, name: &str, default: T) -> T where T::Err: Display {
+        self.try_get(name, default).unwrap_or_else(|e| exit(&e))
+    }
+
+    pub fn unused(&self) -> Vec<String> {
+        let used = self.used.borrow();
+        self.values.keys().filter(|name| !used.contains(*name)).cloned().collect()
+    }
+
+    // Exits if any parameter was given that the day doesn't know about
+    pub fn finish(&self) {
+        let unused = self.unused();
+        if !unused.is_empty() {
+            let known: Vec<String> = self.used.borrow().iter().cloned().collect();
+            exit(&format!("unknown parameters {:?}, expected some of {:?}", unused, known));
+        }
+    }
+}
+
+fn exit(message: &str) -> ! {
+    eprintln!("error: {}", message);
+    eprintln!("Usage: [--params FILE] [name=value]... < input");
+    process::exit(2);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_get() {
+        let params = Params::parse(args("width=11 height=7 name=ex")).unwrap();
+        assert_eq!(params.get("width", 101), 11);
+        assert_eq!(params.get("depth", 5), 5);
+        assert_eq!(params.unused(), vec!["height", "name"]);
+        assert_eq!(params.get("name", String::new()), "ex");
+        assert!(params.try_get("height", 0u8).is_ok());
+        assert!(params.try_get("name", 0).is_err());
+        assert!(params.unused().is_empty());
+        assert!(Params::parse(args("width")).is_err());
+        assert!(Params::parse(args("=3")).is_err());
+    }
+
+    #[test]
+    fn test_file() {
+        assert_eq!(parse_file("# example 1\nwidth = 11\n\nheight=7\n"),
+            Ok(vec![("width".to_string(), "11".to_string()), ("height".to_string(), "7".to_string())]));
+        assert_eq!(parse_file("width 11"), Err("line 1: expected name=value but got \"width 11\"".to_string()));
+
+        let path = std::env::temp_dir().join(format!("params-test-{}", process::id()));
+        fs::write(&path, "width=11\nheight=7\n").unwrap();
+        let params = Params::parse(vec!["height=8".to_string(), "--params".to_string(), path.to_string_lossy().to_string()]).unwrap();
+        assert_eq!(params.get("width", 101), 11);
+        assert_eq!(params.get("height", 103), 8);
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,316 @@
+use std::fmt::{self, Display};
+use std::process;
+use std::str::FromStr;
+
+// Helpers for parsing puzzle inputs into Results instead of panicking, where every error points at
+// the line and column of the input that caused it. Columns count characters, both are from 1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let gutter = self.line.to_string().len();
+        writeln!(f, "line {}, column {}: {}", self.line, self.column, self.message)?;
+        writeln!(f, "{} | {}", self.line, self.text)?;
+        write!(f, "{:gutter$} | {:>column$}", "", "^", gutter = gutter, column = self.column)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+// Part of a line, remembering where it came from for error messages
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Field<'a> {
+    pub line: Line<'a>,
+    pub column: usize,
+    pub text: &'a str,
+}
+
+// Splits the input into lines, tolerating Windows line endings and ignoring blank lines at the end
+pub fn lines(input: &str) -> Vec<Line<'_>> {
+    let mut ret: Vec<Line> = input.split('\n')
+        .enumerate()
+        .map(|(i, text)| Line { number: i + 1, text: text.strip_suffix('\r').unwrap_or(text) })
+        .collect();
+    while ret.last().is_some_and(|line| line.is_blank()) {
+        ret.pop();
+    }
+    ret
+}
+
+// Groups of lines separated by blank lines
+pub fn sections(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut ret = vec![Vec::new()];
+    for line in lines(input) {
+        if line.is_blank() {
+            if !ret.last().unwrap().is_empty() {
+                ret.push(Vec::new());
+            }
+        } else {
+            ret.last_mut().unwrap().push(line);
+        }
+    }
+    ret.retain(|section| !section.is_empty());
+    ret
+}
+
+pub fn parse_lines<T, F>(input: &str, f: F) -> Result<Vec<T>, ParseError>
+where F: FnMut(Line) -> Result<T, ParseError> {
+    lines(input).into_iter().map(f).collect()
+}
+
+// For inputs that are all on one line, like a hash key or a list of numbers
+pub fn single_line(input: &str) -> Result<Line<'_>, ParseError> {
+    match lines(input).as_slice() {
+        [line] => Ok(*line),
+        [] => Err(Line { number: 1, text: "" }.error(1, "expected a line of input")),
+        [_, extra, ..] => Err(extra.error(1, "expected the input to be a single line")),
+    }
+}
+
+// A rectangular grid with a row per line, converting each character with f, which gives None for
+// characters that don't belong in the grid
+pub fn grid<T, F>(lines: &[Line], mut f: F) -> Result<Vec<Vec<T>>, ParseError>
+where F: FnMut(char) -> Option<T> {
+    let mut ret: Vec<Vec<T>> = Vec::new();
+    for line in lines {
+        let row = line.map_chars(&mut f)?;
+        if let Some(first) = ret.first() && row.len() != first.len() {
+            return Err(line.error(row.len().min(first.len()) + 1,
+                                  format!("expected {} columns like line {}", first.len(), lines[0].number)));
+        }
+        ret.push(row);
+    }
+    Ok(ret)
+}
+
+fn column_of(text: &str, byte_offset: usize) -> usize {
+    text[..byte_offset].chars().count() + 1
+}
+
+// Maximal runs of digits, with a leading minus sign unless it follows a letter or digit, so
+// "p=0,4 v=3,-3" gives 0, 4, 3 and -3 but "2-4" gives 2 and 4
+fn number_spans(text: &str) -> Vec<(usize, usize)> {
+    let bytes = text.as_bytes();
+    let mut spans = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let negative = bytes[i] == b'-'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+            && (i == 0 || !bytes[i - 1].is_ascii_alphanumeric());
+        if negative || bytes[i].is_ascii_digit() {
+            let start = i;
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            spans.push((start, i));
+        } else {
+            i += 1;
+        }
+    }
+    spans
+}
+
+impl<'a> Line<'a> {
+    pub fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+
+    pub fn error(&self, column: usize, message: impl Into<String>) -> ParseError {
+        ParseError { line: self.number, column, text: self.text.to_string(), message: message.into() }
+    }
+
+    pub fn as_field(&self) -> Field<'a> {
+        Field { line: *self, column: 1, text: self.text }
+    }
+
+    // The field for a slice of this line's text, like a regex capture, so errors point at it
+    pub fn field<'b>(&self, part: &'b str) -> Field<'b> where 'a: 'b {
+        let offset = (part.as_ptr() as usize).checked_sub(self.text.as_ptr() as usize)
+            .filter(|offset| offset + part.len() <= self.text.len())
+            .expect("field isn't part of the line");
+        Field { line: *self, column: column_of(self.text, offset), text: part }
+    }
+
+    // Whitespace separated fields
+    pub fn fields(&self) -> Vec<Field<'a>> {
+        self.as_field().fields()
+    }
+
+    pub fn split(&self, sep: &str) -> Vec<Field<'a>> {
+        self.as_field().split(sep)
+    }
+
+    // Every number in the line, ignoring anything else
+    pub fn numbers<T: FromStr>(&self) -> Result<Vec<T>, ParseError> where T::Err: Display {
+        self.as_field().numbers()
+    }
+
+    // Exactly N numbers, like the three in "Sensor at x=2, y=18: closest beacon is at x=-2, y=15"
+    pub fn numbers_n<T: FromStr, const N: usize>(&self) -> Result<[T; N], ParseError> where T::Err: Display {
+        let numbers = self.numbers()?;
+        let found = numbers.len();
+        numbers.try_into().map_err(|_| self.error(1, format!("expected {} numbers but found {}", N, found)))
+    }
+
+    pub fn parse<T: FromStr>(&self) -> Result<T, ParseError> where T::Err: Display {
+        self.as_field().trim().parse()
+    }
+
+    pub fn map_chars<T, F>(&self, f: F) -> Result<Vec<T>, ParseError> where F: FnMut(char) -> Option<T> {
+        self.as_field().map_chars(f)
+    }
+}
+
+impl<'a> Field<'a> {
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        self.line.error(self.column, message)
+    }
+
+    pub fn trim(&self) -> Field<'a> {
+        let start = self.text.len() - self.text.trim_start().len();
+        Field {
+            line: self.line,
+            column: self.column + self.text[..start].chars().count(),
+            text: self.text.trim(),
+        }
+    }
+
+    pub fn parse<T: FromStr>(&self) -> Result<T, ParseError> where T::Err: Display {
+        self.text.parse().map_err(|e| self.error(format!("invalid value {:?}: {}", self.text, e)))
+    }
+
+    pub fn fields(&self) -> Vec<Field<'a>> {
+        self.text.split_whitespace().map(|text| self.line.field(text)).collect()
+    }
+
+    pub fn split(&self, sep: &str) -> Vec<Field<'a>> {
+        self.text.split(sep).map(|text| self.line.field(text)).collect()
+    }
+
+    pub fn numbers<T: FromStr>(&self) -> Result<Vec<T>, ParseError> where T::Err: Display {
+        number_spans(self.text).into_iter()
+            .map(|(start, end)| self.line.field(&self.text[start..end]).parse())
+            .collect()
+    }
+
+    // Converts every character, with an error at the first one that f gives None for
+    pub fn map_chars<T, F>(&self, mut f: F) -> Result<Vec<T>, ParseError> where F: FnMut(char) -> Option<T> {
+        self.text.chars().enumerate()
+            .map(|(i, ch)| f(ch).ok_or_else(|| self.line.error(self.column + i, format!("unexpected {:?}", ch))))
+            .collect()
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Field<'a>, ParseError> {
+        match self.text.strip_prefix(prefix) {
+            Some(rest) => Ok(self.line.field(rest)),
+            None => Err(self.error(format!("expected {:?}", prefix))),
+        }
+    }
+
+    pub fn split_once(&self, sep: &str) -> Result<(Field<'a>, Field<'a>), ParseError> {
+        match self.text.split_once(sep) {
+            Some((a, b)) => Ok((
+                Field { text: a, ..*self },
+                Field { line: self.line, column: self.column + column_of(self.text, a.len() + sep.len()) - 1, text: b },
+            )),
+            None => Err(self.error(format!("expected {:?} in {:?}", sep, self.text)))
+        }
+    }
+}
+
+// For the end of main, reporting bad input without a panic's backtrace
+pub fn or_exit<T>(result: Result<T, ParseError>) -> T {
+    result.unwrap_or_else(|e| {
+        eprintln!("error: couldn't parse the input at {}", e);
+        process::exit(1);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines() {
+        let lines = lines("a b\r\n\r\nc\n\n");
+        assert_eq!(lines.iter().map(|line| line.text).collect::<Vec<_>>(), vec!["a b", "", "c"]);
+        assert_eq!(lines[2].number, 3);
+        let sections = sections("\n1\n2\n\n\n3\n");
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[1], vec![Line { number: 6, text: "3" }]);
+    }
+
+    #[test]
+    fn test_fields() {
+        let line = Line { number: 1, text: "  x  12 é7" };
+        let fields = line.fields();
+        assert_eq!(fields.iter().map(|f| (f.column, f.text)).collect::<Vec<_>>(), vec![(3, "x"), (6, "12"), (9, "é7")]);
+        assert_eq!(fields[1].parse::<u8>(), Ok(12));
+        let err = fields[2].parse::<u8>().unwrap_err();
+        assert_eq!(err.column, 9);
+        let split = Line { number: 1, text: "ab, cd" }.split(",");
+        assert_eq!(split[1].trim().column, 5);
+        let (key, value) = Line { number: 1, text: "hgt:183cm" }.as_field().split_once(":").unwrap();
+        assert_eq!((key.text, value.text, value.column), ("hgt", "183cm", 5));
+        assert!(key.split_once(":").is_err());
+    }
+
+    #[test]
+    fn test_numbers() {
+        let line = Line { number: 1, text: "p=0,4 v=3,-3 2-4 x-1" };
+        assert_eq!(line.numbers::<i32>(), Ok(vec![0, 4, 3, -3, 2, 4, 1]));
+        assert_eq!(Line { number: 1, text: "1x2x3" }.numbers_n::<u64, 3>(), Ok([1, 2, 3]));
+        assert!(Line { number: 1, text: "1x2" }.numbers_n::<u64, 3>().is_err());
+        assert_eq!(Line { number: 1, text: "300" }.numbers::<u8>().unwrap_err().column, 1);
+    }
+
+    #[test]
+    fn test_field_of_capture() {
+        let line = Line { number: 3, text: "Game 12: 3 blue" };
+        let caps = line.text.split_once(": ").unwrap();
+        assert_eq!(line.field(caps.1).column, 10);
+        let game = line.as_field().strip_prefix("Game ").unwrap();
+        assert_eq!((game.column, game.split(":")[0].parse::<u32>()), (6, Ok(12)));
+        assert_eq!(line.as_field().strip_prefix("Round ").unwrap_err().column, 1);
+        assert_eq!(game.fields()[1].numbers::<u8>(), Ok(vec![3]));
+    }
+
+    #[test]
+    fn test_single_line() {
+        assert_eq!(single_line("abc\n").unwrap().text, "abc");
+        assert_eq!(single_line("\n").unwrap_err().line, 1);
+        assert_eq!(single_line("abc\ndef\n").unwrap_err().line, 2);
+    }
+
+    #[test]
+    fn test_grid() {
+        let wall = |ch| match ch { '#' => Some(true), '.' => Some(false), _ => None };
+        assert_eq!(grid(&lines("#.\n.#\n"), wall), Ok(vec![vec![true, false], vec![false, true]]));
+        let err = grid(&lines("#.\n.x\n"), wall).unwrap_err();
+        assert_eq!((err.line, err.column, err.message.as_str()), (2, 2, "unexpected 'x'"));
+        let err = grid(&lines("#.\n.\n"), wall).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+
+    #[test]
+    fn test_display() {
+        let err = Line { number: 12, text: "1x2xa" }.error(5, "expected a number");
+        assert_eq!(err.to_string(), "\
+line 12, column 5: expected a number
+12 | 1x2xa
+   |     ^");
+    }
+}
@@ -0,0 +1,376 @@
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+
+// Anything operands can be read from. Dialects with a small fixed set of registers can implement
+// this on a plain struct to avoid the map lookups in Registers.
+pub trait RegisterFile<R> {
+    type Value: Copy;
+    fn read(&self, reg: &R) -> Self::Value;
+}
+
+// A register file where registers that were never written read as zero (V::default()), which is
+// how every year's assembly dialect treats its registers.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Registers<R: Ord, V>(BTreeMap<R, V>);
+
+impl<R: Ord, V: Copy + Default> Registers<R, V> {
+    pub fn new() -> Self {
+        Registers(BTreeMap::new())
+    }
+
+    pub fn get<Q: Ord + ?Sized>(&self, reg: &Q) -> V where R: Borrow<Q> {
+        self.0.get(reg).copied().unwrap_or_default()
+    }
+
+    pub fn get_mut(&mut self, reg: R) -> &mut V {
+        self.0.entry(reg).or_default()
+    }
+
+    pub fn set(&mut self, reg: R, val: V) {
+        self.0.insert(reg, val);
+    }
+
+    // Only the registers that have been written
+    pub fn iter(&self) -> impl Iterator<Item = (&R, V)> {
+        self.0.iter().map(|(reg, val)| (reg, *val))
+    }
+}
+
+impl<R: Ord, V: Copy + Default> RegisterFile<R> for Registers<R, V> {
+    type Value = V;
+    fn read(&self, reg: &R) -> V {
+        self.get(reg)
+    }
+}
+
+impl<R: Ord, V: Copy + Default> Default for Registers<R, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: Ord, V> FromIterator<(R, V)> for Registers<R, V> {
+    fn from_iter<T: IntoIterator<Item = (R, V)>>(iter: T) -> Self {
+        Registers(iter.into_iter().collect())
+    }
+}
+
+// How a dialect spells its register names, e.g. single letters or whole words
+pub trait RegisterName: Sized {
+    fn parse_name(s: &str) -> Option<Self>;
+}
+
+impl RegisterName for char {
+    fn parse_name(s: &str) -> Option<char> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(ch), None) if ch.is_ascii_alphabetic() => Some(ch),
+            _ => None
+        }
+    }
+}
+
+impl RegisterName for String {
+    fn parse_name(s: &str) -> Option<String> {
+        if !s.is_empty() && s.chars().all(|ch| ch.is_ascii_alphabetic()) {
+            Some(s.to_string())
+        } else {
+            None
+        }
+    }
+}
+
+pub fn parse_register<R: RegisterName>(s: &str) -> Result<R, String> {
+    R::parse_name(s).ok_or_else(|| format!("Invalid register: {:?}", s))
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Operand<R, V> {
+    Reg(R),
+    Imm(V)
+}
+
+impl<R, V: Copy> Operand<R, V> {
+    pub fn value<F: RegisterFile<R, Value = V>>(&self, registers: &F) -> V {
+        match self {
+            Operand::Reg(reg) => registers.read(reg),
+            Operand::Imm(val) => *val
+        }
+    }
+}
+
+impl<R: RegisterName, V: FromStr> FromStr for Operand<R, V> {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        if let Ok(val) = s.parse() {
+            Ok(Operand::Imm(val))
+        } else if let Some(reg) = R::parse_name(s) {
+            Ok(Operand::Reg(reg))
+        } else {
+            Err(format!("Invalid operand: {:?}", s))
+        }
+    }
+}
+
+impl<R: fmt::Display, V: fmt::Display> fmt::Display for Operand<R, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Operand::Reg(reg) => write!(f, "{}", reg),
+            Operand::Imm(val) => write!(f, "{}", val)
+        }
+    }
+}
+
+// What an instruction asks the machine to do with the pc after it executes
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Flow {
+    Next,
+    JumpRelative(i64),
+    JumpAbsolute(i64),
+    // Waiting on I/O, the same instruction is retried on the next step
+    Block,
+    Halt
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Status {
+    Running,
+    Blocked,
+    Halted,
+    // Only returned by run_until_loop, the pc is about to revisit an instruction
+    Looped
+}
+
+pub trait Instruction<R, Io = ()> {
+    fn execute(&self, pc: usize, registers: &mut R, io: &mut Io) -> Flow;
+}
+
+// For dialects whose instructions can rewrite the program, like 2016's tgl
+pub trait SelfModifying<R, Io = ()>: Sized {
+    fn execute_in(program: &mut [Self], pc: usize, registers: &mut R, io: &mut Io) -> Flow;
+}
+
+// The pc and register file of a running program. The program itself is passed to each step so
+// that several machines can share it and so that cloning a machine to explore alternate inputs
+// is cheap.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Machine<R> {
+    pub pc: i64,
+    pub registers: R,
+    pub steps: u64,
+    profile: Option<Vec<u64>>
+}
+
+impl<R> Machine<R> {
+    pub fn new(registers: R) -> Self {
+        Machine { pc: 0, registers, steps: 0, profile: None }
+    }
+
+    // Count how many times each instruction is executed, which is the quickest way to find the
+    // hot loop that needs optimizing
+    pub fn with_profiling(mut self, program_len: usize) -> Self {
+        self.profile = Some(vec![0; program_len]);
+        self
+    }
+
+    pub fn profile(&self) -> Option<&[u64]> {
+        self.profile.as_deref()
+    }
+
+    pub fn current<'a, I>(&self, program: &'a [I]) -> Option<&'a I> {
+        if self.pc >= 0 { program.get(self.pc as usize) } else { None }
+    }
+
+    fn advance(&mut self, flow: Flow, pc: usize) -> Status {
+        if flow == Flow::Block { return Status::Blocked }
+        self.steps += 1;
+        if let Some(profile) = self.profile.as_mut() {
+            profile[pc] += 1;
+        }
+        match flow {
+            Flow::Next => self.pc += 1,
+            Flow::JumpRelative(offset) => self.pc += offset,
+            Flow::JumpAbsolute(target) => self.pc = target,
+            Flow::Halt => return Status::Halted,
+            Flow::Block => unreachable!()
+        }
+        Status::Running
+    }
+
+    fn fetch(&self, len: usize) -> Option<usize> {
+        if self.pc >= 0 && (self.pc as usize) < len { Some(self.pc as usize) } else { None }
+    }
+
+    pub fn step<I: Instruction<R, Io>, Io>(&mut self, program: &[I], io: &mut Io) -> Status {
+        match self.fetch(program.len()) {
+            Some(pc) => {
+                let flow = program[pc].execute(pc, &mut self.registers, io);
+                self.advance(flow, pc)
+            },
+            None => Status::Halted
+        }
+    }
+
+    pub fn step_mut<I: SelfModifying<R, Io>, Io>(&mut self, program: &mut [I], io: &mut Io) -> Status {
+        match self.fetch(program.len()) {
+            Some(pc) => {
+                let flow = I::execute_in(program, pc, &mut self.registers, io);
+                self.advance(flow, pc)
+            },
+            None => Status::Halted
+        }
+    }
+
+    // Runs until the program halts or blocks on I/O
+    pub fn run<I: Instruction<R, Io>, Io>(&mut self, program: &[I], io: &mut Io) -> Status {
+        loop {
+            match self.step(program, io) {
+                Status::Running => (),
+                status => return status
+            }
+        }
+    }
+
+    pub fn run_mut<I: SelfModifying<R, Io>, Io>(&mut self, program: &mut [I], io: &mut Io) -> Status {
+        loop {
+            match self.step_mut(program, io) {
+                Status::Running => (),
+                status => return status
+            }
+        }
+    }
+
+    // Returns Running if the program is still going after max_steps
+    pub fn run_for<I: Instruction<R, Io>, Io>(&mut self, program: &[I], io: &mut Io, max_steps: u64) -> Status {
+        for _ in 0..max_steps {
+            match self.step(program, io) {
+                Status::Running => (),
+                status => return status
+            }
+        }
+        Status::Running
+    }
+
+    // Stops as soon as any instruction would be executed a second time. Only meaningful for
+    // dialects where revisiting a pc means an infinite loop, i.e. there are no conditional jumps.
+    pub fn run_until_loop<I: Instruction<R, Io>, Io>(&mut self, program: &[I], io: &mut Io) -> Status {
+        let mut seen = HashSet::new();
+        loop {
+            if !seen.insert(self.pc) { return Status::Looped }
+            match self.step(program, io) {
+                Status::Running => (),
+                status => return status
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Copy, Clone, Debug)]
+    enum Op {
+        Inc(char),
+        Jnz(Operand<char, i64>, Operand<char, i64>),
+        Out(Operand<char, i64>),
+        In(char)
+    }
+
+    // (input, output)
+    type Io = (Vec<i64>, Vec<i64>);
+
+    impl Instruction<Registers<char, i64>, Io> for Op {
+        fn execute(&self, _pc: usize, registers: &mut Registers<char, i64>, io: &mut Io) -> Flow {
+            match *self {
+                Op::Inc(x) => *registers.get_mut(x) += 1,
+                Op::Jnz(x, y) => if x.value(registers) != 0 { return Flow::JumpRelative(y.value(registers)) },
+                Op::Out(x) => io.1.push(x.value(registers)),
+                Op::In(x) => match io.0.pop() {
+                    Some(val) => registers.set(x, val),
+                    None => return Flow::Block
+                }
+            }
+            Flow::Next
+        }
+    }
+
+    // A separate dialect for self modification, where tgl turns its target into an out
+    #[derive(Copy, Clone, Debug)]
+    enum ModOp {
+        Inc(char),
+        Out(i64),
+        Tgl(Operand<char, i64>)
+    }
+
+    impl SelfModifying<Registers<char, i64>, Io> for ModOp {
+        fn execute_in(program: &mut [ModOp], pc: usize, registers: &mut Registers<char, i64>, io: &mut Io) -> Flow {
+            match program[pc] {
+                ModOp::Inc(x) => *registers.get_mut(x) += 1,
+                ModOp::Out(val) => io.1.push(val),
+                ModOp::Tgl(x) => {
+                    let target = pc as i64 + x.value(registers);
+                    program[target as usize] = ModOp::Out(7);
+                }
+            }
+            Flow::Next
+        }
+    }
+
+    #[test]
+    fn test_operand_parse() {
+        assert_eq!("a".parse::<Operand<char, i64>>(), Ok(Operand::Reg('a')));
+        assert_eq!("-12".parse::<Operand<char, i64>>(), Ok(Operand::Imm(-12)));
+        assert_eq!("+3".parse::<Operand<char, i64>>(), Ok(Operand::Imm(3)));
+        assert_eq!("abc".parse::<Operand<String, i64>>(), Ok(Operand::Reg("abc".to_string())));
+        assert!("ab".parse::<Operand<char, i64>>().is_err());
+        assert!("3x".parse::<Operand<String, i64>>().is_err());
+    }
+
+    #[test]
+    fn test_run() {
+        let program = vec![
+            Op::Inc('a'),
+            Op::Out(Operand::Reg('a')),
+            Op::Jnz(Operand::Reg('b'), Operand::Imm(2)),
+            Op::Jnz(Operand::Imm(1), Operand::Imm(-3)),
+            Op::In('c')
+        ];
+        let mut machine = Machine::new(Registers::new()).with_profiling(program.len());
+        let mut io = (Vec::new(), Vec::new());
+        assert_eq!(machine.run_for(&program, &mut io, 8), Status::Running);
+        assert_eq!(io.1, vec![1, 2]);
+        assert_eq!(machine.profile(), Some(&[2, 2, 2, 2, 0][..]));
+
+        let mut machine = Machine::new(vec![('b', 1)].into_iter().collect());
+        let mut io = (Vec::new(), Vec::new());
+        assert_eq!(machine.run(&program, &mut io), Status::Blocked);
+        assert_eq!(machine.pc, 4);
+        assert_eq!(machine.run(&program, &mut io), Status::Blocked);
+        io.0.push(5);
+        assert_eq!(machine.run(&program, &mut io), Status::Halted);
+        assert_eq!(machine.registers.get(&'c'), 5);
+        assert_eq!(machine.steps, 4);
+    }
+
+    #[test]
+    fn test_run_until_loop() {
+        let program = vec![Op::Inc('a'), Op::Jnz(Operand::Imm(1), Operand::Imm(-1))];
+        let mut machine = Machine::new(Registers::new());
+        assert_eq!(machine.run_until_loop(&program, &mut (Vec::new(), Vec::new())), Status::Looped);
+        assert_eq!(machine.registers.get(&'a'), 1);
+    }
+
+    #[test]
+    fn test_self_modifying() {
+        let mut program = vec![ModOp::Tgl(Operand::Imm(1)), ModOp::Inc('a')];
+        let mut machine = Machine::new(Registers::new());
+        let mut io = (Vec::new(), Vec::new());
+        assert_eq!(machine.run_mut(&mut program, &mut io), Status::Halted);
+        assert_eq!(io.1, vec![7]);
+        assert_eq!(machine.registers.get(&'a'), 0);
+    }
+}
@@ -0,0 +1,144 @@
+use std::fmt::Display;
+use std::io::{self, Read};
+use std::process;
+use std::time::Instant;
+
+use params::Params;
+
+// The interface every day's library exposes, so that a day can be solved from other crates as well
+// as from its own binary. A day's lib.rs holds the solution and implements this for a unit struct:
+//   pub struct Day;
+//   impl solution::Solution for Day { ... }
+// and its main.rs is just
+//   fn main() { solution::main::<day1_2024::Day>() }
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    // Day 25s only have one part. They set this to 1 and leave out part2, which is then never called.
+    const PARTS: usize = 2;
+
+    fn parse(puzzle_input: &str) -> Result<Self::Input, String>;
+    fn part1(input: &Self::Input) -> Self::Answer1;
+
+    fn part2(_input: &Self::Input) -> Self::Answer2 {
+        unreachable!("part2 is only called on days with two parts, which have to implement it")
+    }
+
+    // Days whose examples use different sizes to the real input read them here from the binary's
+    // name=value arguments, see the params crate. solve leaves the input as parsed, with the sizes
+    // for the real input.
+    fn configure(_input: &mut Self::Input, _params: &Params) {}
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: String,
+    pub part2: Option<String>,
+}
+
+pub fn solve<S: Solution>(puzzle_input: &str) -> Result<Answers, String> {
+    solve_timed::<S>(puzzle_input).map(|(answers, _)| answers)
+}
+
+// Like solve, along with how many seconds each phase took
+pub fn solve_timed<S: Solution>(puzzle_input: &str) -> Result<(Answers, Vec<(String, f64)>), String> {
+    let mut phases = Vec::new();
+    let mut time = |name: &str, start: Instant| phases.push((name.to_string(), start.elapsed().as_secs_f64()));
+    let start = Instant::now();
+    let input = S::parse(puzzle_input)?;
+    time("parse", start);
+    let start = Instant::now();
+    let part1 = S::part1(&input).to_string();
+    time("part1", start);
+    let mut part2 = None;
+    if S::PARTS > 1 {
+        let start = Instant::now();
+        part2 = Some(S::part2(&input).to_string());
+        time("part2", start);
+    }
+    Ok((Answers { part1, part2 }, phases))
+}
+
+// Solves the input on stdin, printing each answer as soon as it's known and timing each phase for
+// the runner's bench mode
+pub fn main<S: Solution>() {
+    let params = Params::from_args();
+    let mut puzzle_input = String::new();
+    io::stdin().read_to_string(&mut puzzle_input).unwrap();
+
+    let mut input = timing::phase("parse", || S::parse(&puzzle_input)).unwrap_or_else(|e| {
+        eprintln!("error: couldn't parse the input: {}", e);
+        process::exit(1);
+    });
+    S::configure(&mut input, &params);
+    params.finish();
+    println!("{}", timing::phase("part1", || S::part1(&input)));
+    if S::PARTS > 1 {
+        println!("{}", timing::phase("part2", || S::part2(&input)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u64>;
+        type Answer1 = u64;
+        type Answer2 = u64;
+
+        fn parse(puzzle_input: &str) -> Result<Vec<u64>, String> {
+            puzzle_input.lines().map(|line| line.parse().map_err(|e| format!("{:?}: {}", line, e))).collect()
+        }
+
+        fn part1(input: &Vec<u64>) -> u64 {
+            input.iter().sum()
+        }
+
+        fn part2(input: &Vec<u64>) -> u64 {
+            input.iter().product()
+        }
+
+        fn configure(input: &mut Vec<u64>, params: &Params) {
+            input.push(params.get("extra", 1));
+        }
+    }
+
+    struct OnePart;
+
+    impl Solution for OnePart {
+        type Input = ();
+        type Answer1 = &'static str;
+        type Answer2 = &'static str;
+        const PARTS: usize = 1;
+
+        fn parse(_: &str) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn part1(_: &()) -> &'static str {
+            "done"
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(solve::<Sum>("2\n3\n4\n"), Ok(Answers { part1: "9".to_string(), part2: Some("24".to_string()) }));
+        assert!(solve::<Sum>("2\nx\n").is_err());
+        assert_eq!(solve::<OnePart>(""), Ok(Answers { part1: "done".to_string(), part2: None }));
+        let (_, phases) = solve_timed::<Sum>("2\n3\n").unwrap();
+        assert_eq!(phases.iter().map(|(phase, _)| phase.as_str()).collect::<Vec<_>>(), ["parse", "part1", "part2"]);
+        assert_eq!(solve_timed::<OnePart>("").unwrap().1.len(), 2);
+    }
+
+    #[test]
+    fn test_configure() {
+        let mut input = Sum::parse("2\n3\n").unwrap();
+        Sum::configure(&mut input, &Params::parse(vec!["extra=5".to_string()]).unwrap());
+        assert_eq!(Sum::part1(&input), 10);
+    }
+}
@@ -0,0 +1,54 @@
+use std::sync::OnceLock;
+use std::time::Instant;
+
+// Set by `runner bench` to ask days to report how long each phase took
+pub const ENV_VAR: &str = "AOC_TIMING";
+
+// Prefix of the stderr lines that report a phase, followed by tab separated name and seconds
+pub const PREFIX: &str = "timing";
+
+fn enabled() -> bool {
+    static ENABLED: OnceLock<bool> = OnceLock::new();
+    *ENABLED.get_or_init(|| std::env::var_os(ENV_VAR).is_some())
+}
+
+pub fn format_phase(phase: &str, seconds: f64) -> String {
+    format!("{}\t{}\t{:.6}", PREFIX, phase, seconds)
+}
+
+pub fn parse_phase(line: &str) -> Option<(String, f64)> {
+    let mut fields = line.split('\t');
+    if fields.next()? != PREFIX {
+        return None;
+    }
+    let phase = fields.next()?.to_string();
+    let seconds = fields.next()?.parse().ok()?;
+    Some((phase, seconds))
+}
+
+// Runs f, reporting how long it took on stderr when timing is enabled. Typically used as
+//   let input = timing::phase("parse", || parse(&input));
+//   println!("{}", timing::phase("part1", || part1(&input)));
+pub fn phase<T>(name: &str, f: impl FnOnce() -> T) -> T {
+    if !enabled() {
+        return f();
+    }
+    let start = Instant::now();
+    let ret = f();
+    eprintln!("{}", format_phase(name, start.elapsed().as_secs_f64()));
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let line = format_phase("part1", 1.25);
+        assert_eq!(line, "timing\tpart1\t1.250000");
+        assert_eq!(parse_phase(&line), Some(("part1".to_string(), 1.25)));
+        assert_eq!(parse_phase("part1\t1.25"), None);
+        assert_eq!(parse_phase("timing\tpart1\tslow"), None);
+    }
+}